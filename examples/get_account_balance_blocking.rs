@@ -0,0 +1,33 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera::blocking::Client;
+use hedera::{AccountBalanceQuery, AccountId};
+
+// no `#[tokio::main]` here, the blocking client runs its own runtime.
+fn main() -> anyhow::Result<()> {
+    let client = Client::for_testnet();
+
+    let balance = client.execute(AccountBalanceQuery::new().account_id(AccountId::from(7)))?;
+
+    println!("balance = {}", balance.hbars);
+
+    Ok(())
+}
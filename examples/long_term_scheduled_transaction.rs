@@ -23,7 +23,6 @@ use hedera::{
     AccountCreateTransaction, AccountId, AccountInfoQuery, AccountUpdateTransaction, Client, Hbar, Key, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
 };
 use time::{Duration, OffsetDateTime};
-use tokio::time::sleep;
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -173,10 +172,12 @@ async fn main() -> anyhow::Result<()> {
         .hbar_transfer(alice_id, Hbar::new(-1))
         .hbar_transfer(args.operator_account_id, Hbar::new(1));
 
+    let expiration_time = OffsetDateTime::now_utc() + Duration::seconds(10);
+
     let schedule_id = transfer
         .schedule()
         .wait_for_expiry(true)
-        .expiration_time(OffsetDateTime::now_utc() + Duration::seconds(10))
+        .expiration_time(expiration_time)
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -237,7 +238,11 @@ async fn main() -> anyhow::Result<()> {
 
     println!("Alice's account balance before scheduled transfer: {account_balance}");
 
-    sleep(std::time::Duration::from_millis(10_000)).await;
+    let info = client
+        .await_schedule_execution(schedule_id, expiration_time + Duration::minutes(1))
+        .await?;
+
+    println!("Scheduled transfer executed at: {:?}", info.executed_at);
 
     let account_balance = AccountInfoQuery::new()
         .account_id(alice_id)
@@ -50,6 +50,9 @@ fn main() -> anyhow::Result<()> {
 
     let out_dir = env::var("OUT_DIR")?;
     let out_path = Path::new(&out_dir);
+
+    // the `connect` constructors need `tonic::transport`, which doesn't exist on wasm32.
+    let build_transport = env::var("CARGO_CFG_TARGET_ARCH")? != "wasm32";
     let services_tmp_path = out_path.join("services_src");
 
     // ensure we start fresh
@@ -121,6 +124,7 @@ fn main() -> anyhow::Result<()> {
     }
 
     let mut cfg = tonic_build::configure()
+        .build_transport(build_transport)
         // We have already emitted a cargo directive to trigger a rerun on the source folder
         // that the copy this builds is based on. If the directives are not suppressed, the
         // crate will rebuild on every compile due to the modified time stamps post-dating
@@ -196,6 +200,7 @@ fn main() -> anyhow::Result<()> {
 
     tonic_build::configure()
         .build_server(false)
+        .build_transport(build_transport)
         .extern_path(".proto.Timestamp", "crate::services::Timestamp")
         .extern_path(".proto.TopicID", "crate::services::TopicId")
         .extern_path(".proto.FileID", "crate::services::FileId")
@@ -226,7 +231,13 @@ fn main() -> anyhow::Result<()> {
     let cfg = builder::extern_basic_types(cfg);
 
     cfg.out_dir(&streams_out_dir).compile(
-        &["./protobufs/streams/account_balance_file.proto"],
+        &[
+            "./protobufs/streams/account_balance_file.proto",
+            "./protobufs/streams/contract_action.proto",
+            "./protobufs/streams/contract_bytecode.proto",
+            "./protobufs/streams/contract_state_change.proto",
+            "./protobufs/streams/sidecar_file.proto",
+        ],
         &["./protobufs/streams/", "./protobufs/services/"],
     )?;
 
@@ -27,6 +27,8 @@ mod time_0_3;
 #[cfg(feature = "fraction")]
 mod fraction;
 
+mod response_code;
+
 // fixme: Do this, just, don't warn 70 times in generated code.
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub mod services {
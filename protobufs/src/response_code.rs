@@ -0,0 +1,119 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::services::ResponseCodeEnum;
+
+impl ResponseCodeEnum {
+    /// Returns `true` if `self` means the request succeeded.
+    ///
+    /// This is `Ok` (pre-check), `Success` (receipts), and the success variants for specific transactions
+    /// (`FeeScheduleFilePartUploaded` and `SuccessButMissingExpectedOperation`).
+    #[must_use]
+    pub fn is_success(self) -> bool {
+        matches!(
+            self,
+            Self::Ok
+                | Self::Success
+                | Self::FeeScheduleFilePartUploaded
+                | Self::SuccessButMissingExpectedOperation
+        )
+    }
+
+    /// Returns `true` if the same request may succeed if it's sent again later (or to another node).
+    ///
+    /// This is only a classification: the SDK itself retries just `Busy` and `PlatformNotActive` pre-checks on another node,
+    /// and keeps polling receipts and records while they're `Unknown` or not found yet.
+    /// Resubmitting a transaction on any of the others risks a `DuplicateTransaction` failure.
+    ///
+    /// `TransactionExpired` isn't included, as the transaction needs a new transaction ID before it can be sent again.
+    #[must_use]
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Busy
+                | Self::PlatformNotActive
+                | Self::PlatformTransactionNotCreated
+                | Self::Unknown
+                | Self::ReceiptNotFound
+                | Self::RecordNotFound
+        )
+    }
+
+    /// Returns the numeric code of `self`, as sent over the wire.
+    #[must_use]
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns the status with the numeric `code`, or `None` if the code isn't known to this version of the SDK.
+    ///
+    /// This is the inverse of [`code`](Self::code).
+    #[must_use]
+    pub fn from_code(code: i32) -> Option<Self> {
+        Self::try_from(code).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::ResponseCodeEnum;
+
+    // comfortably more than the largest code in use.
+    const MAX_CODE: i32 = 10_000;
+
+    fn all() -> impl Iterator<Item = ResponseCodeEnum> {
+        (0..=MAX_CODE).filter_map(ResponseCodeEnum::from_code)
+    }
+
+    #[test]
+    fn code_round_trip() {
+        assert!(all().count() > 300);
+
+        for status in all() {
+            assert_eq!(ResponseCodeEnum::from_code(status.code()), Some(status));
+            assert_eq!(ResponseCodeEnum::from_str_name(status.as_str_name()), Some(status));
+        }
+    }
+
+    #[test]
+    fn from_unknown_code() {
+        assert_eq!(ResponseCodeEnum::from_code(-1), None);
+        assert_eq!(ResponseCodeEnum::from_code(i32::MAX), None);
+    }
+
+    #[test]
+    fn success_and_retryable_are_disjoint() {
+        for status in all() {
+            assert!(!(status.is_success() && status.is_retryable()), "{status:?}");
+        }
+    }
+
+    #[test]
+    fn classify() {
+        assert!(ResponseCodeEnum::Success.is_success());
+        assert!(ResponseCodeEnum::Ok.is_success());
+        assert!(!ResponseCodeEnum::InvalidSignature.is_success());
+
+        assert!(ResponseCodeEnum::Busy.is_retryable());
+        assert!(ResponseCodeEnum::ReceiptNotFound.is_retryable());
+        assert!(!ResponseCodeEnum::TransactionExpired.is_retryable());
+        assert!(!ResponseCodeEnum::InsufficientPayerBalance.is_retryable());
+    }
+}
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
@@ -32,11 +31,13 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
     Hbar,
+    LedgerId,
     NftId,
     ToProtobuf,
     TokenId,
@@ -84,6 +85,18 @@ impl AccountAllowanceApproveTransaction {
         self
     }
 
+    /// Removes the spender's hbar allowance.
+    ///
+    /// There's no separate removal of hbar allowances, approving an allowance of zero removes it.
+    #[doc(alias = "delete_all_hbar_allowances")]
+    pub fn delete_hbar_allowance(
+        &mut self,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+    ) -> &mut Self {
+        self.approve_hbar_allowance(owner_account_id, spender_account_id, Hbar::ZERO)
+    }
+
     /// Returns the hbar allowances approved by the account owner.
     pub fn hbar_approvals(&self) -> &[HbarAllowance] {
         self.data().hbar_allowances.as_ref()
@@ -106,6 +119,19 @@ impl AccountAllowanceApproveTransaction {
         self
     }
 
+    /// Removes the spender's allowance of a fungible token.
+    ///
+    /// There's no separate removal of fungible token allowances, approving an allowance of zero removes it.
+    #[doc(alias = "delete_all_token_allowances")]
+    pub fn delete_token_allowance(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+    ) -> &mut Self {
+        self.approve_token_allowance(token_id, owner_account_id, spender_account_id, 0)
+    }
+
     /// Returns the fungible token allowances approved by the account owner
     pub fn token_approvals(&self) -> &[TokenAllowance] {
         self.data().token_allowances.as_ref()
@@ -118,13 +144,40 @@ impl AccountAllowanceApproveTransaction {
         owner_account_id: AccountId,
         spender_account_id: AccountId,
     ) -> &mut Self {
-        let nft_id = nft_id.into();
+        self.push_nft_serial(nft_id.into(), owner_account_id, spender_account_id, None)
+    }
+
+    /// Approve the NFT allowance on behalf of `delegating_spender_account_id`,
+    /// which must have been approved for all of the owner's serials of the token.
+    pub fn approve_token_nft_allowance_with_delegating_spender(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+        delegating_spender_account_id: AccountId,
+    ) -> &mut Self {
+        self.push_nft_serial(
+            nft_id.into(),
+            owner_account_id,
+            spender_account_id,
+            Some(delegating_spender_account_id),
+        )
+    }
+
+    fn push_nft_serial(
+        &mut self,
+        nft_id: NftId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+        delegating_spender_account_id: Option<AccountId>,
+    ) -> &mut Self {
         let data = self.data_mut();
 
         if let Some(allowance) = data.nft_allowances.iter_mut().find(|allowance| {
             allowance.token_id == nft_id.token_id
                 && allowance.owner_account_id == owner_account_id
                 && allowance.spender_account_id == spender_account_id
+                && allowance.delegating_spender_account_id == delegating_spender_account_id
                 && allowance.approved_for_all.is_none()
         }) {
             allowance.serials.push(nft_id.serial as i64);
@@ -134,7 +187,7 @@ impl AccountAllowanceApproveTransaction {
                 token_id: nft_id.token_id,
                 spender_account_id,
                 owner_account_id,
-                delegating_spender_account_id: None,
+                delegating_spender_account_id,
                 approved_for_all: None,
             });
         };
@@ -161,10 +214,44 @@ impl AccountAllowanceApproveTransaction {
         self
     }
 
+    /// Removes the spender's allowance on all serial numbers of the token.
+    ///
+    /// Allowances on specific serial numbers are removed with
+    /// [`AccountAllowanceDeleteTransaction`](crate::AccountAllowanceDeleteTransaction) instead.
+    #[doc(alias = "delete_nft_allowance_all_serials")]
+    pub fn delete_token_nft_allowance_all_serials(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+    ) -> &mut Self {
+        self.data_mut().nft_allowances.push(NftAllowance {
+            approved_for_all: Some(false),
+            delegating_spender_account_id: None,
+            spender_account_id,
+            owner_account_id,
+            token_id,
+            serials: Vec::new(),
+        });
+
+        self
+    }
+
     /// Returns the non-fungible token allowances approved by the account owner.
     pub fn token_nft_approvals(&self) -> &[NftAllowance] {
         self.data().nft_allowances.as_ref()
     }
+
+    /// Returns the non-fungible token allowances on specific serial numbers.
+    pub fn token_nft_serial_approvals(&self) -> impl Iterator<Item = &NftAllowance> {
+        self.data().nft_allowances.iter().filter(|it| it.approved_for_all.is_none())
+    }
+
+    /// Returns the non-fungible token allowances on all serial numbers,
+    /// including ones that remove such an allowance (`approved_for_all` is `Some(false)`).
+    pub fn token_nft_all_serials_approvals(&self) -> impl Iterator<Item = &NftAllowance> {
+        self.data().nft_allowances.iter().filter(|it| it.approved_for_all.is_some())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -220,7 +307,33 @@ pub struct NftAllowance {
     pub delegating_spender_account_id: Option<AccountId>,
 }
 
-impl TransactionData for AccountAllowanceApproveTransactionData {}
+impl TransactionData for AccountAllowanceApproveTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        let is_mixed = |allowance: &NftAllowance| {
+            if allowance.approved_for_all.is_some() {
+                return !allowance.serials.is_empty()
+                    || allowance.delegating_spender_account_id.is_some();
+            }
+
+            // approving all serials for a spender *and* specific serials for the same spender is contradictory.
+            self.nft_allowances.iter().any(|other| {
+                other.approved_for_all.is_some()
+                    && other.token_id == allowance.token_id
+                    && other.owner_account_id == allowance.owner_account_id
+                    && other.spender_account_id == allowance.spender_account_id
+            })
+        };
+
+        match self.nft_allowances.iter().find(|it| is_mixed(it)) {
+            Some(allowance) => Err(Error::MixedNftAllowance {
+                token_id: Box::new(allowance.token_id),
+                owner_account_id: Box::new(allowance.owner_account_id),
+                spender_account_id: Box::new(allowance.spender_account_id),
+            }),
+            None => Ok(()),
+        }
+    }
+}
 
 impl TransactionExecute for AccountAllowanceApproveTransactionData {
     fn execute(
@@ -389,6 +502,7 @@ impl ToProtobuf for NftAllowance {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
 
     use crate::transaction::test_helpers::{
@@ -399,6 +513,7 @@ mod tests {
         AccountAllowanceApproveTransaction,
         AccountId,
         AnyTransaction,
+        Error,
         Hbar,
         TokenId,
     };
@@ -701,5 +816,104 @@ mod tests {
         assert!(!tx.hbar_approvals().is_empty());
         assert!(!tx.token_approvals().is_empty());
         assert!(!tx.token_approvals().is_empty());
+        assert_eq!(tx.token_nft_serial_approvals().count(), 3);
+        assert_eq!(tx.token_nft_all_serials_approvals().count(), 1);
+    }
+
+    #[test]
+    fn delegating_spender() {
+        let owner_id = AccountId::new(0, 0, 5);
+        let spender_id = AccountId::new(0, 0, 6);
+        let delegating_spender_id = AccountId::new(0, 0, 7);
+        let token_id = TokenId::new(0, 0, 8);
+
+        let mut tx = AccountAllowanceApproveTransaction::new();
+
+        tx.approve_token_nft_allowance(token_id.nft(1), owner_id, spender_id)
+            .approve_token_nft_allowance_with_delegating_spender(
+                token_id.nft(2),
+                owner_id,
+                spender_id,
+                delegating_spender_id,
+            )
+            .approve_token_nft_allowance_with_delegating_spender(
+                token_id.nft(3),
+                owner_id,
+                spender_id,
+                delegating_spender_id,
+            );
+
+        // serials are only grouped with the same delegating spender.
+        let approvals = tx.token_nft_approvals();
+        assert_eq!(approvals.len(), 2);
+        assert_eq!(approvals[0].serials, [1]);
+        assert_eq!(approvals[0].delegating_spender_account_id, None);
+        assert_eq!(approvals[1].serials, [2, 3]);
+        assert_eq!(approvals[1].delegating_spender_account_id, Some(delegating_spender_id));
+    }
+
+    #[test]
+    fn delete_all_serials() {
+        let mut tx = AccountAllowanceApproveTransaction::new_for_tests();
+
+        tx.delete_token_nft_allowance_all_serials(
+            TokenId::new(0, 0, 8),
+            AccountId::new(0, 0, 5),
+            AccountId::new(0, 0, 6),
+        )
+        .freeze()
+        .unwrap();
+
+        let approvals: Vec<_> = tx.token_nft_all_serials_approvals().collect();
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].approved_for_all, Some(false));
+        assert!(approvals[0].serials.is_empty());
+    }
+
+    #[test]
+    fn delete_hbar_and_token_allowances() {
+        let owner_id = AccountId::new(0, 0, 5);
+        let spender_id = AccountId::new(0, 0, 6);
+        let token_id = TokenId::new(0, 0, 8);
+
+        let mut tx = AccountAllowanceApproveTransaction::new_for_tests();
+
+        tx.delete_hbar_allowance(owner_id, spender_id)
+            .delete_token_allowance(token_id, owner_id, spender_id)
+            .freeze()
+            .unwrap();
+
+        let hbar_approvals = tx.hbar_approvals();
+        assert_eq!(hbar_approvals.len(), 1);
+        assert_eq!(hbar_approvals[0].spender_account_id, spender_id);
+        assert_eq!(hbar_approvals[0].amount, Hbar::ZERO);
+
+        let token_approvals = tx.token_approvals();
+        assert_eq!(token_approvals.len(), 1);
+        assert_eq!(token_approvals[0].token_id, token_id);
+        assert_eq!(token_approvals[0].amount, 0);
+    }
+
+    #[test]
+    fn mixed_serials_and_all_serials_fails() {
+        let owner_id = AccountId::new(0, 0, 5);
+        let spender_id = AccountId::new(0, 0, 6);
+        let token_id = TokenId::new(0, 0, 8);
+
+        let mut tx = AccountAllowanceApproveTransaction::new_for_tests();
+
+        tx.approve_token_nft_allowance(token_id.nft(1), owner_id, spender_id)
+            .approve_token_nft_allowance_all_serials(token_id, owner_id, spender_id);
+
+        let res = tx.freeze();
+        assert_matches!(res, Err(Error::MixedNftAllowance { token_id: it, .. }) if *it == token_id);
+
+        // a different spender is fine though.
+        let mut tx = AccountAllowanceApproveTransaction::new_for_tests();
+
+        tx.approve_token_nft_allowance(token_id.nft(1), owner_id, spender_id)
+            .approve_token_nft_allowance_all_serials(token_id, owner_id, AccountId::new(0, 0, 7))
+            .freeze()
+            .unwrap();
     }
 }
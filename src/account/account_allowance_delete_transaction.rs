@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -35,6 +34,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -48,8 +48,9 @@ use crate::{
 /// Deletes one or more non-fungible approved allowances from an owner's account. This operation
 /// will remove the allowances granted to one or more specific non-fungible token serial numbers. Each owner account
 /// listed as wiping an allowance must sign the transaction. Hbar and fungible token allowances
-/// can be removed by setting the amount to zero in
-/// [`AccountAllowanceApproveTransaction`](crate::AccountAllowanceApproveTransaction).
+/// are removed by approving an amount of zero instead, with
+/// [`AccountAllowanceApproveTransaction::delete_hbar_allowance`](crate::AccountAllowanceApproveTransaction::delete_hbar_allowance)
+/// and [`AccountAllowanceApproveTransaction::delete_token_allowance`](crate::AccountAllowanceApproveTransaction::delete_token_allowance).
 pub type AccountAllowanceDeleteTransaction = Transaction<AccountAllowanceDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
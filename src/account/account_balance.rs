@@ -22,8 +22,17 @@ use std::collections::HashMap;
 
 use hedera_proto::services;
 use prost::Message;
+#[cfg(feature = "mirror-rest")]
+use serde_json::Value;
 
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest::{
+    get_i64,
+    get_str,
+};
 use crate::protobuf::ToProtobuf;
+#[cfg(feature = "mirror-rest")]
+use crate::Error;
 use crate::{
     AccountId,
     FromProtobuf,
@@ -42,12 +51,15 @@ pub struct AccountBalance {
     pub hbars: Hbar,
 
     /// Token balances for the referenced account.
-    // #[deprecated = "use a mirror query"]
+    ///
+    /// Consensus nodes no longer return these,
+    /// use `AccountBalanceQuery::execute_with_token_balances` (requires the `mirror-rest` feature)
+    /// to fill them in from the mirror node.
     pub tokens: HashMap<TokenId, u64>,
 
     /// Token decimals for the referenced account.
-    #[deprecated = "use a mirror query"]
-    #[allow(deprecated)]
+    ///
+    /// Filled in alongside [`tokens`](Self::tokens).
     pub token_decimals: HashMap<TokenId, u32>,
 }
 
@@ -64,15 +76,46 @@ impl AccountBalance {
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
+        let token_balances = self
+            .tokens
+            .iter()
+            .map(|(token_id, balance)| services::TokenBalance {
+                token_id: Some(token_id.to_protobuf()),
+                balance: *balance,
+                decimals: self.token_decimals.get(token_id).copied().unwrap_or_default(),
+            })
+            .collect();
+
         #[allow(deprecated)]
         services::CryptoGetAccountBalanceResponse {
             header: None,
             account_id: Some(self.account_id.to_protobuf()),
             balance: self.hbars.to_tinybars() as u64,
-            token_balances: Vec::default(),
+            token_balances,
         }
         .encode_to_vec()
     }
+
+    /// Replaces the token balances with the `tokens` of a mirror node `/api/v1/accounts/{id}/tokens` response.
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn set_mirror_tokens(&mut self, tokens: &[Value]) -> crate::Result<()> {
+        self.tokens.clear();
+        self.token_decimals.clear();
+
+        for token in tokens {
+            let token_id: TokenId = get_str(token, "token_id")?.parse()?;
+            let balance = u64::try_from(get_i64(token, "balance")?).map_err(Error::mirror_rest)?;
+
+            self.tokens.insert(token_id, balance);
+
+            if let Some(decimals) = token.get("decimals").and_then(Value::as_u64) {
+                let decimals = u32::try_from(decimals).map_err(Error::mirror_rest)?;
+                self.token_decimals.insert(token_id, decimals);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl FromProtobuf<services::CryptoGetAccountBalanceResponse> for AccountBalance {
@@ -107,3 +150,59 @@ impl FromProtobuf<services::response::Response> for AccountBalance {
         Self::from_protobuf(response)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[cfg(feature = "mirror-rest")]
+    use serde_json::json;
+
+    use crate::{
+        AccountBalance,
+        AccountId,
+        Hbar,
+        TokenId,
+    };
+
+    fn make_balance() -> AccountBalance {
+        AccountBalance {
+            account_id: AccountId::new(0, 0, 1001),
+            hbars: Hbar::new(10),
+            tokens: HashMap::new(),
+            token_decimals: HashMap::new(),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "mirror-rest")]
+    fn set_mirror_tokens() {
+        let mut balance = make_balance();
+
+        let tokens = json!([
+            { "token_id": "0.0.2001", "balance": 150, "decimals": 2 },
+            { "token_id": "0.0.2002", "balance": 3, "decimals": 0 },
+        ]);
+
+        balance.set_mirror_tokens(tokens.as_array().unwrap()).unwrap();
+
+        assert_eq!(balance.tokens[&TokenId::new(0, 0, 2001)], 150);
+        assert_eq!(balance.tokens[&TokenId::new(0, 0, 2002)], 3);
+        assert_eq!(balance.token_decimals[&TokenId::new(0, 0, 2001)], 2);
+        assert_eq!(balance.token_decimals[&TokenId::new(0, 0, 2002)], 0);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let mut balance = make_balance();
+        balance.tokens.insert(TokenId::new(0, 0, 2001), 150);
+        balance.token_decimals.insert(TokenId::new(0, 0, 2001), 2);
+
+        let balance2 = AccountBalance::from_bytes(&balance.to_bytes()).unwrap();
+
+        assert_eq!(balance2.account_id, balance.account_id);
+        assert_eq!(balance2.hbars, balance.hbars);
+        assert_eq!(balance2.tokens, balance.tokens);
+        assert_eq!(balance2.token_decimals, balance.token_decimals);
+    }
+}
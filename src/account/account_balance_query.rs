@@ -21,21 +21,26 @@
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use services::crypto_get_account_balance_query::BalanceSource;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest;
 use crate::query::{
     AnyQueryData,
     Query,
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
+#[cfg(feature = "mirror-rest")]
+use crate::Client;
 use crate::{
     AccountBalance,
     AccountId,
     BoxGrpcFuture,
     ContractId,
     Error,
+    FromProtobuf,
     ToProtobuf,
     ValidateChecksums,
 };
@@ -105,6 +110,33 @@ impl AccountBalanceQuery {
         self.data.source = AccountBalanceSource::ContractId(id);
         self
     }
+
+    /// Execute this query, then fill in the token balances from the mirror node if the network didn't return any.
+    ///
+    /// Consensus nodes no longer return token balances,
+    /// this gets them (and their decimals) from the mirror node REST API of `client` instead.
+    ///
+    /// Requires the `mirror-rest` feature.
+    ///
+    /// # Errors
+    /// - Any error [`execute`](Self::execute) can return.
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails or its response couldn't be understood.
+    #[cfg(feature = "mirror-rest")]
+    pub async fn execute_with_token_balances(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<AccountBalance> {
+        let mut balance = self.execute(client).await?;
+
+        if balance.tokens.is_empty() {
+            let path = format!("/api/v1/accounts/{}/tokens?limit=100", balance.account_id);
+            let tokens = mirror_rest::get_all(client, &path, "tokens", None, |_| true).await?;
+
+            balance.set_mirror_tokens(&tokens)?;
+        }
+
+        Ok(balance)
+    }
 }
 
 impl ToQueryProtobuf for AccountBalanceQueryData {
@@ -125,6 +157,21 @@ impl ToQueryProtobuf for AccountBalanceQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetAccountBalanceQuery> for AccountBalanceQueryData {
+    fn from_protobuf(pb: services::CryptoGetAccountBalanceQuery) -> crate::Result<Self> {
+        let source = match pb_getf!(pb, balance_source)? {
+            BalanceSource::AccountId(id) => {
+                AccountBalanceSource::AccountId(AccountId::from_protobuf(id)?)
+            }
+            BalanceSource::ContractId(id) => {
+                AccountBalanceSource::ContractId(ContractId::from_protobuf(id)?)
+            }
+        };
+
+        Ok(Self { source })
+    }
+}
+
 impl QueryExecute for AccountBalanceQueryData {
     type Response = AccountBalance;
 
@@ -21,7 +21,6 @@
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use time::Duration;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -37,6 +36,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -44,6 +44,8 @@ use crate::{
     EvmAddress,
     Hbar,
     Key,
+    LedgerId,
+    PublicKey,
     Transaction,
     ValidateChecksums,
 };
@@ -89,6 +91,11 @@ pub struct AccountCreateTransactionData {
     /// A 20-byte EVM address to be used as the account's alias.
     alias: Option<EvmAddress>,
 
+    /// The key `alias` was derived from, if it was set with `key_with_alias`.
+    ///
+    /// Kept so that a non-ECDSA key can be reported when the transaction is frozen.
+    alias_key: Option<PublicKey>,
+
     /// ID of the account or node to which this account is staking, if any.
     staked_id: Option<StakedId>,
 
@@ -107,6 +114,7 @@ impl Default for AccountCreateTransactionData {
             account_memo: String::new(),
             max_automatic_token_associations: 0,
             alias: None,
+            alias_key: None,
             staked_id: None,
             decline_staking_reward: false,
         }
@@ -207,6 +215,8 @@ impl AccountCreateTransaction {
     }
 
     /// Sets the maximum number of tokens that an Account can be implicitly associated with.
+    ///
+    /// `-1` means unlimited, other negative values are rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, amount: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = amount;
         self
@@ -228,10 +238,39 @@ impl AccountCreateTransaction {
     /// # Network Support
     /// Please note that this not currently supported on mainnet.
     pub fn alias(&mut self, alias: EvmAddress) -> &mut Self {
-        self.data_mut().alias = Some(alias);
+        let data = self.data_mut();
+        data.alias = Some(alias);
+        data.alias_key = None;
+        self
+    }
+
+    /// Sets the key for this account, and the alias to the evm address of `ecdsa_key`.
+    ///
+    /// As per [HIP-583](https://hips.hedera.com/hip/hip-583), the transaction must also be signed by `ecdsa_key`'s private key.
+    ///
+    /// `ecdsa_key` must be an `ECDSA_SECP256K1` key,
+    /// otherwise freezing the transaction fails with [`Error::AliasKeyNotEcdsa`].
+    ///
+    /// # Network Support
+    /// Please note that this not currently supported on mainnet.
+    pub fn key_with_alias(&mut self, key: impl Into<Key>, ecdsa_key: PublicKey) -> &mut Self {
+        let data = self.data_mut();
+        data.key = Some(key.into());
+        data.alias = ecdsa_key.to_evm_address();
+        data.alias_key = Some(ecdsa_key);
         self
     }
 
+    /// Sets both the key and the alias for this account from the same `ECDSA_SECP256K1` key.
+    ///
+    /// This is equivalent to `key_with_alias(ecdsa_key, ecdsa_key)`.
+    ///
+    /// # Network Support
+    /// Please note that this not currently supported on mainnet.
+    pub fn ecdsa_key_with_alias(&mut self, ecdsa_key: PublicKey) -> &mut Self {
+        self.key_with_alias(ecdsa_key, ecdsa_key)
+    }
+
     /// Returns the ID of the account to which this account is staking.
     /// This is mutually exclusive with `staked_node_id`.
     #[must_use]
@@ -273,7 +312,23 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        if self.alias_key.is_some_and(|it| !it.is_ecdsa()) {
+            return Err(Error::AliasKeyNotEcdsa);
+        }
+
+        validate_max_automatic_token_associations(self.max_automatic_token_associations)
+    }
+}
+
+/// Checks that `max` is `-1` (unlimited) or non-negative.
+pub(crate) fn validate_max_automatic_token_associations(max: i32) -> crate::Result<()> {
+    match max >= -1 {
+        true => Ok(()),
+        false => Err(Error::InvalidMaxAutomaticTokenAssociations(max)),
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
@@ -329,6 +384,7 @@ impl FromProtobuf<services::CryptoCreateTransactionBody> for AccountCreateTransa
             account_memo: pb.memo,
             max_automatic_token_associations: pb.max_automatic_token_associations,
             alias,
+            alias_key: None,
             staked_id: Option::from_protobuf(pb.staked_id)?,
             decline_staking_reward: pb.decline_reward,
         })
@@ -365,7 +421,7 @@ impl ToProtobuf for AccountCreateTransactionData {
             realm_id: None,
             new_realm_admin_key: None,
             memo: self.account_memo.clone(),
-            max_automatic_token_associations: i32::from(self.max_automatic_token_associations),
+            max_automatic_token_associations: self.max_automatic_token_associations,
             alias: self.alias.map_or(vec![], |it| it.to_bytes().to_vec()),
             decline_reward: self.decline_staking_reward,
             staked_id,
@@ -375,6 +431,7 @@ impl ToProtobuf for AccountCreateTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use hex_literal::hex;
@@ -395,8 +452,10 @@ mod tests {
         AccountCreateTransaction,
         AccountId,
         AnyTransaction,
+        Error,
         EvmAddress,
         Hbar,
+        PrivateKey,
         PublicKey,
     };
 
@@ -861,4 +920,67 @@ mod tests {
 
         tx.max_automatic_token_associations(MAX_AUTOMATIC_TOKEN_ASSOCIATIONS);
     }
+
+    #[test]
+    fn unlimited_max_automatic_token_associations() {
+        let mut tx = AccountCreateTransaction::new_for_tests();
+
+        tx.key(key()).max_automatic_token_associations(-1);
+
+        tx.freeze().unwrap();
+    }
+
+    #[test]
+    fn invalid_max_automatic_token_associations_fails() {
+        let mut tx = AccountCreateTransaction::new_for_tests();
+
+        tx.key(key()).max_automatic_token_associations(-2);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidMaxAutomaticTokenAssociations(-2)));
+        assert!(!tx.is_frozen());
+    }
+
+    #[test]
+    fn get_set_key_with_alias() {
+        let ecdsa_key = PrivateKey::generate_ecdsa().public_key();
+
+        let mut tx = AccountCreateTransaction::new();
+        tx.key_with_alias(key(), ecdsa_key);
+
+        assert_eq!(tx.get_key(), Some(&key().into()));
+        assert_eq!(tx.get_alias(), ecdsa_key.to_evm_address());
+    }
+
+    #[test]
+    fn get_set_ecdsa_key_with_alias() {
+        let ecdsa_key = PrivateKey::generate_ecdsa().public_key();
+
+        let mut tx = AccountCreateTransaction::new_for_tests();
+        tx.ecdsa_key_with_alias(ecdsa_key);
+
+        assert_eq!(tx.get_key(), Some(&ecdsa_key.into()));
+        assert_eq!(tx.get_alias(), ecdsa_key.to_evm_address());
+
+        tx.freeze().unwrap();
+    }
+
+    #[test]
+    fn key_with_ed25519_alias_fails() {
+        let mut tx = AccountCreateTransaction::new_for_tests();
+
+        tx.key_with_alias(key(), key());
+
+        assert_matches!(tx.freeze(), Err(Error::AliasKeyNotEcdsa));
+        assert!(!tx.is_frozen());
+    }
+
+    #[test]
+    fn alias_after_key_with_alias() {
+        let mut tx = AccountCreateTransaction::new_for_tests();
+
+        tx.key_with_alias(key(), key()).alias(ALIAS);
+
+        assert_eq!(tx.get_alias(), Some(ALIAS));
+        tx.freeze().unwrap();
+    }
 }
@@ -25,9 +25,11 @@ use time::{
     OffsetDateTime,
 };
 
+use crate::compat::CompatWarnings;
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
+    CompatWarning,
     FromProtobuf,
     Hbar,
     Key,
@@ -39,6 +41,7 @@ use crate::{
 
 /// Response from [`AccountInfoQuery`][crate::AccountInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct AccountInfo {
     /// The account that is being referenced.
     pub account_id: AccountId,
@@ -87,6 +90,7 @@ pub struct AccountInfo {
     pub is_receiver_signature_required: bool,
 
     /// The time at which this account is set to expire.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339::option"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The duration for expiration time will extend every this many seconds.
@@ -99,7 +103,9 @@ pub struct AccountInfo {
     pub owned_nfts: u64,
 
     /// The maximum number of tokens that an Account can be implicitly associated with.
-    pub max_automatic_token_associations: u32,
+    ///
+    /// `-1` means unlimited.
+    pub max_automatic_token_associations: i32,
 
     /// The alias of this account.
     pub alias_key: Option<PublicKey>,
@@ -112,6 +118,12 @@ pub struct AccountInfo {
 
     /// Staking metadata for this account.
     pub staking: Option<StakingInfo>,
+
+    /// The fields of the response that this SDK doesn't fully understand.
+    ///
+    /// A non-empty list suggests that the SDK is out of date with the network.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub warnings: Vec<CompatWarning>,
 }
 
 impl AccountInfo {
@@ -140,7 +152,7 @@ impl AccountInfo {
             auto_renew_period: self.auto_renew_period.to_protobuf(),
             memo: self.account_memo.clone(),
             owned_nfts: self.owned_nfts as i64,
-            max_automatic_token_associations: self.max_automatic_token_associations as i32,
+            max_automatic_token_associations: self.max_automatic_token_associations,
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ledger_id: self.ledger_id.to_bytes(),
             ethereum_nonce: self.ethereum_nonce as i64,
@@ -181,6 +193,22 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
         let ledger_id = LedgerId::from_bytes(pb.ledger_id);
         let staking = Option::from_protobuf(pb.staking_info)?;
 
+        let mut warnings = CompatWarnings::new("AccountInfo");
+        #[allow(deprecated)]
+        {
+            warnings.deprecated("proxy_account_id", pb.proxy_account_id.is_some());
+            warnings.deprecated(
+                "generate_send_record_threshold",
+                pb.generate_send_record_threshold != 0,
+            );
+            warnings.deprecated(
+                "generate_receive_record_threshold",
+                pb.generate_receive_record_threshold != 0,
+            );
+            warnings.deprecated("token_relationships", !pb.token_relationships.is_empty());
+            warnings.unsupported("live_hashes", !pb.live_hashes.is_empty());
+        }
+
         #[allow(deprecated)]
         Ok(Self {
             ledger_id,
@@ -195,7 +223,7 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             account_memo: pb.memo,
             owned_nfts: pb.owned_nfts as u64,
-            max_automatic_token_associations: pb.max_automatic_token_associations as u32,
+            max_automatic_token_associations: pb.max_automatic_token_associations,
             alias_key,
             ethereum_nonce: pb.ethereum_nonce as u64,
             is_receiver_signature_required: pb.receiver_sig_required,
@@ -206,6 +234,102 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
             receive_record_threshold: Hbar::from_tinybars(
                 pb.generate_receive_record_threshold as i64,
             ),
+
+            warnings: warnings.finish(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use crate::{
+        AccountId,
+        AccountInfo,
+        CompatWarning,
+        Hbar,
+        LedgerId,
+        PrivateKey,
+        StakingInfo,
+    };
+
+    #[allow(deprecated)]
+    fn make_info() -> AccountInfo {
+        let key = PrivateKey::from_str_ed25519(
+            "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10",
+        )
+        .unwrap()
+        .public_key();
+
+        AccountInfo {
+            account_id: AccountId::new(0, 0, 1001),
+            contract_account_id: "00000000000000000000000000000000000003e9".to_owned(),
+            is_deleted: false,
+            proxy_account_id: None,
+            proxy_received: Hbar::from_tinybars(0),
+            key: key.into(),
+            balance: Hbar::new(10),
+            send_record_threshold: Hbar::from_tinybars(0),
+            receive_record_threshold: Hbar::from_tinybars(0),
+            is_receiver_signature_required: true,
+            expiration_time: Some(OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap()),
+            auto_renew_period: Some(Duration::days(90)),
+            account_memo: "memo".to_owned(),
+            owned_nfts: 2,
+            max_automatic_token_associations: 10,
+            alias_key: Some(key),
+            ethereum_nonce: 4,
+            ledger_id: LedgerId::testnet(),
+            staking: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let a = make_info();
+        let b = AccountInfo::from_bytes(&a.to_bytes()).unwrap();
+
+        assert_eq!(a.to_bytes(), b.to_bytes());
+        assert_eq!(b.warnings, []);
+    }
+
+    #[test]
+    fn staking_to_from_bytes() {
+        let staking = StakingInfo {
+            decline_staking_reward: true,
+            stake_period_start: Some(OffsetDateTime::from_unix_timestamp(1_699_920_000).unwrap()),
+            pending_reward: Hbar::from_tinybars(5),
+            staked_to_me: Hbar::new(10),
+            staked_account_id: None,
+            staked_node_id: Some(3),
+        };
+
+        let a = AccountInfo { staking: Some(staking.clone()), ..make_info() };
+        let b = AccountInfo::from_bytes(&a.to_bytes()).unwrap();
+
+        assert_eq!(b.staking, Some(staking));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_field_warnings() {
+        let mut info = make_info();
+        info.proxy_account_id = Some(AccountId::new(0, 0, 3));
+        info.send_record_threshold = Hbar::new(1);
+
+        let info = AccountInfo::from_bytes(&info.to_bytes()).unwrap();
+
+        assert_eq!(
+            info.warnings,
+            [
+                CompatWarning::DeprecatedField { field: "proxy_account_id" },
+                CompatWarning::DeprecatedField { field: "generate_send_record_threshold" },
+            ]
+        );
+    }
+}
@@ -27,6 +27,7 @@ use crate::{
     Key,
     PublicKey,
     Transaction,
+    TransactionRecord,
 };
 
 async fn query_pk(client: &Client, account_id: AccountId) -> crate::Result<PublicKey> {
@@ -71,3 +72,58 @@ pub async fn verify_transaction_signature<D: TransactionExecute>(
 
     key.verify_transaction(transaction)
 }
+
+/// Returns `Ok(())` if the given account's public key has signed the given transaction,
+/// and the transaction is the one the network reached consensus on with the hash `transaction_hash`.
+///
+/// This is useful for checking a transaction against the `transaction_hash` reported by the mirror node
+/// (the mirror node's REST API encodes it as base64).
+///
+/// # Errors
+/// - [`Error::SignatureVerify`] if none of the transaction's per-node hashes are `transaction_hash`.
+/// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
+///   or the signature associated was invalid.
+/// - See [`AccountInfoQuery::execute`]
+pub async fn verify_transaction_hash_signature<D: TransactionExecute>(
+    client: &Client,
+    account_id: AccountId,
+    transaction: &mut Transaction<D>,
+    transaction_hash: &[u8],
+) -> crate::Result<()> {
+    transaction.freeze()?;
+
+    let matches_hash =
+        transaction.get_transaction_hash_per_node()?.values().any(|it| it.0 == transaction_hash);
+
+    if !matches_hash {
+        return Err(Error::signature_verify("transaction hash doesn't match the transaction"));
+    }
+
+    verify_transaction_signature(client, account_id, transaction).await
+}
+
+/// Returns `Ok(())` if the given account's public key has signed the given transaction,
+/// and `record` is the record of that transaction.
+///
+/// Receipts don't include the transaction's hash, so there's no equivalent for receipts.
+///
+/// # Errors
+/// - [`Error::SignatureVerify`] if `record` is for a different transaction.
+/// - See [`verify_transaction_hash_signature`]
+pub async fn verify_record_signature<D: TransactionExecute>(
+    client: &Client,
+    account_id: AccountId,
+    transaction: &mut Transaction<D>,
+    record: &TransactionRecord,
+) -> crate::Result<()> {
+    let is_same_transaction = transaction
+        .get_transaction_id()
+        .is_some_and(|it| it.is_same_transaction(&record.transaction_id));
+
+    if !is_same_transaction {
+        return Err(Error::signature_verify("record is for a different transaction"));
+    }
+
+    verify_transaction_hash_signature(client, account_id, transaction, &record.transaction_hash)
+        .await
+}
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::account::AccountInfo;
 use crate::ledger_id::RefLedgerId;
@@ -29,10 +28,12 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -83,6 +84,12 @@ impl ToQueryProtobuf for AccountInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetInfoQuery> for AccountInfoQueryData {
+    fn from_protobuf(pb: services::CryptoGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { account_id: Option::from_protobuf(pb.account_id)? })
+    }
+}
+
 impl QueryExecute for AccountInfoQueryData {
     type Response = AccountInfo;
 
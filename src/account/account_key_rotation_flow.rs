@@ -0,0 +1,382 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use parking_lot::Mutex;
+
+use crate::error::KeyRotationErrorKind;
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    AccountInfo,
+    AccountInfoQuery,
+    AccountUpdateTransaction,
+    Client,
+    Error,
+    Key,
+    KeyList,
+    PrivateKey,
+    PublicKey,
+    Status,
+    TransactionId,
+    TransactionReceipt,
+    TransactionResponse,
+};
+
+/// Rotate the key of an account, and check that the network has the new key afterwards.
+///
+/// The operation of this flow is as follows:
+/// 1. Execute an [`AccountUpdateTransaction`] setting the key of the account to the new key,
+///    signed by both the old key and the new key.
+/// 2. Fetch the receipt of the update.
+/// 3. Fetch the [`AccountInfo`] of the account and check that its key is the new key.
+///
+/// A mistake while rotating a key can lock an account forever,
+/// so if any step fails, the error is an [`Error::KeyRotation`] that says whether the key was rotated.
+#[derive(Default, Debug)]
+pub struct AccountKeyRotationFlow {
+    node_account_ids: Option<Vec<AccountId>>,
+    account_id: Option<AccountId>,
+    old_signer: Option<AnySigner>,
+    new_key: Option<Key>,
+    new_signers: Vec<AnySigner>,
+}
+
+/// Response from [`AccountKeyRotationFlow`].
+#[derive(Debug)]
+pub struct AccountKeyRotationFlowResponse {
+    /// The response from the [`AccountUpdateTransaction`].
+    pub response: TransactionResponse,
+
+    /// The receipt of the [`AccountUpdateTransaction`].
+    pub receipt: TransactionReceipt,
+
+    /// The info of the account after the update, whose key is the new key.
+    pub info: AccountInfo,
+}
+
+impl AccountKeyRotationFlow {
+    /// Create a new `AccountKeyRotationFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account IDs of the nodes the transaction may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transaction may be submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Returns the account whose key is rotated.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account whose key is rotated.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+
+        self
+    }
+
+    /// Sets the current key of the account.
+    ///
+    /// Not needed if the current key of the account is the client's operator key.
+    pub fn old_key(&mut self, key: PrivateKey) -> &mut Self {
+        self.old_signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer for the current key of the account.
+    ///
+    /// Not needed if the current key of the account is the client's operator key.
+    pub fn old_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.old_signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Returns the key the account is rotated to.
+    #[must_use]
+    pub fn get_new_key(&self) -> Option<&Key> {
+        self.new_key.as_ref()
+    }
+
+    /// Sets the key the account is rotated to.
+    ///
+    /// The network requires the new key to sign, so that an account can't be rotated to a key nobody holds.
+    pub fn new_key(&mut self, key: PrivateKey) -> &mut Self {
+        self.new_key = Some(Key::Single(key.public_key()));
+        self.new_signers = Vec::from([AnySigner::PrivateKey(key)]);
+
+        self
+    }
+
+    /// Sets the signer for the key the account is rotated to.
+    ///
+    /// The network requires the new key to sign, so that an account can't be rotated to a key nobody holds.
+    pub fn new_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.new_key = Some(Key::Single(public_key));
+        self.new_signers = Vec::from([AnySigner::arbitrary(Box::new(public_key), signer)]);
+
+        self
+    }
+
+    /// Sets the key list the account is rotated to, and the keys that sign for it.
+    ///
+    /// The network requires the new key to sign, so `keys` must be enough to meet the threshold of `key_list`.
+    pub fn new_key_list(
+        &mut self,
+        key_list: KeyList,
+        keys: impl IntoIterator<Item = PrivateKey>,
+    ) -> &mut Self {
+        self.new_key = Some(Key::KeyList(key_list));
+        self.new_signers = keys.into_iter().map(AnySigner::PrivateKey).collect();
+
+        self
+    }
+
+    /// Rotates the key of the account, and checks that the network has the new key.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `account_id` or the new key wasn't set.
+    /// - [`Error::KeyRotation`] if any step fails, or if the key of the account isn't the new key afterwards.
+    pub async fn execute(&self, client: &Client) -> crate::Result<AccountKeyRotationFlowResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Rotates the key of the account, and checks that the network has the new key.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `account_id` or the new key wasn't set.
+    /// - [`Error::KeyRotation`] if any step fails, or if the key of the account isn't the new key afterwards.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_request: std::time::Duration,
+    ) -> crate::Result<AccountKeyRotationFlowResponse> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_request)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_request: Option<std::time::Duration>,
+    ) -> crate::Result<AccountKeyRotationFlowResponse> {
+        let account_id = self.account_id.ok_or(Error::MissingField { field: "account_id" })?;
+        let new_key = self.new_key.clone().ok_or(Error::MissingField { field: "new_key" })?;
+
+        // the ID is picked up front so that it's known no matter which step fails.
+        let Some(payer) = client.get_operator_account_id() else {
+            return Err(Error::key_rotation(
+                KeyRotationErrorKind::NotRotated,
+                account_id,
+                None,
+                None,
+                Some(Error::NoPayerAccountOrTransactionId),
+            ));
+        };
+
+        let transaction_id = TransactionId::generate(payer);
+
+        let mut transaction = AccountUpdateTransaction::new();
+
+        transaction.account_id(account_id).key(new_key.clone()).transaction_id(transaction_id);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.clone());
+        }
+
+        transaction.freeze_with(client).map_err(|e| {
+            Error::key_rotation(
+                KeyRotationErrorKind::NotRotated,
+                account_id,
+                Some(transaction_id),
+                None,
+                Some(e),
+            )
+        })?;
+
+        if let Some(old_signer) = &self.old_signer {
+            transaction.sign_signer(old_signer.clone());
+        }
+
+        for new_signer in &self.new_signers {
+            transaction.sign_signer(new_signer.clone());
+        }
+
+        let attempted_nodes = Mutex::new(Vec::new());
+
+        let response = transaction
+            .execute_recording_attempts(client, timeout_per_request, &attempted_nodes)
+            .await
+            .map_err(|e| {
+                let attempts = attempted_nodes.lock().len();
+
+                let kind = match e {
+                    // the transaction was never sent to a node.
+                    _ if attempts == 0 => KeyRotationErrorKind::NotRotated,
+                    // an earlier submission (such as one that timed out) may still reach consensus.
+                    Error::TransactionPreCheckStatus {
+                        status: Status::DuplicateTransaction,
+                        ..
+                    } => KeyRotationErrorKind::Unknown,
+                    // the only submission was rejected before reaching consensus.
+                    Error::TransactionPreCheckStatus { .. } if attempts == 1 => {
+                        KeyRotationErrorKind::NotRotated
+                    }
+                    _ => KeyRotationErrorKind::Unknown,
+                };
+
+                Error::key_rotation(kind, account_id, Some(transaction_id), None, Some(e))
+            })?;
+
+        let receipt = response
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_request)
+            .await
+            .map_err(|e| {
+                let kind = match e {
+                    Error::ReceiptStatus { .. } => KeyRotationErrorKind::NotRotated,
+                    _ => KeyRotationErrorKind::Unknown,
+                };
+
+                Error::key_rotation(kind, account_id, Some(transaction_id), None, Some(e))
+            })?;
+
+        let info = AccountInfoQuery::new()
+            .account_id(account_id)
+            .execute_with_optional_timeout(client, timeout_per_request)
+            .await
+            .map_err(|e| {
+                Error::key_rotation(
+                    KeyRotationErrorKind::Unverified,
+                    account_id,
+                    Some(transaction_id),
+                    None,
+                    Some(e),
+                )
+            })?;
+
+        if info.key != new_key {
+            return Err(Error::key_rotation(
+                KeyRotationErrorKind::KeyMismatch,
+                account_id,
+                Some(transaction_id),
+                Some(info.key),
+                None,
+            ));
+        }
+
+        Ok(AccountKeyRotationFlowResponse { response, receipt, info })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::AccountKeyRotationFlow;
+    use crate::error::KeyRotationErrorKind;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        Key,
+        KeyList,
+        PrivateKey,
+    };
+
+    #[test]
+    fn get_set() {
+        let new_key = PrivateKey::generate_ed25519();
+
+        let mut flow = AccountKeyRotationFlow::new();
+        flow.account_id(AccountId::new(0, 0, 1001)).new_key(new_key.clone());
+
+        assert_eq!(flow.get_account_id(), Some(AccountId::new(0, 0, 1001)));
+        assert_eq!(flow.get_new_key(), Some(&Key::Single(new_key.public_key())));
+    }
+
+    #[test]
+    fn get_set_key_list() {
+        let keys = [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()];
+        let key_list = KeyList {
+            keys: keys.iter().map(|it| it.public_key().into()).collect(),
+            threshold: Some(1),
+        };
+
+        let mut flow = AccountKeyRotationFlow::new();
+        flow.new_key_list(key_list.clone(), [keys[0].clone()]);
+
+        assert_eq!(flow.get_new_key(), Some(&Key::KeyList(key_list)));
+    }
+
+    #[tokio::test]
+    async fn missing_new_key() {
+        let client = Client::for_testnet();
+
+        let res = AccountKeyRotationFlow::new()
+            .account_id(AccountId::new(0, 0, 1001))
+            .execute(&client)
+            .await;
+
+        assert_matches!(res.err(), Some(Error::MissingField { field: "new_key" }));
+    }
+
+    #[tokio::test]
+    async fn no_operator_is_not_rotated() {
+        // no operator, so there's nobody to pay for the update.
+        let client = Client::for_testnet();
+
+        let res = AccountKeyRotationFlow::new()
+            .account_id(AccountId::new(0, 0, 1001))
+            .new_key(PrivateKey::generate_ed25519())
+            .execute(&client)
+            .await;
+
+        let error = assert_matches!(res, Err(Error::KeyRotation(error)) => error);
+
+        assert_eq!(error.kind, KeyRotationErrorKind::NotRotated);
+        assert_eq!(error.account_id, AccountId::new(0, 0, 1001));
+        assert_eq!(error.transaction_id, None);
+        assert_matches!(error.source, Some(Error::NoPayerAccountOrTransactionId));
+    }
+}
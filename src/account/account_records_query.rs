@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,6 +27,7 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -81,6 +81,12 @@ impl ToQueryProtobuf for AccountRecordsQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetAccountRecordsQuery> for AccountRecordsQueryData {
+    fn from_protobuf(pb: services::CryptoGetAccountRecordsQuery) -> crate::Result<Self> {
+        Ok(Self { account_id: Option::from_protobuf(pb.account_id)? })
+    }
+}
+
 impl QueryExecute for AccountRecordsQueryData {
     type Response = Vec<TransactionRecord>;
 
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,11 +27,13 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     AllProxyStakers,
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -80,6 +81,12 @@ impl ToQueryProtobuf for AccountStakersQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetStakersQuery> for AccountStakersQueryData {
+    fn from_protobuf(pb: services::CryptoGetStakersQuery) -> crate::Result<Self> {
+        Ok(Self { account_id: Option::from_protobuf(pb.account_id)? })
+    }
+}
+
 impl QueryExecute for AccountStakersQueryData {
     type Response = AllProxyStakers;
 
@@ -24,7 +24,6 @@ use time::{
     Duration,
     OffsetDateTime,
 };
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -40,11 +39,13 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
     Key,
+    LedgerId,
     Transaction,
     ValidateChecksums,
 };
@@ -233,6 +234,7 @@ impl AccountUpdateTransaction {
 
     /// Sets the maximum number of tokens that an Account can be implicitly associated with.
     ///
+    /// `-1` means unlimited, other negative values are rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, amount: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = Some(amount);
         self
@@ -291,7 +293,17 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        self.max_automatic_token_associations
+            .map_or(Ok(()), super::validate_max_automatic_token_associations)
+    }
+
+    #[allow(deprecated)]
+    fn deprecated_field(&self) -> Option<&'static str> {
+        self.proxy_account_id.map(|_| "proxy_account_id")
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
@@ -394,7 +406,7 @@ impl ToProtobuf for AccountUpdateTransactionData {
             auto_renew_period,
             expiration_time,
             memo: self.account_memo.clone(),
-            max_automatic_token_associations: self.max_automatic_token_associations.map(Into::into),
+            max_automatic_token_associations: self.max_automatic_token_associations,
             decline_reward: self.decline_staking_reward,
             send_record_threshold_field: None,
             receive_record_threshold_field: None,
@@ -406,6 +418,9 @@ impl ToProtobuf for AccountUpdateTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use time::{
@@ -428,6 +443,8 @@ mod tests {
         AccountId,
         AccountUpdateTransaction,
         AnyTransaction,
+        Client,
+        Error,
         PublicKey,
     };
 
@@ -813,6 +830,29 @@ mod tests {
         tx.proxy_account_id(PROXY_ACCOUNT_ID);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn reject_deprecated_proxy_account_id() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.set_reject_deprecated_fields(true);
+
+        let mut tx = AccountUpdateTransaction::new_for_tests();
+        tx.account_id(ACCOUNT_ID);
+
+        let mut deprecated_tx = tx.clone();
+        deprecated_tx.proxy_account_id(PROXY_ACCOUNT_ID);
+
+        assert_matches!(
+            deprecated_tx.freeze_with(&client),
+            Err(Error::DeprecatedField { field: "proxy_account_id" })
+        );
+
+        tx.freeze_with(&client).unwrap();
+
+        client.set_reject_deprecated_fields(false);
+        deprecated_tx.freeze_with(&client).unwrap();
+    }
+
     #[test]
     fn get_set_auto_renew_period() {
         let mut tx = AccountUpdateTransaction::new();
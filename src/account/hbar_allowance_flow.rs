@@ -0,0 +1,433 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+#[cfg(feature = "mirror-rest")]
+use serde_json::Value;
+
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest;
+use crate::signer::AnySigner;
+use crate::{
+    AccountAllowanceApproveTransaction,
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    PublicKey,
+    Status,
+    TransactionId,
+    TransactionResponse,
+    TransferTransaction,
+};
+
+/// Approve an hbar allowance ([HIP-336](https://hips.hedera.com/hip/hip-336)) and spend from it in a single flow.
+///
+/// The operation of this flow is as follows:
+/// 1. Execute an [`AccountAllowanceApproveTransaction`] granting `spender` an allowance of `allowance` from `owner`,
+///    signed by the owner.
+/// 2. Execute a [`TransferTransaction`] moving `amount` from `owner` to `recipient` using the allowance,
+///    paid for *and* signed by the spender.
+/// 3. Wait for the receipt of the transfer.
+///
+/// With the `mirror-rest` feature, [`HbarAllowanceFlowResponse::get_remaining_allowance`]
+/// then verifies how much of the allowance remains with the mirror node.
+///
+/// The approval is paid for by the client's operator.
+#[derive(Default, Debug)]
+pub struct HbarAllowanceFlow {
+    node_account_ids: Option<Vec<AccountId>>,
+    owner_account_id: Option<AccountId>,
+    spender_account_id: Option<AccountId>,
+    recipient_account_id: Option<AccountId>,
+    allowance: Hbar,
+    amount: Hbar,
+    owner_signer: Option<AnySigner>,
+    spender_signer: Option<AnySigner>,
+}
+
+/// Response from [`HbarAllowanceFlow`].
+#[derive(Debug)]
+pub struct HbarAllowanceFlowResponse {
+    /// The response from the [`AccountAllowanceApproveTransaction`].
+    pub approve_response: TransactionResponse,
+
+    /// The response from the approved [`TransferTransaction`].
+    pub transfer_response: TransactionResponse,
+
+    /// How much of the allowance should remain after the transfer: the approved allowance minus the approved transfer.
+    ///
+    /// This is an estimate, as the spender may also spend from the allowance outside of this flow.
+    pub estimated_remaining_allowance: Hbar,
+
+    /// The account that granted the allowance.
+    pub owner_account_id: AccountId,
+
+    /// The account that the allowance was granted to, and that made the transfer.
+    pub spender_account_id: AccountId,
+}
+
+impl HbarAllowanceFlowResponse {
+    /// Returns how much of the allowance remains, according to the mirror node REST API of `client`.
+    ///
+    /// The mirror node lags consensus by a few seconds,
+    /// so right after the flow this may still return the allowance from before the transfer.
+    ///
+    /// Requires the `mirror-rest` feature.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if the request to the mirror node fails or its response couldn't be understood.
+    #[cfg(feature = "mirror-rest")]
+    pub async fn get_remaining_allowance(&self, client: &Client) -> crate::Result<Hbar> {
+        let path = format!(
+            "/api/v1/accounts/{}/allowances/crypto?spender.id={}",
+            self.owner_account_id, self.spender_account_id
+        );
+
+        remaining_allowance_from_json(&mirror_rest::get(client, &path).await?)
+    }
+}
+
+/// Returns the remaining amount of the only allowance in a mirror node crypto allowances response,
+/// or zero if there is none (it was deleted, or never granted).
+#[cfg(feature = "mirror-rest")]
+fn remaining_allowance_from_json(json: &Value) -> crate::Result<Hbar> {
+    let allowances = json
+        .get("allowances")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::mirror_rest("response is missing `allowances`"))?;
+
+    match allowances.first() {
+        Some(allowance) => Ok(Hbar::from_tinybars(mirror_rest::get_i64(allowance, "amount")?)),
+        None => Ok(Hbar::ZERO),
+    }
+}
+
+impl HbarAllowanceFlow {
+    /// Create a new `HbarAllowanceFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Returns the account that owns the hbar being spent.
+    #[must_use]
+    pub fn get_owner_account_id(&self) -> Option<AccountId> {
+        self.owner_account_id
+    }
+
+    /// Sets the account that owns the hbar being spent.
+    pub fn owner_account_id(&mut self, owner_account_id: AccountId) -> &mut Self {
+        self.owner_account_id = Some(owner_account_id);
+
+        self
+    }
+
+    /// Returns the account that is granted the allowance.
+    #[must_use]
+    pub fn get_spender_account_id(&self) -> Option<AccountId> {
+        self.spender_account_id
+    }
+
+    /// Sets the account that is granted the allowance.
+    ///
+    /// The spender pays for the approved transfer.
+    pub fn spender_account_id(&mut self, spender_account_id: AccountId) -> &mut Self {
+        self.spender_account_id = Some(spender_account_id);
+
+        self
+    }
+
+    /// Returns the account that receives the transferred hbar.
+    #[must_use]
+    pub fn get_recipient_account_id(&self) -> Option<AccountId> {
+        self.recipient_account_id
+    }
+
+    /// Sets the account that receives the transferred hbar.
+    pub fn recipient_account_id(&mut self, recipient_account_id: AccountId) -> &mut Self {
+        self.recipient_account_id = Some(recipient_account_id);
+
+        self
+    }
+
+    /// Returns the allowance to approve.
+    #[must_use]
+    pub fn get_allowance(&self) -> Hbar {
+        self.allowance
+    }
+
+    /// Sets the allowance to approve.
+    pub fn allowance(&mut self, allowance: Hbar) -> &mut Self {
+        self.allowance = allowance;
+
+        self
+    }
+
+    /// Returns the amount to transfer using the allowance.
+    #[must_use]
+    pub fn get_amount(&self) -> Hbar {
+        self.amount
+    }
+
+    /// Sets the amount to transfer using the allowance.
+    pub fn amount(&mut self, amount: Hbar) -> &mut Self {
+        self.amount = amount;
+
+        self
+    }
+
+    /// Sets the key used to sign the [`AccountAllowanceApproveTransaction`] on behalf of the owner.
+    ///
+    /// Not needed if the owner is the client's operator.
+    pub fn owner_key(&mut self, key: PrivateKey) -> &mut Self {
+        self.owner_signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer used to sign the [`AccountAllowanceApproveTransaction`] on behalf of the owner.
+    ///
+    /// Not needed if the owner is the client's operator.
+    pub fn owner_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.owner_signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Sets the key used to sign the approved [`TransferTransaction`] on behalf of the spender.
+    ///
+    /// Not needed if the spender is the client's operator.
+    pub fn spender_key(&mut self, key: PrivateKey) -> &mut Self {
+        self.spender_signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer used to sign the approved [`TransferTransaction`] on behalf of the spender.
+    ///
+    /// Not needed if the spender is the client's operator.
+    pub fn spender_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.spender_signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Generates the required transactions and executes them all.
+    ///
+    /// # Errors
+    /// - [`Error::InsufficientAllowance`] if `amount` is greater than `allowance`,
+    ///   or if the network rejects the transfer because the allowance is insufficient.
+    /// - [`Error::MissingField`] if `owner_account_id`, `spender_account_id`, or `recipient_account_id` weren't set,
+    ///   or if the spender isn't the client's operator and no spender key was set.
+    /// - Any error that can occur while executing the transactions or fetching their receipts.
+    pub async fn execute(&self, client: &Client) -> crate::Result<HbarAllowanceFlowResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Generates the required transactions and executes them all.
+    ///
+    /// # Errors
+    /// - [`Error::InsufficientAllowance`] if `amount` is greater than `allowance`,
+    ///   or if the network rejects the transfer because the allowance is insufficient.
+    /// - [`Error::MissingField`] if `owner_account_id`, `spender_account_id`, or `recipient_account_id` weren't set,
+    ///   or if the spender isn't the client's operator and no spender key was set.
+    /// - Any error that can occur while executing the transactions or fetching their receipts.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<HbarAllowanceFlowResponse> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<HbarAllowanceFlowResponse> {
+        let owner =
+            self.owner_account_id.ok_or(Error::MissingField { field: "owner_account_id" })?;
+        let spender =
+            self.spender_account_id.ok_or(Error::MissingField { field: "spender_account_id" })?;
+        let recipient = self
+            .recipient_account_id
+            .ok_or(Error::MissingField { field: "recipient_account_id" })?;
+
+        // the spender pays for the transfer, so it has to sign it.
+        if self.spender_signer.is_none() && client.get_operator_account_id() != Some(spender) {
+            return Err(Error::MissingField { field: "spender_key" });
+        }
+
+        if self.amount > self.allowance {
+            return Err(Error::InsufficientAllowance {
+                amount: self.amount,
+                allowance: Some(self.allowance),
+            });
+        }
+
+        let approve_response = self
+            .make_approve_transaction(owner, spender)
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        approve_response
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        let transfer_response = self
+            .make_transfer_transaction(owner, spender, recipient)
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await
+            .map_err(|e| self.map_allowance_error(e))?;
+
+        transfer_response
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await
+            .map_err(|e| self.map_allowance_error(e))?;
+
+        // records only have each account's net transfer, which also includes anything the owner received,
+        // so the amount spent from the allowance is taken from the approved transfer itself.
+        Ok(HbarAllowanceFlowResponse {
+            approve_response,
+            transfer_response,
+            estimated_remaining_allowance: self.allowance - self.amount,
+            owner_account_id: owner,
+            spender_account_id: spender,
+        })
+    }
+
+    fn make_approve_transaction(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+    ) -> AccountAllowanceApproveTransaction {
+        let mut tmp = AccountAllowanceApproveTransaction::new();
+
+        tmp.approve_hbar_allowance(owner, spender, self.allowance);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.clone());
+        }
+
+        if let Some(signer) = &self.owner_signer {
+            tmp.sign_signer(signer.clone());
+        }
+
+        tmp
+    }
+
+    fn make_transfer_transaction(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+        recipient: AccountId,
+    ) -> TransferTransaction {
+        let mut tmp = TransferTransaction::new();
+
+        // the payer of an approved transfer *must* be the spender, otherwise the network rejects it
+        // with `SPENDER_DOES_NOT_HAVE_ALLOWANCE`, even if the spender signs.
+        tmp.approved_hbar_transfer(owner, -self.amount)
+            .hbar_transfer(recipient, self.amount)
+            .transaction_id(TransactionId::generate(spender));
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.clone());
+        }
+
+        if let Some(signer) = &self.spender_signer {
+            tmp.sign_signer(signer.clone());
+        }
+
+        tmp
+    }
+
+    fn map_allowance_error(&self, error: Error) -> Error {
+        match error {
+            Error::TransactionPreCheckStatus { status, .. }
+            | Error::ReceiptStatus { status, .. }
+                if matches!(
+                    status,
+                    Status::AmountExceedsAllowance | Status::SpenderDoesNotHaveAllowance
+                ) =>
+            {
+                Error::InsufficientAllowance { amount: self.amount, allowance: None }
+            }
+            error => error,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mirror-rest"))]
+mod tests {
+    use serde_json::json;
+
+    use super::remaining_allowance_from_json;
+    use crate::Hbar;
+
+    #[test]
+    fn remaining_allowance() {
+        let json = json!({
+            "allowances": [{
+                "amount": 600_000_000,
+                "amount_granted": 1_000_000_000,
+                "owner": "0.0.1002",
+                "spender": "0.0.1001",
+                "timestamp": { "from": "1714564800.000000001", "to": null },
+            }],
+            "links": { "next": null },
+        });
+
+        assert_eq!(remaining_allowance_from_json(&json).unwrap(), Hbar::new(6));
+    }
+
+    #[test]
+    fn remaining_allowance_none() {
+        let json = json!({ "allowances": [], "links": { "next": null } });
+
+        assert_eq!(remaining_allowance_from_json(&json).unwrap(), Hbar::ZERO);
+    }
+}
@@ -30,9 +30,12 @@ mod account_info;
 /// Flow for verifying signatures via account info.
 pub mod account_info_flow;
 mod account_info_query;
+mod account_key_rotation_flow;
 mod account_records_query;
 mod account_stakers_query;
 mod account_update_transaction;
+mod hbar_allowance_flow;
+mod multisig_account_flow;
 mod proxy_staker;
 
 pub use account_allowance_approve_transaction::AccountAllowanceApproveTransaction;
@@ -43,19 +46,31 @@ pub use account_balance::AccountBalance;
 pub use account_balance_query::AccountBalanceQuery;
 pub(crate) use account_balance_query::AccountBalanceQueryData;
 pub use account_create_transaction::AccountCreateTransaction;
-pub(crate) use account_create_transaction::AccountCreateTransactionData;
+pub(crate) use account_create_transaction::{
+    validate_max_automatic_token_associations,
+    AccountCreateTransactionData,
+};
 pub use account_delete_transaction::AccountDeleteTransaction;
 pub(crate) use account_delete_transaction::AccountDeleteTransactionData;
 pub use account_id::AccountId;
 pub use account_info::AccountInfo;
 pub use account_info_query::AccountInfoQuery;
 pub(crate) use account_info_query::AccountInfoQueryData;
+pub use account_key_rotation_flow::{
+    AccountKeyRotationFlow,
+    AccountKeyRotationFlowResponse,
+};
 pub use account_records_query::AccountRecordsQuery;
 pub(crate) use account_records_query::AccountRecordsQueryData;
 pub use account_stakers_query::AccountStakersQuery;
 pub(crate) use account_stakers_query::AccountStakersQueryData;
 pub use account_update_transaction::AccountUpdateTransaction;
 pub(crate) use account_update_transaction::AccountUpdateTransactionData;
+pub use hbar_allowance_flow::{
+    HbarAllowanceFlow,
+    HbarAllowanceFlowResponse,
+};
+pub use multisig_account_flow::MultisigAccountFlow;
 pub use proxy_staker::{
     AllProxyStakers,
     ProxyStaker,
@@ -0,0 +1,680 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::signer::AnySigner;
+use crate::{
+    AccountCreateTransaction,
+    AccountId,
+    AnyTransaction,
+    Client,
+    Error,
+    Hbar,
+    Key,
+    KeyList,
+    PrivateKey,
+    PublicKey,
+    TransactionId,
+    TransactionResponse,
+};
+
+/// Create an account controlled by a threshold key, collecting signatures from its key holders.
+///
+/// The operation of this flow is as follows:
+/// 1. Set the public keys of every key holder and how many of them must sign.
+/// 2. Call [`freeze_with`](Self::freeze_with) and distribute [`to_bytes`](Self::to_bytes) to the key holders.
+/// 3. Each key holder signs the bytes with [`sign_bytes`](Self::sign_bytes) and sends the result back.
+/// 4. Add each response with [`add_signed_bytes`](Self::add_signed_bytes).
+/// 5. Once at least `threshold` key holders have signed, [`execute`](Self::execute) the [`AccountCreateTransaction`].
+///
+/// Collecting the signatures proves that the key holders control the keys the account is created with,
+/// and is required by the network when [`receiver_signature_required`](Self::receiver_signature_required) is set.
+///
+/// The account creation is paid for by the client's operator.
+#[derive(Default, Debug)]
+pub struct MultisigAccountFlow {
+    node_account_ids: Option<Vec<AccountId>>,
+    transaction_id: Option<TransactionId>,
+    keys: Vec<PublicKey>,
+    threshold: u32,
+    initial_balance: Hbar,
+    receiver_signature_required: bool,
+    account_memo: String,
+    transaction: Option<AccountCreateTransaction>,
+    signed_keys: Vec<PublicKey>,
+}
+
+impl MultisigAccountFlow {
+    /// Create a new `MultisigAccountFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account IDs of the nodes the transaction may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transaction may be submitted to.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.require_not_frozen();
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Returns the ID of the account creation transaction.
+    #[must_use]
+    pub fn get_transaction_id(&self) -> Option<TransactionId> {
+        self.transaction_id
+    }
+
+    /// Sets the ID of the account creation transaction.
+    ///
+    /// By default one is generated for the client's operator when freezing.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn transaction_id(&mut self, transaction_id: TransactionId) -> &mut Self {
+        self.require_not_frozen();
+        self.transaction_id = Some(transaction_id);
+
+        self
+    }
+
+    /// Returns the public keys of the key holders.
+    #[must_use]
+    pub fn get_keys(&self) -> &[PublicKey] {
+        &self.keys
+    }
+
+    /// Sets the public keys of the key holders.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn keys(&mut self, keys: impl IntoIterator<Item = PublicKey>) -> &mut Self {
+        self.require_not_frozen();
+        self.keys = keys.into_iter().collect();
+
+        self
+    }
+
+    /// Adds the public key of a key holder.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn add_key(&mut self, key: PublicKey) -> &mut Self {
+        self.require_not_frozen();
+        self.keys.push(key);
+
+        self
+    }
+
+    /// Returns how many of the key holders must sign for the account.
+    #[must_use]
+    pub fn get_threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Sets how many of the key holders must sign for the account.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn threshold(&mut self, threshold: u32) -> &mut Self {
+        self.require_not_frozen();
+        self.threshold = threshold;
+
+        self
+    }
+
+    /// Returns the initial balance of the account.
+    #[must_use]
+    pub fn get_initial_balance(&self) -> Hbar {
+        self.initial_balance
+    }
+
+    /// Sets the initial balance of the account, transferred from the operator.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn initial_balance(&mut self, initial_balance: Hbar) -> &mut Self {
+        self.require_not_frozen();
+        self.initial_balance = initial_balance;
+
+        self
+    }
+
+    /// Returns `true` if the account must sign any transfer into it.
+    #[must_use]
+    pub fn get_receiver_signature_required(&self) -> bool {
+        self.receiver_signature_required
+    }
+
+    /// Sets whether the account must sign any transfer into it.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn receiver_signature_required(&mut self, required: bool) -> &mut Self {
+        self.require_not_frozen();
+        self.receiver_signature_required = required;
+
+        self
+    }
+
+    /// Returns the memo of the account.
+    #[must_use]
+    pub fn get_account_memo(&self) -> &str {
+        &self.account_memo
+    }
+
+    /// Sets the memo of the account.
+    ///
+    /// # Panics
+    /// - If the flow has been frozen.
+    pub fn account_memo(&mut self, memo: impl Into<String>) -> &mut Self {
+        self.require_not_frozen();
+        self.account_memo = memo.into();
+
+        self
+    }
+
+    /// Returns the threshold key the account will be created with.
+    #[must_use]
+    pub fn get_key_list(&self) -> KeyList {
+        let mut key_list = KeyList::with_threshold(self.threshold);
+
+        key_list.extend(self.keys.iter().copied().map(Key::Single));
+
+        key_list
+    }
+
+    /// Returns the keys from the key list that have signed so far.
+    #[must_use]
+    pub fn get_signed_keys(&self) -> &[PublicKey] {
+        &self.signed_keys
+    }
+
+    /// Returns `true` if enough of the key holders have signed to execute the flow.
+    #[must_use]
+    pub fn is_threshold_met(&self) -> bool {
+        self.signed_keys.len() >= self.threshold as usize
+    }
+
+    /// Freeze the account creation transaction so that key holders can sign it.
+    ///
+    /// `client` provides the nodes, payer, and defaults for the transaction, as with [`Transaction::freeze_with`](crate::Transaction::freeze_with).
+    ///
+    /// # Errors
+    /// - [`Error::MultisigInvalidThreshold`] if the threshold isn't between `1` and the number of keys.
+    /// - [`Error::NoPayerAccountOrTransactionId`] if no transaction ID was set and `client` has no operator.
+    /// - Any error that [`Transaction::freeze_with`](crate::Transaction::freeze_with) can return.
+    pub fn freeze_with<'a>(
+        &mut self,
+        client: impl Into<Option<&'a Client>>,
+    ) -> crate::Result<&mut Self> {
+        if self.transaction.is_some() {
+            return Ok(self);
+        }
+
+        if !(1..=self.keys.len()).contains(&(self.threshold as usize)) {
+            return Err(Error::MultisigInvalidThreshold {
+                threshold: self.threshold,
+                keys: self.keys.len(),
+            });
+        }
+
+        let client: Option<&Client> = client.into();
+
+        // the key holders sign the exact bytes that get submitted, so the ID can't be generated on the fly.
+        let transaction_id = self
+            .transaction_id
+            .or_else(|| {
+                client.and_then(Client::get_operator_account_id).map(TransactionId::generate)
+            })
+            .ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+        let mut tmp = AccountCreateTransaction::new();
+
+        tmp.key(self.get_key_list())
+            .initial_balance(self.initial_balance)
+            .receiver_signature_required(self.receiver_signature_required)
+            .account_memo(self.account_memo.clone())
+            .transaction_id(transaction_id);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.clone());
+        }
+
+        tmp.freeze_with(client)?;
+
+        self.transaction = Some(tmp);
+
+        Ok(self)
+    }
+
+    /// Convert the frozen account creation transaction to bytes for the key holders to sign.
+    ///
+    /// # Errors
+    /// - [`Error::MultisigNotFrozen`] if the flow hasn't been frozen.
+    /// - Any error that [`Transaction::to_bytes`](crate::Transaction::to_bytes) can return.
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        self.frozen_transaction()?.to_bytes()
+    }
+
+    /// Sign bytes produced by [`to_bytes`](Self::to_bytes) with `key`, returning the signed bytes.
+    ///
+    /// This is what each key holder runs, the result is passed to [`add_signed_bytes`](Self::add_signed_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if `bytes` isn't a valid transaction.
+    /// - [`Error::MultisigTransactionMismatch`] if `bytes` isn't an [`AccountCreateTransaction`].
+    pub fn sign_bytes(bytes: &[u8], key: &PrivateKey) -> crate::Result<Vec<u8>> {
+        let mut transaction = AnyTransaction::from_bytes(bytes)?
+            .downcast::<AccountCreateTransaction>()
+            .map_err(|_| Error::MultisigTransactionMismatch)?;
+
+        transaction.sign(key.clone()).to_bytes()
+    }
+
+    /// Sign the account creation transaction with `key` directly.
+    ///
+    /// Use this for keys held by whoever is running the flow, rather than round-tripping them through bytes.
+    /// Keys that aren't part of the key list are ignored.
+    ///
+    /// # Errors
+    /// - [`Error::MultisigNotFrozen`] if the flow hasn't been frozen.
+    pub fn sign(&mut self, key: PrivateKey) -> crate::Result<&mut Self> {
+        let public_key = key.public_key();
+        let transaction = self.transaction.as_mut().ok_or(Error::MultisigNotFrozen)?;
+
+        if self.keys.contains(&public_key) && !self.signed_keys.contains(&public_key) {
+            transaction.sign(key);
+            self.signed_keys.push(public_key);
+        }
+
+        Ok(self)
+    }
+
+    /// Add the signatures from bytes returned by [`sign_bytes`](Self::sign_bytes).
+    ///
+    /// Signatures from keys that aren't part of the key list, or that have already signed, are ignored.
+    ///
+    /// # Errors
+    /// - [`Error::MultisigNotFrozen`] if the flow hasn't been frozen.
+    /// - [`Error::FromProtobuf`] if `bytes` isn't a valid transaction.
+    /// - [`Error::MultisigTransactionMismatch`] if `bytes` is for a different transaction.
+    /// - [`Error::SignatureVerify`] if a signature from one of the keys is invalid.
+    pub fn add_signed_bytes(&mut self, bytes: &[u8]) -> crate::Result<&mut Self> {
+        let signed = AnyTransaction::from_bytes(bytes)?;
+
+        // `from_bytes` always populates the sources.
+        let signed = signed.sources().expect("BUG: parsed transaction had no sources");
+        let signed = signed.signed_transactions();
+
+        let bodies: Vec<Vec<u8>> = self
+            .frozen_transaction()?
+            .make_sources()?
+            .signed_transactions()
+            .iter()
+            .map(|it| it.body_bytes.clone())
+            .collect();
+
+        if bodies.len() != signed.len()
+            || bodies.iter().zip(signed).any(|(body, it)| *body != it.body_bytes)
+        {
+            return Err(Error::MultisigTransactionMismatch);
+        }
+
+        for key in self.keys.clone() {
+            if self.signed_keys.contains(&key) {
+                continue;
+            }
+
+            let Some(signatures) = signatures_for(&key, signed)? else {
+                continue;
+            };
+
+            let bodies = bodies.clone();
+            let signer = AnySigner::arbitrary(Box::new(key), move |body| {
+                bodies
+                    .iter()
+                    .position(|it| it == body)
+                    .map(|index| signatures[index].clone())
+                    .unwrap_or_default()
+            });
+
+            self.frozen_transaction_mut()?.sign_signer(signer);
+            self.signed_keys.push(key);
+        }
+
+        Ok(self)
+    }
+
+    /// Execute the account creation transaction.
+    ///
+    /// # Errors
+    /// - [`Error::MultisigNotFrozen`] if the flow hasn't been frozen.
+    /// - [`Error::MultisigThresholdNotMet`] if fewer than `threshold` key holders have signed.
+    /// - Any error that can occur while executing the transaction.
+    pub async fn execute(&mut self, client: &Client) -> crate::Result<TransactionResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Execute the account creation transaction.
+    ///
+    /// # Errors
+    /// - [`Error::MultisigNotFrozen`] if the flow hasn't been frozen.
+    /// - [`Error::MultisigThresholdNotMet`] if fewer than `threshold` key holders have signed.
+    /// - Any error that can occur while executing the transaction.
+    pub async fn execute_with_timeout(
+        &mut self,
+        client: &Client,
+        timeout: std::time::Duration,
+    ) -> crate::Result<TransactionResponse> {
+        self.execute_with_optional_timeout(client, Some(timeout)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        if !self.is_threshold_met() {
+            return Err(Error::MultisigThresholdNotMet {
+                signatures: self.signed_keys.len(),
+                threshold: self.threshold,
+            });
+        }
+
+        self.frozen_transaction_mut()?.execute_with_optional_timeout(client, timeout).await
+    }
+
+    #[track_caller]
+    fn require_not_frozen(&self) {
+        assert!(self.transaction.is_none(), "flow is immutable once frozen");
+    }
+
+    fn frozen_transaction(&self) -> crate::Result<&AccountCreateTransaction> {
+        self.transaction.as_ref().ok_or(Error::MultisigNotFrozen)
+    }
+
+    fn frozen_transaction_mut(&mut self) -> crate::Result<&mut AccountCreateTransaction> {
+        self.transaction.as_mut().ok_or(Error::MultisigNotFrozen)
+    }
+}
+
+/// Returns `key`'s signature over each of `signed`, or `None` if `key` didn't sign them all.
+///
+/// # Errors
+/// - [`Error::SignatureVerify`] if any of the signatures are invalid.
+fn signatures_for(
+    key: &PublicKey,
+    signed: &[services::SignedTransaction],
+) -> crate::Result<Option<Vec<Vec<u8>>>> {
+    let key_bytes = key.to_bytes_raw();
+
+    let mut signatures = Vec::with_capacity(signed.len());
+
+    for transaction in signed {
+        let signature = transaction
+            .sig_map
+            .iter()
+            .flat_map(|it| &it.sig_pair)
+            .filter(|it| !it.pub_key_prefix.is_empty() && key_bytes.starts_with(&it.pub_key_prefix))
+            .find_map(|it| match &it.signature {
+                Some(
+                    services::signature_pair::Signature::Ed25519(signature)
+                    | services::signature_pair::Signature::EcdsaSecp256k1(signature),
+                ) => Some(signature),
+                _ => None,
+            });
+
+        let Some(signature) = signature else {
+            return Ok(None);
+        };
+
+        key.verify(&transaction.body_bytes, signature)?;
+
+        signatures.push(signature.clone());
+    }
+
+    Ok(Some(signatures))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::MultisigAccountFlow;
+    use crate::transaction::test_helpers::{
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        Key,
+        KeyList,
+        PrivateKey,
+        TransactionId,
+    };
+
+    fn make_keys() -> [PrivateKey; 3] {
+        [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+            PrivateKey::generate_ed25519(),
+        ]
+    }
+
+    fn make_flow(keys: &[PrivateKey], threshold: u32) -> MultisigAccountFlow {
+        let mut flow = MultisigAccountFlow::new();
+
+        flow.node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .transaction_id(TEST_TX_ID)
+            .keys(keys.iter().map(PrivateKey::public_key))
+            .threshold(threshold)
+            .freeze_with(None)
+            .unwrap();
+
+        flow
+    }
+
+    #[test]
+    fn key_list() {
+        let keys = make_keys();
+        let flow = make_flow(&keys, 2);
+
+        let mut expected = KeyList::with_threshold(2);
+        expected.extend(keys.iter().map(|it| Key::Single(it.public_key())));
+
+        assert_eq!(flow.get_key_list(), expected);
+    }
+
+    #[test]
+    fn add_signed_bytes() {
+        let keys = make_keys();
+        let mut flow = make_flow(&keys, 2);
+
+        let bytes = flow.to_bytes().unwrap();
+
+        flow.add_signed_bytes(&MultisigAccountFlow::sign_bytes(&bytes, &keys[0]).unwrap()).unwrap();
+
+        assert_eq!(flow.get_signed_keys(), &[keys[0].public_key()]);
+        assert!(!flow.is_threshold_met());
+
+        flow.add_signed_bytes(&MultisigAccountFlow::sign_bytes(&bytes, &keys[1]).unwrap()).unwrap();
+
+        assert_eq!(flow.get_signed_keys(), &[keys[0].public_key(), keys[1].public_key()]);
+        assert!(flow.is_threshold_met());
+
+        // the collected signatures make it into the submitted transaction for every node.
+        let mut merged = MultisigAccountFlow::new();
+        merged
+            .node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .transaction_id(TEST_TX_ID)
+            .keys(keys.iter().map(PrivateKey::public_key))
+            .threshold(2)
+            .freeze_with(None)
+            .unwrap()
+            .add_signed_bytes(&flow.to_bytes().unwrap())
+            .unwrap();
+
+        assert_eq!(merged.get_signed_keys(), flow.get_signed_keys());
+    }
+
+    #[test]
+    fn add_signed_bytes_with_several_signatures() {
+        let keys = make_keys();
+        let mut flow = make_flow(&keys, 3);
+
+        let bytes = flow.to_bytes().unwrap();
+        let bytes = MultisigAccountFlow::sign_bytes(&bytes, &keys[2]).unwrap();
+        let bytes = MultisigAccountFlow::sign_bytes(&bytes, &keys[1]).unwrap();
+
+        flow.sign(keys[0].clone()).unwrap().add_signed_bytes(&bytes).unwrap();
+
+        assert_eq!(flow.get_signed_keys().len(), 3);
+        assert!(flow.is_threshold_met());
+    }
+
+    #[test]
+    fn duplicate_signatures_count_once() {
+        let keys = make_keys();
+        let mut flow = make_flow(&keys, 2);
+
+        let bytes = MultisigAccountFlow::sign_bytes(&flow.to_bytes().unwrap(), &keys[0]).unwrap();
+
+        flow.add_signed_bytes(&bytes).unwrap().add_signed_bytes(&bytes).unwrap();
+        flow.sign(keys[0].clone()).unwrap();
+
+        assert_eq!(flow.get_signed_keys(), &[keys[0].public_key()]);
+        assert!(!flow.is_threshold_met());
+    }
+
+    #[test]
+    fn ignores_keys_outside_key_list() {
+        let keys = make_keys();
+        let mut flow = make_flow(&keys, 1);
+
+        let outsider = PrivateKey::generate_ed25519();
+        let bytes = MultisigAccountFlow::sign_bytes(&flow.to_bytes().unwrap(), &outsider).unwrap();
+
+        flow.add_signed_bytes(&bytes).unwrap().sign(outsider).unwrap();
+
+        assert!(flow.get_signed_keys().is_empty());
+        assert!(!flow.is_threshold_met());
+    }
+
+    #[test]
+    fn rejects_bytes_for_another_transaction() {
+        let keys = make_keys();
+        let mut flow = make_flow(&keys, 2);
+
+        let mut other = MultisigAccountFlow::new();
+        other
+            .node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 5006)))
+            .keys(keys.iter().map(PrivateKey::public_key))
+            .threshold(2)
+            .freeze_with(None)
+            .unwrap();
+
+        let bytes = MultisigAccountFlow::sign_bytes(&other.to_bytes().unwrap(), &keys[0]).unwrap();
+
+        assert_matches!(flow.add_signed_bytes(&bytes), Err(Error::MultisigTransactionMismatch));
+        assert!(flow.get_signed_keys().is_empty());
+    }
+
+    #[test]
+    fn sign_bytes_rejects_other_transaction_kinds() {
+        let mut tx = crate::AccountDeleteTransaction::new_for_tests();
+        tx.account_id(AccountId::new(0, 0, 5007))
+            .transfer_account_id(AccountId::new(0, 0, 9))
+            .freeze()
+            .unwrap();
+
+        assert_matches!(
+            MultisigAccountFlow::sign_bytes(
+                &tx.to_bytes().unwrap(),
+                &PrivateKey::generate_ed25519()
+            ),
+            Err(Error::MultisigTransactionMismatch)
+        );
+    }
+
+    #[test]
+    fn freeze_without_payer() {
+        let keys = make_keys();
+        let mut flow = MultisigAccountFlow::new();
+
+        flow.node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .keys(keys.iter().map(PrivateKey::public_key))
+            .threshold(2);
+
+        assert_matches!(flow.freeze_with(None), Err(Error::NoPayerAccountOrTransactionId));
+    }
+
+    #[test]
+    fn freeze_threshold_too_high() {
+        let keys = make_keys();
+        let mut flow = MultisigAccountFlow::new();
+
+        flow.transaction_id(TEST_TX_ID).keys(keys.iter().map(PrivateKey::public_key)).threshold(4);
+
+        assert_matches!(
+            flow.freeze_with(None),
+            Err(Error::MultisigInvalidThreshold { threshold: 4, keys: 3 })
+        );
+    }
+
+    #[test]
+    fn freeze_zero_threshold() {
+        let keys = make_keys();
+        let mut flow = MultisigAccountFlow::new();
+
+        flow.transaction_id(TEST_TX_ID).keys(keys.iter().map(PrivateKey::public_key)).threshold(0);
+
+        assert_matches!(
+            flow.freeze_with(None),
+            Err(Error::MultisigInvalidThreshold { threshold: 0, keys: 3 })
+        );
+    }
+
+    #[test]
+    fn not_frozen() {
+        let keys = make_keys();
+        let mut flow = MultisigAccountFlow::new();
+
+        flow.keys(keys.iter().map(PrivateKey::public_key)).threshold(2);
+
+        assert_matches!(flow.to_bytes(), Err(Error::MultisigNotFrozen));
+        assert_matches!(flow.sign(keys[0].clone()), Err(Error::MultisigNotFrozen));
+    }
+}
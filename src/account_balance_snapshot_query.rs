@@ -0,0 +1,240 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    get_i64,
+    get_str,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    TokenId,
+};
+
+/// Query the balances of an account from the mirror node REST API, optionally as of a past consensus time.
+///
+/// Unlike [`AccountBalanceQuery`](crate::AccountBalanceQuery), which only returns the current balance,
+/// this can answer "what was the balance at `T`", which is useful for reconciliation.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountBalanceSnapshotQuery, AccountId, Client};
+/// use time::OffsetDateTime;
+///
+/// let client = Client::for_mainnet();
+///
+/// let balance = AccountBalanceSnapshotQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     // 2024-01-01 00:00:00 UTC
+///     .as_of(OffsetDateTime::from_unix_timestamp(1_704_067_200).unwrap())
+///     .execute(&client)
+///     .await?;
+///
+/// println!("{} at {}", balance.hbars, balance.timestamp);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AccountBalanceSnapshotQuery {
+    account_id: Option<AccountId>,
+    as_of: Option<OffsetDateTime>,
+}
+
+/// The balances of an account at a point in time, as returned by [`AccountBalanceSnapshotQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountBalanceSnapshot {
+    /// The account the balances are for.
+    pub account_id: AccountId,
+
+    /// The hbar balance of the account.
+    pub hbars: Hbar,
+
+    /// The token balances of the account, in the smallest denomination of each token.
+    pub tokens: HashMap<TokenId, u64>,
+
+    /// The consensus time the balances are as of.
+    ///
+    /// The mirror node takes balance snapshots periodically,
+    /// so this may be earlier than the time that was asked for.
+    pub timestamp: OffsetDateTime,
+}
+
+impl AccountBalanceSnapshot {
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        let balance = match json.get("balances").and_then(Value::as_array) {
+            Some(balances) => balances.first().ok_or_else(|| {
+                Error::mirror_rest("no balance found for the account at the requested time")
+            })?,
+            None => return Err(Error::mirror_rest("response is missing `balances`")),
+        };
+
+        let tokens = match balance.get("tokens").and_then(Value::as_array) {
+            Some(tokens) => tokens
+                .iter()
+                .map(|it| {
+                    let amount =
+                        u64::try_from(get_i64(it, "balance")?).map_err(Error::mirror_rest)?;
+
+                    Ok((get_str(it, "token_id")?.parse()?, amount))
+                })
+                .collect::<crate::Result<_>>()?,
+            None => HashMap::new(),
+        };
+
+        let timestamp = mirror_rest::parse_timestamp(get_str(json, "timestamp")?)?;
+
+        Ok(Self {
+            account_id: get_str(balance, "account")?.parse()?,
+            hbars: Hbar::from_tinybars(get_i64(balance, "balance")?),
+            tokens,
+            timestamp,
+        })
+    }
+}
+
+impl AccountBalanceSnapshotQuery {
+    /// Create a new `AccountBalanceSnapshotQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to query the balances of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to query the balances of.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the consensus time to query the balances as of.
+    #[must_use]
+    pub fn get_as_of(&self) -> Option<OffsetDateTime> {
+        self.as_of
+    }
+
+    /// Sets the consensus time to query the balances as of.
+    ///
+    /// If this isn't set, the latest balances are returned.
+    pub fn as_of(&mut self, timestamp: OffsetDateTime) -> &mut Self {
+        self.as_of = Some(timestamp);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails, its response couldn't be understood,
+    ///   or the mirror node has no balance for the account at the requested time.
+    /// - [`Error::MissingField`] if `account_id` wasn't set.
+    pub async fn execute(&self, client: &Client) -> crate::Result<AccountBalanceSnapshot> {
+        let account_id = self.account_id.ok_or(Error::MissingField { field: "account_id" })?;
+
+        let json = mirror_rest::get(client, &self.path_and_query(account_id)).await?;
+
+        AccountBalanceSnapshot::from_json(&json)
+    }
+
+    fn path_and_query(&self, account_id: AccountId) -> String {
+        let mut path = format!("/api/v1/balances?account.id={account_id}&limit=1");
+
+        if let Some(as_of) = self.as_of {
+            path.push_str(&format!("&timestamp=lte:{}", mirror_rest::format_timestamp(as_of)));
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::AccountBalanceSnapshot;
+    use crate::{
+        AccountBalanceSnapshotQuery,
+        AccountId,
+        Hbar,
+        TokenId,
+    };
+
+    #[test]
+    fn path_and_query() {
+        let mut query = AccountBalanceSnapshotQuery::new();
+
+        expect!["/api/v1/balances?account.id=0.0.1001&limit=1"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+
+        query.as_of(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap());
+
+        expect!["/api/v1/balances?account.id=0.0.1001&limit=1&timestamp=lte:1700000000.000000000"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn snapshot_from_json() {
+        let json = json!({
+            "timestamp": "1699999200.000000001",
+            "balances": [{
+                "account": "0.0.1001",
+                "balance": 1_000,
+                "tokens": [{ "token_id": "0.0.2000", "balance": 5 }]
+            }],
+            "links": { "next": null }
+        });
+
+        let snapshot = AccountBalanceSnapshot::from_json(&json).unwrap();
+
+        assert_eq!(snapshot.account_id, AccountId::new(0, 0, 1001));
+        assert_eq!(snapshot.hbars, Hbar::from_tinybars(1_000));
+        assert_eq!(snapshot.tokens, [(TokenId::new(0, 0, 2000), 5)].into_iter().collect());
+        assert_eq!(
+            snapshot.timestamp,
+            OffsetDateTime::from_unix_timestamp_nanos(1_699_999_200_000_000_001).unwrap()
+        );
+    }
+
+    #[test]
+    fn snapshot_from_json_missing_account() {
+        let json = json!({
+            "timestamp": null,
+            "balances": [],
+            "links": { "next": null }
+        });
+
+        assert!(AccountBalanceSnapshot::from_json(&json).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::{
+    future,
+    stream,
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    get_i64,
+    get_str,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    MirrorQueryStream,
+    TokenId,
+};
+
+/// Query the tokens associated with an account from the mirror node REST API.
+///
+/// Unlike [`AccountInfo`](crate::AccountInfo), which no longer lists an account's tokens,
+/// this returns every token relationship of the account along with its balance and status,
+/// ordered by token ID.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, AccountTokenRelationshipsQuery, Client};
+///
+/// let client = Client::for_testnet();
+///
+/// let relationships = AccountTokenRelationshipsQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     .execute(&client)
+///     .await?;
+///
+/// for it in relationships {
+///     println!("{},{},{:?}", it.token_id, it.balance, it.freeze_status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AccountTokenRelationshipsQuery {
+    account_id: Option<AccountId>,
+    token_id: Option<TokenId>,
+    limit: Option<usize>,
+}
+
+/// The relationship between an account and a token, as returned by [`AccountTokenRelationshipsQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTokenRelationship {
+    /// The token.
+    pub token_id: TokenId,
+
+    /// The account's balance of the token, in the token's smallest denomination.
+    ///
+    /// For non-fungible tokens, this is the number of NFTs the account owns.
+    pub balance: u64,
+
+    /// The number of decimal places of the token.
+    pub decimals: u32,
+
+    /// Whether the account has been granted KYC for the token.
+    ///
+    /// `None` if the token has no KYC key.
+    pub kyc_status: Option<bool>,
+
+    /// Whether the account is frozen for the token.
+    ///
+    /// `None` if the token has no freeze key.
+    pub freeze_status: Option<bool>,
+
+    /// Whether the token was associated automatically, by a transfer, rather than by a
+    /// [`TokenAssociateTransaction`](crate::TokenAssociateTransaction).
+    pub automatic_association: bool,
+
+    /// The consensus time the account was associated with the token.
+    pub created_timestamp: OffsetDateTime,
+}
+
+impl AccountTokenRelationship {
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        let balance = get_i64(json, "balance")?;
+        let decimals = get_i64(json, "decimals")?;
+
+        Ok(Self {
+            token_id: get_str(json, "token_id")?.parse()?,
+            balance: u64::try_from(balance).map_err(Error::mirror_rest)?,
+            decimals: u32::try_from(decimals).map_err(Error::mirror_rest)?,
+            kyc_status: parse_status(get_str(json, "kyc_status")?, "GRANTED", "REVOKED")?,
+            freeze_status: parse_status(get_str(json, "freeze_status")?, "FROZEN", "UNFROZEN")?,
+            automatic_association: json
+                .get("automatic_association")
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+            created_timestamp: mirror_rest::parse_timestamp(get_str(json, "created_timestamp")?)?,
+        })
+    }
+}
+
+/// Parses a mirror node status that's either `on`, `off`, or `NOT_APPLICABLE`.
+fn parse_status(s: &str, on: &str, off: &str) -> crate::Result<Option<bool>> {
+    match s {
+        "NOT_APPLICABLE" => Ok(None),
+        _ if s == on => Ok(Some(true)),
+        _ if s == off => Ok(Some(false)),
+        _ => Err(Error::mirror_rest(format!("unknown status `{s}`"))),
+    }
+}
+
+impl AccountTokenRelationshipsQuery {
+    /// Create a new `AccountTokenRelationshipsQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to query the token relationships of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to query the token relationships of.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the token to return the relationship with, if only one is wanted.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to return the relationship with, if only one is wanted.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the maximum number of relationships to return.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the maximum number of relationships to return.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `account_id` wasn't set.
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails, or its response couldn't be understood.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<AccountTokenRelationship>> {
+        self.relationships(client).try_collect().await
+    }
+
+    fn relationships<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Stream<Item = crate::Result<AccountTokenRelationship>> + 'a {
+        let Some(account_id) = self.account_id else {
+            let error = Error::MissingField { field: "account_id" };
+
+            return stream::once(future::ready(Err(error))).left_stream();
+        };
+
+        mirror_rest::stream_all(
+            client,
+            self.path_and_query(account_id),
+            "tokens",
+            self.limit,
+            |_| true,
+        )
+        .map(|it| it.and_then(|it| AccountTokenRelationship::from_json(&it)))
+        .right_stream()
+    }
+
+    fn path_and_query(&self, account_id: AccountId) -> String {
+        let mut path = format!("/api/v1/accounts/{account_id}/tokens?order=asc&limit=100");
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        path
+    }
+}
+
+impl MirrorQueryStream for AccountTokenRelationshipsQuery {
+    type Item = AccountTokenRelationship;
+
+    /// # Errors
+    /// - The stream yields [`Error::MissingField`] if `account_id` wasn't set.
+    fn stream<'a>(
+        &self,
+        client: &'a Client,
+    ) -> BoxStream<'a, crate::Result<AccountTokenRelationship>> {
+        let stream = self.relationships(client);
+
+        // the mirror node REST client isn't `Send` on wasm.
+        #[cfg(target_arch = "wasm32")]
+        let stream = send_wrapper::SendWrapper::new(stream);
+
+        Box::pin(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::AccountTokenRelationship;
+    use crate::{
+        AccountId,
+        AccountTokenRelationshipsQuery,
+        Client,
+        Error,
+        TokenId,
+    };
+
+    #[tokio::test]
+    async fn missing_account_id() {
+        let client = Client::for_testnet();
+
+        let res = AccountTokenRelationshipsQuery::new().execute(&client).await;
+
+        assert_matches!(res, Err(Error::MissingField { field: "account_id" }));
+    }
+
+    #[test]
+    fn path_and_query() {
+        let mut query = AccountTokenRelationshipsQuery::new();
+
+        query.token_id(TokenId::new(0, 0, 5005));
+
+        expect!["/api/v1/accounts/0.0.1001/tokens?order=asc&limit=100&token.id=0.0.5005"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn relationship_from_json() {
+        let json = json!({
+            "automatic_association": true,
+            "balance": 1_500,
+            "created_timestamp": "1700000001.000000002",
+            "decimals": 2,
+            "freeze_status": "UNFROZEN",
+            "kyc_status": "NOT_APPLICABLE",
+            "token_id": "0.0.5005"
+        });
+
+        assert_eq!(
+            AccountTokenRelationship::from_json(&json).unwrap(),
+            AccountTokenRelationship {
+                token_id: TokenId::new(0, 0, 5005),
+                balance: 1_500,
+                decimals: 2,
+                kyc_status: None,
+                freeze_status: Some(false),
+                automatic_association: true,
+                created_timestamp: OffsetDateTime::from_unix_timestamp_nanos(
+                    1_700_000_001_000_000_002
+                )
+                .unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn relationship_from_json_unknown_status_fails() {
+        let json = json!({
+            "balance": 0,
+            "created_timestamp": "1700000001.000000002",
+            "decimals": 0,
+            "freeze_status": "SOMETIMES",
+            "kyc_status": "GRANTED",
+            "token_id": "0.0.5005"
+        });
+
+        assert!(AccountTokenRelationship::from_json(&json).is_err());
+    }
+}
@@ -22,7 +22,6 @@ use std::net::Ipv4Addr;
 
 use hedera_proto::services;
 use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
@@ -35,6 +34,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -0,0 +1,299 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! A blocking (synchronous) API for applications that don't want to run their own async runtime.
+//!
+//! A [`Client`] here owns a small tokio runtime, and every call blocks the current thread until it's done.
+//!
+//! These must *not* be used from within an async runtime, [`Client::block_on`] panics there.
+//!
+//! # Examples
+//! ```no_run
+//! # fn main() -> hedera::Result<()> {
+//! use hedera::blocking::Client;
+//! use hedera::{
+//!     AccountBalanceQuery,
+//!     AccountId,
+//! };
+//!
+//! let client = Client::for_testnet();
+//!
+//! let balance =
+//!     client.execute(AccountBalanceQuery::new().account_id(AccountId::new(0, 0, 1001)))?;
+//!
+//! println!("balance = {}", balance.hbars);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use futures_core::future::LocalBoxFuture;
+use tokio::runtime::Runtime;
+use triomphe::Arc;
+
+use crate::query::QueryExecute;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    PrivateKey,
+    Query,
+    Transaction,
+    TransactionReceipt,
+    TransactionRecord,
+    TransactionResponse,
+};
+
+/// A blocking wrapper around [`crate::Client`].
+///
+/// Clones share both the underlying client and the runtime.
+#[derive(Clone)]
+pub struct Client {
+    // declared first, so that it's dropped while the runtime is still around.
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Starts a new runtime and runs `make` within it.
+    fn enter<T>(make: impl FnOnce() -> T) -> (Arc<Runtime>, T) {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("hedera-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start the tokio runtime");
+
+        // the client spawns its network update task on construction, which needs a runtime.
+        let value = {
+            let _guard = runtime.enter();
+            make()
+        };
+
+        (Arc::new(runtime), value)
+    }
+
+    fn new(make: impl FnOnce() -> crate::Client) -> Self {
+        let (runtime, inner) = Self::enter(make);
+
+        Self { inner, runtime }
+    }
+
+    fn try_new(make: impl FnOnce() -> crate::Result<crate::Client>) -> crate::Result<Self> {
+        let (runtime, inner) = Self::enter(make);
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    /// Construct a Hedera client pre-configured for mainnet access.
+    ///
+    /// # Panics
+    /// If the runtime can't be started.
+    #[must_use]
+    pub fn for_mainnet() -> Self {
+        Self::new(crate::Client::for_mainnet)
+    }
+
+    /// Construct a Hedera client pre-configured for testnet access.
+    ///
+    /// # Panics
+    /// If the runtime can't be started.
+    #[must_use]
+    pub fn for_testnet() -> Self {
+        Self::new(crate::Client::for_testnet)
+    }
+
+    /// Construct a Hedera client pre-configured for previewnet access.
+    ///
+    /// # Panics
+    /// If the runtime can't be started.
+    #[must_use]
+    pub fn for_previewnet() -> Self {
+        Self::new(crate::Client::for_previewnet)
+    }
+
+    /// Construct a client with the given nodes configured.
+    ///
+    /// See [`crate::Client::for_network`].
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if an error occurs parsing the configuration.
+    ///
+    /// # Panics
+    /// If the runtime can't be started.
+    pub fn for_network(network: HashMap<String, AccountId>) -> crate::Result<Self> {
+        Self::try_new(|| crate::Client::for_network(network))
+    }
+
+    /// Construct a hedera client pre-configured for access to the given network.
+    ///
+    /// See [`crate::Client::for_name`].
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the network name is not a supported network name.
+    ///
+    /// # Panics
+    /// If the runtime can't be started.
+    pub fn for_name(name: &str) -> crate::Result<Self> {
+        Self::try_new(|| crate::Client::for_name(name))
+    }
+
+    /// Returns the underlying async client, for configuration not covered by this wrapper.
+    #[must_use]
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Sets the account that will, by default, be paying for transactions and queries built with this client.
+    ///
+    /// See [`crate::Client::set_operator`].
+    pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
+        self.inner.set_operator(id, key);
+    }
+
+    /// Runs `future` on this client's runtime, blocking until it completes.
+    ///
+    /// This is the escape hatch for anything this wrapper doesn't cover.
+    ///
+    /// # Panics
+    /// If called from within an async runtime.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Execute `executable` (a transaction or query) using this client, blocking until it completes.
+    ///
+    /// # Errors
+    /// The same as the `execute` function of `E`.
+    ///
+    /// # Panics
+    /// If called from within an async runtime.
+    pub fn execute<E: Execute>(&self, executable: &mut E) -> crate::Result<E::Response> {
+        self.block_on(executable.execute_async(&self.inner))
+    }
+
+    /// Get the receipt of `response`, blocking until it's available.
+    ///
+    /// See [`TransactionResponse::get_receipt`].
+    ///
+    /// # Errors
+    /// The same as [`TransactionResponse::get_receipt`].
+    ///
+    /// # Panics
+    /// If called from within an async runtime.
+    pub fn get_receipt(&self, response: &TransactionResponse) -> crate::Result<TransactionReceipt> {
+        self.block_on(response.get_receipt(&self.inner))
+    }
+
+    /// Get the record of `response`, blocking until it's available.
+    ///
+    /// See [`TransactionResponse::get_record`].
+    ///
+    /// # Errors
+    /// The same as [`TransactionResponse::get_record`].
+    ///
+    /// # Panics
+    /// If called from within an async runtime.
+    pub fn get_record(&self, response: &TransactionResponse) -> crate::Result<TransactionRecord> {
+        self.block_on(response.get_record(&self.inner))
+    }
+}
+
+/// Something that can be executed by a blocking [`Client`], IE, a [`Transaction`] or [`Query`].
+pub trait Execute: sealed::Sealed {
+    /// What executing this returns.
+    type Response;
+
+    #[doc(hidden)]
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>>;
+}
+
+impl<D: TransactionExecute> Execute for Transaction<D> {
+    type Response = TransactionResponse;
+
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+        Box::pin(self.execute(client))
+    }
+}
+
+impl<D: QueryExecute> Execute for Query<D> {
+    type Response = D::Response;
+
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+        Box::pin(self.execute(client))
+    }
+}
+
+mod sealed {
+    use crate::query::QueryExecute;
+    use crate::transaction::TransactionExecute;
+    use crate::{
+        Query,
+        Transaction,
+    };
+
+    pub trait Sealed {}
+
+    impl<D: TransactionExecute> Sealed for Transaction<D> {}
+    impl<D: QueryExecute> Sealed for Query<D> {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
+
+    use super::Client;
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        Error,
+    };
+
+    #[test]
+    fn for_name() {
+        let client = Client::for_name("localhost").unwrap();
+
+        assert_eq!(client.as_async().mirror_network(), ["127.0.0.1:5600".to_owned()]);
+        assert_matches!(Client::for_name("nope").err(), Some(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn execute_without_nodes() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.as_async().set_max_attempts(2);
+
+        // no nodes means there's nothing to execute *on*, but that shouldn't block forever (or panic) either.
+        let res = client.execute(AccountBalanceQuery::new().account_id(AccountId::new(0, 0, 3)));
+
+        assert_matches!(res, Err(Error::NoHealthyNodes));
+    }
+}
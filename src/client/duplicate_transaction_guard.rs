@@ -0,0 +1,140 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use triomphe::Arc;
+
+use crate::runtime::Instant;
+use crate::TransactionId;
+
+/// Remembers the IDs of recently executed transactions for a while,
+/// so that executing the same transaction again is caught before it's sent.
+#[derive(Debug)]
+pub(crate) struct DuplicateTransactionGuard {
+    ttl: Duration,
+    transaction_ids: Mutex<HashMap<TransactionId, Instant>>,
+}
+
+impl DuplicateTransactionGuard {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self { ttl, transaction_ids: Mutex::new(HashMap::new()) }
+    }
+
+    pub(crate) fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Reserves `transaction_id`, or returns `None` if it was reserved less than `ttl` ago.
+    ///
+    /// Takes the guard by `Arc` since the reservation keeps it alive to release the ID.
+    pub(crate) fn reserve(
+        guard: &Arc<Self>,
+        transaction_id: TransactionId,
+    ) -> Option<TransactionIdReservation> {
+        let mut transaction_ids = guard.transaction_ids.lock();
+
+        // expired IDs would otherwise pile up forever.
+        transaction_ids.retain(|_, reserved_at| reserved_at.elapsed() < guard.ttl);
+
+        match transaction_ids.entry(transaction_id) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                entry.insert(Instant::now());
+                Some(TransactionIdReservation(Some((Arc::clone(guard), transaction_id))))
+            }
+        }
+    }
+
+    fn release(&self, transaction_id: TransactionId) {
+        self.transaction_ids.lock().remove(&transaction_id);
+    }
+}
+
+/// A transaction ID reserved with a [`DuplicateTransactionGuard`].
+///
+/// Dropping it releases the ID again (the transaction was never sent, or failed), unless it was [`commit`](Self::commit)ted.
+#[must_use]
+#[derive(Debug)]
+pub(crate) struct TransactionIdReservation(Option<(Arc<DuplicateTransactionGuard>, TransactionId)>);
+
+impl TransactionIdReservation {
+    /// A reservation of nothing, for when there's no guard.
+    pub(crate) const fn none() -> Self {
+        Self(None)
+    }
+
+    /// Keeps the ID reserved until it expires.
+    pub(crate) fn commit(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for TransactionIdReservation {
+    fn drop(&mut self) {
+        if let Some((guard, transaction_id)) = self.0.take() {
+            guard.release(transaction_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use triomphe::Arc;
+
+    use super::DuplicateTransactionGuard;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+
+    #[test]
+    fn reserve_twice() {
+        let guard = Arc::new(DuplicateTransactionGuard::new(Duration::from_secs(60)));
+
+        let reservation = DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).unwrap();
+
+        assert!(DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).is_none());
+
+        reservation.commit();
+
+        assert!(DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).is_none());
+    }
+
+    #[test]
+    fn dropped_reservation_is_released() {
+        let guard = Arc::new(DuplicateTransactionGuard::new(Duration::from_secs(60)));
+
+        drop(DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).unwrap());
+
+        assert!(DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).is_some());
+    }
+
+    #[test]
+    fn expired() {
+        let guard = Arc::new(DuplicateTransactionGuard::new(Duration::ZERO));
+
+        DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).unwrap().commit();
+
+        assert!(DuplicateTransactionGuard::reserve(&guard, TEST_TX_ID).is_some());
+    }
+}
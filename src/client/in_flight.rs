@@ -0,0 +1,97 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use tokio::sync::Notify;
+
+/// Counts the requests a client is executing, so that shutting down can wait for them.
+#[derive(Default)]
+pub(crate) struct InFlight {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+impl InFlight {
+    /// Counts a request as in flight until the returned guard is dropped.
+    pub(crate) fn enter(&self) -> InFlightGuard<'_> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        InFlightGuard(self)
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Waits until no requests are in flight.
+    pub(crate) async fn wait_idle(&self) {
+        loop {
+            // register interest *before* checking, otherwise the last request could finish in between.
+            let notified = self.idle.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.count() == 0 {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+}
+
+pub(crate) struct InFlightGuard<'a>(&'a InFlight);
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::InFlight;
+
+    #[tokio::test]
+    async fn wait_idle() {
+        let in_flight = InFlight::default();
+
+        // nothing in flight, nothing to wait for.
+        in_flight.wait_idle().await;
+
+        let guard = in_flight.enter();
+        assert_eq!(in_flight.count(), 1);
+
+        let res = tokio::time::timeout(Duration::from_millis(10), in_flight.wait_idle()).await;
+        assert!(res.is_err());
+
+        let ((), ()) = tokio::join!(in_flight.wait_idle(), async move { drop(guard) });
+
+        assert_eq!(in_flight.count(), 0);
+    }
+}
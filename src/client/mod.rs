@@ -22,6 +22,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 use std::num::{
+    NonZeroU32,
     NonZeroU64,
     NonZeroUsize,
 };
@@ -36,33 +37,95 @@ pub(crate) use network::{
     Network,
     NetworkData,
 };
-pub(crate) use operator::Operator;
+pub use operator::{
+    Operator,
+    OperatorSelection,
+    OperatorSpend,
+};
 use parking_lot::RwLock;
+use time::OffsetDateTime;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
+pub(crate) use self::duplicate_transaction_guard::{
+    DuplicateTransactionGuard,
+    TransactionIdReservation,
+};
+pub use self::endpoint_config::EndpointConfig;
+use self::in_flight::InFlight;
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
+pub use self::network_snapshot::{
+    NetworkSnapshot,
+    NodeSnapshot,
+};
+pub use self::node_selector::{
+    LowestLatencyNodeSelector,
+    NodeCandidate,
+    NodeSelector,
+    RandomNodeSelector,
+    RoundRobinNodeSelector,
+    StickyNodeSelector,
+};
+use self::operator::OperatorPool;
+use self::payer_balance_guard::PayerBalanceGuard;
+pub(crate) use self::query_cost_cache::QueryCostCache;
+pub(crate) use self::receipt_throttle::ReceiptThrottle;
+use self::submission_throttle::SubmissionThrottle;
+pub use self::submission_throttle::ThrottleMode;
+use self::token_metadata_cache::TokenMetadataCache;
+use crate::entity_id::PartialEntityId;
 use crate::ping_query::PingQuery;
+use crate::runtime::Instant;
 use crate::signer::AnySigner;
+use crate::system_files::ThrottleDefinitions;
 use crate::{
+    runtime,
+    AccountBalanceQuery,
     AccountId,
     ArcSwapOption,
+    EntityId,
     Error,
+    FileContentsQuery,
+    FileId,
     Hbar,
     LedgerId,
     NodeAddressBook,
     NodeAddressBookQuery,
     PrivateKey,
     PublicKey,
+    RetryEvent,
+    ScheduleId,
+    ScheduleInfo,
+    ScheduleInfoQuery,
+    ScheduleNotExecutedReason,
+    Status,
+    TokenId,
+    TokenInfo,
+    TokenInfoQuery,
+    TransactionId,
 };
 
 #[cfg(feature = "serde")]
 mod config;
 
+mod duplicate_transaction_guard;
+mod endpoint_config;
+mod in_flight;
 mod network;
+mod network_snapshot;
+mod node_selector;
 mod operator;
+mod payer_balance_guard;
+mod query_cost_cache;
+mod receipt_throttle;
+mod submission_throttle;
+mod token_metadata_cache;
 
 #[derive(Copy, Clone)]
 pub(crate) struct ClientBackoff {
@@ -97,6 +160,7 @@ struct ClientBuilder {
     regenerate_transaction_ids: bool,
     update_network: bool,
     backoff: ClientBackoff,
+    default_shard_realm: (u64, u64),
 }
 
 impl ClientBuilder {
@@ -112,6 +176,7 @@ impl ClientBuilder {
             regenerate_transaction_ids: true,
             update_network: true,
             backoff: ClientBackoff::default(),
+            default_shard_realm: (0, 0),
         }
     }
 
@@ -134,29 +199,53 @@ impl ClientBuilder {
             regenerate_transaction_ids,
             update_network,
             backoff,
+            default_shard_realm,
         } = self;
 
-        let network_update_tx = match update_network {
-            true => network::managed::spawn_network_update(
-                network.clone(),
-                Some(Duration::from_secs(24 * 60 * 60)),
-            ),
+        let (network_update_tx, network_update_task) = match update_network {
+            true => {
+                let (tx, task) = network::managed::spawn_network_update(
+                    network.clone(),
+                    Some(Duration::from_secs(24 * 60 * 60)),
+                );
+
+                (tx, Some(task))
+            }
             // yeah, we just drop the rx.
-            false => watch::channel(None).0,
+            false => (watch::channel(None).0, None),
         };
 
         Client(Arc::new(ClientInner {
             network,
             operator: ArcSwapOption::new(operator.map(Arc::new)),
+            operators: ArcSwapOption::new(None),
+            operator_selection: RwLock::new(OperatorSelection::default()),
+            operator_spend_handler: RwLock::new(None),
+            retry_handler: RwLock::new(None),
+            node_selector: RwLock::new(None),
+            receipt_throttle: ArcSwapOption::new(None),
+            query_cost_cache: ArcSwapOption::new(None),
+            token_metadata_cache: ArcSwapOption::new(None),
+            submission_throttle: ArcSwapOption::new(None),
+            payer_balance_guard: ArcSwapOption::new(None),
+            duplicate_transaction_guard: ArcSwapOption::new(None),
             max_transaction_fee_tinybar: AtomicU64::new(
                 max_transaction_fee.map_or(0, NonZeroU64::get),
             ),
             max_query_payment_tinybar: AtomicU64::new(max_query_payment.map_or(0, NonZeroU64::get)),
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
+            reject_deprecated_fields: AtomicBool::new(false),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
+            validate_receipts: AtomicBool::new(false),
+            node_affinity: AtomicBool::new(false),
             network_update_tx,
+            network_update_task: parking_lot::Mutex::new(network_update_task),
+            in_flight: InFlight::default(),
+            is_shut_down: AtomicBool::new(false),
             backoff: RwLock::new(backoff),
+            default_shard_realm: RwLock::new(default_shard_realm),
+            default_transaction_valid_duration: RwLock::new(None),
         }))
     }
 }
@@ -164,15 +253,48 @@ impl ClientBuilder {
 struct ClientInner {
     network: ManagedNetwork,
     operator: ArcSwapOption<Operator>,
+    // when set, `operator` is the first of `operators`.
+    operators: ArcSwapOption<OperatorPool>,
+    operator_selection: RwLock<OperatorSelection>,
+    operator_spend_handler: RwLock<Option<OperatorSpendHandler>>,
+    retry_handler: RwLock<Option<RetryHandler>>,
+    node_selector: RwLock<Option<Arc<dyn NodeSelector>>>,
+    receipt_throttle: ArcSwapOption<ReceiptThrottle>,
+    query_cost_cache: ArcSwapOption<QueryCostCache>,
+    token_metadata_cache: ArcSwapOption<TokenMetadataCache>,
+    submission_throttle: ArcSwapOption<SubmissionThrottle>,
+    payer_balance_guard: ArcSwapOption<PayerBalanceGuard>,
+    duplicate_transaction_guard: ArcSwapOption<DuplicateTransactionGuard>,
     max_transaction_fee_tinybar: AtomicU64,
     max_query_payment_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
+    reject_deprecated_fields: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
+    validate_receipts: AtomicBool,
+    node_affinity: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
+    network_update_task: parking_lot::Mutex<Option<runtime::TaskHandle>>,
+    in_flight: InFlight,
+    is_shut_down: AtomicBool,
     backoff: RwLock<ClientBackoff>,
+    default_shard_realm: RwLock<(u64, u64)>,
+    default_transaction_valid_duration: RwLock<Option<time::Duration>>,
+}
+
+impl Drop for ClientInner {
+    fn drop(&mut self) {
+        // the update task only holds onto the network, so it would otherwise outlive the client.
+        if let Some(task) = self.network_update_task.get_mut().take() {
+            task.abort();
+        }
+    }
 }
 
+type OperatorSpendHandler = Arc<dyn Fn(&OperatorSpend) + Send + Sync>;
+
+pub(crate) type RetryHandler = Arc<dyn Fn(&RetryEvent<'_>) + Send + Sync>;
+
 /// Managed client for use on the Hedera network.
 #[derive(Clone)]
 pub struct Client(Arc<ClientInner>);
@@ -306,6 +428,53 @@ impl Client {
         Ok(client)
     }
 
+    /// Construct a client from a snapshot taken with [`network_snapshot`](Self::network_snapshot),
+    /// without fetching the address book.
+    ///
+    /// Nodes that were unhealthy when the snapshot was taken stay unhealthy for the rest of their backoff.
+    ///
+    /// If the snapshot has a mirror network the network auto-updates from it, just like with [`for_mainnet`](Self::for_mainnet),
+    /// otherwise auto-updating is disabled, just like with [`for_network`](Self::for_network).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any node address is unparsable, or if a node appears more than once.
+    pub fn from_network_snapshot(snapshot: &NetworkSnapshot) -> crate::Result<Self> {
+        // a snapshot from the future is treated as brand new.
+        let age = (OffsetDateTime::now_utc() - snapshot.taken_at).try_into().unwrap_or_default();
+
+        let mirror = MirrorNetwork::default();
+        mirror.store(
+            MirrorNetworkData::from_addresses(
+                snapshot.mirror_network.iter().cloned().map(Cow::Owned).collect(),
+            )
+            .into(),
+        );
+
+        let network =
+            ManagedNetwork::new(Network::from_node_snapshots(&snapshot.nodes, age)?, mirror);
+
+        let mut builder = ClientBuilder::new(network).ledger_id(snapshot.ledger_id.clone());
+
+        if snapshot.mirror_network.is_empty() {
+            builder = builder.disable_network_updating();
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Returns a snapshot of this client's network: the nodes, their health and latencies, and the mirror network.
+    ///
+    /// Short lived programs can save it, and construct their next client with [`from_network_snapshot`](Self::from_network_snapshot).
+    #[must_use]
+    pub fn network_snapshot(&self) -> NetworkSnapshot {
+        NetworkSnapshot {
+            ledger_id: self.ledger_id_internal().as_deref().cloned(),
+            taken_at: OffsetDateTime::now_utc(),
+            nodes: self.net().0.load().node_snapshots(Instant::now()),
+            mirror_network: self.mirror_network(),
+        }
+    }
+
     /// Construct a Hedera client pre-configured for mainnet access.
     #[must_use]
     pub fn for_mainnet() -> Self {
@@ -391,6 +560,48 @@ impl Client {
         self.net().0.load().set_min_backoff(min_node_backoff)
     }
 
+    /// Sets how this client picks the nodes it sends requests to.
+    ///
+    /// By default nodes are picked at random, and then the ones that have responded fastest recently are tried first,
+    /// latency-sensitive applications might want [`LowestLatencyNodeSelector`] instead.
+    ///
+    /// Note that transactions pick their nodes when they're frozen,
+    /// the selector then only decides which of those nodes is tried first.
+    pub fn set_node_selector<S: NodeSelector + 'static>(&self, selector: S) {
+        *self.0.node_selector.write() =
+            Some(Arc::new(selector).unsize(Coercion!(to dyn NodeSelector)));
+    }
+
+    /// Removes the selector set by [`set_node_selector`](Self::set_node_selector), going back to the default.
+    pub fn clear_node_selector(&self) {
+        *self.0.node_selector.write() = None;
+    }
+
+    pub(crate) fn node_selector(&self) -> Option<Arc<dyn NodeSelector>> {
+        self.0.node_selector.read().clone()
+    }
+
+    /// Returns the moving average of how long each node takes to respond, including to pings.
+    ///
+    /// Nodes that haven't responded to anything yet are left out.
+    #[must_use]
+    pub fn node_latencies(&self) -> HashMap<AccountId, Duration> {
+        self.net().0.load().node_latencies()
+    }
+
+    /// Returns how this client connects to consensus nodes.
+    #[must_use]
+    pub fn endpoint_config(&self) -> EndpointConfig {
+        EndpointConfig::clone(&self.net().endpoint_config())
+    }
+
+    /// Sets how this client connects to consensus nodes.
+    ///
+    /// This replaces every open connection, requests already in flight finish on the old ones.
+    pub fn set_endpoint_config(&self, config: EndpointConfig) {
+        self.net().set_endpoint_config(config);
+    }
+
     /// Construct a hedera client pre-configured for access to the given network.
     ///
     /// Currently supported network names are `"mainnet"`, `"testnet"`, and `"previewnet"`.
@@ -436,6 +647,94 @@ impl Client {
         self.0.auto_validate_checksums.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if transactions using deprecated fields fail to freeze.
+    #[must_use]
+    pub fn reject_deprecated_fields(&self) -> bool {
+        self.0.reject_deprecated_fields.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable rejecting transactions that use deprecated fields.
+    ///
+    /// When enabled, freezing a transaction with this client (including implicitly, by executing it)
+    /// fails with [`Error::DeprecatedField`] if the transaction sets a field the network has deprecated,
+    /// This helps catch uses of fields that are ignored by the network, ahead of their removal.
+    ///
+    /// The only deprecated fields that can be set are the `proxy_account_id` of
+    /// [`AccountUpdateTransaction`](crate::AccountUpdateTransaction) and [`ContractUpdateTransaction`](crate::ContractUpdateTransaction).
+    /// Deprecated fields of transactions parsed with [`Transaction::from_bytes`](crate::Transaction::from_bytes) aren't checked.
+    ///
+    /// Disabled by default.
+    pub fn set_reject_deprecated_fields(&self, value: bool) {
+        self.0.reject_deprecated_fields.store(value, Ordering::Relaxed);
+    }
+
+    /// Returns the shard used for entity IDs that don't specify one.
+    ///
+    /// This is `0` by default.
+    #[must_use]
+    pub fn default_shard(&self) -> u64 {
+        self.0.default_shard_realm.read().0
+    }
+
+    /// Returns the realm used for entity IDs that don't specify one.
+    ///
+    /// This is `0` by default.
+    #[must_use]
+    pub fn default_realm(&self) -> u64 {
+        self.0.default_shard_realm.read().1
+    }
+
+    /// Sets the shard and realm used for entity IDs that don't specify one.
+    ///
+    /// This affects [`entity_id`](Self::entity_id), [`parse_entity_id`](Self::parse_entity_id),
+    /// and [`alias_account_id`](Self::alias_account_id).
+    pub fn set_default_shard_realm(&self, shard: u64, realm: u64) {
+        *self.0.default_shard_realm.write() = (shard, realm);
+    }
+
+    /// Returns the entity ID for `num` in the default shard and realm.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{AccountId, Client};
+    ///
+    /// let client = Client::for_network(Default::default()).unwrap();
+    /// client.set_default_shard_realm(1, 2);
+    ///
+    /// let account_id: AccountId = client.entity_id(5);
+    /// assert_eq!(account_id, AccountId::new(1, 2, 5));
+    /// ```
+    #[must_use]
+    pub fn entity_id<T>(&self, num: u64) -> T
+    where
+        EntityId: Into<T>,
+    {
+        let (shard, realm) = *self.0.default_shard_realm.read();
+
+        EntityId { shard, realm, num, checksum: None }.into()
+    }
+
+    /// Parse an entity ID, using the default shard and realm if `s` is a bare entity num (IE, `"5"`).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `s` isn't a bare entity num or `<shard>.<realm>.<num>`.
+    pub fn parse_entity_id<T>(&self, s: &str) -> crate::Result<T>
+    where
+        EntityId: Into<T>,
+    {
+        let (shard, realm) = *self.0.default_shard_realm.read();
+
+        PartialEntityId::from_str(s)?.finish_with_shard_realm(shard, realm)
+    }
+
+    /// Returns the alias account ID for `public_key` in the default shard and realm.
+    #[must_use]
+    pub fn alias_account_id(&self, public_key: &PublicKey) -> AccountId {
+        let (shard, realm) = *self.0.default_shard_realm.read();
+
+        public_key.to_account_id(shard, realm)
+    }
+
     /// Returns true if transaction IDs should be automatically regenerated.
     ///
     /// This is `true` by default.
@@ -449,6 +748,43 @@ impl Client {
         self.0.regenerate_transaction_ids.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if [`Transaction::execute`](crate::Transaction::execute) should wait for the receipt
+    /// and fail unless the transaction succeeded.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn default_validate_receipt(&self) -> bool {
+        self.0.validate_receipts.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable waiting for, and validating, the receipt of every executed transaction.
+    ///
+    /// When enabled, a transaction that passes precheck but fails at consensus will cause `execute` to return
+    /// [`Error::ReceiptStatus`](crate::Error::ReceiptStatus), rather than a [`TransactionResponse`](crate::TransactionResponse).
+    pub fn set_default_validate_receipt(&self, value: bool) {
+        self.0.validate_receipts.store(value, Ordering::Relaxed);
+    }
+
+    /// Returns true if receipts and records are asked for from the node that accepted the transaction first.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn node_affinity(&self) -> bool {
+        self.0.node_affinity.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable asking the node that accepted a transaction for its receipt and record before any other node.
+    ///
+    /// Other nodes can lag behind the submitting node, so with affinity a receipt or record tends to be available sooner
+    /// and a transaction's receipt and record are seen by the same node.
+    /// Other nodes are still asked if the submitting node is unhealthy or doesn't respond.
+    ///
+    /// This applies to the [`TransactionResponse`](crate::TransactionResponse) `get_receipt*` and `get_record*` methods,
+    /// and to receipts waited for by [`Transaction::execute`](crate::Transaction::execute).
+    pub fn set_node_affinity(&self, value: bool) {
+        self.0.node_affinity.store(value, Ordering::Relaxed);
+    }
+
     /// Sets the account that will, by default, be paying for transactions and queries built with
     /// this client.
     ///
@@ -457,6 +793,7 @@ impl Client {
     ///
     /// The operator private key is used to sign all transactions executed by this client.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
+        self.0.operators.store(None);
         self.0
             .operator
             .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
@@ -475,12 +812,143 @@ impl Client {
         public_key: PublicKey,
         f: F,
     ) {
+        self.0.operators.store(None);
         self.0.operator.store(Some(Arc::new(Operator {
             account_id: id,
             signer: AnySigner::arbitrary(Box::new(public_key), f),
         })));
     }
 
+    /// Sets the accounts that will, by default, be paying for transactions and queries built with
+    /// this client, replacing any operator.
+    ///
+    /// Each request is paid for (and signed) by one of `operators`, picked according to
+    /// [`operator_selection`](Self::operator_selection), which spreads the load of high-throughput services
+    /// across multiple payer accounts.
+    ///
+    /// Functions that return *the* operator, such as [`get_operator_account_id`](Self::get_operator_account_id),
+    /// return the first of `operators`.
+    ///
+    /// An empty `operators` removes the operator entirely.
+    pub fn set_operators(&self, operators: Vec<Operator>) {
+        let pool = OperatorPool::new(operators).map(Arc::new);
+
+        self.0.operator.store(pool.as_ref().map(|it| it.first().clone()));
+        self.0.operators.store(pool);
+    }
+
+    /// Returns how the payer is picked for each request when the client has multiple operators.
+    #[must_use = "this function has no side-effects"]
+    pub fn operator_selection(&self) -> OperatorSelection {
+        *self.0.operator_selection.read()
+    }
+
+    /// Sets how the payer is picked for each request when the client has multiple operators.
+    pub fn set_operator_selection(&self, selection: OperatorSelection) {
+        *self.0.operator_selection.write() = selection;
+    }
+
+    /// Sets a function to call whenever a transaction paid for by an operator of this client is submitted.
+    ///
+    /// This can be used to keep track of how much each operator is spending.
+    pub fn set_operator_spend_handler<F>(&self, handler: F)
+    where
+        F: Fn(&OperatorSpend) + Send + Sync + 'static,
+    {
+        *self.0.operator_spend_handler.write() =
+            Some(Arc::new(handler).unsize(Coercion!(to dyn Fn(&OperatorSpend) + Send + Sync)));
+    }
+
+    /// Removes the function set by [`set_operator_spend_handler`](Self::set_operator_spend_handler).
+    pub fn clear_operator_spend_handler(&self) {
+        *self.0.operator_spend_handler.write() = None;
+    }
+
+    /// Calls the operator spend handler, if any, if the payer of `transaction_id` is an operator of this client.
+    pub(crate) fn report_operator_spend(
+        &self,
+        transaction_id: TransactionId,
+        max_transaction_fee: Hbar,
+    ) {
+        let account_id = transaction_id.account_id;
+
+        if !self.is_operator(account_id) {
+            return;
+        }
+
+        if let Some(guard) = &*self.0.payer_balance_guard.load() {
+            guard.deduct(account_id, max_transaction_fee);
+        }
+
+        // clone the handler out so that it can't deadlock by setting a new handler.
+        let handler = self.0.operator_spend_handler.read().clone();
+
+        if let Some(handler) = handler {
+            handler(&OperatorSpend { account_id, transaction_id, max_transaction_fee });
+        }
+    }
+
+    fn is_operator(&self, account_id: AccountId) -> bool {
+        match &*self.0.operators.load() {
+            Some(pool) => pool.contains(account_id),
+            None => self.load_operator().as_ref().is_some_and(|it| it.account_id == account_id),
+        }
+    }
+
+    /// Returns how long the payer balance guard trusts an operator balance for,
+    /// or `None` if the guard is disabled.
+    #[must_use]
+    pub fn payer_balance_guard(&self) -> Option<Duration> {
+        self.0.payer_balance_guard.load().as_deref().map(PayerBalanceGuard::max_age)
+    }
+
+    /// Sets whether this client checks that an operator can afford a transaction before submitting it.
+    ///
+    /// When enabled, a transaction whose max transaction fee is more than its operator payer's balance
+    /// fails locally with [`Error::InsufficientPayerBalance`], instead of being rejected by a node with
+    /// [`Status::InsufficientPayerBalance`].
+    ///
+    /// Balances are fetched with a (free) [`AccountBalanceQuery`] and trusted for `max_age`,
+    /// in the meantime every submission is assumed to cost its full max transaction fee.
+    /// Transactions paid for by accounts other than the operators aren't checked.
+    ///
+    /// `None` (the default) disables the guard.
+    pub fn set_payer_balance_guard(&self, max_age: Option<Duration>) {
+        self.0.payer_balance_guard.store(max_age.map(|it| Arc::new(PayerBalanceGuard::new(it))));
+    }
+
+    /// Fails if the payer balance guard is enabled, the payer of `transaction_id` is an operator,
+    /// and it can't afford `max_transaction_fee`.
+    pub(crate) async fn check_payer_balance(
+        &self,
+        transaction_id: TransactionId,
+        max_transaction_fee: Hbar,
+    ) -> crate::Result<()> {
+        let Some(guard) = self.0.payer_balance_guard.load_full() else {
+            return Ok(());
+        };
+
+        let account_id = transaction_id.account_id;
+
+        if !self.is_operator(account_id) {
+            return Ok(());
+        }
+
+        let balance = match guard.cached(account_id) {
+            Some(balance) => balance,
+            None => {
+                let balance =
+                    AccountBalanceQuery::new().account_id(account_id).execute(self).await?.hbars;
+
+                guard.store(account_id, balance);
+
+                balance
+            }
+        };
+
+        PayerBalanceGuard::check(account_id, balance, max_transaction_fee)
+    }
+
     /// Gets a reference to the configured network.
     pub(crate) fn net(&self) -> &Network {
         &self.0.network.primary
@@ -501,6 +969,33 @@ impl Client {
         self.0.max_transaction_fee_tinybar.store(amount.to_tinybars() as u64, Ordering::Relaxed);
     }
 
+    /// Returns the valid duration used for transactions that don't set one.
+    #[must_use]
+    pub fn default_transaction_valid_duration(&self) -> Option<time::Duration> {
+        *self.0.default_transaction_valid_duration.read()
+    }
+
+    /// Sets the valid duration used for transactions that don't set one, when they're frozen with this client.
+    ///
+    /// `None` (the default) means transactions fall back to 120 seconds.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionValidDurationOutOfRange`] if `duration` isn't one the network permits,
+    ///   see [`Transaction::MIN_TRANSACTION_VALID_DURATION`](crate::Transaction::MIN_TRANSACTION_VALID_DURATION)
+    ///   and [`Transaction::MAX_TRANSACTION_VALID_DURATION`](crate::Transaction::MAX_TRANSACTION_VALID_DURATION).
+    pub fn set_default_transaction_valid_duration(
+        &self,
+        duration: Option<time::Duration>,
+    ) -> crate::Result<()> {
+        if let Some(duration) = duration {
+            crate::transaction::validate_transaction_valid_duration(duration)?;
+        }
+
+        *self.0.default_transaction_valid_duration.write() = duration;
+
+        Ok(())
+    }
+
     /// Gets the maximum transaction fee the paying account is willing to pay.
     #[must_use]
     pub fn default_max_transaction_fee(&self) -> Option<Hbar> {
@@ -573,6 +1068,222 @@ impl Client {
         self.0.backoff.write().max_backoff = max_backoff;
     }
 
+    /// Sets a function to call whenever an attempt of a request fails and the request is about to be retried.
+    ///
+    /// This is called for both retries on another node and retries after backing off,
+    /// so it can be used to count retries without enabling logging.
+    ///
+    /// The function is called from within the request, so it should return quickly.
+    pub fn set_retry_handler<F>(&self, handler: F)
+    where
+        F: Fn(&RetryEvent<'_>) + Send + Sync + 'static,
+    {
+        *self.0.retry_handler.write() =
+            Some(Arc::new(handler).unsize(Coercion!(to dyn Fn(&RetryEvent<'_>) + Send + Sync)));
+    }
+
+    /// Removes the function set by [`set_retry_handler`](Self::set_retry_handler).
+    pub fn clear_retry_handler(&self) {
+        *self.0.retry_handler.write() = None;
+    }
+
+    pub(crate) fn retry_handler(&self) -> Option<RetryHandler> {
+        self.0.retry_handler.read().clone()
+    }
+
+    /// Returns the maximum number of receipt queries this client will send per second, across all requests.
+    #[must_use]
+    pub fn max_receipt_queries_per_second(&self) -> Option<NonZeroU32> {
+        self.0.receipt_throttle.load().as_deref().map(ReceiptThrottle::max_queries_per_second)
+    }
+
+    /// Sets the maximum number of receipt queries this client will send per second, across all requests.
+    ///
+    /// When many receipts are pending at once (say, after a bulk mint) this keeps polling from overwhelming the nodes,
+    /// every poll waits its turn, with the receipts that have been waiting the longest going first.
+    ///
+    /// `None` (the default) means there's no limit.
+    pub fn set_max_receipt_queries_per_second(&self, max: Option<NonZeroU32>) {
+        self.0.receipt_throttle.store(max.map(|it| Arc::new(ReceiptThrottle::new(it))));
+    }
+
+    pub(crate) fn receipt_throttle(&self) -> Option<Arc<ReceiptThrottle>> {
+        self.0.receipt_throttle.load_full()
+    }
+
+    /// Returns how long this client remembers the cost of each type of query, or `None` if it doesn't.
+    #[must_use]
+    pub fn query_cost_cache_ttl(&self) -> Option<Duration> {
+        self.0.query_cost_cache.load().as_deref().map(QueryCostCache::ttl)
+    }
+
+    /// Sets how long this client remembers the cost of each type of query.
+    ///
+    /// A query without an explicit [`payment_amount`](crate::Query::payment_amount) normally asks a node for its cost
+    /// before it's executed, doubling its latency. With a cache, a query of a type whose cost was fetched
+    /// less than `ttl` ago is paid for with that cost instead.
+    ///
+    /// Costs are cached per type of query, so for queries whose cost depends on their contents
+    /// the cached cost may be too low, in which case the cost is fetched after all and the query is retried,
+    /// or too high, in which case the query is paid for with the cached cost anyway (still capped by the max query payment).
+    /// Queries that [request a state proof](crate::Query::request_state_proof) always fetch their cost.
+    ///
+    /// `None` (the default) disables the cache. Setting the TTL clears any cached costs.
+    pub fn set_query_cost_cache_ttl(&self, ttl: Option<Duration>) {
+        self.0.query_cost_cache.store(ttl.map(|it| Arc::new(QueryCostCache::new(it))));
+    }
+
+    pub(crate) fn query_cost_cache(&self) -> Option<Arc<QueryCostCache>> {
+        self.0.query_cost_cache.load_full()
+    }
+
+    /// Returns how long this client remembers the info of tokens, or `None` if it doesn't.
+    #[must_use]
+    pub fn token_metadata_cache_ttl(&self) -> Option<Duration> {
+        self.0.token_metadata_cache.load().as_deref().map(TokenMetadataCache::ttl)
+    }
+
+    /// Sets how long this client remembers the info of tokens.
+    ///
+    /// Helpers that only need a token's metadata, such as [`token_decimals`](Self::token_decimals),
+    /// normally execute a [`TokenInfoQuery`] every time. With a cache, a token whose info was fetched
+    /// less than `ttl` ago is answered from the cache instead.
+    ///
+    /// Nothing notices when a token is updated, use [`invalidate_token_metadata`](Self::invalidate_token_metadata)
+    /// after updating a token whose info may be cached.
+    ///
+    /// `None` (the default) disables the cache. Setting the TTL clears any cached info.
+    pub fn set_token_metadata_cache_ttl(&self, ttl: Option<Duration>) {
+        self.0.token_metadata_cache.store(ttl.map(|it| Arc::new(TokenMetadataCache::new(it))));
+    }
+
+    /// Forgets the cached info of `token_id`, if any.
+    pub fn invalidate_token_metadata(&self, token_id: TokenId) {
+        if let Some(cache) = &*self.0.token_metadata_cache.load() {
+            cache.remove(token_id);
+        }
+    }
+
+    /// Forgets the cached info of every token.
+    pub fn clear_token_metadata_cache(&self) {
+        if let Some(cache) = &*self.0.token_metadata_cache.load() {
+            cache.clear();
+        }
+    }
+
+    /// Returns how long this client remembers the IDs of executed transactions, or `None` if it doesn't.
+    #[must_use]
+    pub fn duplicate_transaction_guard_ttl(&self) -> Option<Duration> {
+        self.0.duplicate_transaction_guard.load().as_deref().map(DuplicateTransactionGuard::ttl)
+    }
+
+    /// Sets how long this client remembers the IDs of executed transactions.
+    ///
+    /// With a guard, executing a transaction whose ID was executed with this client less than `ttl` ago
+    /// fails with [`Error::DuplicateTransaction`] instead of being sent again.
+    /// This catches the same frozen transaction being executed twice, say, by retry logic layered on top of the SDK,
+    /// which would otherwise be sent to a node only to fail with [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction).
+    ///
+    /// A transaction that fails to execute can be executed again.
+    /// Use [`Transaction::bypass_duplicate_guard`](crate::Transaction::bypass_duplicate_guard) to deliberately re-execute a transaction.
+    ///
+    /// A transaction ID is only valid for at most 3 minutes, so a longer `ttl` has no benefit.
+    ///
+    /// `None` (the default) disables the guard. Setting the TTL forgets every remembered ID.
+    pub fn set_duplicate_transaction_guard_ttl(&self, ttl: Option<Duration>) {
+        self.0
+            .duplicate_transaction_guard
+            .store(ttl.map(|it| Arc::new(DuplicateTransactionGuard::new(it))));
+    }
+
+    pub(crate) fn duplicate_transaction_guard(&self) -> Option<Arc<DuplicateTransactionGuard>> {
+        self.0.duplicate_transaction_guard.load_full()
+    }
+
+    /// Returns the info of `token_id`, from the token metadata cache if it's enabled and fresh,
+    /// or else with a [`TokenInfoQuery`] (which is then cached).
+    ///
+    /// See [`set_token_metadata_cache_ttl`](Self::set_token_metadata_cache_ttl).
+    ///
+    /// # Errors
+    /// - Any error from the [`TokenInfoQuery`].
+    pub async fn token_info(&self, token_id: TokenId) -> crate::Result<TokenInfo> {
+        let cache = self.0.token_metadata_cache.load_full();
+
+        if let Some(info) = cache.as_deref().and_then(|it| it.get(token_id)) {
+            return Ok(info);
+        }
+
+        let info = TokenInfoQuery::new().token_id(token_id).execute(self).await?;
+
+        if let Some(cache) = cache {
+            cache.insert(info.clone());
+        }
+
+        Ok(info)
+    }
+
+    /// Returns the number of decimals of `token_id`, see [`token_info`](Self::token_info).
+    ///
+    /// # Errors
+    /// - Any error from the [`TokenInfoQuery`].
+    pub async fn token_decimals(&self, token_id: TokenId) -> crate::Result<u32> {
+        Ok(self.token_info(token_id).await?.decimals)
+    }
+
+    /// Returns what this client does with transactions its submission throttle has no capacity for,
+    /// or `None` if submissions aren't throttled.
+    #[must_use]
+    pub fn throttle_mode(&self) -> Option<ThrottleMode> {
+        self.0.submission_throttle.load().as_deref().map(SubmissionThrottle::mode)
+    }
+
+    /// Sets the throttles this client applies to its own transaction submissions.
+    ///
+    /// Every submission needs capacity in each throttle group its [`RequestType`](crate::RequestType) is in,
+    /// when there isn't any it's delayed or rejected according to `mode`.
+    /// This keeps bulk jobs (say, minting thousands of NFTs) from running into `BUSY` and retrying in a hot loop.
+    ///
+    /// The network's own definitions can be loaded with [`update_throttle_definitions`](Self::update_throttle_definitions),
+    /// [`ThrottleDefinitions::conservative`] is a fallback for when they can't be read.
+    ///
+    /// `None` (the default) means submissions aren't throttled.
+    pub fn set_throttle_definitions(
+        &self,
+        definitions: Option<&ThrottleDefinitions>,
+        mode: ThrottleMode,
+    ) {
+        self.0
+            .submission_throttle
+            .store(definitions.map(|it| Arc::new(SubmissionThrottle::new(it, mode))));
+    }
+
+    /// Reads the network's throttle definitions from [`FileId::THROTTLE_DEFINITIONS`],
+    /// and throttles this client's submissions with them.
+    ///
+    /// See [`set_throttle_definitions`](Self::set_throttle_definitions).
+    ///
+    /// # Errors
+    /// - Any error from the [`FileContentsQuery`].
+    /// - [`Error::FromProtobuf`] if the file doesn't contain valid throttle definitions.
+    pub async fn update_throttle_definitions(&self, mode: ThrottleMode) -> crate::Result<()> {
+        let contents = FileContentsQuery::new()
+            .file_id(FileId::THROTTLE_DEFINITIONS)
+            .execute(self)
+            .await?
+            .contents;
+
+        let definitions = ThrottleDefinitions::from_bytes(&contents)?;
+
+        self.set_throttle_definitions(Some(&definitions), mode);
+
+        Ok(())
+    }
+
+    pub(crate) fn submission_throttle(&self) -> Option<Arc<SubmissionThrottle>> {
+        self.0.submission_throttle.load_full()
+    }
+
     #[must_use]
     pub(crate) fn backoff(&self) -> ClientBackoff {
         *self.0.backoff.read()
@@ -588,6 +1299,21 @@ impl Client {
         self.0.operator.load_full()
     }
 
+    /// Picks the operator to pay for a transaction, advancing the [`operator_selection`](Self::operator_selection).
+    ///
+    /// A transaction that already has a `payer` gets that payer's operator (or the first operator) instead,
+    /// without advancing the selection.
+    pub(crate) fn select_operator(&self, payer: Option<AccountId>) -> Option<Arc<Operator>> {
+        let Some(pool) = &*self.0.operators.load() else {
+            return self.full_load_operator();
+        };
+
+        Some(match payer {
+            Some(payer) => pool.get(payer).unwrap_or_else(|| pool.first()).clone(),
+            None => pool.select(self.operator_selection()),
+        })
+    }
+
     /// Send a ping to the given node.
     pub async fn ping(&self, node_account_id: AccountId) -> crate::Result<()> {
         PingQuery::new(node_account_id).execute(self, None).await
@@ -622,6 +1348,84 @@ impl Client {
         Ok(())
     }
 
+    /// Waits for a schedule to execute, returning its info once it has.
+    ///
+    /// The schedule's info is polled until it executes, is deleted, or expires, or until `deadline` passes.
+    /// This is mostly useful for long-term schedules (see [`ScheduleCreateTransaction::wait_for_expiry`](crate::ScheduleCreateTransaction::wait_for_expiry)),
+    /// which execute at their expiration time rather than as soon as they're signed.
+    ///
+    /// Each poll is a paid [`ScheduleInfoQuery`], made about every 10 seconds, so a long wait costs a fee per poll.
+    /// Long-term schedules aren't polled before their expiration time, since they can't execute before then.
+    ///
+    /// Note that creating a long-term schedule can fail with [`Status::ScheduleFutureThrottleExceeded`] or [`Status::ScheduleFutureGasLimitExceeded`]
+    /// if too much is already scheduled for its expiration time, in which case it should be created with a different expiration time.
+    ///
+    /// # Errors
+    /// - [`Error::ScheduleNotExecuted`] if the schedule was deleted, expired without executing, or hadn't executed by `deadline`.
+    /// - Any error that [`ScheduleInfoQuery::execute`] can return.
+    pub async fn await_schedule_execution(
+        &self,
+        schedule_id: ScheduleId,
+        deadline: OffsetDateTime,
+    ) -> crate::Result<ScheduleInfo> {
+        const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+        let not_executed =
+            |reason| Error::ScheduleNotExecuted { schedule_id: Box::new(schedule_id), reason };
+
+        // the last seen expiration time of the schedule, the network forgets about schedules some time after they expire.
+        let mut expiration_time = None;
+
+        loop {
+            let info = match ScheduleInfoQuery::new().schedule_id(schedule_id).execute(self).await {
+                Ok(info) => info,
+                Err(
+                    e @ (Error::QueryPaymentPreCheckStatus {
+                        status: Status::InvalidScheduleId,
+                        ..
+                    }
+                    | Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidScheduleId }),
+                ) => {
+                    // only a schedule that was seen and has since expired is forgotten, otherwise the ID is wrong.
+                    return Err(match expiration_time {
+                        Some(it) if OffsetDateTime::now_utc() > it => {
+                            not_executed(ScheduleNotExecutedReason::Expired)
+                        }
+                        _ => e,
+                    });
+                }
+                Err(e) => return Err(e),
+            };
+
+            expiration_time = info.expiration_time;
+
+            if info.executed_at.is_some() {
+                return Ok(info);
+            }
+
+            let now = OffsetDateTime::now_utc();
+
+            if let Some(reason) = info.not_executed_reason(now) {
+                return Err(not_executed(reason));
+            }
+
+            let remaining = match Duration::try_from(deadline - now) {
+                Ok(remaining) if !remaining.is_zero() => remaining,
+                _ => return Err(not_executed(ScheduleNotExecutedReason::DeadlineElapsed)),
+            };
+
+            // a long-term schedule can't execute before it expires, so don't pay to poll it before then.
+            let wait = match info.expiration_time {
+                Some(expiration_time) if info.wait_for_expiry => {
+                    Duration::try_from(expiration_time - now).unwrap_or_default().max(POLL_INTERVAL)
+                }
+                _ => POLL_INTERVAL,
+            };
+
+            runtime::sleep(remaining.min(wait)).await;
+        }
+    }
+
     /// Returns the frequency at which the network will update (if it will update at all).
     #[must_use = "this function has no side-effects"]
     pub fn network_update_period(&self) -> Option<Duration> {
@@ -642,6 +1446,88 @@ impl Client {
         });
     }
 
+    /// Returns when the network was last updated from the mirror network's address book.
+    ///
+    /// This is `None` if the network hasn't been updated yet (or network updates are disabled).
+    #[must_use = "this function has no side-effects"]
+    pub fn network_last_updated(&self) -> Option<OffsetDateTime> {
+        self.0.network.last_update()
+    }
+
+    /// Returns a receiver for the address book most recently fetched by background network updates.
+    ///
+    /// The receiver is notified whenever an update fetches an address book that differs from the previous one,
+    /// which is useful for observing changes to the set of nodes the client uses.
+    /// Its value is `None` until the first update succeeds, and is never updated if network updates are disabled.
+    ///
+    /// Changes made with [`set_network`](Self::set_network) or [`set_network_from_address_book`](Self::set_network_from_address_book)
+    /// aren't published.
+    #[must_use]
+    pub fn watch_network_address_book(&self) -> watch::Receiver<Option<NodeAddressBook>> {
+        self.0.network.subscribe_address_book()
+    }
+
+    /// Sets a function to call whenever a network update fails.
+    ///
+    /// Network updates are retried with a backoff, and after several consecutive failures a client
+    /// created with [`for_mainnet`](Self::for_mainnet), [`for_testnet`](Self::for_testnet) or [`for_previewnet`](Self::for_previewnet)
+    /// falls back to the address book bundled with the SDK, rather than keeping a potentially stale network.
+    pub fn set_network_update_error_handler<F>(&self, handler: F)
+    where
+        F: Fn(&Error) + Send + Sync + 'static,
+    {
+        self.0.network.set_update_error_handler(Some(handler));
+    }
+
+    /// Removes the function set by [`set_network_update_error_handler`](Self::set_network_update_error_handler).
+    pub fn clear_network_update_error_handler(&self) {
+        self.0.network.set_update_error_handler(None::<fn(&Error)>);
+    }
+
+    /// Shuts down the client.
+    ///
+    /// This stops network updates, waits up to `timeout` for in-flight requests to finish,
+    /// and then closes all connections to the network.
+    ///
+    /// Requests started after this is called fail with [`Error::ClientShutdown`].
+    ///
+    /// # Errors
+    /// - [`Error::ShutdownTimedOut`] if requests are still in flight after `timeout`,
+    ///   in which case connections are left open for those requests to finish.
+    pub async fn shutdown(&self, timeout: Duration) -> crate::Result<()> {
+        self.0.is_shut_down.store(true, Ordering::SeqCst);
+
+        if let Some(task) = self.0.network_update_task.lock().take() {
+            task.abort();
+        }
+
+        if runtime::timeout(timeout, self.0.in_flight.wait_idle()).await.is_err() {
+            return Err(Error::ShutdownTimedOut { in_flight: self.0.in_flight.count() });
+        }
+
+        self.0.network.close_connections();
+
+        Ok(())
+    }
+
+    /// Returns `true` if [`shutdown`](Self::shutdown) has been called on this client.
+    #[must_use = "this function has no side-effects"]
+    pub fn is_shut_down(&self) -> bool {
+        self.0.is_shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Counts a request as in flight for as long as the returned guard is alive.
+    pub(crate) fn enter_request(&self) -> crate::Result<in_flight::InFlightGuard<'_>> {
+        let guard = self.0.in_flight.enter();
+
+        // checked *after* entering, so `shutdown` either sees this request or this request sees `shutdown`.
+        if self.is_shut_down() {
+            return Err(Error::ClientShutdown);
+        }
+
+        Ok(guard)
+    }
+
     /// Returns the Account ID for the operator.
     #[must_use]
     pub fn get_operator_account_id(&self) -> Option<AccountId> {
@@ -654,3 +1540,288 @@ impl Client {
         self.load_operator().as_deref().map(|it| it.signer.public_key())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::num::{
+        NonZeroU32,
+        NonZeroUsize,
+    };
+    use std::sync::atomic::{
+        AtomicU64,
+        Ordering,
+    };
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+    use triomphe::Arc;
+
+    use crate::{
+        AccountId,
+        Client,
+        EndpointConfig,
+        Error,
+        FileId,
+        Hbar,
+        Operator,
+        PrivateKey,
+        TokenId,
+        TransactionId,
+    };
+
+    #[test]
+    fn default_shard_realm() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.default_shard(), 0);
+        assert_eq!(client.default_realm(), 0);
+        assert_eq!(client.entity_id::<AccountId>(5), AccountId::new(0, 0, 5));
+
+        client.set_default_shard_realm(1, 2);
+
+        assert_eq!(client.default_shard(), 1);
+        assert_eq!(client.default_realm(), 2);
+        assert_eq!(client.entity_id::<FileId>(5), FileId::new(1, 2, 5));
+    }
+
+    #[test]
+    fn network_update_state() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.network_last_updated(), None);
+        assert!(client.watch_network_address_book().borrow().is_none());
+
+        client.set_network_update_error_handler(|e| panic!("unexpected network update: {e}"));
+        client.clear_network_update_error_handler();
+    }
+
+    #[test]
+    fn max_receipt_queries_per_second() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.max_receipt_queries_per_second(), None);
+
+        client.set_max_receipt_queries_per_second(NonZeroU32::new(50));
+        assert_eq!(client.max_receipt_queries_per_second(), NonZeroU32::new(50));
+
+        client.set_max_receipt_queries_per_second(None);
+        assert_eq!(client.max_receipt_queries_per_second(), None);
+    }
+
+    #[test]
+    fn default_validate_receipt() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert!(!client.default_validate_receipt());
+
+        client.set_default_validate_receipt(true);
+        assert!(client.default_validate_receipt());
+    }
+
+    #[test]
+    fn node_affinity() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert!(!client.node_affinity());
+
+        client.set_node_affinity(true);
+        assert!(client.node_affinity());
+    }
+
+    #[test]
+    fn query_cost_cache_ttl() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.query_cost_cache_ttl(), None);
+
+        client.set_query_cost_cache_ttl(Some(Duration::from_secs(30)));
+        assert_eq!(client.query_cost_cache_ttl(), Some(Duration::from_secs(30)));
+
+        client.set_query_cost_cache_ttl(None);
+        assert_eq!(client.query_cost_cache_ttl(), None);
+    }
+
+    #[test]
+    fn token_metadata_cache_ttl() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.token_metadata_cache_ttl(), None);
+
+        // no-ops without a cache.
+        client.invalidate_token_metadata(TokenId::new(0, 0, 1001));
+        client.clear_token_metadata_cache();
+
+        client.set_token_metadata_cache_ttl(Some(Duration::from_secs(30)));
+        assert_eq!(client.token_metadata_cache_ttl(), Some(Duration::from_secs(30)));
+
+        client.set_token_metadata_cache_ttl(None);
+        assert_eq!(client.token_metadata_cache_ttl(), None);
+    }
+
+    #[test]
+    fn duplicate_transaction_guard_ttl() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.duplicate_transaction_guard_ttl(), None);
+
+        client.set_duplicate_transaction_guard_ttl(Some(Duration::from_secs(180)));
+        assert_eq!(client.duplicate_transaction_guard_ttl(), Some(Duration::from_secs(180)));
+
+        client.set_duplicate_transaction_guard_ttl(None);
+        assert_eq!(client.duplicate_transaction_guard_ttl(), None);
+    }
+
+    #[test]
+    fn operators() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        let key = PrivateKey::generate_ed25519();
+
+        client.set_operators(vec![
+            Operator::new(AccountId::new(0, 0, 1001), key.clone()),
+            Operator::new(AccountId::new(0, 0, 1002), key.clone()),
+        ]);
+
+        assert_eq!(client.get_operator_account_id(), Some(AccountId::new(0, 0, 1001)));
+
+        let payers: Vec<_> =
+            (0..3).map(|_| client.select_operator(None).unwrap().account_id.num).collect();
+        assert_eq!(payers, [1001, 1002, 1001]);
+
+        // neither loading the operator nor a transaction with a payer advances the selection.
+        assert_eq!(client.full_load_operator().unwrap().account_id, AccountId::new(0, 0, 1001));
+        assert_eq!(
+            client.select_operator(Some(AccountId::new(0, 0, 1002))).unwrap().account_id,
+            AccountId::new(0, 0, 1002)
+        );
+        assert_eq!(
+            client.select_operator(Some(AccountId::new(0, 0, 3))).unwrap().account_id,
+            AccountId::new(0, 0, 1001)
+        );
+        assert_eq!(client.select_operator(None).unwrap().account_id, AccountId::new(0, 0, 1002));
+
+        let spent = Arc::new(AtomicU64::new(0));
+        client.set_operator_spend_handler({
+            let spent = spent.clone();
+            move |it| {
+                spent.fetch_add(it.max_transaction_fee.to_tinybars() as u64, Ordering::Relaxed);
+            }
+        });
+
+        client.report_operator_spend(
+            TransactionId::generate(AccountId::new(0, 0, 1002)),
+            Hbar::from_tinybars(5),
+        );
+        client.report_operator_spend(
+            TransactionId::generate(AccountId::new(0, 0, 3)),
+            Hbar::from_tinybars(7),
+        );
+        assert_eq!(spent.load(Ordering::Relaxed), 5);
+
+        client.set_operator(AccountId::new(0, 0, 3), key);
+
+        assert_eq!(client.full_load_operator().unwrap().account_id, AccountId::new(0, 0, 3));
+    }
+
+    #[tokio::test]
+    async fn shutdown() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        let request = client.enter_request().unwrap();
+
+        let res = client.shutdown(Duration::from_millis(10)).await;
+        assert_matches!(res, Err(Error::ShutdownTimedOut { in_flight: 1 }));
+        assert!(client.is_shut_down());
+
+        assert_matches!(client.enter_request().err(), Some(Error::ClientShutdown));
+
+        drop(request);
+
+        client.shutdown(Duration::from_millis(10)).await.unwrap();
+    }
+
+    #[test]
+    fn endpoint_config() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_eq!(client.endpoint_config(), EndpointConfig::default());
+
+        let mut config = EndpointConfig::new();
+        config
+            .set_connections_per_node(NonZeroUsize::new(4).unwrap())
+            .set_concurrency_limit(NonZeroUsize::new(100))
+            .set_connect_timeout(Duration::from_secs(3));
+
+        client.set_endpoint_config(config.clone());
+        assert_eq!(client.endpoint_config(), config);
+
+        // the network changing shouldn't lose the config.
+        client
+            .set_network(HashMap::from([("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3))]))
+            .unwrap();
+        assert_eq!(client.endpoint_config(), config);
+    }
+
+    #[test]
+    fn node_latencies() {
+        let client = Client::for_network(HashMap::from([
+            ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+            ("127.0.0.1:50212".to_owned(), AccountId::new(0, 0, 4)),
+            ("127.0.0.1:50213".to_owned(), AccountId::new(0, 0, 5)),
+        ]))
+        .unwrap();
+
+        assert!(client.node_latencies().is_empty());
+
+        let network = client.net().0.load();
+        let indexes =
+            network.node_indexes_for_ids(&[3, 4, 5].map(|num| AccountId::new(0, 0, num))).unwrap();
+
+        network.record_latency(indexes[1], Duration::from_millis(20));
+        network.record_latency(indexes[2], Duration::from_millis(10));
+
+        assert_eq!(
+            client.node_latencies(),
+            HashMap::from([
+                (AccountId::new(0, 0, 4), Duration::from_millis(20)),
+                (AccountId::new(0, 0, 5), Duration::from_millis(10)),
+            ])
+        );
+
+        // by default the fastest nodes go first, and nodes without a latency go last.
+        let ordered: Vec<_> = network
+            .select_nodes(None, indexes.iter().copied(), 3)
+            .into_iter()
+            .map(|index| network.node_ids()[index].num)
+            .collect();
+        assert_eq!(ordered, [5, 4, 3]);
+    }
+
+    #[test]
+    fn parse_entity_id() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.set_default_shard_realm(1, 2);
+
+        assert_eq!(client.parse_entity_id::<AccountId>("5").unwrap(), AccountId::new(1, 2, 5));
+
+        // explicit shard and realm always win.
+        assert_eq!(client.parse_entity_id::<AccountId>("3.4.5").unwrap(), AccountId::new(3, 4, 5));
+
+        assert!(client.parse_entity_id::<AccountId>("a.b.c").is_err());
+    }
+
+    #[test]
+    fn alias_account_id() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.set_default_shard_realm(1, 2);
+
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let account_id = client.alias_account_id(&key);
+
+        assert_eq!(account_id.shard, 1);
+        assert_eq!(account_id.realm, 2);
+        assert_eq!(account_id.alias, Some(key));
+    }
+}
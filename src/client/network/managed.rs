@@ -1,12 +1,34 @@
 use std::time::Duration;
 
+use parking_lot::RwLock;
 use rand::Rng;
+use time::OffsetDateTime;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use super::mirror::MirrorNetwork;
-use super::Network;
-use crate::NodeAddressBookQuery;
+use super::{
+    Network,
+    MAINNET,
+    PREVIEWNET,
+    TESTNET,
+};
+use crate::runtime::{
+    self,
+    Instant,
+    TaskHandle,
+};
+use crate::{
+    Error,
+    NodeAddressBook,
+    NodeAddressBookQuery,
+};
+
+pub(crate) type NetworkUpdateErrorHandler = Arc<dyn Fn(&Error) + Send + Sync>;
 
 #[derive(Clone)]
 pub(crate) struct ManagedNetwork(Arc<ManagedNetworkInner>);
@@ -15,24 +37,117 @@ impl ManagedNetwork {
     /// The time to wait before updating the network for the first time.
     const NETWORK_FIRST_UPDATE_DELAY: Duration = Duration::from_secs(10);
 
+    /// The time to wait before retrying a failed update, doubled for every consecutive failure.
+    const NETWORK_UPDATE_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+    /// The number of consecutive failed updates after which the network falls back to the bundled address book.
+    const MAX_NETWORK_UPDATE_FAILURES: u32 = 3;
+
     pub(crate) fn new(
         primary: Network,
         mirror: MirrorNetwork,
         // first_update_delay: Duration,
     ) -> Self {
-        Self(Arc::new(ManagedNetworkInner { primary, mirror }))
+        Self::with_fallback(primary, mirror, None)
+    }
+
+    fn with_fallback(
+        primary: Network,
+        mirror: MirrorNetwork,
+        fallback: Option<&'static [(u64, &'static [&'static str])]>,
+    ) -> Self {
+        Self(Arc::new(ManagedNetworkInner {
+            primary,
+            mirror,
+            fallback,
+            last_update: RwLock::new(None),
+            update_error_handler: RwLock::new(None),
+            address_book_tx: watch::channel(None).0,
+        }))
     }
 
     pub(crate) fn mainnet() -> Self {
-        Self::new(Network::mainnet(), MirrorNetwork::mainnet())
+        Self::with_fallback(Network::mainnet(), MirrorNetwork::mainnet(), Some(MAINNET))
     }
 
     pub(crate) fn testnet() -> Self {
-        Self::new(Network::testnet(), MirrorNetwork::testnet())
+        Self::with_fallback(Network::testnet(), MirrorNetwork::testnet(), Some(TESTNET))
     }
 
     pub(crate) fn previewnet() -> Self {
-        Self::new(Network::previewnet(), MirrorNetwork::previewnet())
+        Self::with_fallback(Network::previewnet(), MirrorNetwork::previewnet(), Some(PREVIEWNET))
+    }
+
+    /// Returns when the network was last successfully updated from the mirror network's address book.
+    pub(crate) fn last_update(&self) -> Option<OffsetDateTime> {
+        *self.last_update.read()
+    }
+
+    /// Returns a receiver for the address book most recently fetched by network updates.
+    pub(crate) fn subscribe_address_book(&self) -> watch::Receiver<Option<NodeAddressBook>> {
+        self.address_book_tx.subscribe()
+    }
+
+    /// Publishes `address_book` to subscribers, if it differs from the one last published.
+    fn publish_address_book(&self, address_book: NodeAddressBook) {
+        self.address_book_tx.send_if_modified(|current| {
+            if current.as_ref().is_some_and(|it| it.to_bytes() == address_book.to_bytes()) {
+                return false;
+            }
+
+            *current = Some(address_book);
+            true
+        });
+    }
+
+    /// Drops every cached channel, connections close once the requests still using them finish.
+    ///
+    /// Channels are reopened on demand.
+    pub(crate) fn close_connections(&self) {
+        self.primary.close_connections();
+        self.mirror.close_connections();
+    }
+
+    pub(crate) fn set_update_error_handler<F: Fn(&Error) + Send + Sync + 'static>(
+        &self,
+        handler: Option<F>,
+    ) {
+        *self.update_error_handler.write() = handler
+            .map(|handler| Arc::new(handler).unsize(Coercion!(to dyn Fn(&Error) + Send + Sync)));
+    }
+
+    fn update_failed(&self, error: &Error, consecutive_failures: u32) {
+        log::warn!(
+            "network update failed ({consecutive_failures} consecutive failures): {error:?}"
+        );
+
+        // clone the handler out so that it can't deadlock by setting a new handler.
+        let handler = self.update_error_handler.read().clone();
+        if let Some(handler) = handler {
+            handler(error);
+        }
+
+        if consecutive_failures != Self::MAX_NETWORK_UPDATE_FAILURES {
+            return;
+        }
+
+        if let Some(fallback) = self.fallback {
+            log::warn!(
+                "network update failed {consecutive_failures} times in a row, falling back to the bundled address book"
+            );
+
+            self.primary.update_from_static(fallback);
+        }
+    }
+}
+
+/// Returns how long to wait after an update attempt before the next one.
+fn next_update_delay(update_interval: Duration, consecutive_failures: u32) -> Duration {
+    match consecutive_failures {
+        0 => update_interval,
+        n => ManagedNetwork::NETWORK_UPDATE_RETRY_DELAY
+            .saturating_mul(1 << (n - 1).min(16))
+            .min(update_interval),
     }
 }
 
@@ -49,18 +164,27 @@ pub(crate) struct ManagedNetworkInner {
     pub(crate) primary: Network,
     //
     pub(crate) mirror: MirrorNetwork,
+
+    /// The address book bundled with the SDK, for networks that have one.
+    fallback: Option<&'static [(u64, &'static [&'static str])]>,
+
+    last_update: RwLock<Option<OffsetDateTime>>,
+
+    update_error_handler: RwLock<Option<NetworkUpdateErrorHandler>>,
+
+    address_book_tx: watch::Sender<Option<NodeAddressBook>>,
 }
 
 pub(crate) fn spawn_network_update(
     network: ManagedNetwork,
     initial_update_interval: Option<Duration>,
-) -> watch::Sender<Option<Duration>> {
+) -> (watch::Sender<Option<Duration>>, TaskHandle) {
     let (tx, rx) = watch::channel(initial_update_interval);
 
     // note: this 100% dies if there's no runtime.
-    tokio::task::spawn(update_network(network, rx));
+    let task = runtime::spawn(update_network(network, rx));
 
-    tx
+    (tx, task)
 }
 
 // note: This keeps the `ManagedNetwork` alive (has a strong reference),
@@ -70,11 +194,13 @@ async fn update_network(
     network: ManagedNetwork,
     mut update_interval_rx: watch::Receiver<Option<Duration>>,
 ) {
-    tokio::time::sleep(ManagedNetwork::NETWORK_FIRST_UPDATE_DELAY).await;
+    runtime::sleep(ManagedNetwork::NETWORK_FIRST_UPDATE_DELAY).await;
+
+    let mut consecutive_failures = 0;
 
     'outer: loop {
         // log::debug!("updating network");
-        let start = tokio::time::Instant::now();
+        let start = Instant::now();
 
         // note: ideally we'd have a `select!` on the channel closing, but, we can't
         // since there's no `async fn closed()`, and honestly, I'm not 100% certain these futures are cancel safe.
@@ -82,9 +208,15 @@ async fn update_network(
             .execute_mirrornet(network.mirror.load().channel(), None)
             .await
         {
-            Ok(it) => network.primary.update_from_address_book(&it),
+            Ok(it) => {
+                network.primary.update_from_address_book(&it);
+                *network.last_update.write() = Some(OffsetDateTime::now_utc());
+                consecutive_failures = 0;
+                network.publish_address_book(it);
+            }
             Err(e) => {
-                log::warn!("{e:?}");
+                consecutive_failures += 1;
+                network.update_failed(&e, consecutive_failures);
             }
         }
 
@@ -108,10 +240,14 @@ async fn update_network(
                 }
             };
 
+            let deadline = start
+                + next_update_delay(update_interval, consecutive_failures)
+                + Duration::from_millis(jitter);
+
             tokio::select! {
                 // We very specifically want to use a `sleep_until` here because it means we don't wait at all if the time is in the past
                 // and this can be called multiple times per `'outer` loop which means we don't want to wait the sum of all times.
-                _ = tokio::time::sleep_until(start + update_interval + Duration::from_millis(jitter)) => {
+                _ = runtime::sleep_until(deadline) => {
                     continue 'outer
                 }
 
@@ -122,3 +258,67 @@ async fn update_network(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        next_update_delay,
+        ManagedNetwork,
+        MirrorNetwork,
+        Network,
+    };
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+    };
+
+    fn address_book(node_ids: &[u64]) -> NodeAddressBook {
+        NodeAddressBook {
+            node_addresses: node_ids
+                .iter()
+                .map(|&node_id| NodeAddress {
+                    node_id,
+                    rsa_public_key: Vec::new(),
+                    node_account_id: AccountId::new(0, 0, node_id + 3),
+                    tls_certificate_hash: Vec::new(),
+                    service_endpoints: Vec::new(),
+                    description: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn next_update_delay_backs_off() {
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        assert_eq!(next_update_delay(day, 0), day);
+        assert_eq!(next_update_delay(day, 1), Duration::from_secs(30));
+        assert_eq!(next_update_delay(day, 2), Duration::from_secs(60));
+        assert_eq!(next_update_delay(day, 3), Duration::from_secs(120));
+        assert_eq!(next_update_delay(day, 100), day);
+        assert_eq!(next_update_delay(Duration::from_secs(10), 1), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn publish_address_book_only_on_change() {
+        let network = ManagedNetwork::new(Network::default(), MirrorNetwork::default());
+        let mut rx = network.subscribe_address_book();
+
+        assert!(rx.borrow_and_update().is_none());
+
+        network.publish_address_book(address_book(&[0, 1]));
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().as_ref().unwrap().node_addresses.len(), 2);
+
+        network.publish_address_book(address_book(&[0, 1]));
+        assert!(!rx.has_changed().unwrap());
+
+        network.publish_address_book(address_book(&[0]));
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().as_ref().unwrap().node_addresses.len(), 1);
+    }
+}
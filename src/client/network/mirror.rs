@@ -20,16 +20,14 @@
 
 use std::borrow::Cow;
 use std::ops::Deref;
-use std::time::Duration;
 
 use once_cell::sync::OnceCell;
-use tonic::transport::{
-    Channel,
-    ClientTlsConfig,
-    Endpoint,
-};
 use triomphe::Arc;
 
+use crate::transport::{
+    self,
+    Channel,
+};
 use crate::ArcSwap;
 
 pub(crate) const MAINNET: &str = "mainnet-public.mirrornode.hedera.com:443";
@@ -63,56 +61,48 @@ impl MirrorNetwork {
     }
 
     fn network(address: &'static str) -> Self {
-        let tls_config = ClientTlsConfig::new().domain_name(address.split_once(':').unwrap().0);
+        let tls_domain = address.split_once(':').unwrap().0;
 
-        Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_static(&[address], tls_config))))
+        Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_static(&[address], Some(tls_domain)))))
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
         Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_addresses(addresses))))
     }
+
+    pub(crate) fn close_connections(&self) {
+        self.0.rcu(|old| MirrorNetworkData {
+            channel: OnceCell::new(),
+            ..MirrorNetworkData::clone(old)
+        });
+    }
 }
 
 #[derive(Clone, Default)]
 pub(crate) struct MirrorNetworkData {
     addresses: Vec<Cow<'static, str>>,
     channel: OnceCell<Channel>,
-    tls_config: ClientTlsConfig,
+    tls_domain: Option<&'static str>,
 }
 
 impl MirrorNetworkData {
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
-        Self { addresses, channel: OnceCell::new(), tls_config: ClientTlsConfig::new() }
+        Self { addresses, channel: OnceCell::new(), tls_domain: None }
     }
 
-    pub(crate) fn from_static(network: &[&'static str], tls_config: ClientTlsConfig) -> Self {
+    pub(crate) fn from_static(network: &[&'static str], tls_domain: Option<&'static str>) -> Self {
         let mut addresses = Vec::with_capacity(network.len());
 
         for address in network {
             addresses.push(Cow::Borrowed(*address));
         }
 
-        Self { addresses, channel: OnceCell::new(), tls_config }
+        Self { addresses, channel: OnceCell::new(), tls_domain }
     }
 
     pub(crate) fn channel(&self) -> Channel {
         self.channel
-            .get_or_init(|| {
-                let endpoints = self.addresses.iter().map(|address| {
-                    let uri = format!("https://{address}");
-                    Endpoint::from_shared(uri)
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .tls_config(self.tls_config.clone())
-                        .unwrap()
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
-                });
-
-                Channel::balance_list(endpoints)
-            })
+            .get_or_init(|| transport::mirror_channel(self.addresses(), self.tls_domain))
             .clone()
     }
 
@@ -30,21 +30,29 @@ use std::fmt;
 use std::net::Ipv4Addr;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
-use std::time::{
-    Duration,
-    Instant,
+use std::sync::atomic::{
+    AtomicU64,
+    AtomicUsize,
+    Ordering,
 };
+use std::time::Duration;
 
 use backoff::backoff::Backoff;
-use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
-use rand::thread_rng;
-use tonic::transport::{
-    Channel,
-    Endpoint,
-};
 use triomphe::Arc;
 
+use super::{
+    EndpointConfig,
+    NodeCandidate,
+    NodeSelector,
+    NodeSnapshot,
+    RandomNodeSelector,
+};
+use crate::runtime::Instant;
+use crate::transport::{
+    self,
+    Channel,
+};
 use crate::{
     AccountId,
     ArcSwap,
@@ -124,6 +132,13 @@ impl Network {
         Ok(NetworkData::from_addresses(addresses)?.into())
     }
 
+    pub(super) fn from_node_snapshots(
+        nodes: &[NodeSnapshot],
+        age: Duration,
+    ) -> crate::Result<Self> {
+        Ok(NetworkData::from_node_snapshots(nodes, age)?.into())
+    }
+
     fn try_rcu<T: Into<Arc<NetworkData>>, E, F: FnMut(&Arc<NetworkData>) -> Result<T, E>>(
         &self,
         mut f: F,
@@ -161,10 +176,31 @@ impl Network {
         Ok(())
     }
 
+    pub(crate) fn update_from_static(&self, network: &'static [(u64, &'static [&'static str])]) {
+        self.rcu(|old| NetworkData {
+            endpoint_config: old.endpoint_config.clone(),
+            ..NetworkData::from_static(network)
+        });
+    }
+
     pub(crate) fn update_from_address_book(&self, address_book: &NodeAddressBook) {
         // todo: skip the updating whem `map` is the same and `connections` is the same.
         self.rcu(|old| NetworkData::with_address_book(old, address_book));
     }
+
+    pub(crate) fn close_connections(&self) {
+        self.rcu(|old| old.with_endpoint_config(old.endpoint_config.clone()));
+    }
+
+    pub(crate) fn endpoint_config(&self) -> Arc<EndpointConfig> {
+        self.0.load().endpoint_config.clone()
+    }
+
+    pub(crate) fn set_endpoint_config(&self, config: EndpointConfig) {
+        let config = Arc::new(config);
+
+        self.rcu(|old| old.with_endpoint_config(config.clone()));
+    }
 }
 
 impl From<NetworkData> for Network {
@@ -182,9 +218,30 @@ pub(crate) struct NetworkData {
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
     health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
     connections: Box<[NodeConnection]>,
+    endpoint_config: Arc<EndpointConfig>,
 }
 
 impl NetworkData {
+    /// Returns a copy of `self` that will have to open new channels, using `endpoint_config`.
+    fn with_endpoint_config(&self, endpoint_config: Arc<EndpointConfig>) -> Self {
+        Self {
+            map: self.map.clone(),
+            node_ids: self.node_ids.clone(),
+            backoff: RwLock::new(*self.backoff.read()),
+            health: self.health.clone(),
+            connections: self
+                .connections
+                .iter()
+                .map(|it| NodeConnection {
+                    addresses: it.addresses.clone(),
+                    pool: Arc::default(),
+                    latency: it.latency.clone(),
+                })
+                .collect(),
+            endpoint_config,
+        }
+    }
+
     pub(crate) fn from_addresses(addresses: &HashMap<String, AccountId>) -> crate::Result<Self> {
         Self::default().with_addresses(addresses)
     }
@@ -210,9 +267,61 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            endpoint_config: Arc::default(),
         }
     }
 
+    /// Creates a network from `nodes`, where `age` is how long ago they were snapshotted.
+    fn from_node_snapshots(nodes: &[NodeSnapshot], age: Duration) -> crate::Result<Self> {
+        let now = Instant::now();
+
+        let mut map = HashMap::with_capacity(nodes.len());
+        let mut node_ids = Vec::with_capacity(nodes.len());
+        let mut connections = Vec::with_capacity(nodes.len());
+        let mut health = Vec::with_capacity(nodes.len());
+
+        for (i, node) in nodes.iter().enumerate() {
+            if map.insert(node.account_id, i).is_some() {
+                return Err(Error::basic_parse(format!(
+                    "node `{}` appears more than once in the snapshot",
+                    node.account_id
+                )));
+            }
+
+            let addresses =
+                node.addresses.iter().map(|it| it.parse()).collect::<crate::Result<_>>()?;
+
+            let connection = NodeConnection::new(addresses);
+
+            if let Some(latency) = node.latency {
+                connection.latency.record(latency);
+            }
+
+            // the node's backoff keeps running while the snapshot sits around.
+            let node_health = match node.unhealthy_for.and_then(|it| it.checked_sub(age)) {
+                Some(remaining) if !remaining.is_zero() => NodeHealth::Unhealthy {
+                    backoff: NodeBackoff { current_interval: remaining, ..NodeBackoff::default() },
+                    healthy_at: now + remaining,
+                    attempts: 1,
+                },
+                _ => NodeHealth::Unused,
+            };
+
+            node_ids.push(node.account_id);
+            health.push(Arc::new(parking_lot::RwLock::new(node_health)));
+            connections.push(connection);
+        }
+
+        Ok(Self {
+            map,
+            node_ids: node_ids.into_boxed_slice(),
+            health: health.into_boxed_slice(),
+            connections: connections.into_boxed_slice(),
+            backoff: NodeBackoff::default().into(),
+            endpoint_config: Arc::default(),
+        })
+    }
+
     fn with_address_book(old: &Self, address_book: &NodeAddressBook) -> Self {
         let address_book = &address_book.node_addresses;
 
@@ -239,14 +348,12 @@ impl NetworkData {
                         match old.connections[account].addresses.symmetric_difference(&new).count()
                         {
                             0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
+                            _ => NodeConnection::new(new),
                         };
 
                     (old.health[account].clone(), connection)
                 }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
-                }
+                None => (Arc::default(), NodeConnection::new(new)),
             };
 
             map.insert(address.node_account_id, i);
@@ -261,6 +368,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            endpoint_config: old.endpoint_config.clone(),
         }
     }
 
@@ -284,10 +392,7 @@ impl NetworkData {
                     entry.insert(next_index);
                     node_ids.push(*node);
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
+                    connections.push(NodeConnection::new(BTreeSet::from([address])));
 
                     health.push(match self.map.get(node) {
                         Some(it) => self.health[*it].clone(),
@@ -303,6 +408,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            endpoint_config: self.endpoint_config.clone(),
         })
     }
 
@@ -359,11 +465,25 @@ impl NetworkData {
     pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
         let now = Instant::now();
 
-        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now);
+        let mut health = self.health[node_index].write();
+
+        #[cfg(feature = "metrics")]
+        if !matches!(*health, NodeHealth::Unhealthy { .. }) {
+            crate::metrics::record_node_health_transition(self.node_ids[node_index], false);
+        }
+
+        health.mark_unhealthy(*self.backoff.read(), now);
     }
 
     pub(crate) fn mark_node_healthy(&self, node_index: usize) {
-        self.health[node_index].write().mark_healthy(Instant::now());
+        let mut health = self.health[node_index].write();
+
+        #[cfg(feature = "metrics")]
+        if matches!(*health, NodeHealth::Unhealthy { .. }) {
+            crate::metrics::record_node_health_transition(self.node_ids[node_index], true);
+        }
+
+        health.mark_healthy(Instant::now());
     }
 
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
@@ -380,35 +500,100 @@ impl NetworkData {
         (0..self.node_ids.len()).filter(move |index| self.is_node_healthy(*index, time))
     }
 
-    pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
-        self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
-    }
-    pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
-        let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
+    pub(crate) fn select_node_ids(&self, selector: Option<&dyn NodeSelector>) -> Vec<AccountId> {
+        let mut node_indexes: Vec<_> = self.healthy_node_indexes(Instant::now()).collect();
         // self.remove_dead_nodes();
 
-        if node_ids.is_empty() {
-            log::warn!("No healthy nodes, randomly picking some unhealthy ones");
+        if node_indexes.is_empty() {
+            log::warn!("No healthy nodes, picking some unhealthy ones");
             // hack, slowpath, don't care perf, fix this better later tho.
-            node_ids = self.node_ids.to_vec();
+            node_indexes = (0..self.node_ids.len()).collect();
         }
 
-        let node_sample_amount = (node_ids.len() + 2) / 3;
+        let node_sample_amount = (node_indexes.len() + 2) / 3;
+
+        self.select_nodes(selector, node_indexes, node_sample_amount)
+            .into_iter()
+            .map(|index| self.node_ids[index])
+            .collect()
+    }
 
-        let node_id_indecies =
-            rand::seq::index::sample(&mut thread_rng(), node_ids.len(), node_sample_amount);
+    /// Picks `amount` of the nodes at `indexes` with `selector`, in the order they should be tried.
+    ///
+    /// Without a selector the nodes are picked at random, and then the ones that have responded fastest are tried first.
+    pub(crate) fn select_nodes(
+        &self,
+        selector: Option<&dyn NodeSelector>,
+        indexes: impl IntoIterator<Item = usize>,
+        amount: usize,
+    ) -> Vec<usize> {
+        let mut nodes: Vec<_> = indexes
+            .into_iter()
+            .map(|index| NodeCandidate {
+                index,
+                account_id: self.node_ids[index],
+                latency: self.connections[index].latency.get(),
+            })
+            .collect();
+
+        selector.unwrap_or(&RandomNodeSelector).order(&mut nodes);
+        nodes.truncate(amount);
 
-        node_id_indecies.into_iter().map(|index| node_ids[index]).collect()
+        if selector.is_none() {
+            // nodes that haven't responded yet go last.
+            nodes.sort_by_key(|it| (it.latency.is_none(), it.latency));
+        }
+
+        nodes.into_iter().map(|it| it.index).collect()
+    }
+
+    pub(crate) fn node_latencies(&self) -> HashMap<AccountId, Duration> {
+        self.node_ids
+            .iter()
+            .zip(self.connections.iter())
+            .filter_map(|(&account_id, connection)| Some((account_id, connection.latency.get()?)))
+            .collect()
+    }
+
+    pub(crate) fn record_latency(&self, node_index: usize, latency: Duration) {
+        self.connections[node_index].latency.record(latency);
     }
 
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = self.connections[index].channel(&self.endpoint_config, id);
 
         (id, channel)
     }
 
+    /// Drops the node's channels, so that the next request to it reconnects.
+    ///
+    /// This is for when a connection was closed out from under us (say, by a `GOAWAY`),
+    /// rather than waiting for the channel to notice on its own.
+    pub(crate) fn reconnect_node(&self, index: usize) {
+        self.connections[index].pool.clear();
+    }
+
+    /// Returns the addresses (`host:port`) of the node at `index`.
+    pub(crate) fn node_endpoints(&self, index: usize) -> Vec<String> {
+        self.connections[index].addresses.iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns a snapshot of every node, in index order.
+    pub(crate) fn node_snapshots(&self, now: Instant) -> Vec<NodeSnapshot> {
+        self.node_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &account_id)| NodeSnapshot {
+                account_id,
+                addresses: self.node_endpoints(index),
+                latency: self.connections[index].latency.get(),
+                unhealthy_for: self.health[index].read().unhealthy_for(now),
+            })
+            .collect()
+    }
+
     pub(crate) fn addresses(&self) -> HashMap<String, AccountId> {
         self.map
             .iter()
@@ -519,6 +704,16 @@ impl NodeHealth {
         }
     }
 
+    /// Returns how much longer the node is unhealthy for, or `None` if it's healthy.
+    pub(crate) fn unhealthy_for(&self, now: Instant) -> Option<Duration> {
+        match self {
+            Self::Unhealthy { backoff: _, healthy_at, attempts: _ } if *healthy_at > now => {
+                Some(*healthy_at - now)
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn recently_pinged(&self, now: Instant) -> bool {
         match self {
             // when used at was less than 15 minutes ago we consider ourselves "pinged", otherwise we're basically `.unused`.
@@ -572,36 +767,105 @@ impl From<Ipv4Addr> for HostAndPort {
 #[derive(Clone)]
 struct NodeConnection {
     addresses: BTreeSet<HostAndPort>,
-    channel: OnceCell<Channel>,
+    // shared between clones, so that reconnecting affects every `NetworkData` using this connection.
+    pool: Arc<ChannelPool>,
+    latency: Arc<Latency>,
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
 
+    fn new(addresses: BTreeSet<HostAndPort>) -> NodeConnection {
+        Self { addresses, pool: Arc::default(), latency: Arc::default() }
+    }
+
     fn new_static(addresses: &[&'static str]) -> NodeConnection {
-        Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
+        Self::new(addresses.iter().copied().map(HostAndPort::from_static).collect())
+    }
+
+    pub(crate) fn channel(&self, config: &EndpointConfig, node_account_id: AccountId) -> Channel {
+        self.pool.channel(config, || {
+            transport::node_channel(
+                self.addresses.iter().map(ToString::to_string),
+                config,
+                node_account_id,
+            )
+        })
+    }
+}
+
+/// The channels to a single node, every channel is its own set of HTTP/2 connections.
+#[derive(Default)]
+struct ChannelPool {
+    channels: RwLock<Vec<Channel>>,
+    next: AtomicUsize,
+}
+
+impl ChannelPool {
+    /// Returns the next channel round-robin, opening `config.connections_per_node()` channels if there are none.
+    ///
+    /// Opening a channel is lazy, the connections themselves are established on first use.
+    fn channel(&self, config: &EndpointConfig, open: impl Fn() -> Channel) -> Channel {
+        let next = self.next.fetch_add(1, Ordering::Relaxed);
+
+        {
+            let channels = self.channels.read();
+            if !channels.is_empty() {
+                return channels[next % channels.len()].clone();
+            }
+        }
+
+        let mut channels = self.channels.write();
+
+        // someone else might've gotten here first.
+        if channels.is_empty() {
+            channels.extend((0..config.connections_per_node().get()).map(|_| open()));
         }
+
+        channels[next % channels.len()].clone()
     }
 
-    pub(crate) fn channel(&self) -> Channel {
-        let channel = self
-            .channel
-            .get_or_init(|| {
-                let addresses = self.addresses.iter().map(|it| {
-                    Endpoint::from_shared(format!("tcp://{it}"))
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
-                });
-
-                Channel::balance_list(addresses)
-            })
-            .clone();
+    fn clear(&self) {
+        self.channels.write().clear();
+    }
+}
+
+/// An exponentially weighted moving average of how long a node takes to respond.
+#[derive(Default)]
+struct Latency {
+    // `0` means there haven't been any samples yet.
+    nanos: AtomicU64,
+}
+
+impl Latency {
+    fn get(&self) -> Option<Duration> {
+        match self.nanos.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(Duration::from_nanos(nanos)),
+        }
+    }
 
-        channel
+    fn record(&self, sample: Duration) {
+        let sample = u64::try_from(sample.as_nanos()).unwrap_or(u64::MAX).max(1);
+
+        let mut average = self.nanos.load(Ordering::Relaxed);
+
+        loop {
+            // each sample has a weight of 1/5th, the previous average the other 4/5ths.
+            let new = match average {
+                0 => sample,
+                average => average - average / 5 + sample / 5,
+            };
+
+            match self.nanos.compare_exchange_weak(
+                average,
+                new,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => average = current,
+            }
+        }
     }
 }
@@ -0,0 +1,166 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use crate::{
+    AccountId,
+    LedgerId,
+};
+
+/// A copy of a [`Client`](crate::Client)'s network at a point in time,
+/// taken with [`Client::network_snapshot`](crate::Client::network_snapshot).
+///
+/// A client constructed with [`Client::from_network_snapshot`](crate::Client::from_network_snapshot)
+/// starts out knowing every node, and how they've been behaving, without fetching the address book.
+///
+/// With the `serde` feature, snapshots can be saved between runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct NetworkSnapshot {
+    /// The ledger the network belongs to, if known.
+    pub ledger_id: Option<LedgerId>,
+
+    /// When this snapshot was taken.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339"))]
+    pub taken_at: OffsetDateTime,
+
+    /// The consensus nodes.
+    pub nodes: Vec<NodeSnapshot>,
+
+    /// The mirror node addresses (`host:port`).
+    pub mirror_network: Vec<String>,
+}
+
+/// A single consensus node in a [`NetworkSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct NodeSnapshot {
+    /// The node's account ID.
+    pub account_id: AccountId,
+
+    /// The node's addresses (`host:port`).
+    pub addresses: Vec<String>,
+
+    /// The node's average response time, if it's responded to anything.
+    pub latency: Option<Duration>,
+
+    /// How much longer the node was going to be considered unhealthy for when the snapshot was taken,
+    /// or `None` if it was healthy.
+    pub unhealthy_for: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use crate::runtime::Instant;
+    use crate::{
+        AccountId,
+        Client,
+        LedgerId,
+    };
+
+    fn client() -> Client {
+        Client::for_network(HashMap::from([
+            ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+            ("127.0.0.2:50211".to_owned(), AccountId::new(0, 0, 4)),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let client = client();
+        client.set_ledger_id(Some(LedgerId::testnet()));
+        client.net().0.load().record_latency(0, Duration::from_millis(10));
+
+        let snapshot = client.network_snapshot();
+
+        assert_eq!(snapshot.ledger_id, Some(LedgerId::testnet()));
+        assert_eq!(snapshot.nodes.len(), 2);
+        assert!(snapshot.mirror_network.is_empty());
+
+        let restored = Client::from_network_snapshot(&snapshot).unwrap();
+
+        assert_eq!(restored.network(), client.network());
+        assert_eq!(restored.node_latencies(), client.node_latencies());
+        assert_eq!(restored.network_snapshot().nodes, snapshot.nodes);
+    }
+
+    #[test]
+    fn unhealthy_nodes_stay_unhealthy() {
+        let client = client();
+        client.net().0.load().mark_node_unhealthy(1);
+
+        let snapshot = client.network_snapshot();
+
+        assert_eq!(snapshot.nodes[0].unhealthy_for, None);
+        assert!(snapshot.nodes[1].unhealthy_for.is_some());
+
+        let restored = Client::from_network_snapshot(&snapshot).unwrap();
+        let network = restored.net().0.load();
+
+        let unhealthy =
+            network.node_ids().iter().position(|it| *it == snapshot.nodes[1].account_id);
+
+        assert!(!network.is_node_healthy(unhealthy.unwrap(), Instant::now()));
+    }
+
+    #[test]
+    fn expired_unhealthiness_is_ignored() {
+        let client = client();
+        client.net().0.load().mark_node_unhealthy(1);
+
+        let mut snapshot = client.network_snapshot();
+        snapshot.taken_at -= time::Duration::hours(2);
+
+        let restored = Client::from_network_snapshot(&snapshot).unwrap();
+        let network = restored.net().0.load();
+
+        assert!(network.healthy_node_indexes(Instant::now()).eq(0..2));
+    }
+
+    #[test]
+    fn duplicate_node() {
+        let mut snapshot = client().network_snapshot();
+        snapshot.nodes[1].account_id = snapshot.nodes[0].account_id;
+
+        assert!(Client::from_network_snapshot(&snapshot).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let client = client();
+        client.net().0.load().record_latency(0, Duration::from_millis(10));
+
+        let snapshot = client.network_snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+
+        assert_eq!(serde_json::from_str::<super::NetworkSnapshot>(&json).unwrap(), snapshot);
+    }
+}
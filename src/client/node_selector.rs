@@ -0,0 +1,206 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::AccountId;
+
+/// Picks which nodes a [`Client`](crate::Client) sends requests to.
+///
+/// The client only ever offers healthy nodes (unless none are), and it takes care of how many of them are used,
+/// a selector just puts them in order of preference.
+///
+/// See [`Client::set_node_selector`](crate::Client::set_node_selector).
+pub trait NodeSelector: Send + Sync {
+    /// Orders `nodes` by preference, the most preferred node first.
+    fn order(&self, nodes: &mut [NodeCandidate]);
+}
+
+/// A node that a [`NodeSelector`] can pick.
+#[derive(Debug, Clone, Copy)]
+pub struct NodeCandidate {
+    pub(crate) index: usize,
+    pub(crate) account_id: AccountId,
+    pub(crate) latency: Option<Duration>,
+}
+
+impl NodeCandidate {
+    /// Returns the node's account ID.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the moving average of how long the node takes to respond, including to pings.
+    ///
+    /// This is `None` if the node hasn't responded to anything yet.
+    #[must_use]
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency
+    }
+}
+
+/// Orders nodes randomly, spreading requests evenly over the network.
+///
+/// Unlike the default, this doesn't try faster nodes first.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RandomNodeSelector;
+
+impl NodeSelector for RandomNodeSelector {
+    fn order(&self, nodes: &mut [NodeCandidate]) {
+        nodes.shuffle(&mut thread_rng());
+    }
+}
+
+/// Prefers the nodes that respond the fastest, which are usually the closest ones.
+///
+/// Nodes that haven't responded to anything yet come first, so that every node gets measured.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LowestLatencyNodeSelector;
+
+impl NodeSelector for LowestLatencyNodeSelector {
+    fn order(&self, nodes: &mut [NodeCandidate]) {
+        // shuffle first so that ties are broken randomly (the sort is stable).
+        nodes.shuffle(&mut thread_rng());
+        nodes.sort_by_key(|it| it.latency.unwrap_or(Duration::ZERO));
+    }
+}
+
+/// Cycles through the nodes in order of account ID, starting one node further every time.
+#[derive(Debug, Default)]
+pub struct RoundRobinNodeSelector {
+    next: AtomicUsize,
+}
+
+impl NodeSelector for RoundRobinNodeSelector {
+    fn order(&self, nodes: &mut [NodeCandidate]) {
+        if nodes.is_empty() {
+            return;
+        }
+
+        nodes.sort_by_key(|it| it.account_id.num);
+
+        let next = self.next.fetch_add(1, Ordering::Relaxed);
+        nodes.rotate_left(next % nodes.len());
+    }
+}
+
+/// Keeps using the same node for as long as it stays healthy, then picks a new one at random.
+#[derive(Debug, Default)]
+pub struct StickyNodeSelector {
+    node: Mutex<Option<AccountId>>,
+}
+
+impl NodeSelector for StickyNodeSelector {
+    fn order(&self, nodes: &mut [NodeCandidate]) {
+        nodes.shuffle(&mut thread_rng());
+
+        let mut node = self.node.lock();
+
+        let position =
+            node.and_then(|account_id| nodes.iter().position(|it| it.account_id == account_id));
+
+        match position {
+            Some(position) => nodes[..=position].rotate_right(1),
+            None => *node = nodes.first().map(|it| it.account_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        LowestLatencyNodeSelector,
+        NodeCandidate,
+        NodeSelector,
+        RoundRobinNodeSelector,
+        StickyNodeSelector,
+    };
+    use crate::AccountId;
+
+    fn nodes(latencies: &[Option<u64>]) -> Vec<NodeCandidate> {
+        latencies
+            .iter()
+            .enumerate()
+            .map(|(index, latency)| NodeCandidate {
+                index,
+                account_id: AccountId::new(0, 0, index as u64 + 3),
+                latency: latency.map(Duration::from_millis),
+            })
+            .collect()
+    }
+
+    fn order(selector: &dyn NodeSelector, nodes: &mut [NodeCandidate]) -> Vec<usize> {
+        selector.order(nodes);
+        nodes.iter().map(|it| it.index).collect()
+    }
+
+    #[test]
+    fn lowest_latency() {
+        let selector = LowestLatencyNodeSelector;
+
+        assert_eq!(
+            order(&selector, &mut nodes(&[Some(30), Some(10), None, Some(20)])),
+            [2, 1, 3, 0]
+        );
+    }
+
+    #[test]
+    fn round_robin() {
+        let selector = RoundRobinNodeSelector::default();
+
+        let firsts: Vec<_> = (0..4).map(|_| order(&selector, &mut nodes(&[None; 3]))[0]).collect();
+
+        assert_eq!(firsts, [0, 1, 2, 0]);
+        assert_eq!(order(&selector, &mut nodes(&[None; 3])), [1, 2, 0]);
+    }
+
+    #[test]
+    fn sticky() {
+        let selector = StickyNodeSelector::default();
+
+        let first = order(&selector, &mut nodes(&[None; 5]))[0];
+
+        for _ in 0..10 {
+            assert_eq!(order(&selector, &mut nodes(&[None; 5]))[0], first);
+        }
+
+        // the node going away (being unhealthy) means a new one gets picked.
+        let mut without_first = nodes(&[None; 5]);
+        without_first.remove(first);
+
+        let second = order(&selector, &mut without_first)[0];
+        assert_ne!(second, first);
+
+        for _ in 0..10 {
+            assert_eq!(order(&selector, &mut nodes(&[None; 5]))[0], second);
+        }
+    }
+}
@@ -1,17 +1,58 @@
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use parking_lot::Mutex;
+use triomphe::Arc;
+
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    Hbar,
+    PrivateKey,
     PublicKey,
     TransactionId,
 };
 
-#[derive(Debug)]
-pub(crate) struct Operator {
+/// An account that pays for, and signs, requests made with a [`Client`](crate::Client).
+///
+/// See [`Client::set_operators`](crate::Client::set_operators).
+#[derive(Debug, Clone)]
+pub struct Operator {
     pub(crate) account_id: AccountId,
     pub(crate) signer: AnySigner,
 }
 
 impl Operator {
+    /// Create a new operator that signs with `key`.
+    #[must_use]
+    pub fn new(account_id: AccountId, key: PrivateKey) -> Self {
+        Self { account_id, signer: AnySigner::PrivateKey(key) }
+    }
+
+    /// Create a new operator that signs with `f`, which must produce signatures for `public_key`.
+    #[must_use]
+    pub fn with_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        account_id: AccountId,
+        public_key: PublicKey,
+        f: F,
+    ) -> Self {
+        Self { account_id, signer: AnySigner::arbitrary(Box::new(public_key), f) }
+    }
+
+    /// Returns the account that pays for requests.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the public key of the operator's signer.
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        self.signer.public_key()
+    }
+
     #[must_use]
     pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
         self.signer.sign(body_bytes)
@@ -22,3 +63,139 @@ impl Operator {
         TransactionId::generate(self.account_id)
     }
 }
+
+/// How a [`Client`](crate::Client) with multiple operators picks the payer for each request.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperatorSelection {
+    /// Use each operator in turn.
+    #[default]
+    RoundRobin,
+
+    /// Use the operator that was selected the longest time ago.
+    LeastRecentlyUsed,
+}
+
+/// A transaction submitted with a payer selected by the [`Client`](crate::Client).
+///
+/// See [`Client::set_operator_spend_handler`](crate::Client::set_operator_spend_handler).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OperatorSpend {
+    /// The account that paid for the transaction.
+    pub account_id: AccountId,
+
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The most the transaction may charge the payer.
+    ///
+    /// The actual fee is only known once the transaction reaches consensus, see [`TransactionRecord::transaction_fee`](crate::TransactionRecord::transaction_fee).
+    pub max_transaction_fee: Hbar,
+}
+
+/// Multiple operators, see [`Client::set_operators`](crate::Client::set_operators).
+pub(crate) struct OperatorPool {
+    operators: Box<[Arc<Operator>]>,
+    /// Incremented on every selection, doubles as a logical clock for `last_used`.
+    next: AtomicUsize,
+    /// The value of `next` when each operator was last selected, `0` if never.
+    last_used: Mutex<Box<[usize]>>,
+}
+
+impl OperatorPool {
+    /// Returns `None` if `operators` is empty.
+    pub(crate) fn new(operators: Vec<Operator>) -> Option<Self> {
+        if operators.is_empty() {
+            return None;
+        }
+
+        let last_used = vec![0; operators.len()].into_boxed_slice();
+
+        Some(Self {
+            operators: operators.into_iter().map(Arc::new).collect(),
+            next: AtomicUsize::new(0),
+            last_used: Mutex::new(last_used),
+        })
+    }
+
+    pub(crate) fn first(&self) -> &Arc<Operator> {
+        &self.operators[0]
+    }
+
+    pub(crate) fn contains(&self, account_id: AccountId) -> bool {
+        self.get(account_id).is_some()
+    }
+
+    pub(crate) fn get(&self, account_id: AccountId) -> Option<&Arc<Operator>> {
+        self.operators.iter().find(|it| it.account_id == account_id)
+    }
+
+    pub(crate) fn select(&self, selection: OperatorSelection) -> Arc<Operator> {
+        let index = match selection {
+            OperatorSelection::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.operators.len()
+            }
+            OperatorSelection::LeastRecentlyUsed => {
+                let mut last_used = self.last_used.lock();
+
+                let index = (0..last_used.len()).min_by_key(|it| last_used[*it]).unwrap_or(0);
+
+                last_used[index] = self.next.fetch_add(1, Ordering::Relaxed) + 1;
+
+                index
+            }
+        };
+
+        self.operators[index].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Operator,
+        OperatorPool,
+        OperatorSelection,
+    };
+    use crate::{
+        AccountId,
+        PrivateKey,
+    };
+
+    fn make_pool() -> OperatorPool {
+        let key = PrivateKey::generate_ed25519();
+
+        OperatorPool::new(
+            (1..=3).map(|num| Operator::new(AccountId::new(0, 0, num), key.clone())).collect(),
+        )
+        .unwrap()
+    }
+
+    fn select_nums(pool: &OperatorPool, selection: OperatorSelection, count: usize) -> Vec<u64> {
+        (0..count).map(|_| pool.select(selection).account_id.num).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert!(OperatorPool::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn round_robin() {
+        let pool = make_pool();
+
+        assert_eq!(select_nums(&pool, OperatorSelection::RoundRobin, 5), [1, 2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn least_recently_used() {
+        let pool = make_pool();
+
+        assert_eq!(select_nums(&pool, OperatorSelection::LeastRecentlyUsed, 3), [1, 2, 3]);
+
+        // the least recently used is the one used the longest time ago, regardless of how many times it was used.
+        assert_eq!(pool.select(OperatorSelection::LeastRecentlyUsed).account_id.num, 1);
+        assert_eq!(pool.select(OperatorSelection::LeastRecentlyUsed).account_id.num, 2);
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::runtime::Instant;
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+};
+
+/// Caches payer balances, so that transactions a payer can't afford fail before they're submitted.
+pub(crate) struct PayerBalanceGuard {
+    max_age: Duration,
+    balances: Mutex<HashMap<AccountId, CachedBalance>>,
+}
+
+#[derive(Clone, Copy)]
+struct CachedBalance {
+    balance: Hbar,
+    fetched_at: Instant,
+}
+
+impl PayerBalanceGuard {
+    pub(crate) fn new(max_age: Duration) -> Self {
+        Self { max_age, balances: Mutex::new(HashMap::new()) }
+    }
+
+    pub(crate) fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Returns the cached balance of `account_id`, if it's younger than `max_age`.
+    pub(crate) fn cached(&self, account_id: AccountId) -> Option<Hbar> {
+        let balances = self.balances.lock();
+        let cached = balances.get(&account_id)?;
+
+        (cached.fetched_at.elapsed() < self.max_age).then_some(cached.balance)
+    }
+
+    pub(crate) fn store(&self, account_id: AccountId, balance: Hbar) {
+        self.balances
+            .lock()
+            .insert(account_id, CachedBalance { balance, fetched_at: Instant::now() });
+    }
+
+    /// Assumes the worst, that a transaction charged `account_id` all of `max_transaction_fee`.
+    pub(crate) fn deduct(&self, account_id: AccountId, max_transaction_fee: Hbar) {
+        if let Some(cached) = self.balances.lock().get_mut(&account_id) {
+            cached.balance -= max_transaction_fee;
+        }
+    }
+
+    pub(crate) fn check(
+        account_id: AccountId,
+        balance: Hbar,
+        max_transaction_fee: Hbar,
+    ) -> crate::Result<()> {
+        if balance < max_transaction_fee {
+            return Err(Error::InsufficientPayerBalance {
+                account_id: Box::new(account_id),
+                balance,
+                max_transaction_fee,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use super::PayerBalanceGuard;
+    use crate::{
+        AccountId,
+        Error,
+        Hbar,
+    };
+
+    const ACCOUNT: AccountId = AccountId::new(0, 0, 1001);
+
+    #[test]
+    fn check() {
+        assert_matches!(PayerBalanceGuard::check(ACCOUNT, Hbar::new(2), Hbar::new(1)), Ok(()));
+        assert_matches!(PayerBalanceGuard::check(ACCOUNT, Hbar::new(1), Hbar::new(1)), Ok(()));
+        assert_matches!(
+            PayerBalanceGuard::check(ACCOUNT, Hbar::new(1), Hbar::new(2)),
+            Err(Error::InsufficientPayerBalance { account_id, .. }) if *account_id == ACCOUNT
+        );
+    }
+
+    #[test]
+    fn cached() {
+        let guard = PayerBalanceGuard::new(Duration::from_secs(60));
+
+        assert_eq!(guard.cached(ACCOUNT), None);
+
+        guard.store(ACCOUNT, Hbar::new(5));
+        assert_eq!(guard.cached(ACCOUNT), Some(Hbar::new(5)));
+    }
+
+    #[test]
+    fn cache_expires() {
+        let guard = PayerBalanceGuard::new(Duration::ZERO);
+
+        guard.store(ACCOUNT, Hbar::new(5));
+        assert_eq!(guard.cached(ACCOUNT), None);
+    }
+
+    #[test]
+    fn deduct() {
+        let guard = PayerBalanceGuard::new(Duration::from_secs(60));
+
+        // nothing cached, nothing to deduct from.
+        guard.deduct(ACCOUNT, Hbar::new(1));
+        assert_eq!(guard.cached(ACCOUNT), None);
+
+        guard.store(ACCOUNT, Hbar::new(5));
+        guard.deduct(ACCOUNT, Hbar::new(2));
+        assert_eq!(guard.cached(ACCOUNT), Some(Hbar::new(3)));
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::runtime::Instant;
+use crate::Hbar;
+
+/// Remembers the cost of each type of query for a while,
+/// so that queries without an explicit payment amount can skip asking a node for their cost first.
+///
+/// Costs are keyed by the query's type, not its contents,
+/// so a cached cost is only an estimate for queries whose cost depends on their contents,
+/// one that's too high overpays for the query.
+pub(crate) struct QueryCostCache {
+    ttl: Duration,
+    costs: Mutex<HashMap<&'static str, (Hbar, Instant)>>,
+}
+
+impl QueryCostCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self { ttl, costs: Mutex::new(HashMap::new()) }
+    }
+
+    pub(crate) fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the cost of `query_type`, if it was cached less than `ttl` ago.
+    pub(crate) fn get(&self, query_type: &'static str) -> Option<Hbar> {
+        let mut costs = self.costs.lock();
+
+        match costs.get(query_type) {
+            Some(&(cost, cached_at)) if cached_at.elapsed() < self.ttl => Some(cost),
+            Some(_) => {
+                costs.remove(query_type);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, query_type: &'static str, cost: Hbar) {
+        self.costs.lock().insert(query_type, (cost, Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::QueryCostCache;
+    use crate::Hbar;
+
+    #[test]
+    fn get_insert() {
+        let cache = QueryCostCache::new(Duration::from_secs(60));
+
+        assert_eq!(cache.get("a"), None);
+
+        cache.insert("a", Hbar::from_tinybars(10));
+        cache.insert("b", Hbar::from_tinybars(20));
+
+        assert_eq!(cache.get("a"), Some(Hbar::from_tinybars(10)));
+        assert_eq!(cache.get("b"), Some(Hbar::from_tinybars(20)));
+
+        cache.insert("a", Hbar::from_tinybars(30));
+
+        assert_eq!(cache.get("a"), Some(Hbar::from_tinybars(30)));
+    }
+
+    #[test]
+    fn expired() {
+        let cache = QueryCostCache::new(Duration::ZERO);
+
+        cache.insert("a", Hbar::from_tinybars(10));
+
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.costs.lock().is_empty());
+    }
+}
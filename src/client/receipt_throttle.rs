@@ -0,0 +1,185 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::BTreeSet;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::runtime::{
+    self,
+    Instant,
+};
+
+/// Spaces out receipt queries across the whole client.
+///
+/// Waiters are served oldest first, where a waiter's age is the time its receipt query started
+/// (not the time it started waiting), so that a receipt that has been polled for the longest isn't starved
+/// by newer ones.
+pub(crate) struct ReceiptThrottle {
+    interval: Duration,
+    state: Mutex<State>,
+    notify: Notify,
+}
+
+struct State {
+    /// The earliest time the next query may be sent.
+    next_slot: Instant,
+    /// `(priority, ticket)`, where `ticket` breaks ties between equal priorities.
+    waiting: BTreeSet<(Instant, u64)>,
+    next_ticket: u64,
+}
+
+impl ReceiptThrottle {
+    pub(crate) fn new(max_queries_per_second: NonZeroU32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / max_queries_per_second.get(),
+            state: Mutex::new(State {
+                next_slot: Instant::now(),
+                waiting: BTreeSet::new(),
+                next_ticket: 0,
+            }),
+            notify: Notify::new(),
+        }
+    }
+
+    pub(crate) fn max_queries_per_second(&self) -> NonZeroU32 {
+        let qps = Duration::from_secs(1).as_nanos() / self.interval.as_nanos().max(1);
+
+        NonZeroU32::new(u32::try_from(qps).unwrap_or(u32::MAX)).unwrap_or(NonZeroU32::MIN)
+    }
+
+    /// Waits until a query with the given `priority` may be sent.
+    pub(crate) async fn acquire(&self, priority: Instant) {
+        let key = {
+            let mut state = self.state.lock();
+            let key = (priority, state.next_ticket);
+            state.next_ticket += 1;
+            state.waiting.insert(key);
+            key
+        };
+
+        // removes `key` and wakes the next waiter, both when done and if this future is dropped while waiting
+        // (otherwise it'd block everyone else forever).
+        let _guard = WaitingGuard { throttle: self, key };
+
+        loop {
+            let notified = self.notify.notified();
+
+            let wait_until = {
+                let mut state = self.state.lock();
+                let now = Instant::now();
+
+                if state.waiting.first() == Some(&key) {
+                    if state.next_slot <= now {
+                        state.waiting.remove(&key);
+                        state.next_slot = now + self.interval;
+                        return;
+                    }
+
+                    Some(state.next_slot)
+                } else {
+                    None
+                }
+            };
+
+            match wait_until {
+                Some(wait_until) => {
+                    tokio::select! {
+                        () = runtime::sleep_until(wait_until) => {}
+                        () = notified => {}
+                    }
+                }
+                None => notified.await,
+            }
+        }
+    }
+}
+
+struct WaitingGuard<'a> {
+    throttle: &'a ReceiptThrottle,
+    key: (Instant, u64),
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.throttle.state.lock().waiting.remove(&self.key);
+        self.throttle.notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+
+    use super::ReceiptThrottle;
+    use crate::runtime::Instant;
+
+    #[test]
+    fn max_queries_per_second() {
+        let throttle = ReceiptThrottle::new(NonZeroU32::new(40).unwrap());
+
+        assert_eq!(throttle.interval, Duration::from_millis(25));
+        assert_eq!(throttle.max_queries_per_second().get(), 40);
+    }
+
+    #[tokio::test]
+    async fn spaces_out_queries() {
+        let throttle = ReceiptThrottle::new(NonZeroU32::new(100).unwrap());
+
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            throttle.acquire(Instant::now()).await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn oldest_first() {
+        let throttle = ReceiptThrottle::new(NonZeroU32::new(100).unwrap());
+        let order = Mutex::new(Vec::new());
+
+        let older = Instant::now();
+        let newer = older + Duration::from_secs(1);
+
+        // use up the current slot so that both of the following have to wait.
+        throttle.acquire(older).await;
+
+        let acquire = |name: &'static str, priority: Instant| {
+            let throttle = &throttle;
+            let order = &order;
+            async move {
+                throttle.acquire(priority).await;
+                order.lock().push(name);
+            }
+        };
+
+        tokio::join!(acquire("newer", newer), acquire("older", older));
+
+        assert_eq!(*order.lock(), ["older", "newer"]);
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::runtime::{
+    self,
+    Instant,
+};
+use crate::system_files::ThrottleDefinitions;
+use crate::{
+    Error,
+    RequestType,
+};
+
+/// What a [`Client`](crate::Client) does with a transaction its submission throttle has no capacity for.
+///
+/// See [`Client::set_throttle_definitions`](crate::Client::set_throttle_definitions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThrottleMode {
+    /// Wait until there's capacity, then submit the transaction.
+    Delay,
+
+    /// Fail with [`Error::Throttled`] without submitting the transaction.
+    Reject,
+}
+
+/// Token amounts are in milli-operation-nanoseconds, so that refilling is exact.
+const OPERATION: u128 = 1_000 * 1_000_000_000;
+
+/// Spaces out transaction submissions per [`RequestType`], across the whole client.
+///
+/// Every throttle group is a token bucket that refills at the group's rate and holds up to
+/// a burst period's worth of operations, a submission takes one operation from every group its request type is in.
+pub(crate) struct SubmissionThrottle {
+    mode: ThrottleMode,
+    groups_by_request_type: HashMap<RequestType, Vec<usize>>,
+    groups: Mutex<Vec<Group>>,
+}
+
+struct Group {
+    milli_ops_per_sec: u128,
+    capacity: u128,
+    tokens: u128,
+    last_refill: Instant,
+}
+
+impl Group {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_nanos();
+
+        self.tokens = self
+            .tokens
+            .saturating_add(elapsed.saturating_mul(self.milli_ops_per_sec))
+            .min(self.capacity);
+        self.last_refill = now;
+    }
+
+    fn time_until_available(&self) -> Duration {
+        let missing = OPERATION.saturating_sub(self.tokens);
+
+        Duration::from_nanos(
+            u64::try_from(missing.div_ceil(self.milli_ops_per_sec)).unwrap_or(u64::MAX),
+        )
+    }
+}
+
+impl SubmissionThrottle {
+    pub(crate) fn new(definitions: &ThrottleDefinitions, mode: ThrottleMode) -> Self {
+        let now = Instant::now();
+        let mut groups = Vec::new();
+        let mut groups_by_request_type: HashMap<RequestType, Vec<usize>> = HashMap::new();
+
+        for bucket in &definitions.throttle_buckets {
+            for group in &bucket.throttle_groups {
+                // a group without any capacity would block its operations forever, leave those to the network.
+                if group.milli_ops_per_sec == 0 {
+                    continue;
+                }
+
+                let index = groups.len();
+                let milli_ops_per_sec = u128::from(group.milli_ops_per_sec);
+                let capacity = (milli_ops_per_sec * u128::from(bucket.burst_period_ms) * 1_000_000)
+                    .max(OPERATION);
+
+                for operation in &group.operations {
+                    let indices = groups_by_request_type.entry(operation.clone()).or_default();
+
+                    if indices.last() != Some(&index) {
+                        indices.push(index);
+                    }
+                }
+
+                groups.push(Group {
+                    milli_ops_per_sec,
+                    capacity,
+                    tokens: capacity,
+                    last_refill: now,
+                });
+            }
+        }
+
+        Self { mode, groups_by_request_type, groups: Mutex::new(groups) }
+    }
+
+    pub(crate) fn mode(&self) -> ThrottleMode {
+        self.mode
+    }
+
+    /// Takes one `request_type` operation from every group it's in,
+    /// waiting or failing (depending on the mode) while any of them are empty.
+    ///
+    /// Request types that aren't in any group are never throttled.
+    pub(crate) async fn acquire(&self, request_type: RequestType) -> crate::Result<()> {
+        let Some(indices) = self.groups_by_request_type.get(&request_type) else {
+            return Ok(());
+        };
+
+        loop {
+            let wait = {
+                let mut groups = self.groups.lock();
+                let now = Instant::now();
+
+                let wait = indices
+                    .iter()
+                    .map(|&index| {
+                        let group = &mut groups[index];
+                        group.refill(now);
+                        group.time_until_available()
+                    })
+                    .max()
+                    .unwrap_or_default();
+
+                if wait.is_zero() {
+                    for &index in indices {
+                        groups[index].tokens -= OPERATION;
+                    }
+
+                    return Ok(());
+                }
+
+                wait
+            };
+
+            match self.mode {
+                ThrottleMode::Delay => runtime::sleep(wait).await,
+                ThrottleMode::Reject => {
+                    return Err(Error::Throttled { request_type, retry_after: wait })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use super::{
+        SubmissionThrottle,
+        ThrottleMode,
+    };
+    use crate::runtime::Instant;
+    use crate::system_files::{
+        ThrottleBucket,
+        ThrottleDefinitions,
+        ThrottleGroup,
+    };
+    use crate::{
+        Error,
+        RequestType,
+    };
+
+    fn definitions(burst_period_ms: u64, groups: Vec<ThrottleGroup>) -> ThrottleDefinitions {
+        ThrottleDefinitions {
+            throttle_buckets: vec![ThrottleBucket {
+                name: "test".to_owned(),
+                burst_period_ms,
+                throttle_groups: groups,
+            }],
+        }
+    }
+
+    fn group(operations: Vec<RequestType>, ops_per_sec: u64) -> ThrottleGroup {
+        ThrottleGroup { operations, milli_ops_per_sec: ops_per_sec * 1000 }
+    }
+
+    #[tokio::test]
+    async fn reject_when_empty() {
+        let throttle = SubmissionThrottle::new(
+            &definitions(1000, vec![group(vec![RequestType::TokenMint], 10)]),
+            ThrottleMode::Reject,
+        );
+
+        for _ in 0..10 {
+            throttle.acquire(RequestType::TokenMint).await.unwrap();
+        }
+
+        let res = throttle.acquire(RequestType::TokenMint).await;
+
+        assert_matches!(
+            res,
+            Err(Error::Throttled { request_type: RequestType::TokenMint, retry_after })
+                if retry_after <= Duration::from_millis(100)
+        );
+    }
+
+    #[tokio::test]
+    async fn delay_spaces_out_submissions() {
+        let throttle = SubmissionThrottle::new(
+            &definitions(0, vec![group(vec![RequestType::CryptoTransfer], 100)]),
+            ThrottleMode::Delay,
+        );
+
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            throttle.acquire(RequestType::CryptoTransfer).await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn unlisted_request_types_pass() {
+        let throttle = SubmissionThrottle::new(
+            &definitions(0, vec![group(vec![RequestType::TokenMint], 1)]),
+            ThrottleMode::Reject,
+        );
+
+        for _ in 0..100 {
+            throttle.acquire(RequestType::CryptoTransfer).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn groups_share_capacity() {
+        let throttle = SubmissionThrottle::new(
+            &definitions(
+                1000,
+                vec![
+                    group(vec![RequestType::TokenMint, RequestType::CryptoTransfer], 2),
+                    group(vec![RequestType::CryptoTransfer], 100),
+                ],
+            ),
+            ThrottleMode::Reject,
+        );
+
+        throttle.acquire(RequestType::TokenMint).await.unwrap();
+        throttle.acquire(RequestType::CryptoTransfer).await.unwrap();
+
+        assert_matches!(
+            throttle.acquire(RequestType::CryptoTransfer).await,
+            Err(Error::Throttled { .. })
+        );
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::runtime::Instant;
+use crate::{
+    TokenId,
+    TokenInfo,
+};
+
+/// Remembers the info of tokens for a while,
+/// so that helpers that only need a token's metadata (such as its decimals) can skip a `TokenInfoQuery`.
+///
+/// Nothing notices when a token is updated, so entries have to be invalidated manually, or expire.
+pub(crate) struct TokenMetadataCache {
+    ttl: Duration,
+    tokens: Mutex<HashMap<TokenId, (TokenInfo, Instant)>>,
+}
+
+impl TokenMetadataCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self { ttl, tokens: Mutex::new(HashMap::new()) }
+    }
+
+    pub(crate) fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the info of `token_id`, if it was cached less than `ttl` ago.
+    pub(crate) fn get(&self, token_id: TokenId) -> Option<TokenInfo> {
+        let mut tokens = self.tokens.lock();
+
+        match tokens.get(&token_id) {
+            Some((info, cached_at)) if cached_at.elapsed() < self.ttl => Some(info.clone()),
+            Some(_) => {
+                tokens.remove(&token_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, info: TokenInfo) {
+        self.tokens.lock().insert(info.token_id, (info, Instant::now()));
+    }
+
+    pub(crate) fn remove(&self, token_id: TokenId) {
+        self.tokens.lock().remove(&token_id);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.tokens.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hedera_proto::services;
+
+    use super::TokenMetadataCache;
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        FromProtobuf,
+        TokenId,
+        TokenInfo,
+    };
+
+    fn make_info(token_id: TokenId, decimals: u32) -> TokenInfo {
+        TokenInfo::from_protobuf(services::TokenInfo {
+            token_id: Some(token_id.to_protobuf()),
+            treasury: Some(AccountId::new(0, 0, 2).to_protobuf()),
+            decimals,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn get_insert_remove() {
+        let a = TokenId::new(0, 0, 1001);
+        let b = TokenId::new(0, 0, 1002);
+
+        let cache = TokenMetadataCache::new(Duration::from_secs(60));
+
+        assert!(cache.get(a).is_none());
+
+        cache.insert(make_info(a, 2));
+        cache.insert(make_info(b, 8));
+
+        assert_eq!(cache.get(a).map(|it| it.decimals), Some(2));
+        assert_eq!(cache.get(b).map(|it| it.decimals), Some(8));
+
+        cache.remove(a);
+
+        assert!(cache.get(a).is_none());
+        assert_eq!(cache.get(b).map(|it| it.decimals), Some(8));
+
+        cache.clear();
+
+        assert!(cache.get(b).is_none());
+    }
+
+    #[test]
+    fn expired() {
+        let token_id = TokenId::new(0, 0, 1001);
+        let cache = TokenMetadataCache::new(Duration::ZERO);
+
+        cache.insert(make_info(token_id, 2));
+
+        assert!(cache.get(token_id).is_none());
+        assert!(cache.tokens.lock().is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+/// A field of a network response that this version of the SDK doesn't fully understand.
+///
+/// These don't prevent the response from being parsed, but they suggest that the SDK and the network's HAPI version
+/// have drifted apart, and that some information from the response may have been dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompatWarning {
+    /// A field the network has deprecated was set.
+    DeprecatedField {
+        /// The protobuf name of the field.
+        field: &'static str,
+    },
+
+    /// A field this SDK doesn't expose was set, so it was dropped.
+    UnsupportedField {
+        /// The protobuf name of the field.
+        field: &'static str,
+    },
+
+    /// An enum field had a value this SDK doesn't recognize, so the enum's default was used instead.
+    UnknownEnumValue {
+        /// The protobuf name of the field.
+        field: &'static str,
+
+        /// The raw value of the field.
+        value: i32,
+    },
+}
+
+impl fmt::Display for CompatWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeprecatedField { field } => write!(f, "deprecated field `{field}` is set"),
+            Self::UnsupportedField { field } => {
+                write!(f, "unsupported field `{field}` is set and was dropped")
+            }
+            Self::UnknownEnumValue { field, value } => {
+                write!(f, "field `{field}` has unknown value {value}")
+            }
+        }
+    }
+}
+
+/// Collects [`CompatWarning`]s while converting a protobuf into an SDK type.
+pub(crate) struct CompatWarnings {
+    type_name: &'static str,
+    warnings: Vec<CompatWarning>,
+}
+
+impl CompatWarnings {
+    pub(crate) fn new(type_name: &'static str) -> Self {
+        Self { type_name, warnings: Vec::new() }
+    }
+
+    /// Records `field` as deprecated if `is_set`.
+    pub(crate) fn deprecated(&mut self, field: &'static str, is_set: bool) {
+        if is_set {
+            self.push(CompatWarning::DeprecatedField { field });
+        }
+    }
+
+    /// Records `field` as unsupported if `is_set`.
+    pub(crate) fn unsupported(&mut self, field: &'static str, is_set: bool) {
+        if is_set {
+            self.push(CompatWarning::UnsupportedField { field });
+        }
+    }
+
+    /// Records `value` if it isn't a known value of `E`.
+    pub(crate) fn enum_value<E: TryFrom<i32>>(&mut self, field: &'static str, value: i32) {
+        if E::try_from(value).is_err() {
+            self.push(CompatWarning::UnknownEnumValue { field, value });
+        }
+    }
+
+    fn push(&mut self, warning: CompatWarning) {
+        log::warn!("`{}`: {warning}; the SDK may be out of date with the network", self.type_name);
+
+        self.warnings.push(warning);
+    }
+
+    pub(crate) fn finish(self) -> Vec<CompatWarning> {
+        self.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use super::{
+        CompatWarning,
+        CompatWarnings,
+    };
+
+    #[test]
+    fn collects_warnings() {
+        let mut warnings = CompatWarnings::new("Test");
+
+        warnings.deprecated("unset", false);
+        warnings.deprecated("set", true);
+        warnings.unsupported("unset", false);
+        warnings.unsupported("set", true);
+        warnings.enum_value::<services::TokenType>(
+            "known",
+            services::TokenType::NonFungibleUnique as i32,
+        );
+        warnings.enum_value::<services::TokenType>("unknown", 1234);
+
+        assert_eq!(
+            warnings.finish(),
+            [
+                CompatWarning::DeprecatedField { field: "set" },
+                CompatWarning::UnsupportedField { field: "set" },
+                CompatWarning::UnknownEnumValue { field: "unknown", value: 1234 },
+            ]
+        );
+    }
+}
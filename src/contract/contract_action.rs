@@ -0,0 +1,329 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::streams;
+
+use crate::protobuf::FromProtobuf;
+use crate::{
+    AccountId,
+    ContractId,
+    Hbar,
+};
+
+/// A single call, create, or precompile invocation made while a contract transaction executed.
+///
+/// Part of a [`TransactionSidecarRecord`](crate::TransactionSidecarRecord).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub struct ContractAction {
+    /// The kind of action.
+    pub call_type: ContractActionType,
+
+    /// The account or contract that made the call.
+    pub caller: Option<ContractActionCaller>,
+
+    /// The gas made available for the call.
+    pub gas: u64,
+
+    /// The input data passed to the call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub input: Vec<u8>,
+
+    /// The account or contract that was called.
+    pub recipient: Option<ContractActionRecipient>,
+
+    /// The value sent with the call.
+    pub value: Hbar,
+
+    /// The gas used by the call.
+    pub gas_used: u64,
+
+    /// What the call returned, reverted with, or failed with.
+    pub result: Option<ContractActionResult>,
+
+    /// How deeply nested this call was, `0` for the top level call.
+    pub call_depth: u32,
+
+    /// The EVM opcode that made the call.
+    pub call_operation_type: CallOperationType,
+}
+
+/// The kind of a [`ContractAction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum ContractActionType {
+    /// Default value, not a valid action.
+    NoAction,
+
+    /// A call to a contract or account.
+    Call,
+
+    /// A contract creation.
+    Create,
+
+    /// A call to a precompiled contract.
+    Precompile,
+
+    /// A call to a system contract.
+    System,
+}
+
+/// The EVM opcode behind a [`ContractAction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum CallOperationType {
+    /// The opcode isn't known.
+    Unknown,
+
+    /// `CALL`
+    Call,
+
+    /// `CALLCODE`
+    CallCode,
+
+    /// `DELEGATECALL`
+    DelegateCall,
+
+    /// `STATICCALL`
+    StaticCall,
+
+    /// `CREATE`
+    Create,
+
+    /// `CREATE2`
+    Create2,
+}
+
+/// The account or contract that made a [`ContractAction`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum ContractActionCaller {
+    /// An account (the top level call of a transaction).
+    Account(AccountId),
+
+    /// A contract.
+    Contract(ContractId),
+}
+
+/// The target of a [`ContractAction`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum ContractActionRecipient {
+    /// An account, for hbar transfers.
+    Account(AccountId),
+
+    /// A contract.
+    Contract(ContractId),
+
+    /// An EVM address that doesn't belong to any account or contract.
+    TargetedAddress(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))] Vec<u8>,
+    ),
+}
+
+/// The outcome of a [`ContractAction`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum ContractActionResult {
+    /// The call succeeded and returned this output.
+    Output(#[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))] Vec<u8>),
+
+    /// The call reverted with this reason.
+    RevertReason(#[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))] Vec<u8>),
+
+    /// The call failed with this error.
+    Error(#[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))] Vec<u8>),
+}
+
+impl ContractAction {
+    /// Create a new `ContractAction` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<streams::ContractAction>::from_bytes(bytes)
+    }
+}
+
+impl FromProtobuf<streams::ContractAction> for ContractAction {
+    fn from_protobuf(pb: streams::ContractAction) -> crate::Result<Self> {
+        use streams::contract_action::{
+            Caller,
+            Recipient,
+            ResultData,
+        };
+
+        let call_type = ContractActionType::from_protobuf(pb.call_type())?;
+        let call_operation_type = CallOperationType::from_protobuf(pb.call_operation_type())?;
+
+        let caller = pb
+            .caller
+            .map(|it| {
+                crate::Result::Ok(match it {
+                    Caller::CallingAccount(it) => {
+                        ContractActionCaller::Account(AccountId::from_protobuf(it)?)
+                    }
+                    Caller::CallingContract(it) => {
+                        ContractActionCaller::Contract(ContractId::from_protobuf(it)?)
+                    }
+                })
+            })
+            .transpose()?;
+
+        let recipient = pb
+            .recipient
+            .map(|it| {
+                crate::Result::Ok(match it {
+                    Recipient::RecipientAccount(it) => {
+                        ContractActionRecipient::Account(AccountId::from_protobuf(it)?)
+                    }
+                    Recipient::RecipientContract(it) => {
+                        ContractActionRecipient::Contract(ContractId::from_protobuf(it)?)
+                    }
+                    Recipient::TargetedAddress(it) => ContractActionRecipient::TargetedAddress(it),
+                })
+            })
+            .transpose()?;
+
+        let result = pb.result_data.map(|it| match it {
+            ResultData::Output(it) => ContractActionResult::Output(it),
+            ResultData::RevertReason(it) => ContractActionResult::RevertReason(it),
+            ResultData::Error(it) => ContractActionResult::Error(it),
+        });
+
+        Ok(Self {
+            call_type,
+            caller,
+            gas: pb.gas as u64,
+            input: pb.input,
+            recipient,
+            value: Hbar::from_tinybars(pb.value),
+            gas_used: pb.gas_used as u64,
+            result,
+            call_depth: pb.call_depth as u32,
+            call_operation_type,
+        })
+    }
+}
+
+impl FromProtobuf<streams::ContractActionType> for ContractActionType {
+    fn from_protobuf(pb: streams::ContractActionType) -> crate::Result<Self> {
+        use streams::ContractActionType as Pb;
+
+        Ok(match pb {
+            Pb::NoAction => Self::NoAction,
+            Pb::Call => Self::Call,
+            Pb::Create => Self::Create,
+            Pb::Precompile => Self::Precompile,
+            Pb::System => Self::System,
+        })
+    }
+}
+
+impl FromProtobuf<streams::CallOperationType> for CallOperationType {
+    fn from_protobuf(pb: streams::CallOperationType) -> crate::Result<Self> {
+        use streams::CallOperationType as Pb;
+
+        Ok(match pb {
+            Pb::OpUnknown => Self::Unknown,
+            Pb::OpCall => Self::Call,
+            Pb::OpCallcode => Self::CallCode,
+            Pb::OpDelegatecall => Self::DelegateCall,
+            Pb::OpStaticcall => Self::StaticCall,
+            Pb::OpCreate => Self::Create,
+            Pb::OpCreate2 => Self::Create2,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::{
+        services,
+        streams,
+    };
+
+    use super::ContractAction;
+    use crate::protobuf::FromProtobuf;
+
+    #[test]
+    fn from_protobuf() {
+        let pb = streams::ContractAction {
+            call_type: streams::ContractActionType::Call as i32,
+            caller: Some(streams::contract_action::Caller::CallingAccount(services::AccountId {
+                shard_num: 0,
+                realm_num: 0,
+                account: Some(services::account_id::Account::AccountNum(1001)),
+            })),
+            gas: 100_000,
+            input: vec![0xde, 0xad],
+            recipient: Some(streams::contract_action::Recipient::RecipientContract(
+                services::ContractId {
+                    shard_num: 0,
+                    realm_num: 0,
+                    contract: Some(services::contract_id::Contract::ContractNum(1002)),
+                },
+            )),
+            value: 5,
+            gas_used: 21_000,
+            result_data: Some(streams::contract_action::ResultData::RevertReason(vec![0xbe, 0xef])),
+            call_depth: 1,
+            call_operation_type: streams::CallOperationType::OpDelegatecall as i32,
+        };
+
+        expect![[r#"
+            ContractAction {
+                call_type: Call,
+                caller: Some(
+                    Account(
+                        "0.0.1001",
+                    ),
+                ),
+                gas: 100000,
+                input: [
+                    222,
+                    173,
+                ],
+                recipient: Some(
+                    Contract(
+                        "0.0.1002",
+                    ),
+                ),
+                value: "5 tℏ",
+                gas_used: 21000,
+                result: Some(
+                    RevertReason(
+                        [
+                            190,
+                            239,
+                        ],
+                    ),
+                ),
+                call_depth: 1,
+                call_operation_type: DelegateCall,
+            }
+        "#]]
+        .assert_debug_eq(&ContractAction::from_protobuf(pb).unwrap());
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::streams;
+
+use crate::protobuf::FromProtobuf;
+use crate::ContractId;
+
+/// The bytecode of a contract, as recorded when it was created or first called.
+///
+/// Part of a [`TransactionSidecarRecord`](crate::TransactionSidecarRecord).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub struct ContractBytecode {
+    /// The contract the bytecode belongs to.
+    pub contract_id: ContractId,
+
+    /// The bytecode that ran to create the contract, empty if the contract wasn't created by this transaction.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub initcode: Vec<u8>,
+
+    /// The bytecode stored on the network for the contract.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub runtime_bytecode: Vec<u8>,
+}
+
+impl ContractBytecode {
+    /// Create a new `ContractBytecode` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<streams::ContractBytecode>::from_bytes(bytes)
+    }
+}
+
+impl FromProtobuf<streams::ContractBytecode> for ContractBytecode {
+    fn from_protobuf(pb: streams::ContractBytecode) -> crate::Result<Self> {
+        Ok(Self {
+            contract_id: ContractId::from_protobuf(pb_getf!(pb, contract_id)?)?,
+            initcode: pb.initcode,
+            runtime_bytecode: pb.runtime_bytecode,
+        })
+    }
+}
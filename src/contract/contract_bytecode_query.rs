@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,6 +27,7 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     ContractId,
@@ -80,6 +80,12 @@ impl ToQueryProtobuf for ContractBytecodeQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractGetBytecodeQuery> for ContractBytecodeQueryData {
+    fn from_protobuf(pb: services::ContractGetBytecodeQuery) -> crate::Result<Self> {
+        Ok(Self { contract_id: Option::from_protobuf(pb.contract_id)? })
+    }
+}
+
 impl QueryExecute for ContractBytecodeQueryData {
     type Response = Vec<u8>;
 
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,6 +27,7 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -35,6 +35,8 @@ use crate::{
     ContractFunctionResult,
     ContractId,
     Error,
+    FromProtobuf,
+    Gas,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -89,6 +91,20 @@ impl ContractCallQuery {
         self
     }
 
+    /// Gets the amount of gas to use for the call as a typed [`Gas`] amount.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> Gas {
+        Gas::new(self.data.gas)
+    }
+
+    /// Sets the amount of gas to use for the call.
+    ///
+    /// This is the same as [`gas`](Self::gas), but takes a typed [`Gas`] amount
+    /// so that it can't be confused with an amount of tinybars.
+    pub fn gas_limit(&mut self, gas: Gas) -> &mut Self {
+        self.gas(gas.get())
+    }
+
     /// Gets the function parameters as their raw bytes.
     #[must_use]
     pub fn get_contract_parameters(&self) -> &[u8] {
@@ -156,6 +172,17 @@ impl ToQueryProtobuf for ContractCallQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractCallLocalQuery> for ContractCallQueryData {
+    fn from_protobuf(pb: services::ContractCallLocalQuery) -> crate::Result<Self> {
+        Ok(Self {
+            contract_id: Option::from_protobuf(pb.contract_id)?,
+            gas: pb.gas as u64,
+            function_parameters: pb.function_parameters,
+            sender_account_id: Option::from_protobuf(pb.sender_id)?,
+        })
+    }
+}
+
 impl QueryExecute for ContractCallQueryData {
     type Response = ContractFunctionResult;
 
@@ -166,6 +166,8 @@ impl ContractCreateFlow {
     }
 
     /// Sets the maximum number of tokens that the contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited.
     pub fn max_automatic_token_associations(
         &mut self,
         max_automatic_token_associations: i32,
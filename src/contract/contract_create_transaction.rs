@@ -21,7 +21,6 @@
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
 use time::Duration;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
@@ -34,13 +33,16 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
     FileId,
+    Gas,
     Hbar,
     Key,
+    LedgerId,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -145,6 +147,20 @@ impl ContractCreateTransaction {
         self
     }
 
+    /// Returns the gas limit to deploy the smart contract as a typed [`Gas`] amount.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> Gas {
+        Gas::new(self.data().gas)
+    }
+
+    /// Sets the gas limit to deploy the smart contract.
+    ///
+    /// This is the same as [`gas`](Self::gas), but takes a typed [`Gas`] amount
+    /// so that it can't be confused with an amount of tinybars.
+    pub fn gas_limit(&mut self, gas: Gas) -> &mut Self {
+        self.gas(gas.get())
+    }
+
     /// Returns the initial balance to put into the cryptocurrency account associated with the new smart contract.
     #[must_use]
     pub fn get_initial_balance(&self) -> Hbar {
@@ -201,6 +217,8 @@ impl ContractCreateTransaction {
     }
 
     /// Sets the maximum number of tokens that this contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited, other negative values are rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, max: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = max;
         self
@@ -261,6 +279,14 @@ impl ContractCreateTransaction {
 }
 
 impl TransactionData for ContractCreateTransactionData {
+    fn validate_data(&self, ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        crate::account::validate_max_automatic_token_associations(
+            self.max_automatic_token_associations,
+        )?;
+
+        ledger_id.map_or(Ok(()), |it| Gas::new(self.gas).validate_for_ledger(it))
+    }
+
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
@@ -397,6 +423,7 @@ impl ToProtobuf for ContractCreateTransactionData {
 #[cfg(test)]
 mod tests {
 
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use time::Duration;
@@ -414,8 +441,11 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Client,
         ContractCreateTransaction,
+        Error,
         FileId,
+        Gas,
         Hbar,
         PublicKey,
     };
@@ -813,6 +843,18 @@ mod tests {
         make_transaction().gas(GAS);
     }
 
+    #[tokio::test]
+    async fn freeze_gas_over_ledger_max() {
+        let client = Client::for_testnet();
+
+        let res = ContractCreateTransaction::new()
+            .gas_limit(Gas::MAX_PER_TRANSACTION.saturating_add(Gas::new(1)))
+            .freeze_with(&client)
+            .err();
+
+        assert_matches!(res, Some(Error::GasLimitExceeded { .. }));
+    }
+
     #[test]
     fn get_set_initial_balance() {
         let mut tx = ContractCreateTransaction::new();
@@ -910,4 +952,39 @@ mod tests {
     fn get_set_staked_node_id_frozen_panics() {
         make_transaction().staked_node_id(STAKED_NODE_ID);
     }
+
+    #[test]
+    fn get_set_decline_staking_reward() {
+        let mut tx = ContractCreateTransaction::new();
+        tx.decline_staking_reward(true);
+
+        assert!(tx.get_decline_staking_reward());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_decline_staking_reward_frozen_panics() {
+        make_transaction().decline_staking_reward(true);
+    }
+
+    #[test]
+    fn staking_fields_round_trip() {
+        let mut tx = ContractCreateTransaction::new();
+        tx.bytecode_file_id(BYTECODE_FILE_ID)
+            .auto_renew_account_id(AUTO_RENEW_ACCOUNT_ID)
+            .max_automatic_token_associations(MAX_AUTOMATIC_TOKEN_ASSOCIATIONS)
+            .staked_node_id(STAKED_NODE_ID)
+            .decline_staking_reward(true);
+
+        let pb = tx.data().to_protobuf();
+
+        assert!(pb.decline_reward);
+
+        let tx = ContractCreateTransactionData::from_protobuf(pb).unwrap();
+
+        assert_eq!(tx.auto_renew_account_id, Some(AUTO_RENEW_ACCOUNT_ID));
+        assert_eq!(tx.max_automatic_token_associations, MAX_AUTOMATIC_TOKEN_ASSOCIATIONS);
+        assert_eq!(tx.staked_id, Some(crate::staked_id::StakedId::NodeId(STAKED_NODE_ID)));
+        assert!(tx.decline_staking_reward);
+    }
 }
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -35,11 +34,13 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     ContractId,
     Error,
+    LedgerId,
     Transaction,
     ValidateChecksums,
 };
@@ -56,6 +57,8 @@ pub struct ContractDeleteTransactionData {
     transfer_account_id: Option<AccountId>,
 
     transfer_contract_id: Option<ContractId>,
+
+    permanent_removal: bool,
 }
 
 impl ContractDeleteTransaction {
@@ -94,9 +97,25 @@ impl ContractDeleteTransaction {
         self.data_mut().transfer_contract_id = Some(id);
         self
     }
+
+    /// Returns `true` if the contract is to be removed from state entirely.
+    ///
+    /// This can only be set by the network itself (when removing a system contract),
+    /// so it's only ever `true` for transactions parsed from bytes or records.
+    #[must_use]
+    pub fn get_permanent_removal(&self) -> bool {
+        self.data().permanent_removal
+    }
 }
 
-impl TransactionData for ContractDeleteTransactionData {}
+impl TransactionData for ContractDeleteTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        match (self.transfer_account_id, self.transfer_contract_id) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(Error::InvalidObtainer),
+        }
+    }
+}
 
 impl TransactionExecute for ContractDeleteTransactionData {
     fn execute(
@@ -149,6 +168,7 @@ impl FromProtobuf<services::ContractDeleteTransactionBody> for ContractDeleteTra
             contract_id: Option::from_protobuf(pb.contract_id)?,
             transfer_account_id,
             transfer_contract_id,
+            permanent_removal: pb.permanent_removal,
         })
     }
 }
@@ -183,7 +203,7 @@ impl ToProtobuf for ContractDeleteTransactionData {
 
         services::ContractDeleteTransactionBody {
             contract_id: delete_contract_id,
-            permanent_removal: false,
+            permanent_removal: self.permanent_removal,
             obtainers,
         }
     }
@@ -191,6 +211,7 @@ impl ToProtobuf for ContractDeleteTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
@@ -208,6 +229,7 @@ mod tests {
         AnyTransaction,
         ContractDeleteTransaction,
         ContractId,
+        Error,
     };
 
     const CONTRACT_ID: ContractId = ContractId::new(0, 0, 5007);
@@ -217,11 +239,7 @@ mod tests {
     fn make_transaction() -> ContractDeleteTransaction {
         let mut tx = ContractDeleteTransaction::new_for_tests();
 
-        tx.contract_id(CONTRACT_ID)
-            .transfer_account_id(TRANSFER_ACCOUNT_ID)
-            .transfer_contract_id(TRANSFER_CONTRACT_ID)
-            .freeze()
-            .unwrap();
+        tx.contract_id(CONTRACT_ID).transfer_account_id(TRANSFER_ACCOUNT_ID).freeze().unwrap();
 
         tx
     }
@@ -249,7 +267,19 @@ mod tests {
                         },
                     ),
                     permanent_removal: false,
-                    obtainers: None,
+                    obtainers: Some(
+                        TransferAccountId(
+                            AccountId {
+                                shard_num: 0,
+                                realm_num: 0,
+                                account: Some(
+                                    AccountNum(
+                                        9,
+                                    ),
+                                ),
+                            },
+                        ),
+                    ),
                 },
             )
         "#]]
@@ -286,6 +316,39 @@ mod tests {
         assert_eq!(tx.contract_id, Some(CONTRACT_ID));
         assert_eq!(tx.transfer_account_id, Some(TRANSFER_ACCOUNT_ID));
         assert_eq!(tx.transfer_contract_id, None);
+        assert!(!tx.permanent_removal);
+    }
+
+    #[test]
+    fn from_proto_body_permanent_removal() {
+        let tx = services::ContractDeleteTransactionBody {
+            contract_id: Some(CONTRACT_ID.to_protobuf()),
+            obtainers: None,
+            permanent_removal: true,
+        };
+
+        let tx = ContractDeleteTransactionData::from_protobuf(tx).unwrap();
+
+        assert!(tx.permanent_removal);
+        assert!(tx.to_protobuf().permanent_removal);
+    }
+
+    #[test]
+    fn freeze_requires_one_obtainer() {
+        let mut tx = ContractDeleteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidObtainer));
+
+        tx.transfer_account_id(TRANSFER_ACCOUNT_ID).transfer_contract_id(TRANSFER_CONTRACT_ID);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidObtainer));
+        assert!(!tx.is_frozen());
+
+        let mut tx = ContractDeleteTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID).transfer_contract_id(TRANSFER_CONTRACT_ID);
+
+        tx.freeze().unwrap();
     }
 
     #[test]
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
@@ -32,12 +31,15 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     ContractFunctionParameters,
     ContractId,
     Error,
+    Gas,
     Hbar,
+    LedgerId,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -95,6 +97,20 @@ impl ContractExecuteTransaction {
         self
     }
 
+    /// Returns the maximum amount of gas to use for the call as a typed [`Gas`] amount.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> Gas {
+        Gas::new(self.data().gas)
+    }
+
+    /// Sets the maximum amount of gas to use for the call.
+    ///
+    /// This is the same as [`gas`](Self::gas), but takes a typed [`Gas`] amount
+    /// so that it can't be confused with an amount of tinybars.
+    pub fn gas_limit(&mut self, gas: Gas) -> &mut Self {
+        self.gas(gas.get())
+    }
+
     /// Returns the number of hbars to be sent with this function call.
     #[must_use]
     pub fn get_payable_amount(&self) -> Hbar {
@@ -134,7 +150,11 @@ impl ContractExecuteTransaction {
     }
 }
 
-impl TransactionData for ContractExecuteTransactionData {}
+impl TransactionData for ContractExecuteTransactionData {
+    fn validate_data(&self, ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        ledger_id.map_or(Ok(()), |it| Gas::new(self.gas).validate_for_ledger(it))
+    }
+}
 
 impl TransactionExecute for ContractExecuteTransactionData {
     fn execute(
@@ -210,6 +230,7 @@ impl ToProtobuf for ContractExecuteTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
@@ -224,8 +245,11 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Client,
         ContractExecuteTransaction,
         ContractId,
+        Error,
+        Gas,
         Hbar,
     };
 
@@ -343,6 +367,27 @@ mod tests {
         make_transaction().gas(GAS);
     }
 
+    #[test]
+    fn get_set_gas_limit() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.gas_limit(Gas::new(GAS));
+
+        assert_eq!(tx.get_gas_limit(), Gas::new(GAS));
+        assert_eq!(tx.get_gas(), GAS);
+    }
+
+    #[tokio::test]
+    async fn freeze_gas_over_ledger_max() {
+        let client = Client::for_testnet();
+
+        let res = ContractExecuteTransaction::new()
+            .gas_limit(Gas::MAX_PER_TRANSACTION.saturating_add(Gas::new(1)))
+            .freeze_with(&client)
+            .err();
+
+        assert_matches!(res, Some(Error::GasLimitExceeded { .. }));
+    }
+
     #[test]
     fn get_set_payable_amount() {
         let mut tx = ContractExecuteTransaction::new();
@@ -38,6 +38,7 @@ use crate::{
 
 /// The result returned by a call to a smart contract function.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
     pub contract_id: ContractId,
@@ -46,12 +47,14 @@ pub struct ContractFunctionResult {
     pub evm_address: Option<ContractId>,
 
     /// The raw bytes returned by the function.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bytes: Vec<u8>,
 
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
     /// Bloom filter for record.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bloom: Vec<u8>,
 
     /// Units of gas used to execute contract.
@@ -64,6 +67,7 @@ pub struct ContractFunctionResult {
     pub hbar_amount: u64,
 
     /// The parameters passed into the contract call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contract_function_parameters_bytes: Vec<u8>,
 
     /// The account that is the "sender." If not present it is the accountId from the transactionId.
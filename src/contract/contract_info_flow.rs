@@ -0,0 +1,103 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    Client,
+    ContractId,
+    ContractInfoQuery,
+    Error,
+    Key,
+    Transaction,
+};
+
+async fn query_admin_key(client: &Client, contract_id: ContractId) -> crate::Result<Key> {
+    let key = ContractInfoQuery::new().contract_id(contract_id).execute(client).await?.admin_key;
+
+    key.ok_or_else(|| {
+        Error::signature_verify(format!("`{contract_id}`: contract has no admin key"))
+    })
+}
+
+/// Verify the `signature` for `msg` via the given contract's admin key.
+///
+/// The admin key may be a key list or threshold key, in which case the one signature must be enough to satisfy it
+/// (for instance, a threshold key with a threshold of `1`).
+///
+/// # Errors
+/// - [`Error::SignatureVerify`] if the contract has no admin key (it's immutable).
+/// - [`Error::SignatureVerify`] if the signature isn't valid for enough of the admin key's public keys.
+/// - See [`ContractInfoQuery::execute`]
+pub async fn verify_signature(
+    client: &Client,
+    contract_id: ContractId,
+    msg: &[u8],
+    signature: &[u8],
+) -> crate::Result<()> {
+    let key = query_admin_key(client, contract_id).await?;
+
+    if let Key::Single(key) = key {
+        return key.verify(msg, signature);
+    }
+
+    if !key.is_satisfied_by(&|it| it.verify(msg, signature).is_ok()) {
+        return Err(Error::signature_verify(format!(
+            "`{contract_id}`: signature doesn't satisfy the admin key"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns `Ok(())` if the given contract's admin key has signed the given transaction.
+///
+/// Key lists and threshold keys are checked recursively, as the network would.
+///
+/// # Errors
+/// - [`Error::SignatureVerify`] if the contract has no admin key (it's immutable).
+/// - [`Error::SignatureVerify`] if the transaction's signatures don't satisfy the admin key.
+/// - See [`ContractInfoQuery::execute`]
+pub async fn verify_transaction_signature<D: TransactionExecute>(
+    client: &Client,
+    contract_id: ContractId,
+    transaction: &mut Transaction<D>,
+) -> crate::Result<()> {
+    let key = query_admin_key(client, contract_id).await?;
+
+    if let Key::Single(key) = key {
+        return key.verify_transaction(transaction);
+    }
+
+    // `verify_transaction` needs a `&mut Transaction` (it might have to freeze), so check every key up front.
+    let mut signed = Vec::new();
+    for it in key.public_keys() {
+        if it.verify_transaction(transaction).is_ok() {
+            signed.push(*it);
+        }
+    }
+
+    if !key.is_satisfied_by(&|it| signed.contains(it)) {
+        return Err(Error::signature_verify(format!(
+            "`{contract_id}`: transaction signatures don't satisfy the admin key"
+        )));
+    }
+
+    Ok(())
+}
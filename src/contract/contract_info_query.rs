@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,11 +27,13 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     ContractId,
     ContractInfo,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -81,6 +82,12 @@ impl ToQueryProtobuf for ContractInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractGetInfoQuery> for ContractInfoQueryData {
+    fn from_protobuf(pb: services::ContractGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { contract_id: Option::from_protobuf(pb.contract_id)? })
+    }
+}
+
 impl QueryExecute for ContractInfoQueryData {
     type Response = ContractInfo;
 
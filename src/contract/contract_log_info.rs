@@ -9,17 +9,21 @@ use crate::ContractId;
 /// The log information for an event returned by a smart contract function call.
 /// One function call may return several such events.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct ContractLogInfo {
     /// Address of the contract that emitted the event.
     pub contract_id: ContractId,
 
     /// Bloom filter for this log.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bloom: Vec<u8>,
 
     /// A list of topics this log is relevent to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex::vec"))]
     pub topics: Vec<Vec<u8>>,
 
     /// The log's data payload.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub data: Vec<u8>,
 }
 
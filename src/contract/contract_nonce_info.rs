@@ -29,6 +29,7 @@ use crate::ContractId;
 /// Info about a contract account's nonce value.
 /// The nonce for a contract is only incremented when that contract creates another contract.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct ContractNonceInfo {
     /// The contract's ID.
     pub contract_id: ContractId,
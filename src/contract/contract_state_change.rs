@@ -0,0 +1,144 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::streams;
+
+use crate::protobuf::FromProtobuf;
+use crate::ContractId;
+
+/// The storage slots of a contract that were read or written while a transaction executed.
+///
+/// Part of a [`TransactionSidecarRecord`](crate::TransactionSidecarRecord).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub struct ContractStateChange {
+    /// The contract whose storage changed.
+    pub contract_id: ContractId,
+
+    /// The slots that were read or written.
+    pub storage_changes: Vec<StorageChange>,
+}
+
+/// A single storage slot that was read, and possibly written, while a transaction executed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub struct StorageChange {
+    /// The storage slot, with leading zeros removed.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub slot: Vec<u8>,
+
+    /// The value read from the slot, with leading zeros removed.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub value_read: Vec<u8>,
+
+    /// The value written to the slot, with leading zeros removed.
+    ///
+    /// `None` if the slot was only read, an empty value means the slot was zeroed.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex::option"))]
+    pub value_written: Option<Vec<u8>>,
+}
+
+impl ContractStateChange {
+    /// Create a new `ContractStateChange` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<streams::ContractStateChange>::from_bytes(bytes)
+    }
+}
+
+impl FromProtobuf<streams::ContractStateChange> for ContractStateChange {
+    fn from_protobuf(pb: streams::ContractStateChange) -> crate::Result<Self> {
+        Ok(Self {
+            contract_id: ContractId::from_protobuf(pb_getf!(pb, contract_id)?)?,
+            storage_changes: Vec::from_protobuf(pb.storage_changes)?,
+        })
+    }
+}
+
+impl FromProtobuf<streams::StorageChange> for StorageChange {
+    fn from_protobuf(pb: streams::StorageChange) -> crate::Result<Self> {
+        Ok(Self { slot: pb.slot, value_read: pb.value_read, value_written: pb.value_written })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::{
+        services,
+        streams,
+    };
+
+    use super::ContractStateChange;
+    use crate::protobuf::FromProtobuf;
+
+    #[test]
+    fn from_protobuf() {
+        let pb = streams::ContractStateChange {
+            contract_id: Some(services::ContractId {
+                shard_num: 0,
+                realm_num: 0,
+                contract: Some(services::contract_id::Contract::ContractNum(1001)),
+            }),
+            storage_changes: vec![
+                streams::StorageChange {
+                    slot: vec![1],
+                    value_read: vec![2],
+                    value_written: Some(vec![3]),
+                },
+                streams::StorageChange { slot: vec![4], value_read: vec![5], value_written: None },
+            ],
+        };
+
+        expect![[r#"
+            ContractStateChange {
+                contract_id: "0.0.1001",
+                storage_changes: [
+                    StorageChange {
+                        slot: [
+                            1,
+                        ],
+                        value_read: [
+                            2,
+                        ],
+                        value_written: Some(
+                            [
+                                3,
+                            ],
+                        ),
+                    },
+                    StorageChange {
+                        slot: [
+                            4,
+                        ],
+                        value_read: [
+                            5,
+                        ],
+                        value_written: None,
+                    },
+                ],
+            }
+        "#]]
+        .assert_debug_eq(&ContractStateChange::from_protobuf(pb).unwrap());
+    }
+}
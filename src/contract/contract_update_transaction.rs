@@ -24,7 +24,6 @@ use time::{
     Duration,
     OffsetDateTime,
 };
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
@@ -37,12 +36,14 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     ContractId,
     Error,
     Key,
+    LedgerId,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -143,6 +144,8 @@ impl ContractUpdateTransaction {
     }
 
     /// Sets the maximum number of tokens that this contract can be automatically associated with.
+    ///
+    /// `-1` means unlimited, other negative values are rejected when the transaction is frozen.
     pub fn max_automatic_token_associations(&mut self, max: i32) -> &mut Self {
         self.data_mut().max_automatic_token_associations = Some(max);
         self
@@ -215,7 +218,16 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        self.max_automatic_token_associations
+            .map_or(Ok(()), crate::account::validate_max_automatic_token_associations)
+    }
+
+    fn deprecated_field(&self) -> Option<&'static str> {
+        self.proxy_account_id.map(|_| "proxy_account_id")
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
@@ -331,7 +343,9 @@ impl From<ContractUpdateTransactionData> for AnyTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
 
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
     use time::{
@@ -352,8 +366,10 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Client,
         ContractId,
         ContractUpdateTransaction,
+        Error,
         PublicKey,
     };
 
@@ -716,6 +732,20 @@ mod tests {
         assert_eq!(tx.staked_id, Some(crate::staked_id::StakedId::AccountId(STAKED_ACCOUNT_ID)));
     }
 
+    #[test]
+    fn reject_deprecated_proxy_account_id() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.set_reject_deprecated_fields(true);
+
+        let mut tx = ContractUpdateTransaction::new_for_tests();
+        tx.contract_id(CONTRACT_ID).proxy_account_id(PROXY_ACCOUNT_ID);
+
+        assert_matches!(
+            tx.freeze_with(&client),
+            Err(Error::DeprecatedField { field: "proxy_account_id" })
+        );
+    }
+
     mod get_set {
         use super::*;
 
@@ -764,6 +794,17 @@ mod tests {
             make_transaction().max_automatic_token_associations(MAX_AUTOMATIC_TOKEN_ASSOCIATIONS);
         }
 
+        #[test]
+        fn invalid_max_automatic_token_associations_fails() {
+            let mut tx = ContractUpdateTransaction::new_for_tests();
+            tx.contract_id(CONTRACT_ID).max_automatic_token_associations(-2);
+
+            assert_matches!(
+                tx.freeze(),
+                Err(crate::Error::InvalidMaxAutomaticTokenAssociations(-2))
+            );
+        }
+
         #[test]
         fn auto_renew_period() {
             let mut tx = ContractUpdateTransaction::new();
@@ -861,5 +902,40 @@ mod tests {
         fn staked_node_id_frozen_panics() {
             make_transaction().staked_node_id(STAKED_NODE_ID);
         }
+
+        #[test]
+        fn decline_staking_reward() {
+            let mut tx = ContractUpdateTransaction::new();
+            tx.decline_staking_reward(true);
+
+            assert_eq!(tx.get_decline_staking_reward(), Some(true));
+        }
+
+        #[test]
+        #[should_panic]
+        fn decline_staking_reward_frozen_panics() {
+            make_transaction().decline_staking_reward(true);
+        }
+    }
+
+    #[test]
+    fn staking_fields_round_trip() {
+        let mut tx = ContractUpdateTransaction::new();
+        tx.contract_id(CONTRACT_ID)
+            .auto_renew_account_id(AUTO_RENEW_ACCOUNT_ID)
+            .max_automatic_token_associations(MAX_AUTOMATIC_TOKEN_ASSOCIATIONS)
+            .staked_node_id(STAKED_NODE_ID)
+            .decline_staking_reward(true);
+
+        let pb = tx.data().to_protobuf();
+
+        assert_eq!(pb.decline_reward, Some(true));
+
+        let tx = ContractUpdateTransactionData::from_protobuf(pb).unwrap();
+
+        assert_eq!(tx.auto_renew_account_id, Some(AUTO_RENEW_ACCOUNT_ID));
+        assert_eq!(tx.max_automatic_token_associations, Some(MAX_AUTOMATIC_TOKEN_ASSOCIATIONS));
+        assert_eq!(tx.staked_id, Some(crate::staked_id::StakedId::NodeId(STAKED_NODE_ID)));
+        assert_eq!(tx.decline_staking_reward, Some(true));
     }
 }
@@ -0,0 +1,209 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt::{
+    self,
+    Debug,
+    Display,
+    Formatter,
+};
+use std::str::FromStr;
+
+use crate::{
+    Error,
+    LedgerId,
+};
+
+/// An amount of gas for use in smart contract calls.
+///
+/// Gas is _not_ denominated in [`Hbar`](crate::Hbar) or tinybars,
+/// this type exists to keep the two from being mixed up.
+///
+/// # Examples
+/// ```
+/// use hedera::Gas;
+/// let gas = Gas::new(100_000);
+///
+/// assert_eq!(gas.checked_add(Gas::new(5)), Some(Gas::new(100_005)));
+/// assert_eq!(Gas::new(5).checked_sub(Gas::new(6)), None);
+/// ```
+#[derive(Default, Copy, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+pub struct Gas(u64);
+
+impl Gas {
+    /// No gas at all.
+    pub const ZERO: Self = Self(0);
+
+    /// The maximum amount of gas a single contract transaction or query may use on
+    /// `mainnet`, `testnet`, and `previewnet`.
+    pub const MAX_PER_TRANSACTION: Self = Self(15_000_000);
+
+    /// Create a `Gas` containing `amount` units of gas.
+    #[must_use]
+    pub const fn new(amount: u64) -> Self {
+        Self(amount)
+    }
+
+    /// Returns the amount of gas as a plain integer.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `self + rhs`, or `None` if that would overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if that would underflow.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` if that would overflow.
+    #[must_use]
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self + rhs`, clamped to [`u64::MAX`] gas.
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns `self - rhs`, clamped to zero gas.
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns the maximum amount of gas a single request may use on the given ledger, if known.
+    ///
+    /// Returns `None` for ledgers other than `mainnet`, `testnet`, and `previewnet`,
+    /// because a local or private network can configure its own limit.
+    #[must_use]
+    pub fn max_for_ledger(ledger_id: &LedgerId) -> Option<Self> {
+        ledger_id.is_known_network().then_some(Self::MAX_PER_TRANSACTION)
+    }
+
+    /// Check that `self` doesn't exceed the maximum gas allowed by the given ledger.
+    ///
+    /// [`ContractCreateTransaction`](crate::ContractCreateTransaction) and [`ContractExecuteTransaction`](crate::ContractExecuteTransaction)
+    /// check their gas limit with this when they're frozen with a client.
+    ///
+    /// # Errors
+    /// - [`Error::GasLimitExceeded`] if `self` is greater than [`max_for_ledger`](Self::max_for_ledger).
+    pub fn validate_for_ledger(self, ledger_id: &LedgerId) -> crate::Result<()> {
+        match Self::max_for_ledger(ledger_id) {
+            Some(max_gas) if self > max_gas => Err(Error::GasLimitExceeded { gas: self, max_gas }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<u64> for Gas {
+    fn from(amount: u64) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<Gas> for u64 {
+    fn from(gas: Gas) -> Self {
+        gas.0
+    }
+}
+
+impl Display for Gas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Debug for Gas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+impl FromStr for Gas {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self).map_err(Error::basic_parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::{
+        Error,
+        Gas,
+        LedgerId,
+    };
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(Gas::new(10).checked_add(Gas::new(5)), Some(Gas::new(15)));
+        assert_eq!(Gas::new(u64::MAX).checked_add(Gas::new(1)), None);
+        assert_eq!(Gas::new(10).checked_sub(Gas::new(5)), Some(Gas::new(5)));
+        assert_eq!(Gas::new(5).checked_sub(Gas::new(10)), None);
+        assert_eq!(Gas::new(10).checked_mul(3), Some(Gas::new(30)));
+        assert_eq!(Gas::new(u64::MAX).checked_mul(2), None);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(Gas::new(u64::MAX).saturating_add(Gas::new(1)), Gas::new(u64::MAX));
+        assert_eq!(Gas::new(5).saturating_sub(Gas::new(10)), Gas::ZERO);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("31415".parse::<Gas>().unwrap(), Gas::new(31415));
+        assert_matches!("-1".parse::<Gas>(), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn validate_for_ledger() {
+        Gas::MAX_PER_TRANSACTION.validate_for_ledger(&LedgerId::testnet()).unwrap();
+
+        assert_matches!(
+            Gas::new(15_000_001).validate_for_ledger(&LedgerId::mainnet()),
+            Err(Error::GasLimitExceeded { .. })
+        );
+
+        // unknown ledgers have no known limit.
+        Gas::new(u64::MAX).validate_for_ledger(&LedgerId::from_bytes(vec![3])).unwrap();
+    }
+}
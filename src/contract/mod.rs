@@ -18,6 +18,8 @@
  * ‍
  */
 
+mod contract_action;
+mod contract_bytecode;
 mod contract_bytecode_query;
 mod contract_call_query;
 mod contract_create_flow;
@@ -29,12 +31,25 @@ mod contract_function_result;
 mod contract_function_selector;
 mod contract_id;
 mod contract_info;
+/// Flow for verifying signatures via contract info.
+pub mod contract_info_flow;
 mod contract_info_query;
 mod contract_log_info;
 mod contract_nonce_info;
+mod contract_state_change;
 mod contract_update_transaction;
 mod delegate_contract_id;
+mod gas;
 
+pub use contract_action::{
+    CallOperationType,
+    ContractAction,
+    ContractActionCaller,
+    ContractActionRecipient,
+    ContractActionResult,
+    ContractActionType,
+};
+pub use contract_bytecode::ContractBytecode;
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
@@ -54,6 +69,11 @@ pub use contract_info_query::ContractInfoQuery;
 pub(crate) use contract_info_query::ContractInfoQueryData;
 pub use contract_log_info::ContractLogInfo;
 pub use contract_nonce_info::ContractNonceInfo;
+pub use contract_state_change::{
+    ContractStateChange,
+    StorageChange,
+};
 pub use contract_update_transaction::ContractUpdateTransaction;
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
 pub use delegate_contract_id::DelegateContractId;
+pub use gas::Gas;
@@ -0,0 +1,234 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    get_str,
+};
+use crate::{
+    Client,
+    ContractId,
+    Error,
+};
+
+/// Query the storage of a contract from the mirror node REST API, optionally as of a past consensus time.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{Client, ContractId, ContractStateQuery};
+/// use time::OffsetDateTime;
+///
+/// let client = Client::for_testnet();
+///
+/// let slots = ContractStateQuery::new()
+///     .contract_id(ContractId::new(0, 0, 1001))
+///     // 2024-01-01 00:00:00 UTC
+///     .as_of(OffsetDateTime::from_unix_timestamp(1_704_067_200).unwrap())
+///     .execute(&client)
+///     .await?;
+///
+/// for it in slots {
+///     println!("{:?},{:?}", it.slot, it.value);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ContractStateQuery {
+    contract_id: Option<ContractId>,
+    as_of: Option<OffsetDateTime>,
+    limit: Option<usize>,
+}
+
+/// A storage slot of a contract, as returned by [`ContractStateQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractStateSlot {
+    /// The slot's key.
+    pub slot: [u8; 32],
+
+    /// The slot's value.
+    pub value: [u8; 32],
+
+    /// The consensus time the slot was last modified at.
+    pub timestamp: OffsetDateTime,
+}
+
+impl ContractStateSlot {
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        Ok(Self {
+            slot: parse_word(get_str(json, "slot")?)?,
+            value: parse_word(get_str(json, "value")?)?,
+            timestamp: mirror_rest::parse_timestamp(get_str(json, "timestamp")?)?,
+        })
+    }
+}
+
+/// Parses a `0x` prefixed, hex encoded, 32 byte word.
+fn parse_word(s: &str) -> crate::Result<[u8; 32]> {
+    let mut word = [0; 32];
+
+    hex::decode_to_slice(s.strip_prefix("0x").unwrap_or(s), &mut word)
+        .map_err(Error::mirror_rest)?;
+
+    Ok(word)
+}
+
+impl ContractStateQuery {
+    /// Create a new `ContractStateQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contract to query the storage of.
+    #[must_use]
+    pub fn get_contract_id(&self) -> Option<ContractId> {
+        self.contract_id
+    }
+
+    /// Sets the contract to query the storage of.
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    /// Returns the consensus time to query the storage as of.
+    #[must_use]
+    pub fn get_as_of(&self) -> Option<OffsetDateTime> {
+        self.as_of
+    }
+
+    /// Sets the consensus time to query the storage as of.
+    ///
+    /// If this isn't set, the current storage is returned.
+    pub fn as_of(&mut self, timestamp: OffsetDateTime) -> &mut Self {
+        self.as_of = Some(timestamp);
+        self
+    }
+
+    /// Returns the maximum number of slots to return.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the maximum number of slots to return.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// Slots are returned in ascending order of their key.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails, or its response couldn't be understood.
+    /// - [`Error::MissingField`] if `contract_id` wasn't set.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<ContractStateSlot>> {
+        let contract_id = self.contract_id.ok_or(Error::MissingField { field: "contract_id" })?;
+
+        let slots = mirror_rest::get_all(
+            client,
+            &self.path_and_query(contract_id),
+            "state",
+            self.limit,
+            |_| true,
+        )
+        .await?;
+
+        slots.iter().map(ContractStateSlot::from_json).collect()
+    }
+
+    fn path_and_query(&self, contract_id: ContractId) -> String {
+        let mut path = format!("/api/v1/contracts/{contract_id}/state?order=asc&limit=100");
+
+        if let Some(as_of) = self.as_of {
+            path.push_str(&format!("&timestamp=lte:{}", mirror_rest::format_timestamp(as_of)));
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::ContractStateSlot;
+    use crate::{
+        ContractId,
+        ContractStateQuery,
+    };
+
+    #[test]
+    fn path_and_query() {
+        let mut query = ContractStateQuery::new();
+
+        query.as_of(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap());
+
+        expect!["/api/v1/contracts/0.0.1001/state?order=asc&limit=100&timestamp=lte:1700000000.000000000"]
+            .assert_eq(&query.path_and_query(ContractId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn slot_from_json() {
+        let json = json!({
+            "address": "0x00000000000000000000000000000000000003e9",
+            "contract_id": "0.0.1001",
+            "slot": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "timestamp": "1700000000.000000001",
+            "value": "0x00000000000000000000000000000000000000000000000000000000000000ff"
+        });
+
+        let slot = ContractStateSlot::from_json(&json).unwrap();
+
+        let mut expected_slot = [0; 32];
+        expected_slot[31] = 1;
+
+        let mut expected_value = [0; 32];
+        expected_value[31] = 0xff;
+
+        assert_eq!(slot.slot, expected_slot);
+        assert_eq!(slot.value, expected_value);
+        assert_eq!(
+            slot.timestamp,
+            OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_000_000_001).unwrap()
+        );
+    }
+
+    #[test]
+    fn slot_from_json_bad_length() {
+        let json = json!({
+            "slot": "0x01",
+            "timestamp": "1700000000.000000001",
+            "value": "0x00"
+        });
+
+        assert!(ContractStateSlot::from_json(&json).is_err());
+    }
+}
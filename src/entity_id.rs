@@ -125,6 +125,17 @@ impl<'a> PartialEntityId<'a> {
         }
     }
 
+    /// Like [`finish`](Self::finish), but a bare entity num is placed in `shard` and `realm` rather than `0.0`.
+    pub(crate) fn finish_with_shard_realm<T>(self, shard: u64, realm: u64) -> crate::Result<T>
+    where
+        EntityId: Into<T>,
+    {
+        match self {
+            Self::ShortNum(num) => Ok(EntityId { shard, realm, num, checksum: None }.into()),
+            _ => self.finish(),
+        }
+    }
+
     // `FromStr` doesn't allow lifetime bounds.
     pub(crate) fn from_str(s: &'a str) -> crate::Result<Self> {
         let expecting =
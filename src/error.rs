@@ -19,13 +19,20 @@
  */
 
 use std::error::Error as StdError;
+use std::fmt;
 use std::result::Result as StdResult;
 
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    Gas,
     Hbar,
+    Key,
+    NftId,
+    ScheduleId,
+    ScheduleNotExecutedReason,
     Status,
+    TokenId,
     TransactionId,
 };
 
@@ -43,9 +50,17 @@ pub enum Error {
     TimedOut(#[source] Box<Error>),
 
     /// GRPC status code was an error.
+    ///
+    /// Failures of the connection to a node are reported as [`GrpcTransport`](Self::GrpcTransport) instead.
     #[error("grpc: {0:?}")]
     GrpcStatus(#[from] tonic::Status),
 
+    /// The connection to a node failed, rather than the node responding with an error.
+    ///
+    /// See [`GrpcTransportError::kind`] for why.
+    #[error("grpc transport: {0}")]
+    GrpcTransport(#[source] Box<GrpcTransportError>),
+
     /// Failed to parse an SDK type from a protobuf response.
     #[error("failed to create a SDK type from a protobuf response: {0}")]
     FromProtobuf(#[source] BoxStdError),
@@ -206,9 +221,330 @@ pub enum Error {
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A contract request's gas limit is greater than the network allows.
+    #[error("gas limit of {gas} exceeds the network maximum of {max_gas}")]
+    GasLimitExceeded {
+        /// The gas limit that was requested.
+        gas: Gas,
+        /// The maximum gas limit allowed by the network.
+        max_gas: Gas,
+    },
+
+    /// An approved transfer was larger than the spender's allowance.
+    #[error("approved transfer of {amount} exceeds the spender's allowance")]
+    InsufficientAllowance {
+        /// The amount that was to be transferred.
+        amount: Hbar,
+        /// The allowance, if known.
+        ///
+        /// `None` if the network rejected the transfer, as the remaining allowance isn't part of the response.
+        allowance: Option<Hbar>,
+    },
+
+    /// A [`MultisigAccountFlow`](crate::MultisigAccountFlow) was executed before enough of its keys signed.
+    #[error("only {signatures} of the {threshold} required signatures have been collected")]
+    MultisigThresholdNotMet {
+        /// How many distinct keys from the key list have signed.
+        signatures: usize,
+        /// How many signatures the key list requires.
+        threshold: u32,
+    },
+
+    /// Signed bytes given to a [`MultisigAccountFlow`](crate::MultisigAccountFlow)
+    /// aren't for the account creation transaction that the flow is collecting signatures for.
+    #[error("signed bytes are for a different transaction than the multisig account creation")]
+    MultisigTransactionMismatch,
+
+    /// A [`MultisigAccountFlow`](crate::MultisigAccountFlow)'s threshold isn't between `1` and the number of its keys.
+    #[error("threshold must be between 1 and the number of keys ({keys}), got {threshold}")]
+    MultisigInvalidThreshold {
+        /// The threshold that was set.
+        threshold: u32,
+        /// How many keys are in the key list.
+        keys: usize,
+    },
+
+    /// A [`MultisigAccountFlow`](crate::MultisigAccountFlow) was used before it was frozen with
+    /// [`freeze_with`](crate::MultisigAccountFlow::freeze_with).
+    #[error("multisig account flow must be frozen with `freeze_with` first")]
+    MultisigNotFrozen,
+
+    /// An [`AccountKeyRotationFlow`](crate::AccountKeyRotationFlow) failed, or the rotation couldn't be verified.
+    ///
+    /// See [`KeyRotationError::kind`] for whether the key was rotated.
+    #[error("key rotation: {0}")]
+    KeyRotation(#[source] Box<KeyRotationError>),
+
+    /// A transaction is larger than the network will accept.
+    ///
+    /// The network rejects any transaction larger than [`Transaction::MAX_SIZE_BYTES`](crate::Transaction::MAX_SIZE_BYTES)
+    /// with `TRANSACTION_OVERSIZE`, this error is returned instead of sending it.
+    #[error("transaction size of `{size}` bytes exceeds the maximum of `{max_size}` bytes")]
+    TransactionOversize {
+        /// The size of the transaction in bytes.
+        size: usize,
+        /// The maximum size the network allows.
+        max_size: usize,
+    },
+
+    /// A transaction's valid duration is outside of the range the network permits.
+    ///
+    /// See [`Transaction::transaction_valid_duration`](crate::Transaction::transaction_valid_duration).
+    #[error("transaction valid duration of {duration} is outside of the permitted range of {min} to {max}")]
+    TransactionValidDurationOutOfRange {
+        /// The duration that was used.
+        duration: time::Duration,
+        /// The shortest duration the network permits.
+        min: time::Duration,
+        /// The longest duration the network permits.
+        max: time::Duration,
+    },
+
+    /// A request was made using a client that has been shut down.
+    ///
+    /// See [`Client::shutdown`](crate::Client::shutdown).
+    #[error("client has been shut down")]
+    ClientShutdown,
+
+    /// The request was cancelled with its [`CancellationToken`](crate::CancellationToken).
+    ///
+    /// A transaction that was already sent to a node when it was cancelled may still reach consensus.
+    #[error("request was cancelled")]
+    Cancelled,
+
+    /// [`Client::shutdown`](crate::Client::shutdown) timed out waiting for in-flight requests to finish.
+    #[error("timed out waiting for {in_flight} in-flight request(s) to finish during shutdown")]
+    ShutdownTimedOut {
+        /// The number of requests that were still in flight.
+        in_flight: usize,
+    },
+
+    /// Reading the contents of a transaction failed.
+    ///
+    /// See [`FileAppendTransaction::execute_all_from_reader`](crate::FileAppendTransaction::execute_all_from_reader).
+    #[error("failed to read transaction contents: {0}")]
+    ContentsRead(#[source] std::io::Error),
+
+    /// A request to the mirror node REST API failed, or its response couldn't be understood.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST request failed: {0}")]
+    MirrorRest(#[source] BoxStdError),
+
+    /// Fetching or validating HIP-412 NFT metadata failed.
+    ///
+    /// See [`nft_metadata`](crate::nft_metadata).
+    #[cfg(feature = "nft-metadata")]
+    #[error("failed to fetch NFT metadata: {0}")]
+    NftMetadata(#[source] BoxStdError),
+
+    /// A schedule can't be deleted, so the delete transaction wasn't sent.
+    ///
+    /// See [`ScheduleDeleteTransaction::execute_checked`](crate::ScheduleDeleteTransaction::execute_checked).
+    #[error("schedule `{schedule_id}` can't be deleted: `{status:?}`")]
+    ScheduleNotDeletable {
+        /// The schedule that was to be deleted.
+        schedule_id: Box<ScheduleId>,
+        /// The status the network would have failed the transaction with.
+        status: Status,
+    },
+
+    /// A [`ScheduleCreateTransaction`](crate::ScheduleCreateTransaction) waits for expiry, but has no expiration time.
+    ///
+    /// The network would otherwise fail the transaction with `MISSING_EXPIRY_TIME`.
+    #[error("a schedule that waits for expiry must have an expiration time")]
+    ScheduleExpirationTimeRequired,
+
+    /// A [`ContractDeleteTransaction`](crate::ContractDeleteTransaction) must have exactly one of
+    /// `transfer_account_id` or `transfer_contract_id` set, to receive the contract's remaining hbars.
+    ///
+    /// The network would otherwise fail the transaction with `OBTAINER_REQUIRED`, or ignore both.
+    #[error("exactly one of `transfer_account_id` or `transfer_contract_id` must be set")]
+    InvalidObtainer,
+
+    /// A transaction's `max_automatic_token_associations` was neither `-1` (unlimited) nor non-negative.
+    ///
+    /// The network would otherwise fail the transaction with `INVALID_MAX_AUTO_ASSOCIATIONS`.
+    #[error("`max_automatic_token_associations` must be `-1` or non-negative, got {0}")]
+    InvalidMaxAutomaticTokenAssociations(i32),
+
+    /// An [`AccountCreateTransaction`](crate::AccountCreateTransaction)'s alias was to be derived from a key
+    /// that isn't an `ECDSA_SECP256K1` key.
+    ///
+    /// Only `ECDSA_SECP256K1` keys have an EVM address, see [`PublicKey::to_evm_address`](crate::PublicKey::to_evm_address).
+    #[error("an account alias can only be derived from an ECDSA key")]
+    AliasKeyNotEcdsa,
+
+    /// An [`Eip1559EthereumData`](crate::Eip1559EthereumData) was to be signed with a key
+    /// that isn't an `ECDSA_SECP256K1` key.
+    #[error("an ethereum transaction can only be signed with an ECDSA key")]
+    EthereumSignerKeyNotEcdsa,
+
+    /// A [`Fraction`](crate::Fraction) would have a zero denominator, or a part that doesn't fit in an `i64`.
+    #[error("`{numerator}/{denominator}` is not a valid fraction")]
+    InvalidFraction {
+        /// The numerator that was given.
+        numerator: u64,
+        /// The denominator that was given.
+        denominator: u64,
+    },
+
+    /// A token mint, burn, or wipe transaction lists more NFTs than a single transaction may.
+    ///
+    /// The network would otherwise fail the transaction with `BATCH_SIZE_LIMIT_EXCEEDED`.
+    /// Use `execute_batched` (for instance [`TokenBurnTransaction::execute_batched`](crate::TokenBurnTransaction::execute_batched))
+    /// to split the list across as many transactions as needed.
+    #[error("{count} NFTs exceeds the maximum of {max} per transaction")]
+    NftBatchSizeExceeded {
+        /// The number of NFTs listed.
+        count: usize,
+        /// The maximum number of NFTs per transaction.
+        max: usize,
+    },
+
+    /// An [`AccountAllowanceApproveTransaction`](crate::AccountAllowanceApproveTransaction) mixes
+    /// an allowance on specific serial numbers of an NFT with an allowance on all of them, for the same spender.
+    #[error("NFT allowance for token `{token_id}` from `{owner_account_id}` to `{spender_account_id}` mixes specific serials with all serials")]
+    MixedNftAllowance {
+        /// The token of the allowance.
+        token_id: Box<TokenId>,
+
+        /// The owner of the token.
+        owner_account_id: Box<AccountId>,
+
+        /// The spender of the allowance.
+        spender_account_id: Box<AccountId>,
+    },
+
+    /// A transaction sets a field that the network has deprecated.
+    ///
+    /// See [`Client::set_reject_deprecated_fields`](crate::Client::set_reject_deprecated_fields).
+    #[error("`{field}` is deprecated")]
+    DeprecatedField {
+        /// The name of the deprecated field.
+        field: &'static str,
+    },
+
+    /// The client's submission throttle had no capacity for a transaction.
+    ///
+    /// See [`Client::set_throttle_definitions`](crate::Client::set_throttle_definitions).
+    #[error(
+        "client-side throttle has no capacity for `{request_type:?}`, retry after {retry_after:?}"
+    )]
+    Throttled {
+        /// The kind of transaction that was throttled.
+        request_type: crate::RequestType,
+
+        /// How long until there will be capacity for it.
+        retry_after: std::time::Duration,
+    },
+
+    /// The payer of a transaction can't afford its max transaction fee, so it wasn't submitted.
+    ///
+    /// See [`Client::set_payer_balance_guard`](crate::Client::set_payer_balance_guard).
+    #[error("payer `{account_id}` has a balance of {balance}, less than the max transaction fee of {max_transaction_fee}")]
+    InsufficientPayerBalance {
+        /// The account that would have paid for the transaction.
+        account_id: Box<AccountId>,
+
+        /// The payer's (cached) balance.
+        balance: Hbar,
+
+        /// The most the transaction may charge the payer.
+        max_transaction_fee: Hbar,
+    },
+
+    /// A transaction was executed again within the client's duplicate transaction guard TTL, so it wasn't sent.
+    ///
+    /// See [`Client::set_duplicate_transaction_guard_ttl`](crate::Client::set_duplicate_transaction_guard_ttl).
+    #[error("transaction `{transaction_id}` was already executed")]
+    DuplicateTransaction {
+        /// The ID of the transaction.
+        transaction_id: Box<TransactionId>,
+    },
+
+    /// A schedule was deleted or expired without executing, or didn't execute before a deadline.
+    ///
+    /// See [`Client::await_schedule_execution`](crate::Client::await_schedule_execution).
+    #[error("schedule `{schedule_id}` was not executed: `{reason:?}`")]
+    ScheduleNotExecuted {
+        /// The schedule that was waited on.
+        schedule_id: Box<ScheduleId>,
+        /// Why the schedule didn't execute.
+        reason: ScheduleNotExecutedReason,
+    },
+
+    /// The transfers of a [`TransferTransaction`](crate::TransferTransaction) would be rejected by the network.
+    ///
+    /// Checked when freezing a [`TransferTransaction`](crate::TransferTransaction),
+    /// see [`TransferTransaction::validate_transfers`](crate::TransferTransaction::validate_transfers).
+    #[error("invalid transfers: {0}")]
+    TransferValidation(#[source] Box<TransferValidationError>),
+
+    /// The custom fees of a token would be rejected by the network.
+    ///
+    /// Checked when freezing a [`TokenCreateTransaction`](crate::TokenCreateTransaction)
+    /// or [`TokenFeeScheduleUpdateTransaction`](crate::TokenFeeScheduleUpdateTransaction).
+    #[error("invalid custom fees: {0}")]
+    CustomFeeValidation(#[source] Box<CustomFeeValidationError>),
+
+    /// A request was executed without setting a field it requires.
+    #[error("`{field}` must be set before executing")]
+    MissingField {
+        /// The name of the setter for the missing field.
+        field: &'static str,
+    },
+
+    /// A request timed out without ever being sent, because the network had no healthy nodes to send it to.
+    #[error("failed to complete request within the maximum time allowed; the network had no healthy nodes")]
+    NoHealthyNodes,
 }
 
 impl Error {
+    /// Returns `true` if the operation that failed with this error may succeed if it's tried again later.
+    ///
+    /// This is the case for timeouts, connection errors, mirror node requests that failed to connect or got a server error,
+    /// and errors caused by a [`Status`] for which [`Status::is_retryable`] returns `true`.
+    ///
+    /// See [`retry`](crate::retry).
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::TimedOut(_) | Self::NoHealthyNodes | Self::Throttled { .. } => true,
+
+            // a malformed response, or a client error such as a missing entity, won't go away on its own.
+            #[cfg(feature = "mirror-rest")]
+            Self::MirrorRest(error) => {
+                error.downcast_ref::<reqwest::Error>().is_some_and(|error| match error.status() {
+                    Some(status) => status.is_server_error(),
+                    None => error.is_connect() || error.is_timeout() || error.is_request(),
+                })
+            }
+
+            Self::GrpcTransport(error) => matches!(
+                error.kind,
+                GrpcTransportErrorKind::Timeout
+                    | GrpcTransportErrorKind::Unavailable
+                    | GrpcTransportErrorKind::ConnectionRefused
+            ),
+
+            Self::GrpcStatus(status) => matches!(
+                status.code(),
+                tonic::Code::Unavailable
+                    | tonic::Code::ResourceExhausted
+                    | tonic::Code::DeadlineExceeded
+            ),
+
+            Self::TransactionPreCheckStatus { status, .. }
+            | Self::QueryPreCheckStatus { status, .. }
+            | Self::QueryPaymentPreCheckStatus { status, .. }
+            | Self::QueryNoPaymentPreCheckStatus { status }
+            | Self::ReceiptStatus { status, .. } => status.is_retryable(),
+
+            _ => false,
+        }
+    }
+
     pub(crate) fn from_protobuf<E: Into<BoxStdError>>(error: E) -> Self {
         Self::FromProtobuf(error.into())
     }
@@ -228,6 +564,301 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorRest(error.into())
+    }
+
+    #[cfg(feature = "nft-metadata")]
+    pub(crate) fn nft_metadata(error: impl Into<BoxStdError>) -> Self {
+        Self::NftMetadata(error.into())
+    }
+
+    pub(crate) fn grpc_transport(
+        kind: GrpcTransportErrorKind,
+        node_account_id: AccountId,
+        endpoints: Vec<String>,
+        status: tonic::Status,
+    ) -> Self {
+        Self::GrpcTransport(Box::new(GrpcTransportError {
+            kind,
+            node_account_id,
+            endpoints,
+            status,
+        }))
+    }
+
+    pub(crate) fn key_rotation(
+        kind: KeyRotationErrorKind,
+        account_id: AccountId,
+        transaction_id: Option<TransactionId>,
+        current_key: Option<Key>,
+        source: Option<Error>,
+    ) -> Self {
+        Self::KeyRotation(Box::new(KeyRotationError {
+            kind,
+            account_id,
+            transaction_id,
+            current_key,
+            source,
+        }))
+    }
+
+    pub(crate) fn transfer_validation(error: TransferValidationError) -> Self {
+        Self::TransferValidation(Box::new(error))
+    }
+
+    pub(crate) fn custom_fee_validation(error: CustomFeeValidationError) -> Self {
+        Self::CustomFeeValidation(Box::new(error))
+    }
+}
+
+/// A failure of the connection to a consensus node.
+#[derive(Debug, thiserror::Error)]
+#[error("{kind} for node `{node_account_id}`")]
+pub struct GrpcTransportError {
+    /// What went wrong.
+    pub kind: GrpcTransportErrorKind,
+
+    /// The account ID of the node the request was sent to.
+    pub node_account_id: AccountId,
+
+    /// The addresses (`host:port`) of the node.
+    ///
+    /// Requests are balanced across all of a node's addresses, so which one failed isn't known.
+    pub endpoints: Vec<String>,
+
+    /// The status the failure was reported with.
+    #[source]
+    pub status: tonic::Status,
+}
+
+/// Why the connection to a node failed, see [`GrpcTransportError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrpcTransportErrorKind {
+    /// The request didn't complete in time.
+    Timeout,
+
+    /// The node couldn't be reached, or said it's unavailable.
+    Unavailable,
+
+    /// The node refused the connection.
+    ConnectionRefused,
+
+    /// The TLS handshake with the node failed.
+    TlsHandshake,
+
+    /// The request was cancelled before it completed, usually because the connection was closed.
+    Cancelled,
+}
+
+impl fmt::Display for GrpcTransportErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Timeout => "request timed out",
+            Self::Unavailable => "node unavailable",
+            Self::ConnectionRefused => "connection refused",
+            Self::TlsHandshake => "TLS handshake failed",
+            Self::Cancelled => "request cancelled",
+        })
+    }
+}
+
+/// A failed or unverified key rotation, see [`AccountKeyRotationFlow`](crate::AccountKeyRotationFlow).
+#[derive(Debug, thiserror::Error)]
+#[error("{kind} for account `{account_id}`")]
+pub struct KeyRotationError {
+    /// What's known about the key of the account.
+    pub kind: KeyRotationErrorKind,
+
+    /// The account whose key was being rotated.
+    pub account_id: AccountId,
+
+    /// The ID of the update transaction, `None` if it was never submitted.
+    pub transaction_id: Option<TransactionId>,
+
+    /// The key of the account according to the network, if it was fetched.
+    pub current_key: Option<Key>,
+
+    /// The error that stopped the rotation, `None` if the key was fetched and is the wrong one.
+    #[source]
+    pub source: Option<Error>,
+}
+
+/// What's known about the key of an account after a failed [`KeyRotationError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyRotationErrorKind {
+    /// The update was rejected, the old key is still the key of the account.
+    NotRotated,
+
+    /// It isn't known whether the update reached consensus, the key of the account is either the old key or the new one.
+    Unknown,
+
+    /// The update succeeded, but the key of the account couldn't be fetched to check it.
+    Unverified,
+
+    /// The update succeeded, but the key of the account isn't the new key.
+    KeyMismatch,
+}
+
+impl fmt::Display for KeyRotationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotRotated => "key was not rotated",
+            Self::Unknown => "key may or may not have been rotated",
+            Self::Unverified => "key was rotated but couldn't be verified",
+            Self::KeyMismatch => "key was rotated but isn't the new key",
+        })
+    }
+}
+
+/// A problem with the transfers of a [`TransferTransaction`](crate::TransferTransaction).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum TransferValidationError {
+    /// The hbar transfers don't sum to zero.
+    #[error("hbar transfers sum to `{sum}` instead of zero")]
+    UnbalancedHbar {
+        /// The sum of the hbar transfers.
+        sum: Hbar,
+    },
+
+    /// The transfers of a fungible token don't sum to zero.
+    #[error("transfers of token `{token_id}` sum to `{sum}` instead of zero")]
+    UnbalancedToken {
+        /// The token whose transfers are unbalanced.
+        token_id: TokenId,
+        /// The sum of the token's transfers, in the lowest denomination of the token.
+        sum: i64,
+    },
+
+    /// The same NFT is transferred more than once.
+    #[error("NFT `{nft_id}` is transferred more than once")]
+    DuplicateNft {
+        /// The NFT that is transferred more than once.
+        nft_id: NftId,
+    },
+
+    /// An account has more than one hbar transfer with the same approval.
+    ///
+    /// The network would otherwise fail the transaction with `ACCOUNT_REPEATED_IN_ACCOUNT_AMOUNTS`.
+    #[error("account `{account_id}` has more than one hbar transfer")]
+    RepeatedHbarAccount {
+        /// The account with more than one transfer.
+        account_id: AccountId,
+    },
+
+    /// An account has more than one transfer of a fungible token with the same approval.
+    ///
+    /// The network would otherwise fail the transaction with `ACCOUNT_REPEATED_IN_ACCOUNT_AMOUNTS`.
+    #[error("account `{account_id}` has more than one transfer of token `{token_id}`")]
+    RepeatedTokenAccount {
+        /// The token with more than one transfer for the account.
+        token_id: TokenId,
+        /// The account with more than one transfer.
+        account_id: AccountId,
+    },
+
+    /// There are more hbar transfers than the network allows.
+    ///
+    /// The network would otherwise fail the transaction with `TRANSFER_LIST_SIZE_LIMIT_EXCEEDED`.
+    #[error("{count} hbar transfers exceeds the maximum of {max}")]
+    TooManyHbarTransfers {
+        /// The number of hbar transfers.
+        count: usize,
+        /// The most hbar transfers a transaction may have.
+        max: usize,
+    },
+
+    /// There are more fungible token transfers, across all tokens, than the network allows.
+    ///
+    /// The network would otherwise fail the transaction with `TOKEN_TRANSFER_LIST_SIZE_LIMIT_EXCEEDED`.
+    #[error("{count} token transfers exceeds the maximum of {max}")]
+    TooManyTokenTransfers {
+        /// The number of fungible token transfers.
+        count: usize,
+        /// The most fungible token transfers a transaction may have.
+        max: usize,
+    },
+
+    /// There are more NFT transfers, across all tokens, than the network allows.
+    ///
+    /// The network would otherwise fail the transaction with `BATCH_SIZE_LIMIT_EXCEEDED`.
+    #[error("{count} NFT transfers exceeds the maximum of {max}")]
+    TooManyNftTransfers {
+        /// The number of NFT transfers.
+        count: usize,
+        /// The most NFT transfers a transaction may have.
+        max: usize,
+    },
+}
+
+/// A problem with the custom fees of a token.
+///
+/// `index` is the position of the offending fee in the list of custom fees.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum CustomFeeValidationError {
+    /// There are more custom fees than a token may have.
+    #[error("{count} custom fees exceeds the maximum of {max}")]
+    TooManyFees {
+        /// The number of custom fees.
+        count: usize,
+        /// The maximum number of custom fees a token may have.
+        max: usize,
+    },
+
+    /// A fee amount, or the numerator of a fee fraction, isn't positive.
+    ///
+    /// The minimum and maximum amounts of a fractional fee may also be zero.
+    #[error("custom fee {index} has an amount that isn't positive")]
+    NonPositiveAmount {
+        /// The position of the fee.
+        index: usize,
+    },
+
+    /// The fraction of a royalty fee is greater than one.
+    #[error("custom fee {index} is a royalty of more than the whole exchanged value")]
+    RoyaltyFractionExceedsOne {
+        /// The position of the fee.
+        index: usize,
+    },
+
+    /// The maximum amount of a fractional fee is less than its minimum amount.
+    #[error("custom fee {index} has a maximum amount less than its minimum amount")]
+    MaximumLessThanMinimum {
+        /// The position of the fee.
+        index: usize,
+    },
+
+    /// A fractional fee is attached to a non-fungible token.
+    #[error("custom fee {index} is a fractional fee, which only fungible tokens may have")]
+    FractionalFeeOnNonFungibleToken {
+        /// The position of the fee.
+        index: usize,
+    },
+
+    /// A royalty fee is attached to a fungible token.
+    #[error("custom fee {index} is a royalty fee, which only non-fungible tokens may have")]
+    RoyaltyFeeOnFungibleToken {
+        /// The position of the fee.
+        index: usize,
+    },
+
+    /// The token a fee is denominated in doesn't exist.
+    ///
+    /// Only checked by [`TokenCreateTransaction::validate_denominating_tokens`](crate::TokenCreateTransaction::validate_denominating_tokens)
+    /// and [`TokenFeeScheduleUpdateTransaction::validate_denominating_tokens`](crate::TokenFeeScheduleUpdateTransaction::validate_denominating_tokens).
+    #[error("custom fee {index} is denominated in token `{token_id}`, which doesn't exist")]
+    DenominatingTokenNotFound {
+        /// The position of the fee.
+        index: usize,
+        /// The token the fee is denominated in.
+        token_id: TokenId,
+    },
 }
 
 /// Failed to parse a mnemonic.
@@ -280,4 +911,12 @@ pub enum MnemonicEntropyError {
     /// Used a passphrase with a legacy [`Mnemonic`](crate::Mnemonic).
     #[error("used a passphrase with a legacy mnemonic")]
     LegacyWithPassphrase,
+
+    /// The [`Mnemonic`](crate::Mnemonic) contains words that aren't in its word list.
+    #[error("unknown words at indecies: `{0:?}`")]
+    UnknownWords(Vec<usize>),
+
+    /// Tried to create a [`Mnemonic`](crate::Mnemonic) from entropy that isn't 16 or 32 bytes long.
+    #[error("bad entropy length: expected `16` or `32` bytes, found `{0}`")]
+    BadEntropyLength(usize),
 }
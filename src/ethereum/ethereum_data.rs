@@ -25,8 +25,12 @@ use bytes::{
     BytesMut,
 };
 use rlp::Rlp;
+use sha3::Digest;
 
-use crate::Error;
+use crate::{
+    Error,
+    PrivateKey,
+};
 
 /// Data for an [`EthereumTransaction`](crate::EthereumTransaction).
 #[derive(Debug, Clone)]
@@ -291,6 +295,49 @@ impl Eip1559EthereumData {
         Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
     }
 
+    /// Returns the hash that's signed for this transaction,
+    /// the Keccak-256 hash of the rlp encoded data without `recovery_id`, `r`, and `s`.
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x02);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 9);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<Vec<_>, _>(self.access_list.as_slice());
+
+        sha3::Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Signs this transaction with `key`, replacing `recovery_id`, `r`, and `s`.
+    ///
+    /// # Errors
+    /// - [`Error::EthereumSignerKeyNotEcdsa`] if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign(&mut self, key: &PrivateKey) -> crate::Result<()> {
+        // these are rlp integers, which can't have leading zeros.
+        fn trim(bytes: &[u8]) -> Vec<u8> {
+            let start = bytes.iter().position(|&it| it != 0).unwrap_or(bytes.len());
+            bytes[start..].to_vec()
+        }
+
+        let (r, s, v) = key
+            .sign_hash_recoverable(&self.signing_hash())
+            .ok_or(Error::EthereumSignerKeyNotEcdsa)?;
+
+        self.recovery_id = trim(&[v]);
+        self.r = trim(&r);
+        self.s = trim(&s);
+
+        Ok(())
+    }
+
     /// Convert this data to rlp encoded bytes.
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -317,10 +364,16 @@ impl Eip1559EthereumData {
 
 #[cfg(test)]
 mod test {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hex_literal::hex;
 
     use crate::ethereum::EthereumData;
+    use crate::{
+        Error,
+        PrivateKey,
+        PublicKey,
+    };
     // https://github.com/hashgraph/hedera-services/blob/1e01d9c6b8923639b41359c55413640b589c4ec7/hapi-utils/src/test/java/com/hedera/services/ethereum/EthTxDataTest.java#L49
     const RAW_TX_TYPE_0: &[u8]  =
         &hex!("f864012f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc18180827653820277a0f9fbff985d374be4a55f296915002eec11ac96f1ce2df183adf992baa9390b2fa00c1e867cc960d9c74ec2e6a662b7908ec4c8cc9f3091e886bcefbeb2290fb792");
@@ -380,4 +433,59 @@ mod test {
         "#]]
         .assert_debug_eq(&data);
     }
+
+    #[test]
+    fn eip1559_sign() {
+        let EthereumData::Eip1559(mut data) = EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap()
+        else {
+            unreachable!()
+        };
+
+        let key = PrivateKey::from_str_ecdsa(
+            "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+        )
+        .unwrap();
+
+        data.sign(&key).unwrap();
+
+        let (r, s, v) = key.sign_hash_recoverable(&data.signing_hash()).unwrap();
+
+        let signature = [r.as_slice(), s.as_slice(), &[v]].concat();
+
+        assert_eq!(
+            PublicKey::recover_from_hash(&data.signing_hash(), &signature).unwrap(),
+            key.public_key()
+        );
+
+        // the signature isn't part of the signing hash, so it survives a round trip.
+        let EthereumData::Eip1559(decoded) = EthereumData::from_bytes(&data.to_bytes()).unwrap()
+        else {
+            unreachable!()
+        };
+
+        assert_eq!(decoded.signing_hash(), data.signing_hash());
+        assert_eq!(decoded.recovery_id, if v == 0 { Vec::new() } else { vec![v] });
+
+        let mut padded = [0; 32];
+        padded[32 - decoded.r.len()..].copy_from_slice(&decoded.r);
+        assert_eq!(padded, r);
+
+        let mut padded = [0; 32];
+        padded[32 - decoded.s.len()..].copy_from_slice(&decoded.s);
+        assert_eq!(padded, s);
+    }
+
+    #[test]
+    fn eip1559_sign_ed25519() {
+        let EthereumData::Eip1559(mut data) = EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap()
+        else {
+            unreachable!()
+        };
+
+        assert_matches!(
+            data.sign(&PrivateKey::generate_ed25519()),
+            Err(Error::EthereumSignerKeyNotEcdsa)
+        );
+        assert_eq!(data.to_bytes(), RAW_TX_TYPE_2);
+    }
 }
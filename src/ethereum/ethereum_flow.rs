@@ -36,10 +36,22 @@ use crate::{
 pub struct EthereumFlow {
     ethereum_data: Option<EthereumData>,
     max_gas_allowance: Option<Hbar>,
+    max_ethereum_data_size: Option<usize>,
+}
+
+/// Response from [`EthereumFlow::execute_with_file_id`].
+#[derive(Debug)]
+pub struct EthereumFlowResponse {
+    /// The response from the [`EthereumTransaction`].
+    pub response: TransactionResponse,
+
+    /// The file the call data was moved to, if the ethereum data was too large to send inline.
+    pub call_data_file_id: Option<FileId>,
 }
 
 impl EthereumFlow {
-    const MAX_ETHEREUM_DATA_SIZE: usize = 5120;
+    /// The default for [`max_ethereum_data_size`](Self::max_ethereum_data_size).
+    pub const DEFAULT_MAX_ETHEREUM_DATA_SIZE: usize = 5120;
 
     /// Create a new `EthereumFlow` ready for configuartion.
     #[must_use]
@@ -65,20 +77,55 @@ impl EthereumFlow {
 
     /// Returns the maximum amount that the payer of the hedera transaction is willing to pay to complete the transaction.
     #[must_use]
-    pub fn get_max_gas_allowance(&self) -> Option<Hbar> {
+    pub fn get_max_gas_allowance_hbar(&self) -> Option<Hbar> {
         self.max_gas_allowance
     }
 
+    /// Sets the maximum amount that the payer of the hedera transaction is willing to pay to complete the transaction.
+    pub fn max_gas_allowance_hbar(&mut self, hbar: Hbar) -> &mut Self {
+        self.max_gas_allowance = Some(hbar);
+
+        self
+    }
+
+    /// Returns the maximum amount that the payer of the hedera transaction is willing to pay to complete the transaction.
+    ///
+    /// Deprecated, use [`get_max_gas_allowance_hbar`](Self::get_max_gas_allowance_hbar) instead.
+    #[deprecated]
+    #[must_use]
+    pub fn get_max_gas_allowance(&self) -> Option<Hbar> {
+        self.get_max_gas_allowance_hbar()
+    }
+
     /// Sets the maximum amount that the payer of the ethereum transaction is willing to pay to complete the transaction.
+    ///
+    /// Deprecated, use [`max_gas_allowance_hbar`](Self::max_gas_allowance_hbar) instead.
+    #[deprecated]
     pub fn max_gas_allowance(&mut self, hbar: Hbar) -> &mut Self {
-        self.max_gas_allowance = Some(hbar);
+        self.max_gas_allowance_hbar(hbar)
+    }
+
+    /// Returns the largest ethereum data (in bytes) that's sent inline, rather than moving the call data to a file.
+    #[must_use]
+    pub fn get_max_ethereum_data_size(&self) -> usize {
+        self.max_ethereum_data_size.unwrap_or(Self::DEFAULT_MAX_ETHEREUM_DATA_SIZE)
+    }
+
+    /// Sets the largest ethereum data (in bytes) that's sent inline, rather than moving the call data to a file.
+    ///
+    /// Defaults to [`DEFAULT_MAX_ETHEREUM_DATA_SIZE`](Self::DEFAULT_MAX_ETHEREUM_DATA_SIZE).
+    ///
+    /// Moving the call data to a file costs a [`FileCreateTransaction`] (and possibly some [`FileAppendTransaction`]s),
+    /// but the network rejects ethereum transactions that are too large.
+    pub fn max_ethereum_data_size(&mut self, size: usize) -> &mut Self {
+        self.max_ethereum_data_size = Some(size);
 
         self
     }
 
     /// Generates the required transactions and executes them all.
     pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
-        self.execute_with_optional_timeout(client, None).await
+        Ok(self.execute_with_optional_timeout(client, None).await?.response)
     }
 
     /// Generates the required transactions and executes them all.
@@ -87,6 +134,28 @@ impl EthereumFlow {
         client: &Client,
         timeout_per_transaction: std::time::Duration,
     ) -> crate::Result<TransactionResponse> {
+        Ok(self
+            .execute_with_optional_timeout(client, Some(timeout_per_transaction))
+            .await?
+            .response)
+    }
+
+    /// Generates the required transactions and executes them all,
+    /// also returning the file the call data was moved to, if any.
+    pub async fn execute_with_file_id(
+        &self,
+        client: &Client,
+    ) -> crate::Result<EthereumFlowResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Generates the required transactions and executes them all,
+    /// also returning the file the call data was moved to, if any.
+    pub async fn execute_with_file_id_and_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<EthereumFlowResponse> {
         self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
     }
 
@@ -94,7 +163,7 @@ impl EthereumFlow {
         &self,
         client: &Client,
         timeout_per_transaction: Option<std::time::Duration>,
-    ) -> crate::Result<TransactionResponse> {
+    ) -> crate::Result<EthereumFlowResponse> {
         let mut ethereum_data = self
             .ethereum_data
             .clone()
@@ -108,11 +177,13 @@ impl EthereumFlow {
             ethereum_transaction.max_gas_allowance_hbar(allowance);
         }
 
-        if ethereum_data_bytes.len() <= Self::MAX_ETHEREUM_DATA_SIZE {
-            return ethereum_transaction
+        if ethereum_data_bytes.len() <= self.get_max_ethereum_data_size() {
+            let response = ethereum_transaction
                 .ethereum_data(ethereum_data_bytes)
                 .execute_with_optional_timeout(client, timeout_per_transaction)
-                .await;
+                .await?;
+
+            return Ok(EthereumFlowResponse { response, call_data_file_id: None });
         }
 
         let call_data = mem::take(ethereum_data.call_data_mut());
@@ -120,7 +191,11 @@ impl EthereumFlow {
         let ethereum_data_bytes = ethereum_data.to_bytes();
         ethereum_transaction.call_data_file_id(file_id).ethereum_data(ethereum_data_bytes);
 
-        ethereum_transaction.execute_with_optional_timeout(client, timeout_per_transaction).await
+        let response = ethereum_transaction
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        Ok(EthereumFlowResponse { response, call_data_file_id: Some(file_id) })
     }
 }
 
@@ -167,3 +242,30 @@ async fn create_file(
 
     Ok(file_id)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EthereumFlow,
+        Hbar,
+    };
+
+    #[test]
+    fn get_set_max_gas_allowance_hbar() {
+        let mut flow = EthereumFlow::new();
+        flow.max_gas_allowance_hbar(Hbar::new(10));
+
+        assert_eq!(flow.get_max_gas_allowance_hbar(), Some(Hbar::new(10)));
+    }
+
+    #[test]
+    fn get_set_max_ethereum_data_size() {
+        let mut flow = EthereumFlow::new();
+
+        assert_eq!(flow.get_max_ethereum_data_size(), EthereumFlow::DEFAULT_MAX_ETHEREUM_DATA_SIZE);
+
+        flow.max_ethereum_data_size(1024);
+
+        assert_eq!(flow.get_max_ethereum_data_size(), 1024);
+    }
+}
@@ -28,7 +28,10 @@ pub use ethereum_data::{
     EthereumData,
     LegacyEthereumData,
 };
-pub use ethereum_flow::EthereumFlow;
+pub use ethereum_flow::{
+    EthereumFlow,
+    EthereumFlowResponse,
+};
 pub use ethereum_transaction::EthereumTransaction;
 pub(crate) use ethereum_transaction::EthereumTransactionData;
 pub use evm_address::EvmAddress;
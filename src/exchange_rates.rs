@@ -26,6 +26,7 @@ use crate::ToProtobuf;
 
 /// The current and next exchange rates between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct ExchangeRates {
     /// The current exchange rate between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
     pub current_rate: ExchangeRate,
@@ -42,6 +43,12 @@ impl ExchangeRates {
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         FromProtobuf::from_bytes(bytes)
     }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
 }
 
 impl FromProtobuf<services::ExchangeRateSet> for ExchangeRates {
@@ -66,6 +73,7 @@ impl ToProtobuf for ExchangeRates {
 
 /// Denotes a conversion between Hbars and cents (USD).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct ExchangeRate {
     /// Denotes [`Hbar`](crate::HbarUnit::Hbar) equivalent to cents (USD).
     pub hbars: u32,
@@ -74,6 +82,7 @@ pub struct ExchangeRate {
     pub cents: u32,
 
     /// Expiration time of this exchange rate.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339"))]
     pub expiration_time: OffsetDateTime,
 }
 
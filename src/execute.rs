@@ -21,11 +21,9 @@ mod error;
 
 use std::any::type_name;
 use std::borrow::Cow;
+use std::future::Future;
 use std::ops::ControlFlow;
-use std::time::{
-    Duration,
-    Instant,
-};
+use std::time::Duration;
 
 use backoff::{
     ExponentialBackoff,
@@ -33,16 +31,27 @@ use backoff::{
 };
 use futures_core::future::BoxFuture;
 use futures_util::StreamExt;
+use parking_lot::Mutex;
 use prost::Message;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+use tokio_util::sync::CancellationToken;
 use tonic::metadata::AsciiMetadataValue;
-use tonic::transport::Channel;
 use triomphe::Arc;
 
-use crate::client::NetworkData;
-use crate::execute::error::is_tonic_status_transient;
+use crate::client::{
+    NetworkData,
+    NodeSelector,
+    ReceiptThrottle,
+};
+use crate::execute::error::{
+    grpc_transport_error_kind,
+    is_tonic_status_transient,
+};
 use crate::ping_query::PingQuery;
+use crate::runtime::{
+    self,
+    Instant,
+};
+use crate::transport::Channel;
 use crate::{
     client,
     retry,
@@ -50,6 +59,7 @@ use crate::{
     BoxGrpcFuture,
     Client,
     Error,
+    RetryEvent,
     Status,
     TransactionId,
     ValidateChecksums,
@@ -78,21 +88,37 @@ pub(crate) trait Execute: ValidateChecksums {
     /// Get the _explicit_ transaction ID that this request will use.
     fn transaction_id(&self) -> Option<TransactionId>;
 
+    /// Get the node to try before any other, if it's healthy.
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        None
+    }
+
     /// Get whether to generate transaction IDs for request creation.
     fn requires_transaction_id(&self) -> bool;
 
     /// Returns whether to regenerate transaction IDs for request creation.
     ///
-    /// Transaction ID regeneration only can happen when `transaction_id` is None and `requires_transaction_id` is true.
+    /// Transaction ID regeneration only can happen when `requires_transaction_id` is true,
+    /// and `transaction_id` is None or `regenerates_explicit_transaction_id` is true.
     fn regenerate_transaction_id(&self) -> Option<bool> {
         None
     }
 
+    /// Returns whether an explicit `transaction_id` may be regenerated (for the same payer) when it expires.
+    fn regenerates_explicit_transaction_id(&self) -> bool {
+        false
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
     }
 
+    /// Returns whether this request polls for a receipt, and so is subject to the client's receipt query limit.
+    fn is_receipt_poll(&self) -> bool {
+        false
+    }
+
     /// Check whether we should retry an otherwise successful response.
     #[allow(unused_variables)]
     fn should_retry(&self, response: &Self::GrpcResponse) -> bool {
@@ -126,6 +152,11 @@ pub(crate) trait Execute: ValidateChecksums {
         transaction_id: Option<&TransactionId>,
     ) -> crate::Result<Self::Response>;
 
+    /// Record every node that was tried, in order, on a successful response.
+    ///
+    /// The last node is the one that produced the response.
+    fn record_attempted_nodes(_response: &mut Self::Response, _node_account_ids: Vec<AccountId>) {}
+
     /// Create an error from the given pre-check status.
     fn make_error_pre_check(
         &self,
@@ -139,13 +170,19 @@ pub(crate) trait Execute: ValidateChecksums {
 }
 
 struct ExecuteContext {
-    // When `Some` the `transaction_id` will be regenerated when expired.
+    // When `Some` the `transaction_id` will be generated from this account.
     operator_account_id: Option<AccountId>,
+    // When `true` (and `operator_account_id` is `Some`) the `transaction_id` will be regenerated when expired.
+    regenerate_transaction_id: bool,
     network: Arc<NetworkData>,
+    node_selector: Option<Arc<dyn NodeSelector>>,
     backoff_config: ExponentialBackoff,
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    // When `Some` every attempt waits for its turn, with the time the request started as its priority.
+    receipt_throttle: Option<(Arc<ReceiptThrottle>, Instant)>,
+    retry_handler: Option<client::RetryHandler>,
 }
 
 pub(crate) async fn execute<E>(
@@ -156,6 +193,30 @@ pub(crate) async fn execute<E>(
 where
     E: Execute + Sync,
 {
+    let mut backoff = client.backoff();
+
+    if let Some(timeout) = timeout {
+        backoff.request_timeout = Some(timeout);
+    }
+
+    execute_with_backoff(client, executable, backoff, None).await
+}
+
+/// Like [`execute`], but with an explicit backoff configuration rather than the client's.
+///
+/// If `attempted_nodes` is `Some`, every node the request is sent to is pushed to it,
+/// which is still filled in when the request fails.
+pub(crate) async fn execute_with_backoff<E>(
+    client: &Client,
+    executable: &E,
+    backoff: client::ClientBackoff,
+    attempted_nodes: Option<&Mutex<Vec<AccountId>>>,
+) -> crate::Result<E::Response>
+where
+    E: Execute + Sync,
+{
+    let _in_flight = client.enter_request()?;
+
     if client.auto_validate_checksums() {
         let ledger_id = client.ledger_id_internal();
         let ledger_id = ledger_id
@@ -165,46 +226,96 @@ where
         executable.validate_checksums(ledger_id.as_ref_ledger_id())?;
     }
 
-    let operator_account_id = 'op: {
-        if executable.transaction_id().is_some()
-            || !executable
-                .regenerate_transaction_id()
-                .unwrap_or(client.default_regenerate_transaction_id())
-        {
-            break 'op None;
-        }
+    let operator_account_id = regeneration_account_id(executable, client);
 
-        executable
-            .operator_account_id()
-            .copied()
-            .or_else(|| client.load_operator().as_ref().map(|it| it.account_id))
-    };
+    let regenerate_transaction_id = executable
+        .regenerate_transaction_id()
+        .unwrap_or(client.default_regenerate_transaction_id());
 
-    let backoff = client.backoff();
     let mut backoff_builder = ExponentialBackoffBuilder::new();
 
     backoff_builder
         .with_initial_interval(backoff.initial_backoff)
         .with_max_interval(backoff.max_backoff);
 
-    if let Some(timeout) = timeout.or(backoff.request_timeout) {
+    if let Some(timeout) = backoff.request_timeout {
         backoff_builder.with_max_elapsed_time(Some(timeout));
     }
 
-    execute_inner(
+    #[cfg(feature = "metrics")]
+    let started_at = Instant::now();
+
+    let res = execute_inner(
         &ExecuteContext {
             max_attempts: backoff.max_attempts,
             backoff_config: backoff_builder.build(),
             operator_account_id,
+            regenerate_transaction_id,
             network: client.net().0.load_full(),
+            node_selector: client.node_selector(),
             grpc_timeout: backoff.grpc_timeout,
+            receipt_throttle: executable
+                .is_receipt_poll()
+                .then(|| client.receipt_throttle())
+                .flatten()
+                .map(|it| (it, Instant::now())),
+            retry_handler: client.retry_handler(),
         },
         executable,
+        attempted_nodes,
     )
-    .await
+    .await;
+
+    #[cfg(feature = "metrics")]
+    {
+        let elapsed = started_at.elapsed();
+
+        crate::metrics::record_request(request_name::<E>(), res.is_ok(), elapsed);
+
+        if executable.is_receipt_poll() {
+            crate::metrics::record_receipt_wait(res.is_ok(), elapsed);
+        }
+    }
+
+    res
+}
+
+/// Returns the account to generate (and regenerate) the transaction ID of `executable` from.
+pub(crate) fn regeneration_account_id<E: Execute>(
+    executable: &E,
+    client: &Client,
+) -> Option<AccountId> {
+    match executable.transaction_id() {
+        Some(id) if executable.regenerates_explicit_transaction_id() => Some(id.account_id),
+        // an explicit transaction ID is otherwise never replaced, even if it expires.
+        Some(_) => None,
+        None => executable
+            .operator_account_id()
+            .copied()
+            .or_else(|| client.load_operator().as_ref().map(|it| it.account_id)),
+    }
 }
 
-async fn execute_inner<E>(ctx: &ExecuteContext, executable: &E) -> crate::Result<E::Response>
+/// Runs `fut` unless `cancellation` is cancelled first, in which case `fut` is dropped.
+///
+/// Requests are safe to drop at any point, nothing in the request itself is left half changed,
+/// but a transaction that was already sent to a node may still reach consensus.
+pub(crate) async fn cancellable<T>(
+    cancellation: &CancellationToken,
+    fut: impl Future<Output = crate::Result<T>>,
+) -> crate::Result<T> {
+    tokio::select! {
+        biased;
+        () = cancellation.cancelled() => Err(Error::Cancelled),
+        res = fut => res,
+    }
+}
+
+async fn execute_inner<E>(
+    ctx: &ExecuteContext,
+    executable: &E,
+    attempted_nodes: Option<&Mutex<Vec<AccountId>>>,
+) -> crate::Result<E::Response>
 where
     E: Execute + Sync,
 {
@@ -212,14 +323,18 @@ where
         Box::pin(async move {
             let ctx = ExecuteContext {
                 operator_account_id: None,
+                regenerate_transaction_id: false,
                 network: Arc::clone(&ctx.network),
+                node_selector: ctx.node_selector.clone(),
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                receipt_throttle: None,
+                retry_handler: None,
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
-            execute_inner(&ctx, &ping_query).await.is_ok()
+            execute_inner(&ctx, &ping_query, None).await.is_ok()
         })
     }
 
@@ -245,12 +360,51 @@ where
 
     let explicit_node_indexes = explicit_node_indexes.as_deref();
 
+    // a node that isn't part of the network (anymore) just isn't preferred.
+    let preferred_node_index = executable
+        .preferred_node_account_id()
+        .and_then(|id| ctx.network.node_indexes_for_ids(&[id]).ok())
+        .and_then(|indexes| indexes.first().copied());
+
+    // every node a request is sent to, across all retries.
+    let own_attempted_nodes = Mutex::new(Vec::new());
+    let attempted_nodes = attempted_nodes.unwrap_or(&own_attempted_nodes);
+
+    let report_retry = move |delay: Duration, cause: &Error| {
+        let (node_account_id, attempt) = {
+            let attempted_nodes = attempted_nodes.lock();
+            (attempted_nodes.last().copied(), attempted_nodes.len())
+        };
+
+        let Some(node_account_id) = node_account_id else {
+            return;
+        };
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_retry(request_name::<E>(), node_account_id, delay);
+
+        if let Some(handler) = &ctx.retry_handler {
+            handler(&RetryEvent {
+                request: request_name::<E>(),
+                node_account_id,
+                attempt,
+                delay,
+                cause,
+            });
+        }
+    };
+
     let layer = move || async move {
         loop {
             let mut last_error: Option<Error> = None;
 
-            let random_node_indexes = random_node_indexes(&ctx.network, explicit_node_indexes)
-                .ok_or(retry::Error::EmptyTransient)?;
+            let random_node_indexes = select_node_indexes(
+                &ctx.network,
+                ctx.node_selector.as_deref(),
+                explicit_node_indexes,
+                preferred_node_index,
+            )
+            .ok_or(retry::Error::EmptyTransient)?;
 
             let random_node_indexes = {
                 let random_node_indexes = &random_node_indexes;
@@ -269,6 +423,13 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
+                // the previous node failed in a way that moves straight on to this one.
+                if let Some(err) = &last_error {
+                    report_retry(Duration::ZERO, err);
+                }
+
+                attempted_nodes.lock().push(ctx.network.node_ids()[node_index]);
+
                 let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
 
                 log::log!(
@@ -295,7 +456,10 @@ where
 
                 match tmp? {
                     ControlFlow::Continue(err) => last_error = Some(err),
-                    ControlFlow::Break(res) => return Ok(res),
+                    ControlFlow::Break(mut res) => {
+                        E::record_attempted_nodes(&mut res, attempted_nodes.lock().clone());
+                        return Ok(res);
+                    }
                 }
             }
 
@@ -310,7 +474,15 @@ where
     // the outer loop continues until we timeout or reach the maximum number of "attempts"
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
-    crate::retry(backoff, Some(ctx.max_attempts), layer).await
+    retry::retry_with_backoff(backoff, Some(ctx.max_attempts), layer, report_retry).await
+}
+
+/// Returns a short name for the request `E`, IE, `TransferTransaction` rather than `hedera::transaction::Transaction<...>`.
+fn request_name<E>() -> &'static str {
+    let name = type_name::<E>().trim_end_matches('>');
+    let name = name.rsplit("::").next().unwrap_or(name);
+
+    name.strip_suffix("Data").unwrap_or(name)
 }
 
 fn map_tonic_error(
@@ -327,8 +499,16 @@ fn map_tonic_error(
             // NOTE: this is an "unhealthy" node
             network.mark_node_unhealthy(node_index);
 
+            // a status with a source came from our end of the connection (say, the node sent a `GOAWAY`),
+            // rather than from the node, so the connection is likely gone.
+            if status.code() == tonic::Code::Unavailable
+                && std::error::Error::source(&status).is_some()
+            {
+                network.reconnect_node(node_index);
+            }
+
             // try the next node in our allowed list, immediately
-            retry::Error::Transient(status.into())
+            retry::Error::Transient(grpc_error(status, network, node_index))
         }
 
         // todo: find a way to make this less fragile
@@ -345,19 +525,32 @@ fn map_tonic_error(
             // hack to the hack:
             // if this is a free request let's try retrying it anyway...
             match request_free {
-                true => retry::Error::Transient(status.into()),
-                false => retry::Error::Permanent(status.into()),
+                true => retry::Error::Transient(grpc_error(status, network, node_index)),
+                false => retry::Error::Permanent(grpc_error(status, network, node_index)),
             }
         }
 
         _ if is_tonic_status_transient(&status) => {
             network.mark_node_unhealthy(node_index);
 
-            retry::Error::Transient(status.into())
+            retry::Error::Transient(grpc_error(status, network, node_index))
         }
 
         // fail immediately
-        _ => retry::Error::Permanent(status.into()),
+        _ => retry::Error::Permanent(grpc_error(status, network, node_index)),
+    }
+}
+
+/// Converts `status` to an error, attaching the node to it if the connection to the node failed.
+fn grpc_error(status: tonic::Status, network: &client::NetworkData, node_index: usize) -> Error {
+    match grpc_transport_error_kind(&status) {
+        Some(kind) => Error::grpc_transport(
+            kind,
+            network.node_ids()[node_index],
+            network.node_endpoints(node_index),
+            status,
+        ),
+        None => Error::GrpcStatus(status),
     }
 }
 
@@ -384,14 +577,22 @@ async fn execute_single<E: Execute + Sync>(
         type_name::<E>()
     );
 
+    if let Some((throttle, priority)) = &ctx.receipt_throttle {
+        throttle.acquire(*priority).await;
+    }
+
+    let started_at = Instant::now();
+
     let fut = executable.execute(channel, request);
 
     let response = match ctx.grpc_timeout {
-        Some(it) => match tokio::time::timeout(it, fut).await {
+        Some(it) => match runtime::timeout(it, fut).await {
             Ok(it) => it,
             Err(_) => {
-                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
+                return Ok(ControlFlow::Continue(grpc_error(
                     tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
+                    &ctx.network,
+                    node_index,
                 )))
             }
         },
@@ -413,6 +614,10 @@ async fn execute_single<E: Execute + Sync>(
 
     // at this point, any failure isn't from the node, it's from the request.
     ctx.network.mark_node_healthy(node_index);
+    ctx.network.record_latency(node_index, started_at.elapsed());
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_node_request(request_name::<E>(), node_account_id, started_at.elapsed());
 
     let status = E::response_pre_check_status(&response)
         .and_then(|status| {
@@ -442,7 +647,9 @@ async fn execute_single<E: Execute + Sync>(
         }
 
         // would do an `if_let` but, not stable ._.
-        Status::TransactionExpired if ctx.operator_account_id.is_some() => {
+        Status::TransactionExpired
+            if ctx.regenerate_transaction_id && ctx.operator_account_id.is_some() =>
+        {
             // the transaction that was generated has since expired
             // re-generate the transaction ID and try again, immediately
 
@@ -478,40 +685,103 @@ async fn execute_single<E: Execute + Sync>(
 }
 
 // todo: return an iterator.
-fn random_node_indexes(
+fn select_node_indexes(
     network: &client::NetworkData,
+    selector: Option<&dyn NodeSelector>,
     explicit_node_indexes: Option<&[usize]>,
+    preferred_node_index: Option<usize>,
 ) -> Option<Vec<usize>> {
-    // cache the rng impl and "now" because `thread_rng` is TLS (a thread local),
-    // and because using the same reference time avoids situations where a node that wasn't available becomes available.
-    let mut rng = thread_rng();
+    // cache "now" because using the same reference time avoids situations where a node that wasn't available becomes available.
     let now = Instant::now();
 
+    let mut indexes = select_node_indexes_at(network, selector, explicit_node_indexes, now)?;
+
+    if let Some(preferred) = preferred_node_index {
+        // an explicit list of nodes is never added to.
+        let allowed = explicit_node_indexes.map_or(true, |it| it.contains(&preferred));
+
+        if allowed && network.is_node_healthy(preferred, now) {
+            prefer_node_index(&mut indexes, preferred);
+        }
+    }
+
+    Some(indexes)
+}
+
+/// Moves `preferred` to the front of `indexes`, adding it if it isn't there.
+fn prefer_node_index(indexes: &mut Vec<usize>, preferred: usize) {
+    match indexes.iter().position(|&it| it == preferred) {
+        Some(position) => indexes[..=position].rotate_right(1),
+        None => indexes.insert(0, preferred),
+    }
+}
+
+fn select_node_indexes_at(
+    network: &client::NetworkData,
+    selector: Option<&dyn NodeSelector>,
+    explicit_node_indexes: Option<&[usize]>,
+    now: Instant,
+) -> Option<Vec<usize>> {
     if let Some(indexes) = explicit_node_indexes {
         let tmp: Vec<_> =
             indexes.iter().copied().filter(|index| network.is_node_healthy(*index, now)).collect();
 
-        let mut indexes = if tmp.is_empty() { indexes.to_vec() } else { tmp };
+        let indexes = if tmp.is_empty() { indexes.to_vec() } else { tmp };
 
         assert!(!indexes.is_empty(), "empty explicitly set nodes");
 
-        indexes.shuffle(&mut rng);
+        let amount = indexes.len();
 
-        return Some(indexes);
+        return Some(network.select_nodes(selector, indexes, amount));
     }
 
     {
-        let mut indexes: Vec<_> = network.healthy_node_indexes(now).collect();
+        let indexes: Vec<_> = network.healthy_node_indexes(now).collect();
 
         if indexes.is_empty() {
             return None;
         }
 
-        // would put this inline, but borrowck wouldn't allow that.
         let amount = (indexes.len() + 2) / 3;
 
-        let (shuffled, _) = indexes.partial_shuffle(&mut rng, amount);
+        Some(network.select_nodes(selector, indexes, amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        prefer_node_index,
+        request_name,
+    };
+    use crate::ping_query::PingQuery;
+    use crate::transaction::AnyTransactionData;
+    use crate::{
+        AccountBalanceQuery,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn request_names() {
+        assert_eq!(request_name::<TransferTransaction>(), "TransferTransaction");
+        assert_eq!(request_name::<AccountBalanceQuery>(), "AccountBalanceQuery");
+        assert_eq!(request_name::<PingQuery>(), "PingQuery");
+        assert_eq!(request_name::<AnyTransactionData>(), "AnyTransaction");
+    }
+
+    #[test]
+    fn prefer_node_index_moves_to_front() {
+        let mut indexes = vec![4, 2, 7, 1];
+        prefer_node_index(&mut indexes, 7);
+
+        assert_eq!(indexes, [7, 4, 2, 1]);
+    }
+
+    #[test]
+    fn prefer_node_index_adds_missing() {
+        let mut indexes = vec![4, 2];
+        prefer_node_index(&mut indexes, 7);
 
-        Some(shuffled.to_vec())
+        assert_eq!(indexes, [7, 4, 2]);
     }
 }
@@ -17,10 +17,14 @@
  * limitations under the License.
  * ‍
  */
-use std::error::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use std::error::Error as StdError;
 
-use serde::de::StdError;
+use tonic::Code;
 
+use crate::GrpcTransportErrorKind;
+
+#[cfg(not(target_arch = "wasm32"))]
 fn has_transient_io_error<E: StdError>(error: E) -> bool {
     let Some(source) = error.source() else {
         return false;
@@ -34,6 +38,7 @@ fn has_transient_io_error<E: StdError>(error: E) -> bool {
 }
 
 // tonic 0.11 (current dependency)
+#[cfg(not(target_arch = "wasm32"))]
 fn is_hyper_0_error_transient(error: &hyper_0::Error) -> bool {
     if error.is_canceled() || has_transient_io_error(error) {
         true
@@ -49,6 +54,7 @@ fn is_hyper_0_error_transient(error: &hyper_0::Error) -> bool {
 }
 
 // tonic 0.12
+#[cfg(not(target_arch = "wasm32"))]
 fn is_hyper_error_transient(error: &hyper::Error) -> bool {
     if error.is_canceled() || has_transient_io_error(error) {
         true
@@ -63,6 +69,7 @@ fn is_hyper_error_transient(error: &hyper::Error) -> bool {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn is_io_error_transient(error: &std::io::Error) -> bool {
     match error.kind() {
         std::io::ErrorKind::BrokenPipe => true,
@@ -70,6 +77,7 @@ fn is_io_error_transient(error: &std::io::Error) -> bool {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub(super) fn is_tonic_status_transient(status: &tonic::Status) -> bool {
     let source = status
         .source()
@@ -89,6 +97,64 @@ pub(super) fn is_tonic_status_transient(status: &tonic::Status) -> bool {
     }
 }
 
+// gRPC-web surfaces connection failures as plain statuses, there's nothing underneath to inspect.
+#[cfg(target_arch = "wasm32")]
+pub(super) fn is_tonic_status_transient(_status: &tonic::Status) -> bool {
+    false
+}
+
+/// Returns why the connection to a node failed, or `None` if `status` is a response from the node.
+pub(super) fn grpc_transport_error_kind(status: &tonic::Status) -> Option<GrpcTransportErrorKind> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(kind) = transport_source_error_kind(status) {
+        return Some(kind);
+    }
+
+    match status.code() {
+        Code::DeadlineExceeded => Some(GrpcTransportErrorKind::Timeout),
+        Code::Unavailable => Some(GrpcTransportErrorKind::Unavailable),
+        Code::Cancelled => Some(GrpcTransportErrorKind::Cancelled),
+        _ => None,
+    }
+}
+
+// the code alone can't tell a refused connection from any other, so look at what caused it.
+#[cfg(not(target_arch = "wasm32"))]
+fn transport_source_error_kind(status: &tonic::Status) -> Option<GrpcTransportErrorKind> {
+    let mut source = status.source();
+
+    while let Some(error) = source {
+        if let Some(io_error) = error.downcast_ref::<std::io::Error>() {
+            match io_error.kind() {
+                std::io::ErrorKind::ConnectionRefused => {
+                    return Some(GrpcTransportErrorKind::ConnectionRefused)
+                }
+                std::io::ErrorKind::TimedOut => return Some(GrpcTransportErrorKind::Timeout),
+                // rustls reports handshake failures as `InvalidData`.
+                std::io::ErrorKind::InvalidData => {
+                    return Some(GrpcTransportErrorKind::TlsHandshake)
+                }
+                _ => {}
+            }
+        } else if let Some(hyper_0) = error.downcast_ref::<hyper_0::Error>() {
+            if hyper_0.is_timeout() {
+                return Some(GrpcTransportErrorKind::Timeout);
+            }
+        } else if let Some(hyper) = error.downcast_ref::<hyper::Error>() {
+            if hyper.is_timeout() {
+                return Some(GrpcTransportErrorKind::Timeout);
+            }
+        } else if error.is::<tonic::transport::TimeoutExpired>() {
+            // tonic reports its own timeouts as `Cancelled`.
+            return Some(GrpcTransportErrorKind::Timeout);
+        }
+
+        source = error.source();
+    }
+
+    None
+}
+
 /// Tests some non-detection scenarios.
 ///
 /// Because hyper does not expose constructors for its error variants, there is no
@@ -113,3 +179,49 @@ mod test_is_tonic_status_transient {
         assert!(!is_tonic_status_transient(&input));
     }
 }
+
+#[cfg(test)]
+mod test_grpc_transport_error_kind {
+    use tonic::Code;
+
+    use super::grpc_transport_error_kind;
+    use crate::GrpcTransportErrorKind;
+
+    #[test]
+    fn codes() {
+        for (code, expected) in [
+            (Code::DeadlineExceeded, Some(GrpcTransportErrorKind::Timeout)),
+            (Code::Unavailable, Some(GrpcTransportErrorKind::Unavailable)),
+            (Code::Cancelled, Some(GrpcTransportErrorKind::Cancelled)),
+            (Code::Internal, None),
+            (Code::NotFound, None),
+            (Code::ResourceExhausted, None),
+        ] {
+            let input = tonic::Status::new(code, "foo");
+
+            assert_eq!(grpc_transport_error_kind(&input), expected, "{code:?}");
+        }
+    }
+
+    #[test]
+    fn connection_refused() {
+        let input = tonic::Status::from_error(Box::new(std::io::Error::from(
+            std::io::ErrorKind::ConnectionRefused,
+        )));
+
+        assert_eq!(
+            grpc_transport_error_kind(&input),
+            Some(GrpcTransportErrorKind::ConnectionRefused)
+        );
+    }
+
+    #[test]
+    fn tls_handshake() {
+        let input = tonic::Status::from_error(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "invalid peer certificate",
+        )));
+
+        assert_eq!(grpc_transport_error_kind(&input), Some(GrpcTransportErrorKind::TlsHandshake));
+    }
+}
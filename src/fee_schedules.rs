@@ -170,7 +170,7 @@ impl ToProtobuf for TransactionFeeSchedule {
 }
 
 /// The functionality provided by Hedera.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum RequestType {
     /// UNSPECIFIED - Need to keep first value as unspecified because first element is ignored and not parsed (0 is ignored by parser)
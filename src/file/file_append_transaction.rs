@@ -23,7 +23,10 @@ use std::num::NonZeroUsize;
 
 use hedera_proto::services;
 use hedera_proto::services::file_service_client::FileServiceClient;
-use tonic::transport::Channel;
+use tokio::io::{
+    AsyncRead,
+    AsyncReadExt,
+};
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -41,11 +44,15 @@ use crate::transaction::{
     TransactionExecute,
     TransactionExecuteChunked,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
     FileId,
     Transaction,
+    TransactionId,
+    TransactionResponse,
     ValidateChecksums,
 };
 
@@ -95,6 +102,79 @@ impl FileAppendTransaction {
         self.data_mut().chunk_data.data = contents.into();
         self
     }
+
+    /// Appends everything read from `reader` to the end of the specified file, one chunk at a time.
+    ///
+    /// Unlike setting [`contents`](Self::contents) and calling [`execute_all`](Self::execute_all),
+    /// only a single chunk of [`chunk_size`](Self::chunk_size) bytes is held in memory at once,
+    /// which makes this suitable for uploading large files, such as network upgrade files, from disk.
+    ///
+    /// Each chunk is submitted as its own transaction, with the contents and [`max_chunks`](Self::max_chunks)
+    /// of `self` ignored, and its receipt is awaited before the next chunk is read.
+    /// If a transaction ID was set explicitly, it's used for the first chunk,
+    /// and every later chunk gets a new one for the same payer.
+    ///
+    /// # Errors
+    /// - [`Error::ContentsRead`] if reading from `reader` fails.
+    /// - Any error [`execute`](Self::execute) can return, for any of the chunks.
+    ///   Chunks submitted before the error remain appended to the file.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub async fn execute_all_from_reader<R: AsyncRead + Unpin + Send>(
+        &self,
+        client: &Client,
+        mut reader: R,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.require_not_frozen();
+
+        let chunk_size = self.get_chunk_size();
+        let payer_account_id = self.get_transaction_id().map(|it| it.account_id);
+
+        let mut buf = vec![0; chunk_size];
+        let mut responses = Vec::new();
+
+        loop {
+            let len = read_chunk(&mut reader, &mut buf).await.map_err(Error::ContentsRead)?;
+
+            // an empty reader still appends (nothing) once, like empty `contents` would.
+            if len == 0 && !responses.is_empty() {
+                break;
+            }
+
+            let mut transaction = self.clone();
+            transaction.contents(&buf[..len]);
+
+            if let Some(payer_account_id) = payer_account_id.filter(|_| !responses.is_empty()) {
+                transaction.transaction_id(TransactionId::generate(payer_account_id));
+            }
+
+            responses.push(transaction.execute(client).await?);
+
+            if len < chunk_size {
+                break;
+            }
+        }
+
+        Ok(responses)
+    }
+}
+
+/// Reads from `reader` until `buf` is full or `reader` is exhausted, returning the number of bytes read.
+async fn read_chunk<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> std::io::Result<usize> {
+    let mut len = 0;
+
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]).await? {
+            0 => break,
+            n => len += n,
+        }
+    }
+
+    Ok(len)
 }
 
 impl TransactionData for FileAppendTransactionData {
@@ -227,6 +307,20 @@ mod tests {
 
     const CONTENTS: &[u8] = br#"{"foo": 231}"#;
 
+    #[tokio::test]
+    async fn read_chunk() {
+        let mut reader = tokio::io::AsyncReadExt::chain(&CONTENTS[..5], &CONTENTS[5..]);
+        let mut buf = [0; 8];
+
+        assert_eq!(super::read_chunk(&mut reader, &mut buf).await.unwrap(), 8);
+        assert_eq!(&buf, &CONTENTS[..8]);
+
+        assert_eq!(super::read_chunk(&mut reader, &mut buf).await.unwrap(), 4);
+        assert_eq!(&buf[..4], &CONTENTS[8..]);
+
+        assert_eq!(super::read_chunk(&mut reader, &mut buf).await.unwrap(), 0);
+    }
+
     fn make_transaction() -> FileAppendTransaction {
         let mut tx = FileAppendTransaction::new_for_tests();
         tx.file_id(FILE_ID).contents(CONTENTS).freeze().unwrap();
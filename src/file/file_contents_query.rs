@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::file_service_client::FileServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -29,18 +28,29 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
     FileContentsResponse,
     FileId,
+    FromProtobuf,
     ToProtobuf,
     ValidateChecksums,
 };
 
 /// Get the contents of a file.
+///
+/// The network always returns a file's entire contents in a single response (`FileGetContents` has no range),
+/// so the response may be larger than gRPC's default 4 MiB message size limit, up to just over 256 MiB.
 pub type FileContentsQuery = Query<FileContentsQueryData>;
 
+/// The largest file contents response that will be decoded.
+///
+/// Regular files are limited to 1 MiB (`files.maxSizeKb`), but special files such as upgrade files
+/// can be much larger, so this leaves room for those, plus the rest of the response.
+const MAX_RESPONSE_SIZE: usize = 256 * 1024 * 1024 + 64 * 1024;
+
 #[derive(Clone, Default, Debug)]
 pub struct FileContentsQueryData {
     /// The file ID for which contents are requested.
@@ -79,6 +89,12 @@ impl ToQueryProtobuf for FileContentsQueryData {
     }
 }
 
+impl FromProtobuf<services::FileGetContentsQuery> for FileContentsQueryData {
+    fn from_protobuf(pb: services::FileGetContentsQuery) -> crate::Result<Self> {
+        Ok(Self { file_id: Option::from_protobuf(pb.file_id)? })
+    }
+}
+
 impl QueryExecute for FileContentsQueryData {
     type Response = FileContentsResponse;
 
@@ -87,7 +103,14 @@ impl QueryExecute for FileContentsQueryData {
         channel: Channel,
         request: services::Query,
     ) -> BoxGrpcFuture<'_, services::Response> {
-        Box::pin(async { FileServiceClient::new(channel).get_file_content(request).await })
+        Box::pin(async {
+            // files (notably upgrade files) can be larger than tonic's default 4 MiB decoding limit,
+            // and there's no way to request them in pieces.
+            FileServiceClient::new(channel)
+                .max_decoding_message_size(MAX_RESPONSE_SIZE)
+                .get_file_content(request)
+                .await
+        })
     }
 }
 
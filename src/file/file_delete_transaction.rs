@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::file_service_client::FileServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -35,6 +34,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
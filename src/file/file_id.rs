@@ -61,12 +61,21 @@ impl FileId {
     /// Address of the public [node address book](crate::NodeAddressBook) for the current network.
     pub const ADDRESS_BOOK: Self = Self::new(0, 0, 102);
 
-    /// Address of the current fee schedule for the network.
+    /// Address of the network's original node address book.
+    ///
+    /// Unlike [`ADDRESS_BOOK`](Self::ADDRESS_BOOK), this doesn't include
+    /// [TLS certificate hashes](crate::NodeAddress::tls_certificate_hash).
+    pub const LEGACY_ADDRESS_BOOK: Self = Self::new(0, 0, 101);
+
+    /// Address of the current [fee schedule](crate::FeeSchedules) for the network.
     pub const FEE_SCHEDULE: Self = Self::new(0, 0, 111);
 
     /// Address of the [current exchange rate](crate::ExchangeRates) of HBAR to USD.
     pub const EXCHANGE_RATES: Self = Self::new(0, 0, 112);
 
+    /// Address of the [throttle definitions](crate::system_files::ThrottleDefinitions) for the network.
+    pub const THROTTLE_DEFINITIONS: Self = Self::new(0, 0, 123);
+
     /// Create a `FileId` with the given `shard.realm.num`.
     pub const fn new(shard: u64, realm: u64, num: u64) -> Self {
         Self { shard, realm, num, checksum: None }
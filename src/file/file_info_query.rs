@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::file_service_client::FileServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,11 +27,13 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
     FileId,
     FileInfo,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -80,6 +81,12 @@ impl ToQueryProtobuf for FileInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::FileGetInfoQuery> for FileInfoQueryData {
+    fn from_protobuf(pb: services::FileGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { file_id: Option::from_protobuf(pb.file_id)? })
+    }
+}
+
 impl QueryExecute for FileInfoQueryData {
     type Response = FileInfo;
 
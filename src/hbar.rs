@@ -3,8 +3,11 @@ use std::fmt::{
     Display,
     Formatter,
 };
-use std::ops;
 use std::str::FromStr;
+use std::{
+    iter,
+    ops,
+};
 
 use rust_decimal::prelude::*;
 
@@ -214,6 +217,66 @@ impl Hbar {
     pub fn negated(self) -> Self {
         -self
     }
+
+    /// Returns `self` formatted in `unit`, followed by the unit's symbol.
+    ///
+    /// Unlike [`Display`] this never picks the unit itself, and the amount is never rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::{Hbar, HbarUnit};
+    /// assert_eq!(Hbar::new(2).to_string_with_unit(HbarUnit::Millibar), "2000 mℏ");
+    /// assert_eq!(Hbar::from_tinybars(15).to_string_with_unit(HbarUnit::Hbar), "0.00000015 ℏ");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_unit(self, unit: HbarUnit) -> String {
+        format!("{} {}", self.to(unit).normalize(), unit.symbol())
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_mul(self, rhs: i64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Computes `self + rhs`, saturating at the bounds of [`Tinybar`] rather than overflowing.
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Computes `self - rhs`, saturating at the bounds of [`Tinybar`] rather than overflowing.
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Computes `self * rhs`, saturating at the bounds of [`Tinybar`] rather than overflowing.
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: i64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
 }
 
 impl From<Hbar> for Decimal {
@@ -291,6 +354,18 @@ impl ops::SubAssign for Hbar {
     }
 }
 
+impl iter::Sum for Hbar {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, ops::Add::add)
+    }
+}
+
+impl<'a> iter::Sum<&'a Hbar> for Hbar {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl<T> ops::Mul<T> for Hbar
 where
     i64: ops::Mul<T, Output = i64>,
@@ -383,4 +458,44 @@ mod tests {
         assert_eq!(m.to_tinybars(), 3);
         assert_eq!((-m).to_tinybars(), -3);
     }
+
+    #[test]
+    fn checked_and_saturating() {
+        let max = Hbar::from_tinybars(i64::MAX);
+        let one = Hbar::from_tinybars(1);
+
+        assert_eq!(one.checked_add(one), Some(Hbar::from_tinybars(2)));
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!((-max).checked_sub(one.saturating_add(one)), None);
+        assert_eq!(one.checked_mul(3), Some(Hbar::from_tinybars(3)));
+        assert_eq!(max.checked_mul(2), None);
+
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(
+            Hbar::from_tinybars(i64::MIN).saturating_sub(one),
+            Hbar::from_tinybars(i64::MIN)
+        );
+        assert_eq!(max.saturating_mul(-2), Hbar::from_tinybars(i64::MIN));
+    }
+
+    #[test]
+    fn sum() {
+        let amounts = [Hbar::new(1), Hbar::from_tinybars(-5), Hbar::from_tinybars(7)];
+
+        assert_eq!(amounts.iter().sum::<Hbar>(), Hbar::from_tinybars(100_000_002));
+        assert_eq!(amounts.into_iter().sum::<Hbar>(), Hbar::from_tinybars(100_000_002));
+        assert_eq!(std::iter::empty::<Hbar>().sum::<Hbar>(), Hbar::ZERO);
+    }
+
+    #[test]
+    fn to_string_with_unit() {
+        let value = Hbar::from_tinybars(150_000_000);
+
+        assert_eq!(value.to_string_with_unit(HbarUnit::Tinybar), "150000000 tℏ");
+        assert_eq!(value.to_string_with_unit(HbarUnit::Millibar), "1500 mℏ");
+        assert_eq!(value.to_string_with_unit(HbarUnit::Hbar), "1.5 ℏ");
+        assert_eq!(value.to_string_with_unit(HbarUnit::Kilobar), "0.0015 kℏ");
+        assert_eq!((-value).to_string_with_unit(HbarUnit::Hbar), "-1.5 ℏ");
+        assert_eq!(Hbar::ZERO.to_string_with_unit(HbarUnit::Megabar), "0 Mℏ");
+    }
 }
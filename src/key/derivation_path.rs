@@ -0,0 +1,175 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A `BIP-32` (or `SLIP-10`) key derivation path, such as `m/44'/3030'/0'/0'/0'`.
+///
+/// Hardened indices are stored with [`HARDENED`](Self::HARDENED) set, as in `BIP-32`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// The bit that marks an index as hardened.
+    pub const HARDENED: u32 = 1 << 31;
+
+    /// Create a path from raw indices, hardened indices must have [`HARDENED`](Self::HARDENED) set.
+    #[must_use]
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self { indices }
+    }
+
+    /// The path used by the Hedera wallets for Ed25519 keys: `m/44'/3030'/0'/0'/{index}'`.
+    #[must_use]
+    pub fn hedera_ed25519(index: u32) -> Self {
+        Self::hedera_ed25519_account().child(index, true)
+    }
+
+    /// The standard path for ECDSA(secp256k1) keys, shared with Ethereum wallets: `m/44'/60'/0'/0/{index}`.
+    #[must_use]
+    pub fn standard_ecdsa(index: u32) -> Self {
+        Self::new(vec![44 | Self::HARDENED, 60 | Self::HARDENED, Self::HARDENED, 0])
+            .child(index, false)
+    }
+
+    // the path `PrivateKey::from_mnemonic` has always used, which stops short of an index.
+    pub(crate) fn hedera_ed25519_account() -> Self {
+        Self::new(vec![44 | Self::HARDENED, 3030 | Self::HARDENED, Self::HARDENED, Self::HARDENED])
+    }
+
+    /// Returns `self` with `index` appended to it.
+    #[must_use]
+    pub fn child(mut self, index: u32, hardened: bool) -> Self {
+        self.indices.push(if hardened { index | Self::HARDENED } else { index });
+        self
+    }
+
+    /// Returns the raw indices of this path.
+    #[must_use]
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns `true` if every index in this path is hardened.
+    #[must_use]
+    pub fn is_hardened(&self) -> bool {
+        self.indices.iter().all(|it| it & Self::HARDENED != 0)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+
+        for index in &self.indices {
+            write!(f, "/{}", index & !Self::HARDENED)?;
+
+            if index & Self::HARDENED != 0 {
+                f.write_str("'")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(Error::basic_parse(format!(
+                "derivation path must start with `m`, found `{s}`"
+            )));
+        }
+
+        let indices = parts
+            .map(|part| {
+                let (index, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                    Some(index) => (index, true),
+                    None => (part, false),
+                };
+
+                let index: u32 = index.parse().map_err(Error::basic_parse)?;
+
+                if index & Self::HARDENED != 0 {
+                    return Err(Error::basic_parse(format!(
+                        "derivation path index out of range: `{part}`"
+                    )));
+                }
+
+                Ok(if hardened { index | Self::HARDENED } else { index })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { indices })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+
+    use super::DerivationPath;
+    use crate::Error;
+
+    #[test]
+    fn parse() {
+        let path = DerivationPath::from_str("m/44'/60h/0'/0/7").unwrap();
+
+        assert_eq!(path, DerivationPath::standard_ecdsa(7));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(DerivationPath::hedera_ed25519(0).to_string(), "m/44'/3030'/0'/0'/0'");
+        assert_eq!(DerivationPath::standard_ecdsa(3).to_string(), "m/44'/60'/0'/0/3");
+        assert_eq!(DerivationPath::default().to_string(), "m");
+    }
+
+    #[test]
+    fn round_trip() {
+        for s in ["m", "m/0", "m/0'/1/2'/2/1000000000", "m/2147483647'"] {
+            assert_eq!(DerivationPath::from_str(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in ["", "44'/0", "m/", "m/x", "m/-1", "m/2147483648", "m/0''"] {
+            assert_matches!(DerivationPath::from_str(s), Err(Error::BasicParse(_)), "{s}");
+        }
+    }
+
+    #[test]
+    fn is_hardened() {
+        assert!(DerivationPath::hedera_ed25519(5).is_hardened());
+        assert!(!DerivationPath::standard_ecdsa(5).is_hardened());
+    }
+}
@@ -18,6 +18,8 @@
  * ‍
  */
 
+use std::fmt;
+
 use hedera_proto::services;
 
 use crate::contract::DelegateContractId;
@@ -33,6 +35,7 @@ use crate::{
 /// Any method that can be used to authorize an operation on Hedera.
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub enum Key {
     // todo(sr): not happy with any of these (fix before merge)
     /// A single public key.
@@ -49,11 +52,133 @@ pub enum Key {
 }
 
 impl Key {
+    /// Create a new `Key` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`] if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::from_bytes(bytes)
+    }
+
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns `true` if `self` is a single public key.
+    #[must_use]
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::Single(_))
+    }
+
+    /// Returns `true` if `self` is a key list without a threshold (all of its keys must sign).
+    #[must_use]
+    pub fn is_key_list(&self) -> bool {
+        matches!(self, Self::KeyList(KeyList { threshold: None, .. }))
+    }
+
+    /// Returns `true` if `self` is a threshold key (a key list with a threshold).
+    #[must_use]
+    pub fn is_threshold(&self) -> bool {
+        matches!(self, Self::KeyList(KeyList { threshold: Some(_), .. }))
+    }
+
+    /// Returns the public key if `self` is a single key.
+    #[must_use]
+    pub fn as_public_key(&self) -> Option<&PublicKey> {
+        match self {
+            Self::Single(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the key list if `self` is a key list or threshold key.
+    #[must_use]
+    pub fn as_key_list(&self) -> Option<&KeyList> {
+        match self {
+            Self::KeyList(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the threshold if `self` is a threshold key.
+    #[must_use]
+    pub fn threshold(&self) -> Option<u32> {
+        self.as_key_list().and_then(|it| it.threshold)
+    }
+
+    /// Returns every key nested in `self` that isn't a key list, depth first.
+    ///
+    /// For anything but a key list that's just `self`.
+    pub fn leaves(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || loop {
+            match stack.pop()? {
+                Self::KeyList(list) => stack.extend(list.keys.iter().rev()),
+                key => return Some(key),
+            }
+        })
+    }
+
+    /// Returns every public key nested in `self`, depth first.
+    pub fn public_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.leaves().filter_map(Self::as_public_key)
+    }
+
+    /// Returns the smallest number of leaf keys that must sign to satisfy `self`.
+    ///
+    /// Every leaf (including contract IDs) counts as one,
+    /// a key list needs all of its keys, and a threshold key needs its `threshold` cheapest keys.
+    #[must_use]
+    pub fn required_signatures(&self) -> usize {
+        let Self::KeyList(list) = self else {
+            return 1;
+        };
+
+        let mut required: Vec<_> = list.keys.iter().map(Self::required_signatures).collect();
+
+        match list.threshold {
+            Some(threshold) => {
+                required.sort_unstable();
+                required.into_iter().take(threshold as usize).sum()
+            }
+            None => required.into_iter().sum(),
+        }
+    }
+
+    /// Returns `true` if the public keys accepted by `signed` are enough to satisfy `self`.
+    ///
+    /// Contract IDs can't sign, so they never count towards a key list.
+    /// An empty key list, or one with a threshold of `0`, is never satisfied, as the network treats it as no key.
+    pub(crate) fn is_satisfied_by(&self, signed: &dyn Fn(&PublicKey) -> bool) -> bool {
+        match self {
+            Self::Single(it) => signed(it),
+            Self::ContractId(_) | Self::DelegateContractId(_) => false,
+            Self::KeyList(list) if list.keys.is_empty() || list.threshold == Some(0) => false,
+            Self::KeyList(list) => {
+                let satisfied = list.keys.iter().filter(|it| it.is_satisfied_by(signed)).count();
+
+                match list.threshold {
+                    Some(threshold) => satisfied >= threshold as usize,
+                    None => satisfied == list.keys.len(),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(it) => fmt::Display::fmt(it, f),
+            Self::ContractId(it) => fmt::Display::fmt(it, f),
+            Self::DelegateContractId(it) => fmt::Display::fmt(it, f),
+            Self::KeyList(it) => fmt::Display::fmt(it, f),
+        }
+    }
 }
 
 impl ToProtobuf for Key {
@@ -136,6 +261,8 @@ mod tests {
     use crate::protobuf::FromProtobuf;
     use crate::{
         Key,
+        KeyList,
+        PrivateKey,
         PublicKey,
     };
 
@@ -229,4 +356,98 @@ mod tests {
 
         assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
     }
+
+    fn nested_key() -> (Key, [PublicKey; 4]) {
+        let keys = [(); 4].map(|()| PrivateKey::generate_ed25519().public_key());
+
+        let inner = KeyList { keys: vec![keys[1].into(), keys[2].into()], threshold: None };
+
+        let key = Key::KeyList(KeyList {
+            keys: vec![keys[0].into(), inner.into(), keys[3].into()],
+            threshold: Some(2),
+        });
+
+        (key, keys)
+    }
+
+    #[test]
+    fn introspection() {
+        let (key, keys) = nested_key();
+
+        assert!(key.is_threshold());
+        assert!(!key.is_key_list());
+        assert_eq!(key.threshold(), Some(2));
+        assert!(key.as_key_list().unwrap()[1].is_key_list());
+        assert!(Key::from(keys[0]).is_single());
+        assert_eq!(Key::from(keys[0]).as_public_key(), Some(&keys[0]));
+    }
+
+    #[test]
+    fn leaves() {
+        let (key, keys) = nested_key();
+
+        assert_eq!(key.public_keys().copied().collect::<Vec<_>>(), keys);
+    }
+
+    #[test]
+    fn required_signatures() {
+        let (key, _) = nested_key();
+
+        // the two single keys are cheaper than the inner key list.
+        assert_eq!(key.required_signatures(), 2);
+
+        let Key::KeyList(mut list) = key else { unreachable!() };
+
+        list.threshold = None;
+
+        assert_eq!(Key::KeyList(list).required_signatures(), 4);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let (key, _) = nested_key();
+
+        assert_eq!(Key::from_bytes(&key.to_bytes()).unwrap(), key);
+    }
+
+    #[test]
+    fn is_satisfied_by() {
+        let [a, b, c] = [(); 3].map(|()| PrivateKey::generate_ed25519().public_key());
+
+        let only_a = |key: &PublicKey| *key == a;
+
+        assert!(Key::Single(a).is_satisfied_by(&only_a));
+        assert!(!Key::Single(b).is_satisfied_by(&only_a));
+
+        let list = Key::KeyList(KeyList::from([a, b]));
+        assert!(!list.is_satisfied_by(&only_a));
+
+        let mut threshold = KeyList::with_threshold(1);
+        threshold.push(Key::KeyList(KeyList::from([a, c])));
+        threshold.push(b.into());
+        assert!(!Key::KeyList(threshold.clone()).is_satisfied_by(&only_a));
+        assert!(Key::KeyList(threshold).is_satisfied_by(&|key| *key != b));
+    }
+
+    #[test]
+    fn is_satisfied_by_empty() {
+        let a = PrivateKey::generate_ed25519().public_key();
+
+        let all = |_: &PublicKey| true;
+
+        assert!(!Key::KeyList(KeyList::new()).is_satisfied_by(&all));
+        assert!(!Key::KeyList(KeyList::with_threshold(1)).is_satisfied_by(&all));
+
+        let mut zero = KeyList::with_threshold(0);
+        zero.push(a.into());
+        assert!(!Key::KeyList(zero).is_satisfied_by(&all));
+
+        // an empty list nested in a threshold key doesn't count towards it.
+        let mut nested = KeyList::with_threshold(1);
+        nested.push(Key::KeyList(KeyList::new()));
+        assert!(!Key::KeyList(nested.clone()).is_satisfied_by(&all));
+
+        nested.push(a.into());
+        assert!(Key::KeyList(nested).is_satisfied_by(&all));
+    }
 }
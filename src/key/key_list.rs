@@ -1,15 +1,22 @@
+use std::fmt;
+
 use hedera_proto::services;
+use prost::Message;
 
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::Key;
+use crate::{
+    Error,
+    Key,
+};
 
 // note: it appears keylists "just" implement the APIs of arrays in their language, which means, uh...
 // todo: Copy over the _entire_ `Vec` API?.
 /// A list of keys with an optional threshold.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct KeyList {
     // todo: better doc comment?
     /// The list of keys.
@@ -40,6 +47,35 @@ impl KeyList {
         Self::default()
     }
 
+    /// Create a new empty threshold key, which needs `threshold` of its keys to sign.
+    #[must_use]
+    pub fn with_threshold(threshold: u32) -> Self {
+        Self { keys: Vec::new(), threshold: Some(threshold) }
+    }
+
+    /// Create a new `KeyList` from protobuf-encoded `bytes`.
+    ///
+    /// The bytes are a `Key` protobuf holding either a key list or a threshold key, as produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`] if decoding the protobuf fails.
+    /// - [`Error::FromProtobuf`] if the key isn't a key list or threshold key.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        match Key::from_bytes(bytes)? {
+            Key::KeyList(it) => Ok(it),
+            _ => Err(Error::from_protobuf("expected a key list or threshold key")),
+        }
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    ///
+    /// This is encoded as a `Key` rather than a `KeyList`, so that the threshold isn't lost.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        services::Key { key: Some(self.to_protobuf_key()) }.encode_to_vec()
+    }
+
     /// Returns `true` if this keylist is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -69,6 +105,27 @@ impl KeyList {
     }
 }
 
+/// Formats as `[key, ...]`, or `threshold of [key, ...]` for a threshold key.
+impl fmt::Display for KeyList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(threshold) = self.threshold {
+            write!(f, "{threshold} of ")?;
+        }
+
+        f.write_str("[")?;
+
+        for (index, key) in self.keys.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{key}")?;
+        }
+
+        f.write_str("]")
+    }
+}
+
 impl ToProtobuf for KeyList {
     type Protobuf = services::KeyList;
 
@@ -236,4 +293,42 @@ mod tests {
 
         assert!(key_list.is_empty());
     }
+
+    #[test]
+    fn with_threshold() {
+        let mut key_list = KeyList::with_threshold(2);
+        key_list.extend(keys().map(Into::into));
+
+        assert_eq!(key_list.threshold, Some(2));
+        assert_matches!(key_list.to_protobuf_key(), services::key::Key::ThresholdKey(it) if it.threshold == 2);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let mut key_list = KeyList::from(keys());
+
+        assert_eq!(KeyList::from_bytes(&key_list.to_bytes()).unwrap(), key_list);
+
+        key_list.threshold = Some(2);
+
+        assert_eq!(KeyList::from_bytes(&key_list.to_bytes()).unwrap(), key_list);
+    }
+
+    #[test]
+    fn from_bytes_single_key_fails() {
+        let bytes = crate::Key::from(keys()[0]).to_bytes();
+
+        assert_matches!(KeyList::from_bytes(&bytes), Err(crate::Error::FromProtobuf(_)));
+    }
+
+    #[test]
+    fn display() {
+        let [a, b, c] = keys();
+
+        let mut key_list = KeyList::with_threshold(1);
+        key_list.push(a.into());
+        key_list.push(KeyList::from([b, c]).into());
+
+        assert_eq!(key_list.to_string(), format!("1 of [{a}, [{b}, {c}]]"));
+    }
 }
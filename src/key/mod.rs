@@ -18,12 +18,14 @@
  * ‍
  */
 
+mod derivation_path;
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
 mod private_key;
 mod public_key;
 
+pub use derivation_path::DerivationPath;
 pub use key::Key;
 pub use key_list::KeyList;
 pub use private_key::PrivateKey;
@@ -34,3 +36,29 @@ pub(crate) enum KeyKind {
     Ed25519,
     Ecdsa,
 }
+
+/// Compares key bytes, in constant time with the `security-hardened` feature.
+pub(crate) fn key_bytes_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    #[cfg(feature = "security-hardened")]
+    {
+        // note: the lengths are compared in variable time, but key lengths aren't secret.
+        subtle::ConstantTimeEq::ct_eq(lhs, rhs).into()
+    }
+
+    #[cfg(not(feature = "security-hardened"))]
+    {
+        lhs == rhs
+    }
+}
+
+/// Returns `signature` in low-S form if it's a high-S ECDSA(secp256k1) signature, otherwise returns it unchanged.
+#[cfg(feature = "security-hardened")]
+pub(crate) fn normalize_ecdsa_signature(signature: Vec<u8>) -> Vec<u8> {
+    match k256::ecdsa::Signature::try_from(signature.as_slice())
+        .ok()
+        .and_then(|it| it.normalize_s())
+    {
+        Some(normalized) => normalized.to_vec(),
+        None => signature,
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! The keystore format used by the Hedera JavaScript and Java SDKs (and the wallets built on them).
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "crypto": {
+//!     "ciphertext": "<hex>",
+//!     "cipherparams": { "iv": "<hex>" },
+//!     "cipher": "AES-128-CTR",
+//!     "kdf": "pbkdf2",
+//!     "kdfparams": { "dkLen": 32, "salt": "<hex>", "c": 262144, "prf": "hmac-sha256" },
+//!     "mac": "<hex>"
+//!   }
+//! }
+//! ```
+//!
+//! The first half of the derived key encrypts the private key, the second half keys an HMAC-SHA384 of the ciphertext.
+
+use aes::cipher::{
+    KeyIvInit,
+    StreamCipher,
+};
+use hmac::{
+    Hmac,
+    Mac,
+};
+use rand::RngCore;
+use serde_json::{
+    json,
+    Value,
+};
+use sha2::{
+    Sha256,
+    Sha384,
+};
+use zeroize::Zeroizing;
+
+use crate::Error;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const VERSION: u64 = 1;
+const ITERATIONS: u32 = 262_144;
+const DERIVED_KEY_LEN: usize = 32;
+
+pub(super) fn encrypt(key: &[u8], passphrase: &str) -> Vec<u8> {
+    encrypt_with_iterations(key, passphrase, ITERATIONS)
+}
+
+fn encrypt_with_iterations(key: &[u8], passphrase: &str, iterations: u32) -> Vec<u8> {
+    let mut salt = [0; 32];
+    let mut iv = [0; 16];
+
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut iv);
+
+    let derived = Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<Sha256, DERIVED_KEY_LEN>(
+        passphrase.as_bytes(),
+        &salt,
+        iterations,
+    ));
+
+    let mut ciphertext = key.to_vec();
+    Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+    let mac = mac(&derived, &ciphertext).finalize().into_bytes();
+
+    let keystore = json!({
+        "version": VERSION,
+        "crypto": {
+            "ciphertext": hex::encode(&ciphertext),
+            "cipherparams": { "iv": hex::encode(iv) },
+            "cipher": "AES-128-CTR",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "dkLen": DERIVED_KEY_LEN,
+                "salt": hex::encode(salt),
+                "c": iterations,
+                "prf": "hmac-sha256",
+            },
+            "mac": hex::encode(mac),
+        },
+    });
+
+    serde_json::to_vec(&keystore).expect("serializing a `Value` can't fail")
+}
+
+pub(super) fn decrypt(keystore: &[u8], passphrase: &str) -> crate::Result<Vec<u8>> {
+    fn field<'a>(value: &'a Value, name: &str) -> crate::Result<&'a Value> {
+        value.get(name).ok_or_else(|| Error::key_parse(format!("keystore is missing `{name}`")))
+    }
+
+    fn str_field<'a>(value: &'a Value, name: &str) -> crate::Result<&'a str> {
+        field(value, name)?
+            .as_str()
+            .ok_or_else(|| Error::key_parse(format!("keystore `{name}` isn't a string")))
+    }
+
+    fn u64_field(value: &Value, name: &str) -> crate::Result<u64> {
+        field(value, name)?
+            .as_u64()
+            .ok_or_else(|| Error::key_parse(format!("keystore `{name}` isn't an integer")))
+    }
+
+    fn hex_field(value: &Value, name: &str) -> crate::Result<Vec<u8>> {
+        hex::decode(str_field(value, name)?)
+            .map_err(|e| Error::key_parse(format!("keystore `{name}` isn't hex: {e}")))
+    }
+
+    let keystore: Value = serde_json::from_slice(keystore).map_err(Error::key_parse)?;
+
+    let version = u64_field(&keystore, "version")?;
+    if version != VERSION {
+        return Err(Error::key_parse(format!("unsupported keystore version: {version}")));
+    }
+
+    let crypto = field(&keystore, "crypto")?;
+    let kdf_params = field(crypto, "kdfparams")?;
+
+    let cipher = str_field(crypto, "cipher")?;
+    if !cipher.eq_ignore_ascii_case("aes-128-ctr") {
+        return Err(Error::key_parse(format!("unsupported keystore cipher: {cipher}")));
+    }
+
+    let kdf = str_field(crypto, "kdf")?;
+    let prf = str_field(kdf_params, "prf")?;
+    if kdf != "pbkdf2" || prf != "hmac-sha256" {
+        return Err(Error::key_parse(format!("unsupported keystore kdf: {kdf} ({prf})")));
+    }
+
+    if u64_field(kdf_params, "dkLen")? != DERIVED_KEY_LEN as u64 {
+        return Err(Error::key_parse("unsupported keystore derived key length"));
+    }
+
+    let iterations = u32::try_from(u64_field(kdf_params, "c")?)
+        .map_err(|_| Error::key_parse("keystore iteration count is too large"))?;
+
+    let salt = hex_field(kdf_params, "salt")?;
+    let iv: [u8; 16] = hex_field(field(crypto, "cipherparams")?, "iv")?
+        .try_into()
+        .map_err(|_| Error::key_parse("keystore IV must be 16 bytes"))?;
+    let mut ciphertext = hex_field(crypto, "ciphertext")?;
+    let expected_mac = hex_field(crypto, "mac")?;
+
+    let derived = Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<Sha256, DERIVED_KEY_LEN>(
+        passphrase.as_bytes(),
+        &salt,
+        iterations,
+    ));
+
+    mac(&derived, &ciphertext)
+        .verify_slice(&expected_mac)
+        .map_err(|_| Error::key_parse("keystore MAC mismatch (wrong passphrase?)"))?;
+
+    Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+    Ok(ciphertext)
+}
+
+fn mac(derived: &[u8; DERIVED_KEY_LEN], ciphertext: &[u8]) -> Hmac<Sha384> {
+    let mut mac =
+        Hmac::<Sha384>::new_from_slice(&derived[16..]).expect("HMAC accepts any key length");
+    mac.update(ciphertext);
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        decrypt,
+        encrypt_with_iterations,
+    };
+    use crate::Error;
+
+    const KEY: &[u8] = b"not a real key, but 32 bytes....";
+
+    #[test]
+    fn round_trip() {
+        let keystore = encrypt_with_iterations(KEY, "hunter2", 16);
+
+        assert_eq!(decrypt(&keystore, "hunter2").unwrap(), KEY);
+    }
+
+    #[test]
+    fn wrong_passphrase_fails() {
+        let keystore = encrypt_with_iterations(KEY, "hunter2", 16);
+
+        assert_matches!(decrypt(&keystore, "hunter3"), Err(Error::KeyParse(_)));
+    }
+
+    #[test]
+    fn unsupported_version_fails() {
+        let mut keystore: serde_json::Value =
+            serde_json::from_slice(&encrypt_with_iterations(KEY, "hunter2", 16)).unwrap();
+
+        keystore["version"] = 2.into();
+
+        assert_matches!(
+            decrypt(&serde_json::to_vec(&keystore).unwrap(), "hunter2"),
+            Err(Error::KeyParse(_))
+        );
+    }
+}
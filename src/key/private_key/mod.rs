@@ -18,6 +18,8 @@
  * ‍
  */
 
+#[cfg(feature = "serde")]
+mod keystore;
 #[cfg(test)]
 mod tests;
 
@@ -43,6 +45,7 @@ use hmac::{
     Mac,
 };
 use k256::ecdsa::signature::DigestSigner;
+use k256::elliptic_curve::PrimeField;
 use pkcs8::der::oid::ObjectIdentifier;
 use pkcs8::der::{
     Decode,
@@ -52,10 +55,15 @@ use sec1::EcPrivateKey;
 use sha2::Sha512;
 use sha3::Digest;
 use triomphe::Arc;
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
 
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    DerivationPath,
     Error,
     PublicKey,
     Transaction,
@@ -103,6 +111,14 @@ struct PrivateKeyDataWrapper {
     chain_code: Option<[u8; 32]>,
 }
 
+// `data` wipes itself on drop (both `ed25519_dalek` and `k256` signing keys zeroize their secrets),
+// but the chain code is ours, and it's just as secret for derivable keys.
+impl Drop for PrivateKeyDataWrapper {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+    }
+}
+
 impl PrivateKeyDataWrapper {
     fn new(inner: PrivateKeyData) -> Self {
         Self { data: inner, chain_code: None }
@@ -462,14 +478,42 @@ impl PrivateKey {
         inner(pem.as_ref(), password.as_ref())
     }
 
+    /// Decrypt a private key from a keystore produced by [`to_keystore`](Self::to_keystore)
+    /// or by the Hedera JavaScript and Java SDKs.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `keystore` isn't a supported keystore, `passphrase` is wrong,
+    ///   or the decrypted bytes aren't a valid private key.
+    #[cfg(feature = "serde")]
+    pub fn from_keystore(keystore: &[u8], passphrase: &str) -> crate::Result<Self> {
+        Self::from_bytes(&Zeroizing::new(keystore::decrypt(keystore, passphrase)?))
+    }
+
+    /// Encrypt this `PrivateKey` with `passphrase` into a JSON keystore.
+    ///
+    /// The keystore is compatible with the Hedera JavaScript and Java SDKs,
+    /// and can be decrypted again with [`from_keystore`](Self::from_keystore).
+    ///
+    /// Like those SDKs, the encrypted payload is the key as returned by [`to_bytes`](Self::to_bytes):
+    /// the raw key for Ed25519 and the DER encoding for ECDSA, so that the key type survives the round trip.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_keystore(&self, passphrase: &str) -> Vec<u8> {
+        keystore::encrypt(&Zeroizing::new(self.to_bytes()), passphrase)
+    }
+
     /// Return this `PrivateKey`, serialized as der encoded bytes.
     // panic should be impossible (`unreachable`)
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn to_bytes_der(&self) -> Vec<u8> {
-        let mut inner = Vec::with_capacity(34);
+        let mut inner = Zeroizing::new(Vec::with_capacity(34));
 
-        pkcs8::der::asn1::OctetStringRef::new(&self.to_bytes_raw_internal())
+        pkcs8::der::asn1::OctetStringRef::new(self.to_bytes_raw_internal().as_slice())
             .unwrap()
             .encode_to_vec(&mut inner)
             .unwrap();
@@ -505,11 +549,11 @@ impl PrivateKey {
     }
 
     #[must_use]
-    fn to_bytes_raw_internal(&self) -> [u8; 32] {
-        match &self.0.data {
+    fn to_bytes_raw_internal(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(match &self.0.data {
             PrivateKeyData::Ed25519(key) => key.to_bytes(),
             PrivateKeyData::Ecdsa(key) => key.to_bytes().into(),
-        }
+        })
     }
 
     /// DER encodes self, then hex encodes the result.
@@ -521,7 +565,7 @@ impl PrivateKey {
     /// Returns the raw bytes of `self` after hex encoding.
     #[must_use]
     pub fn to_string_raw(&self) -> String {
-        hex::encode(self.to_bytes_raw_internal())
+        hex::encode(self.to_bytes_raw_internal().as_slice())
     }
 
     /// Creates an [`AccountId`] with the given `shard`, `realm`, and `self.public_key()` as an [`alias`](AccountId::alias).
@@ -606,6 +650,57 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the given `message` with ECDSA(secp256k1), returning the signature as `(r, s, v)`,
+    /// where `v` is the recovery ID (`0` or `1`), as Ethereum expects.
+    ///
+    /// `message` is hashed with Keccak-256, just like with [`sign`](Self::sign).
+    ///
+    /// Returns `Some((r, s, v))` if `self.is_ecdsa()`, otherwise `None`.
+    #[must_use]
+    pub fn sign_recoverable(&self, message: &[u8]) -> Option<([u8; 32], [u8; 32], u8)> {
+        self.sign_hash_recoverable(&sha3::Keccak256::digest(message).into())
+    }
+
+    /// Signs the given Keccak-256 `hash` with ECDSA(secp256k1), returning the signature as `r || s`.
+    ///
+    /// `key.sign(message)` is equivalent to `key.sign_hash(&keccak256(message))`.
+    ///
+    /// Returns `Some(signature)` if `self.is_ecdsa()`, otherwise `None`.
+    #[must_use]
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Option<Vec<u8>> {
+        let (signature, _) = self.sign_hash_ecdsa(hash)?;
+
+        Some(signature.to_vec())
+    }
+
+    /// Signs the given Keccak-256 `hash` with ECDSA(secp256k1), returning the signature as `(r, s, v)`,
+    /// where `v` is the recovery ID (`0` or `1`), as Ethereum expects.
+    ///
+    /// Returns `Some((r, s, v))` if `self.is_ecdsa()`, otherwise `None`.
+    #[must_use]
+    pub fn sign_hash_recoverable(&self, hash: &[u8; 32]) -> Option<([u8; 32], [u8; 32], u8)> {
+        let (signature, recovery_id) = self.sign_hash_ecdsa(hash)?;
+
+        let (r, s) = signature.split_bytes();
+
+        Some((r.into(), s.into(), recovery_id.to_byte()))
+    }
+
+    fn sign_hash_ecdsa(
+        &self,
+        hash: &[u8; 32],
+    ) -> Option<(k256::ecdsa::Signature, k256::ecdsa::RecoveryId)> {
+        let PrivateKeyData::Ecdsa(key) = &self.0.data else {
+            return None;
+        };
+
+        // note: `k256` normalizes the signature to low-S, and adjusts the recovery ID to match.
+        Some(
+            key.sign_prehash_recoverable(hash)
+                .expect("BUG: signing a 32 byte hash should never fail"),
+        )
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
@@ -626,14 +721,20 @@ impl PrivateKey {
     /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
     #[must_use]
     pub fn is_derivable(&self) -> bool {
-        self.is_ed25519() && self.0.chain_code.is_some()
+        self.0.chain_code.is_some()
     }
 
     /// Derives a child key based on `index`.
     ///
+    /// Ed25519 keys are derived with `SLIP-10`, which only supports hardened derivation,
+    /// so `index` is always treated as hardened.
+    ///
+    /// Ecdsa keys are derived with `BIP-32`, `index` is hardened if it has
+    /// [`DerivationPath::HARDENED`] set (that is, if it's negative).
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
     /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if `index` produces an invalid Ecdsa key (vanishingly unlikely, try the next index)
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
@@ -649,14 +750,16 @@ impl PrivateKey {
                 // force hardened.
                 let index = index | HARDEND_MASK;
 
-                let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(chain_code)
-                    .expect("HMAC can take keys of any size")
-                    .chain_update([0])
-                    .chain_update(key.to_bytes())
-                    .chain_update(index.to_be_bytes())
-                    .finalize()
-                    .into_bytes()
-                    .into();
+                let output: Zeroizing<[u8; 64]> = Zeroizing::new(
+                    Hmac::<Sha512>::new_from_slice(chain_code)
+                        .expect("HMAC can take keys of any size")
+                        .chain_update([0])
+                        .chain_update(Zeroizing::new(key.to_bytes()).as_slice())
+                        .chain_update(index.to_be_bytes())
+                        .finalize()
+                        .into_bytes()
+                        .into(),
+                );
 
                 // todo: use `split_array_ref` when that's stable.
                 let (data, chain_code) = split_key_array(&output);
@@ -665,12 +768,64 @@ impl PrivateKey {
 
                 Ok(Self::new_derivable(data.into(), *chain_code))
             }
-            PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+            PrivateKeyData::Ecdsa(key) => {
+                let mut mac = Hmac::<Sha512>::new_from_slice(chain_code)
+                    .expect("HMAC can take keys of any size");
+
+                if index & HARDEND_MASK == 0 {
+                    mac.update(key.verifying_key().to_encoded_point(true).as_bytes());
+                } else {
+                    mac.update(&[0]);
+                    mac.update(Zeroizing::new(<[u8; 32]>::from(key.to_bytes())).as_slice());
+                }
+
+                mac.update(&index.to_be_bytes());
+
+                let output: Zeroizing<[u8; 64]> =
+                    Zeroizing::new(mac.finalize().into_bytes().into());
+
+                let (tweak, chain_code) = split_key_array(&output);
+
+                let invalid =
+                    || Error::key_derive(format!("index `{index}` derives an invalid key"));
+
+                let tweak = Option::<k256::Scalar>::from(k256::Scalar::from_repr(
+                    *GenericArray::from_slice(tweak),
+                ))
+                .ok_or_else(invalid)?;
+
+                let data = Option::<k256::NonZeroScalar>::from(k256::NonZeroScalar::new(
+                    **key.as_nonzero_scalar() + tweak,
+                ))
+                .ok_or_else(invalid)?;
+
+                Ok(Self::new_derivable(k256::ecdsa::SigningKey::from(data).into(), *chain_code))
             }
         }
     }
 
+    /// Derives a child key for each index in `path`, in order.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key and `path` has non-hardened indices (unsupported by `SLIP-10`)
+    /// - any error [`derive`](Self::derive) can return.
+    pub fn derive_path(&self, path: &DerivationPath) -> crate::Result<Self> {
+        if self.is_ed25519() && !path.is_hardened() {
+            return Err(Error::key_derive(format!(
+                "Ed25519 keys only support hardened derivation, but `{path}` isn't hardened"
+            )));
+        }
+
+        let mut key = self.clone();
+
+        for &index in path.indices() {
+            key = key.derive(index as i32)?;
+        }
+
+        Ok(key)
+    }
+
     // todo: what do we do about i32?
     // It's basically just a cast to support them, but, unlike Java, operator overloading doesn't exist.
     /// Derive a `PrivateKey` based on `index`.
@@ -681,10 +836,10 @@ impl PrivateKey {
     pub fn legacy_derive(&self, index: i64) -> crate::Result<Self> {
         match &self.0.data {
             PrivateKeyData::Ed25519(key) => {
-                let entropy = key.to_bytes();
-                let mut seed = Vec::with_capacity(entropy.len() + 8);
+                let entropy = Zeroizing::new(key.to_bytes());
+                let mut seed = Zeroizing::new(Vec::with_capacity(entropy.len() + 8));
 
-                seed.extend_from_slice(&entropy);
+                seed.extend_from_slice(entropy.as_slice());
 
                 let i1: i32 = match index {
                     0x00ff_ffff_ffff => 0xff,
@@ -698,7 +853,8 @@ impl PrivateKey {
                 // any better way to do this?
                 seed.extend_from_slice(&[i2; 4]);
 
-                let mat = pbkdf2::pbkdf2_hmac_array::<Sha512, 32>(&seed, &[0xff], 2048);
+                let mat =
+                    Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<Sha512, 32>(&seed, &[0xff], 2048));
 
                 Ok(Self::ed25519(ed25519_dalek::SigningKey::from_bytes(&mat)))
             }
@@ -711,42 +867,60 @@ impl PrivateKey {
 
     #[cfg(feature = "mnemonic")]
     pub(crate) fn from_mnemonic_seed(seed: &[u8]) -> Self {
-        let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
-            .expect("hmac can take a seed of any size")
-            .chain_update(seed)
-            .finalize()
-            .into_bytes()
-            .into();
+        Self::ed25519_from_seed(seed)
+            .derive_path(&DerivationPath::hedera_ed25519_account())
+            .expect("BUG: we set the chain code in `ed25519_from_seed`")
+    }
+
+    /// The `SLIP-10` Ed25519 master key for `seed`.
+    #[cfg(feature = "mnemonic")]
+    pub(crate) fn ed25519_from_seed(seed: &[u8]) -> Self {
+        let output: Zeroizing<[u8; 64]> = Zeroizing::new(
+            Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
+                .expect("hmac can take a seed of any size")
+                .chain_update(seed)
+                .finalize()
+                .into_bytes()
+                .into(),
+        );
 
         // todo: use `split_array_ref` when that's stable.
-        let (left, right) = {
-            let (left, right) = output.split_at(32);
-            let left: [u8; 32] = left.try_into().unwrap();
-            let right: [u8; 32] = right.try_into().unwrap();
-            (left, right)
-        };
+        let (left, right) = split_key_array(&output);
 
-        let data = ed25519_dalek::SigningKey::from_bytes(&left);
+        let data = ed25519_dalek::SigningKey::from_bytes(left);
 
-        let mut key = Self::new_derivable(data.into(), right);
+        Self::new_derivable(data.into(), *right)
+    }
 
-        for index in [44, 3030, 0, 0] {
-            key = key.derive(index).expect("BUG: we set the chain code earlier in this function");
-        }
+    /// The `BIP-32` secp256k1 master key for `seed`.
+    #[cfg(feature = "mnemonic")]
+    pub(crate) fn ecdsa_from_seed(seed: &[u8]) -> crate::Result<Self> {
+        let output: Zeroizing<[u8; 64]> = Zeroizing::new(
+            Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")
+                .expect("hmac can take a seed of any size")
+                .chain_update(seed)
+                .finalize()
+                .into_bytes()
+                .into(),
+        );
+
+        let (data, chain_code) = split_key_array(&output);
 
-        key
+        let data = k256::ecdsa::SigningKey::from_bytes(GenericArray::from_slice(data))
+            .map_err(|_| Error::key_derive("seed derives an invalid master key"))?;
+
+        Ok(Self::new_derivable(data.into(), *chain_code))
     }
 
     /// Recover a `PrivateKey` from a mnemonic phrase and a passphrase.
-    // this is specifically for the two `try_into`s which depend on `split_array_ref`.
-    // There *is* a 3rd unwrap for a "key is not derivable" error, but we construct a key that _is_ derivable.
+    // this is specifically for the "key is not derivable" error, but we construct a key that _is_ derivable.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[cfg(feature = "mnemonic")]
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn from_mnemonic(mnemonic: &crate::Mnemonic, passphrase: &str) -> Self {
         let seed = mnemonic.to_seed(passphrase);
-        Self::from_mnemonic_seed(&seed)
+        Self::from_mnemonic_seed(seed.as_slice())
     }
 
     #[must_use]
@@ -10,7 +10,10 @@ use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
 };
-use crate::Error;
+use crate::{
+    DerivationPath,
+    Error,
+};
 
 #[test]
 fn ed25519_from_str() {
@@ -408,3 +411,131 @@ fn ecdsa_ec_private_key_no_public_key_der() {
         "03b69a75a5ddb1c0747e995d47555019e5d8a28003ab5202bd92f534361fb4ec8a"
     );
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn keystore_round_trip() {
+    for sk in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+        let keystore = sk.to_keystore("hunter2");
+
+        let decrypted = PrivateKey::from_keystore(&keystore, "hunter2").unwrap();
+
+        assert_eq!(decrypted.to_bytes_der(), sk.to_bytes_der());
+        assert_eq!(decrypted.is_ecdsa(), sk.is_ecdsa());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn keystore_ed25519_payload_is_raw() {
+    let sk = PrivateKey::generate_ed25519();
+
+    let payload = super::keystore::decrypt(&sk.to_keystore("hunter2"), "hunter2").unwrap();
+
+    assert_eq!(payload, sk.to_bytes_raw());
+}
+
+// Produced the same way as `createKeystore` in `@hashgraph/cryptography`:
+// PBKDF2-HMAC-SHA256 (262144 rounds), AES-128-CTR over the raw Ed25519 key, HMAC-SHA384 over the ciphertext.
+#[cfg(feature = "serde")]
+#[test]
+fn keystore_from_js_sdk() {
+    const KEYSTORE: &str = r#"{"version":1,"crypto":{"ciphertext":"1902ab51857a28b30b9e2bf600c83d02de3a4519e99aafc1d2d3e4bf47f4f861","cipherparams":{"iv":"11c4d309d7f9e1edbcf9c7e80a506446"},"cipher":"AES-128-CTR","kdf":"pbkdf2","kdfparams":{"dkLen":32,"salt":"19447abf3bc219a81e630014499f8de0993f6c007432cd86f331da3da1a748c3","c":262144,"prf":"hmac-sha256"},"mac":"668af2400a35b5f925a601558d4ed2e9b10371ceea8f45c59eed6a9f2ff0759e199f7065ac4ecc31c928631d4c326569"}}"#;
+
+    let sk = PrivateKey::from_keystore(KEYSTORE.as_bytes(), "HelloHashgraph!").unwrap();
+
+    assert_eq!(
+        sk.to_string(),
+        "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10"
+    );
+}
+
+// SLIP-10 test vector 1 for ed25519.
+#[cfg(feature = "mnemonic")]
+#[test]
+fn ed25519_derive_path_slip10() {
+    let master = PrivateKey::ed25519_from_seed(&hex!("000102030405060708090a0b0c0d0e0f"));
+
+    assert_eq!(
+        master.to_string_raw(),
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    );
+
+    let path = DerivationPath::from_str("m/0'/1'/2'/2'/1000000000'").unwrap();
+    let child = master.derive_path(&path).unwrap();
+
+    assert_eq!(
+        child.to_string_raw(),
+        "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"
+    );
+    assert_eq!(
+        child.0.chain_code,
+        Some(hex!("68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230"))
+    );
+
+    assert_matches!(
+        master.derive_path(&DerivationPath::from_str("m/0").unwrap()),
+        Err(Error::KeyDerive(_))
+    );
+}
+
+// BIP-32 test vector 1.
+#[cfg(feature = "mnemonic")]
+#[test]
+fn ecdsa_derive_path_bip32() {
+    let master = PrivateKey::ecdsa_from_seed(&hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+
+    assert_eq!(
+        master.to_string_raw(),
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+    );
+
+    let path = DerivationPath::from_str("m/0'/1/2'/2/1000000000").unwrap();
+    let child = master.derive_path(&path).unwrap();
+
+    assert_eq!(
+        child.to_string_raw(),
+        "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+    );
+    assert_eq!(
+        child.0.chain_code,
+        Some(hex!("c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e"))
+    );
+}
+
+#[test]
+fn ecdsa_sign_recoverable() {
+    let key = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let (r, s, v) = key.sign_recoverable(b"hello world").unwrap();
+
+    assert_eq!([r.as_slice(), s.as_slice()].concat(), key.sign(b"hello world"));
+    assert!(v <= 1);
+}
+
+#[test]
+fn ecdsa_sign_hash() {
+    use sha3::Digest;
+
+    let key = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let hash: [u8; 32] = sha3::Keccak256::digest(b"hello world").into();
+
+    assert_eq!(key.sign_hash(&hash).unwrap(), key.sign(b"hello world"));
+    assert_eq!(key.sign_hash_recoverable(&hash), key.sign_recoverable(b"hello world"));
+}
+
+#[test]
+fn ed25519_sign_recoverable() {
+    let key = PrivateKey::generate_ed25519();
+
+    assert_eq!(key.sign_recoverable(b"hello world"), None);
+    assert_eq!(key.sign_hash(&[0; 32]), None);
+    assert_eq!(key.sign_hash_recoverable(&[0; 32]), None);
+}
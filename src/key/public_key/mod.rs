@@ -45,6 +45,7 @@ use pkcs8::ObjectIdentifier;
 use prost::Message;
 use sha2::Digest;
 
+use crate::key::key_bytes_eq;
 use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
@@ -89,8 +90,11 @@ impl Hash for PublicKeyData {
 impl PartialEq for PublicKeyData {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Ed25519(l0), Self::Ed25519(r0)) => l0 == r0,
-            (Self::Ecdsa(l0), Self::Ecdsa(r0)) => l0 == r0,
+            (Self::Ed25519(l0), Self::Ed25519(r0)) => key_bytes_eq(l0.as_bytes(), r0.as_bytes()),
+            (Self::Ecdsa(l0), Self::Ecdsa(r0)) => key_bytes_eq(
+                l0.to_encoded_point(true).as_bytes(),
+                r0.to_encoded_point(true).as_bytes(),
+            ),
             _ => false,
         }
     }
@@ -352,24 +356,81 @@ impl PublicKey {
     /// # Errors
     /// - [`Error::SignatureVerify`] if the signature algorithm doesn't match this `PublicKey`.
     /// - [`Error::SignatureVerify`] if the signature is invalid for this `PublicKey`.
+    ///
+    /// With the `security-hardened` feature, Ed25519 signatures are verified strictly,
+    /// so signatures by weak (small order) keys are rejected.
+    ///
+    /// High-S ECDSA signatures are always rejected.
     pub fn verify(&self, msg: &[u8], signature: &[u8]) -> crate::Result<()> {
         match &self.0 {
             PublicKeyData::Ed25519(key) => {
                 let signature = ed25519_dalek::Signature::try_from(signature)
                     .map_err(Error::signature_verify)?;
 
-                key.verify(msg, &signature).map_err(Error::signature_verify)
+                if cfg!(feature = "security-hardened") {
+                    key.verify_strict(msg, &signature)
+                } else {
+                    key.verify(msg, &signature)
+                }
+                .map_err(Error::signature_verify)
             }
             PublicKeyData::Ecdsa(key) => {
                 let signature =
                     ecdsa::Signature::try_from(signature).map_err(Error::signature_verify)?;
 
+                // `k256` rejects these as well, but doesn't say why.
+                if cfg!(feature = "security-hardened") && signature.normalize_s().is_some() {
+                    return Err(Error::signature_verify("non-canonical (high-S) ECDSA signature"));
+                }
+
                 key.verify_digest(sha3::Keccak256::new_with_prefix(msg), &signature)
                     .map_err(Error::signature_verify)
             }
         }
     }
 
+    /// Recovers the ECDSA(secp256k1) `PublicKey` that signed `msg`, from a 65 byte `r || s || v` signature.
+    ///
+    /// `msg` is hashed with Keccak-256, just like with [`PrivateKey::sign_recoverable`](crate::PrivateKey::sign_recoverable).
+    ///
+    /// `v` can either be the recovery ID (`0` or `1`), or the recovery ID plus `27`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if `signature` isn't 65 bytes, or `v` isn't a valid recovery ID.
+    /// - [`Error::SignatureVerify`] if no public key can be recovered from `signature`.
+    pub fn recover_from_signature(msg: &[u8], signature: &[u8]) -> crate::Result<Self> {
+        Self::recover_from_hash(&sha3::Keccak256::digest(msg).into(), signature)
+    }
+
+    /// Recovers the ECDSA(secp256k1) `PublicKey` that signed the Keccak-256 `hash`, from a 65 byte `r || s || v` signature.
+    ///
+    /// `v` can either be the recovery ID (`0` or `1`), or the recovery ID plus `27`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if `signature` isn't 65 bytes, or `v` isn't a valid recovery ID.
+    /// - [`Error::SignatureVerify`] if no public key can be recovered from `signature`.
+    pub fn recover_from_hash(hash: &[u8; 32], signature: &[u8]) -> crate::Result<Self> {
+        let (signature, v) = match signature {
+            [signature @ .., v] if signature.len() == 64 => (signature, *v),
+            _ => {
+                return Err(Error::signature_verify(format!(
+                    "expected a 65 byte `r || s || v` signature, found {} bytes",
+                    signature.len()
+                )))
+            }
+        };
+
+        let recovery_id = ecdsa::RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })
+            .ok_or_else(|| Error::signature_verify(format!("invalid recovery id `{v}`")))?;
+
+        let signature = ecdsa::Signature::try_from(signature).map_err(Error::signature_verify)?;
+
+        let key = ecdsa::VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+            .map_err(Error::signature_verify)?;
+
+        Ok(Self::ecdsa(key))
+    }
+
     pub(crate) fn verify_transaction_sources(
         &self,
         sources: &TransactionSources,
@@ -382,7 +443,8 @@ impl PublicKey {
             for sig_pair in
                 signed_transaction.sig_map.as_ref().map_or_else(|| [].as_slice(), |it| &it.sig_pair)
             {
-                if !pk_bytes.starts_with(&sig_pair.pub_key_prefix) {
+                let prefix = &sig_pair.pub_key_prefix;
+                if !pk_bytes.get(..prefix.len()).map_or(false, |it| key_bytes_eq(it, prefix)) {
                     continue;
                 }
 
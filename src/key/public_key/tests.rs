@@ -228,3 +228,158 @@ fn k256_uncompressed_pkcs8_ec_spki_der() {
         "03aaac1c3ac1bea0245b8e00ce1e2018f9eab61b6331fbef7266f2287750a65977"
     )
 }
+
+// the identity point is a valid, but weak, Ed25519 key, and this signature verifies for *any* message with it.
+const WEAK_ED25519_KEY: [u8; 32] =
+    hex!("0100000000000000000000000000000000000000000000000000000000000000");
+const WEAK_ED25519_SIGNATURE: [u8; 64] = hex!(
+    "0100000000000000000000000000000000000000000000000000000000000000"
+    "0000000000000000000000000000000000000000000000000000000000000000"
+);
+
+#[cfg(not(feature = "security-hardened"))]
+#[test]
+fn ed25519_verify_weak_key() {
+    let pk = PublicKey::from_bytes_ed25519(&WEAK_ED25519_KEY).unwrap();
+
+    pk.verify(b"hello, world", &WEAK_ED25519_SIGNATURE).unwrap();
+}
+
+#[cfg(feature = "security-hardened")]
+#[test]
+fn ed25519_verify_weak_key() {
+    let pk = PublicKey::from_bytes_ed25519(&WEAK_ED25519_KEY).unwrap();
+
+    assert_matches!(
+        pk.verify(b"hello, world", &WEAK_ED25519_SIGNATURE),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
+
+/// Returns a low-S signature of `msg` by `sk`, and the same signature in high-S form.
+fn ecdsa_signature_pair(sk: &PrivateKey, msg: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let low = sk.sign(msg);
+
+    let signature = k256::ecdsa::Signature::try_from(low.as_slice()).unwrap();
+    let high = k256::ecdsa::Signature::from_scalars(
+        signature.r().to_bytes(),
+        (-*signature.s()).to_bytes(),
+    )
+    .unwrap();
+
+    (low, high.to_vec())
+}
+
+#[test]
+fn ecdsa_verify_high_s() {
+    let sk = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let (low, high) = ecdsa_signature_pair(&sk, b"hello, world");
+
+    sk.public_key().verify(b"hello, world", &low).unwrap();
+
+    assert_matches!(
+        sk.public_key().verify(b"hello, world", &high),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
+
+#[cfg(feature = "security-hardened")]
+#[test]
+fn ecdsa_normalize_signature() {
+    let sk = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let (low, high) = ecdsa_signature_pair(&sk, b"hello, world");
+
+    assert_ne!(low, high);
+    assert_eq!(crate::key::normalize_ecdsa_signature(high), low);
+    assert_eq!(crate::key::normalize_ecdsa_signature(low.clone()), low);
+
+    // not an ECDSA signature, left alone.
+    assert_eq!(crate::key::normalize_ecdsa_signature(vec![1, 2, 3]), vec![1, 2, 3]);
+}
+
+#[test]
+fn key_bytes_eq() {
+    assert!(crate::key::key_bytes_eq(&[1, 2, 3], &[1, 2, 3]));
+    assert!(!crate::key::key_bytes_eq(&[1, 2, 3], &[1, 2, 4]));
+    assert!(!crate::key::key_bytes_eq(&[1, 2, 3], &[1, 2]));
+    assert!(crate::key::key_bytes_eq(&[], &[]));
+}
+
+#[test]
+fn eq() {
+    let sk = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    assert_eq!(sk.public_key(), sk.public_key());
+    assert_ne!(sk.public_key(), PrivateKey::generate_ecdsa().public_key());
+    assert_ne!(
+        PublicKey::from_bytes_ed25519(&WEAK_ED25519_KEY).unwrap(),
+        PrivateKey::generate_ed25519().public_key()
+    );
+}
+
+#[test]
+fn ecdsa_recover_from_signature() {
+    let sk = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let (r, s, v) = sk.sign_recoverable(b"hello, world").unwrap();
+
+    let signature = [r.as_slice(), s.as_slice(), &[v]].concat();
+    assert_eq!(
+        PublicKey::recover_from_signature(b"hello, world", &signature).unwrap(),
+        sk.public_key()
+    );
+
+    // ethereum style `v`.
+    let signature = [r.as_slice(), s.as_slice(), &[v + 27]].concat();
+    assert_eq!(
+        PublicKey::recover_from_signature(b"hello, world", &signature).unwrap(),
+        sk.public_key()
+    );
+
+    // a different message recovers a different key.
+    assert_ne!(
+        PublicKey::recover_from_signature(b"hello world", &signature).unwrap(),
+        sk.public_key()
+    );
+}
+
+#[test]
+fn ecdsa_recover_from_signature_error() {
+    let sk = PrivateKey::from_str_ecdsa(
+        "8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048",
+    )
+    .unwrap();
+
+    let (r, s, _) = sk.sign_recoverable(b"hello, world").unwrap();
+
+    let err = assert_matches!(
+        PublicKey::recover_from_signature(b"hello, world", &[r, s].concat()),
+        Err(e) => e
+    );
+
+    expect![
+        "failed to verify a signature: expected a 65 byte `r || s || v` signature, found 64 bytes"
+    ]
+    .assert_eq(&err.to_string());
+
+    let err = assert_matches!(
+        PublicKey::recover_from_signature(b"hello, world", &[r.as_slice(), s.as_slice(), &[5]].concat()),
+        Err(e) => e
+    );
+
+    expect!["failed to verify a signature: invalid recovery id `5`"].assert_eq(&err.to_string());
+}
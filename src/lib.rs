@@ -118,9 +118,18 @@
 mod protobuf;
 
 mod account;
+#[cfg(feature = "mirror-rest")]
+mod account_balance_snapshot_query;
+#[cfg(feature = "mirror-rest")]
+mod account_token_relationships_query;
 mod address_book;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
+mod compat;
 mod contract;
+#[cfg(feature = "mirror-rest")]
+mod contract_state_query;
 mod downcast;
 mod entity_id;
 mod error;
@@ -132,39 +141,59 @@ mod file;
 mod hbar;
 mod key;
 mod ledger_id;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod mirror_query;
+#[cfg(feature = "mirror-rest")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 mod network_version_info;
 mod network_version_info_query;
+#[cfg(feature = "nft-metadata")]
+pub mod nft_metadata;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
+mod node_ping_query;
 mod pending_airdrop_id;
 mod pending_airdrop_record;
+#[cfg(feature = "mirror-rest")]
+mod pending_airdrops_query;
 mod ping_query;
 mod prng_transaction;
 mod query;
+mod receipt_polling_policy;
 mod retry;
+mod runtime;
 mod schedule;
 mod semantic_version;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod service_endpoint;
 mod signer;
 mod staked_id;
 mod staking_info;
+#[cfg(feature = "mirror-rest")]
+mod staking_rewards_query;
 mod system;
+pub mod system_files;
 mod token;
 mod topic;
 mod transaction;
 mod transaction_hash;
+#[cfg(feature = "mirror-rest")]
+mod transaction_history_query;
 mod transaction_id;
 mod transaction_receipt;
 mod transaction_receipt_query;
 mod transaction_record;
 mod transaction_record_query;
 mod transaction_response;
+mod transaction_sidecar_record;
 mod transfer;
 mod transfer_transaction;
+mod transport;
 
 pub use account::{
     account_info_flow,
@@ -177,20 +206,58 @@ pub use account::{
     AccountId,
     AccountInfo,
     AccountInfoQuery,
+    AccountKeyRotationFlow,
+    AccountKeyRotationFlowResponse,
     AccountRecordsQuery,
     AccountStakersQuery,
     AccountUpdateTransaction,
     AllProxyStakers,
+    HbarAllowanceFlow,
+    HbarAllowanceFlowResponse,
+    MultisigAccountFlow,
     ProxyStaker,
 };
+#[cfg(feature = "mirror-rest")]
+pub use account_balance_snapshot_query::{
+    AccountBalanceSnapshot,
+    AccountBalanceSnapshotQuery,
+};
+#[cfg(feature = "mirror-rest")]
+pub use account_token_relationships_query::{
+    AccountTokenRelationship,
+    AccountTokenRelationshipsQuery,
+};
 pub use address_book::{
     NodeCreateTransaction,
     NodeDeleteTransaction,
     NodeUpdateTransaction,
 };
-pub use client::Client;
-pub(crate) use client::Operator;
+pub use client::{
+    Client,
+    EndpointConfig,
+    LowestLatencyNodeSelector,
+    NetworkSnapshot,
+    NodeCandidate,
+    NodeSelector,
+    NodeSnapshot,
+    Operator,
+    OperatorSelection,
+    OperatorSpend,
+    RandomNodeSelector,
+    RoundRobinNodeSelector,
+    StickyNodeSelector,
+    ThrottleMode,
+};
+pub use compat::CompatWarning;
 pub use contract::{
+    contract_info_flow,
+    CallOperationType,
+    ContractAction,
+    ContractActionCaller,
+    ContractActionRecipient,
+    ContractActionResult,
+    ContractActionType,
+    ContractBytecode,
     ContractBytecodeQuery,
     ContractCallQuery,
     ContractCreateFlow,
@@ -204,14 +271,28 @@ pub use contract::{
     ContractInfoQuery,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractStateChange,
     ContractUpdateTransaction,
     DelegateContractId,
+    Gas,
+    StorageChange,
+};
+#[cfg(feature = "mirror-rest")]
+pub use contract_state_query::{
+    ContractStateQuery,
+    ContractStateSlot,
 };
 pub use entity_id::EntityId;
 pub(crate) use entity_id::ValidateChecksums;
 pub use error::{
+    CustomFeeValidationError,
     Error,
+    GrpcTransportError,
+    GrpcTransportErrorKind,
+    KeyRotationError,
+    KeyRotationErrorKind,
     Result,
+    TransferValidationError,
 };
 #[cfg(feature = "mnemonic")]
 pub use error::{
@@ -222,6 +303,7 @@ pub use ethereum::{
     Eip1559EthereumData,
     EthereumData,
     EthereumFlow,
+    EthereumFlowResponse,
     EthereumTransaction,
     EvmAddress,
     LegacyEthereumData,
@@ -255,8 +337,14 @@ pub use hbar::{
     HbarUnit,
     Tinybar,
 };
+/// The raw HAPI protobuf messages, for use with [`FromProtobuf`] and [`ToProtobuf`].
+///
+/// Requires the `raw-protobuf` feature, which is exempt from semver.
+#[cfg(feature = "raw-protobuf")]
+pub use hedera_proto;
 pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
+    DerivationPath,
     Key,
     KeyList,
     PrivateKey,
@@ -267,9 +355,13 @@ pub use mirror_query::{
     AnyMirrorQuery,
     AnyMirrorQueryResponse,
     MirrorQuery,
+    MirrorQueryStream,
 };
 #[cfg(feature = "mnemonic")]
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{
+    Mnemonic,
+    WordList,
+};
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
@@ -277,29 +369,64 @@ pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
+pub use node_ping_query::{
+    NodePingQuery,
+    NodePingResponse,
+};
+pub use pending_airdrop_id::PendingAirdropId;
 pub use pending_airdrop_record::PendingAirdropRecord;
+#[cfg(feature = "mirror-rest")]
+pub use pending_airdrops_query::PendingAirdropsQuery;
 pub use prng_transaction::PrngTransaction;
+#[cfg(not(feature = "raw-protobuf"))]
 pub(crate) use protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+/// Conversions between SDK types and raw HAPI protobuf messages.
+///
+/// Requires the `raw-protobuf` feature, which is exempt from semver:
+/// these traits (and which types implement them) change along with the protobufs.
+#[cfg(feature = "raw-protobuf")]
+pub use protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
 pub use query::{
     AnyQuery,
     AnyQueryResponse,
     Query,
+    StateProof,
+    StateProofResponse,
+};
+pub use receipt_polling_policy::ReceiptPollingPolicy;
+pub use retry::{
+    retry,
+    RetryEvent,
+    RetryPolicy,
 };
-pub(crate) use retry::retry;
 pub use schedule::{
     ScheduleCreateTransaction,
     ScheduleDeleteTransaction,
     ScheduleId,
     ScheduleInfo,
     ScheduleInfoQuery,
+    ScheduleNotExecutedReason,
+    ScheduleSignFlow,
     ScheduleSignTransaction,
+    ScheduleSignatureProgress,
+    ScheduledTransferFlow,
+    ScheduledTransferResponse,
+    ScheduledTransferStatus,
 };
 pub use semantic_version::SemanticVersion;
 pub use service_endpoint::ServiceEndpoint;
 pub use staking_info::StakingInfo;
+#[cfg(feature = "mirror-rest")]
+pub use staking_rewards_query::{
+    StakingReward,
+    StakingRewardsQuery,
+};
 pub use system::{
     FreezeTransaction,
     FreezeType,
@@ -314,6 +441,7 @@ pub use token::{
     FeeAssessmentMethod,
     FixedFee,
     FixedFeeData,
+    Fraction,
     FractionalFee,
     FractionalFeeData,
     NftId,
@@ -351,6 +479,8 @@ pub use token::{
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+/// Cancels requests started with methods such as [`Transaction::execute_with_cancellation`].
+pub use tokio_util::sync::CancellationToken;
 pub use topic::{
     TopicCreateTransaction,
     TopicDeleteTransaction,
@@ -367,13 +497,26 @@ pub use transaction::{
     Transaction,
 };
 pub use transaction_hash::TransactionHash;
+#[cfg(feature = "mirror-rest")]
+pub use transaction_history_query::{
+    TransactionHistoryQuery,
+    TransactionSummary,
+};
 pub use transaction_id::TransactionId;
 pub use transaction_receipt::TransactionReceipt;
 pub use transaction_receipt_query::TransactionReceiptQuery;
 pub use transaction_record::TransactionRecord;
 pub use transaction_record_query::TransactionRecordQuery;
 pub(crate) use transaction_record_query::TransactionRecordQueryData;
-pub use transaction_response::TransactionResponse;
+pub use transaction_response::{
+    ExecutedTransaction,
+    TransactionResponse,
+    TransactionWarning,
+};
+pub use transaction_sidecar_record::{
+    TransactionSidecar,
+    TransactionSidecarRecord,
+};
 pub use transfer::Transfer;
 pub use transfer_transaction::TransferTransaction;
 
@@ -0,0 +1,106 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Metrics about the requests the SDK makes, reported through the [`metrics`](::metrics) facade.
+//!
+//! Nothing is recorded until a recorder is installed, for instance, `metrics-exporter-prometheus`.
+//! Durations are recorded in seconds.
+//!
+//! Requires the `metrics` feature.
+//!
+//! # Labels
+//! - `request`: the kind of request, IE, `TransferTransaction` or `AccountBalanceQuery`.
+//! - `node`: the account ID of a consensus node, IE, `0.0.3`.
+//! - `outcome`: `success` or `failure`.
+//! - `reason`: `node` for a retry on another node straight away, `backoff` for a retry after backing off.
+//! - `state`: `healthy` or `unhealthy`.
+
+use std::time::Duration;
+
+use crate::AccountId;
+
+/// Counter of requests, by `request` and `outcome`.
+///
+/// A request is counted once, no matter how many times it was retried.
+pub const REQUESTS: &str = "hedera_requests_total";
+
+/// Histogram of how long requests took, retries included, by `request` and `outcome`.
+pub const REQUEST_DURATION: &str = "hedera_request_duration_seconds";
+
+/// Histogram of how long a node took to respond to a single attempt of a request, by `request` and `node`.
+pub const NODE_REQUEST_DURATION: &str = "hedera_node_request_duration_seconds";
+
+/// Counter of retried attempts, by `request`, `node` and `reason`.
+///
+/// See [`Client::set_retry_handler`](crate::Client::set_retry_handler) for the details of every retry.
+pub const RETRIES: &str = "hedera_retries_total";
+
+/// Counter of nodes changing health, by `node` and the `state` they changed to.
+///
+/// A node is marked unhealthy when it fails in a way that suggests it's unavailable,
+/// and healthy again once it responds.
+pub const NODE_HEALTH_TRANSITIONS: &str = "hedera_node_health_transitions_total";
+
+/// Histogram of how long it took for the receipt of a transaction to be available, polling included, by `outcome`.
+pub const RECEIPT_WAIT: &str = "hedera_receipt_wait_seconds";
+
+fn outcome(success: bool) -> &'static str {
+    match success {
+        true => "success",
+        false => "failure",
+    }
+}
+
+pub(crate) fn record_request(request: &'static str, success: bool, duration: Duration) {
+    let outcome = outcome(success);
+
+    ::metrics::counter!(REQUESTS, "request" => request, "outcome" => outcome).increment(1);
+    ::metrics::histogram!(REQUEST_DURATION, "request" => request, "outcome" => outcome)
+        .record(duration);
+}
+
+pub(crate) fn record_node_request(request: &'static str, node: AccountId, duration: Duration) {
+    ::metrics::histogram!(NODE_REQUEST_DURATION, "request" => request, "node" => node.to_string())
+        .record(duration);
+}
+
+pub(crate) fn record_retry(request: &'static str, node: AccountId, delay: Duration) {
+    let reason = match delay.is_zero() {
+        true => "node",
+        false => "backoff",
+    };
+
+    ::metrics::counter!(RETRIES, "request" => request, "node" => node.to_string(), "reason" => reason)
+        .increment(1);
+}
+
+pub(crate) fn record_node_health_transition(node: AccountId, healthy: bool) {
+    let state = match healthy {
+        true => "healthy",
+        false => "unhealthy",
+    };
+
+    ::metrics::counter!(NODE_HEALTH_TRANSITIONS, "node" => node.to_string(), "state" => state)
+        .increment(1);
+}
+
+pub(crate) fn record_receipt_wait(success: bool, duration: Duration) {
+    ::metrics::histogram!(RECEIPT_WAIT, "outcome" => outcome(success)).record(duration);
+}
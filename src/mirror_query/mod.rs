@@ -19,6 +19,7 @@
  */
 
 mod any;
+mod stream;
 mod subscribe;
 
 pub(crate) use any::AnyMirrorQueryData;
@@ -27,6 +28,7 @@ pub use any::{
     AnyMirrorQueryMessage,
     AnyMirrorQueryResponse,
 };
+pub use stream::MirrorQueryStream;
 pub(crate) use subscribe::{
     subscribe,
     MirrorRequest,
@@ -0,0 +1,62 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+
+use crate::Client;
+
+/// A query against the mirror node whose results can be consumed as a stream.
+///
+/// This is implemented by every mirror query that returns a sequence of items,
+/// whether it's served by the mirror node's gRPC API (like [`TopicMessageQuery`](crate::TopicMessageQuery))
+/// or its REST API (like `TransactionHistoryQuery`, with the `mirror-rest` feature),
+/// so they can all be consumed the same way.
+///
+/// Results are requested from the mirror node as the stream is polled,
+/// so a slow consumer applies backpressure instead of having results buffered in memory.
+/// Each query's own `limit`, `start_time` and `end_time` (where it has them) apply to the stream.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use futures_util::TryStreamExt;
+/// use hedera::{AccountId, Client, MirrorQueryStream, TransactionHistoryQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let mut transactions = TransactionHistoryQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     .limit(1000)
+///     .stream(&client);
+///
+/// while let Some(it) = transactions.try_next().await? {
+///     println!("{},{}", it.consensus_timestamp, it.transaction_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait MirrorQueryStream {
+    /// The type of the items the stream yields.
+    type Item;
+
+    /// Returns a stream of the results of this query, using the mirror network of `client`.
+    fn stream<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<Self::Item>>;
+}
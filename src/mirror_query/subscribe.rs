@@ -24,11 +24,11 @@ use backoff::ExponentialBackoff;
 use futures_core::future::BoxFuture;
 use futures_core::Stream;
 use futures_util::StreamExt;
-use tokio::time::sleep;
-use tonic::transport::Channel;
 use tonic::Status;
 
 use crate::mirror_query::AnyMirrorQueryData;
+use crate::runtime::sleep;
+use crate::transport::Channel;
 use crate::{
     Client,
     Error,
@@ -145,7 +145,7 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
     }
 
     fn execute_with_optional_timeout<'a>(
@@ -161,7 +161,7 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
     }
 }
 
@@ -187,13 +187,13 @@ pub trait MirrorRequest: Send {
         false
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem);
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 }
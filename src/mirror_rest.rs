@@ -0,0 +1,187 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+// A minimal client for the mirror node REST API.
+// Only what the SDK's mirror-backed queries need: `GET` a JSON document and follow `links.next` pagination.
+
+use futures_core::Stream;
+use futures_util::TryStreamExt;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::{
+    Client,
+    Error,
+};
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+/// Returns the base URL (scheme and authority) of the mirror node REST API for `client`.
+///
+/// The mirror network is configured with gRPC addresses,
+/// the REST API is served over HTTPS on the same host, except for local nodes, where it's served over HTTP on port 5551.
+pub(crate) fn base_url(client: &Client) -> crate::Result<String> {
+    let address = client
+        .mirror_network()
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::mirror_rest("client has no mirror network configured"))?;
+
+    let host = address.rsplit_once(':').map_or(address.as_str(), |(host, _)| host);
+
+    match host {
+        "localhost" | "127.0.0.1" => Ok(format!("http://{host}:5551")),
+        _ => Ok(format!("https://{host}")),
+    }
+}
+
+/// `GET`s `path_and_query` (starting with `/api/v1/`) and parses the response as JSON.
+pub(crate) async fn get(client: &Client, path_and_query: &str) -> crate::Result<Value> {
+    let url = format!("{}{path_and_query}", base_url(client)?);
+
+    let response = HTTP_CLIENT
+        .get(&url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(Error::mirror_rest)?;
+
+    let bytes = response.bytes().await.map_err(Error::mirror_rest)?;
+
+    serde_json::from_slice(&bytes).map_err(Error::mirror_rest)
+}
+
+/// `GET`s every page of `path_and_query`, collecting the elements of the `key` array of each page for which `keep` returns `true`.
+///
+/// Stops early once `limit` elements have been collected.
+pub(crate) async fn get_all(
+    client: &Client,
+    path_and_query: &str,
+    key: &str,
+    limit: Option<usize>,
+    keep: impl FnMut(&Value) -> bool + Send,
+) -> crate::Result<Vec<Value>> {
+    stream_all(client, path_and_query.to_owned(), key, limit, keep).try_collect().await
+}
+
+/// Streams the elements of the `key` array of every page of `path_and_query` for which `keep` returns `true`.
+///
+/// Each page is only requested once the previous one has been consumed,
+/// and no more pages are requested once `limit` elements have been yielded.
+pub(crate) fn stream_all<'a>(
+    client: &'a Client,
+    path_and_query: String,
+    key: &'a str,
+    limit: Option<usize>,
+    mut keep: impl FnMut(&Value) -> bool + Send + 'a,
+) -> impl Stream<Item = crate::Result<Value>> + 'a {
+    async_stream::try_stream! {
+        let mut remaining = limit.unwrap_or(usize::MAX);
+        let mut next = Some(path_and_query);
+
+        'pages: while let Some(path_and_query) = next.take() {
+            if remaining == 0 {
+                break;
+            }
+
+            let mut page = get(client, &path_and_query).await?;
+
+            let items = match page.get_mut(key).map(Value::take) {
+                Some(Value::Array(items)) => items,
+                _ => Err(Error::mirror_rest(format!("response is missing `{key}`")))?,
+            };
+
+            next = page.pointer("/links/next").and_then(Value::as_str).map(ToOwned::to_owned);
+
+            for item in items.into_iter().filter(&mut keep) {
+                if remaining == 0 {
+                    break 'pages;
+                }
+
+                remaining -= 1;
+
+                yield item;
+            }
+        }
+    }
+}
+
+/// Formats `timestamp` the way the mirror node expects it in query parameters (`<seconds>.<nanos>`).
+pub(crate) fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    format!("{}.{:09}", timestamp.unix_timestamp(), timestamp.nanosecond())
+}
+
+/// Parses a mirror node timestamp (`<seconds>.<nanos>`).
+pub(crate) fn parse_timestamp(s: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, nanos) = s.split_once('.').unwrap_or((s, "0"));
+
+    let seconds: i64 = seconds.parse().map_err(Error::mirror_rest)?;
+    let nanos: i64 = nanos.parse().map_err(Error::mirror_rest)?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(
+        i128::from(seconds) * 1_000_000_000 + i128::from(nanos),
+    )
+    .map_err(Error::mirror_rest)
+}
+
+/// Returns the string field `key` of `value`.
+pub(crate) fn get_str<'a>(value: &'a Value, key: &str) -> crate::Result<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::mirror_rest(format!("response is missing `{key}`")))
+}
+
+/// Returns the integer field `key` of `value`.
+pub(crate) fn get_i64(value: &Value, key: &str) -> crate::Result<i64> {
+    value
+        .get(key)
+        .and_then(Value::as_i64)
+        .ok_or_else(|| Error::mirror_rest(format!("response is missing `{key}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{
+        format_timestamp,
+        parse_timestamp,
+    };
+
+    #[test]
+    fn timestamp_round_trip() {
+        let timestamp =
+            OffsetDateTime::from_unix_timestamp_nanos(1_714_564_800_000_000_001).unwrap();
+
+        assert_eq!(format_timestamp(timestamp), "1714564800.000000001");
+        assert_eq!(parse_timestamp("1714564800.000000001").unwrap(), timestamp);
+    }
+
+    #[test]
+    fn parse_timestamp_without_nanos() {
+        assert_eq!(
+            parse_timestamp("1714564800").unwrap(),
+            OffsetDateTime::from_unix_timestamp(1_714_564_800).unwrap()
+        );
+    }
+}
@@ -33,12 +33,18 @@ use rand::{
     RngCore,
 };
 use sha2::Digest;
+use triomphe::Arc;
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
 
 use crate::error::{
     MnemonicEntropyError,
     MnemonicParseError,
 };
 use crate::{
+    DerivationPath,
     Error,
     PrivateKey,
 };
@@ -53,11 +59,11 @@ fn is_sorted<T: PartialOrd>(vs: &[T]) -> bool {
 }
 
 // sadly can't do this with a const.
-static BIP39_WORD_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    let it: Vec<_> = BIP39.split_whitespace().collect();
+static BIP39_WORD_LIST: Lazy<WordList> = Lazy::new(|| {
+    let it = WordList::from_str(BIP39).expect("BUG: the english word list should be valid");
     // if the word list is sorted we can use the power of `binary_search` which turns the `O(n)` search into a `O(log n)`
     // n is a constant here, but perf is perf.
-    assert!(is_sorted(&it));
+    assert!(it.0.sorted);
     it
 });
 
@@ -65,6 +71,101 @@ static BIP39_WORD_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
 static LEGACY_WORD_LIST: Lazy<Vec<&'static str>> =
     Lazy::new(|| LEGACY.split_whitespace().collect());
 
+/// A `BIP-39` word list, used to encode and decode [`Mnemonic`]s.
+///
+/// The standard English word list is available as [`WordList::english`],
+/// other languages (or entirely custom lists) can be supplied with [`WordList::new`].
+///
+/// Words are compared exactly, so lists for languages with accented characters should be `NFKD` normalized,
+/// as should any words parsed with them.
+#[derive(Clone)]
+pub struct WordList(Arc<WordListInner>);
+
+struct WordListInner {
+    words: Vec<String>,
+    sorted: bool,
+}
+
+impl WordList {
+    /// The number of words in every `BIP-39` word list.
+    pub const LEN: usize = 2048;
+
+    /// Returns the standard `BIP-39` English word list.
+    #[must_use]
+    pub fn english() -> Self {
+        BIP39_WORD_LIST.clone()
+    }
+
+    /// Creates a word list from exactly [`LEN`](Self::LEN) unique words.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there aren't exactly [`LEN`](Self::LEN) words,
+    ///   if any word is empty or contains whitespace, or if any word appears more than once.
+    pub fn new(words: Vec<String>) -> crate::Result<Self> {
+        if words.len() != Self::LEN {
+            return Err(Error::basic_parse(format!(
+                "word list must contain exactly {} words, found {}",
+                Self::LEN,
+                words.len()
+            )));
+        }
+
+        if let Some(word) =
+            words.iter().find(|it| it.is_empty() || it.contains(char::is_whitespace))
+        {
+            return Err(Error::basic_parse(format!("invalid word in word list: `{word:?}`")));
+        }
+
+        let mut sorted_words: Vec<_> = words.iter().collect();
+        sorted_words.sort_unstable();
+
+        if let Some(w) = sorted_words.windows(2).find(|w| w[0] == w[1]) {
+            return Err(Error::basic_parse(format!("duplicate word in word list: `{}`", w[0])));
+        }
+
+        let sorted = is_sorted(&words);
+
+        Ok(Self(Arc::new(WordListInner { words, sorted })))
+    }
+
+    /// Returns the words in this list, in index order.
+    #[must_use]
+    pub fn words(&self) -> &[String] {
+        &self.0.words
+    }
+
+    fn index_of(&self, word: &str) -> Option<u16> {
+        let words = &self.0.words;
+
+        let index = if self.0.sorted {
+            words.binary_search_by(|it| it.as_str().cmp(word)).ok()
+        } else {
+            words.iter().position(|it| it == word)
+        };
+
+        // there are always exactly 2048 words, so any index fits in a `u16`.
+        index.map(|it| it as u16)
+    }
+}
+
+impl fmt::Debug for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WordList")
+            .field("first", &self.0.words[0])
+            .field("last", &self.0.words[Self::LEN - 1])
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromStr for WordList {
+    type Err = crate::Error;
+
+    /// Parses a whitespace separated word list, such as the files published alongside `BIP-39`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.split_whitespace().map(str::to_owned).collect())
+    }
+}
+
 ///  `BIP-39` 24-word mnemonic phrase compatible with the Android and iOS mobile wallets.
 pub struct Mnemonic(MnemonicData);
 
@@ -107,7 +208,22 @@ impl Mnemonic {
             Err(words) => words,
         };
 
-        let mnemonic = Self(MnemonicV2V3 { words }.into());
+        Self::from_words_with_word_list(words, &WordList::english())
+    }
+
+    /// Constructs a 12 or 24 word `Mnemonic` using the given `word_list`.
+    ///
+    /// Legacy (22 word) mnemonics only exist in English, use [`from_words`](Self::from_words) for those.
+    ///
+    /// # Errors
+    /// * if the mnemonic has an invalid length.
+    /// * if the mnemonic uses words that aren't in `word_list`.
+    /// * if the mnemonic has an invalid checksum.
+    pub fn from_words_with_word_list(
+        words: Vec<String>,
+        word_list: &WordList,
+    ) -> crate::Result<Self> {
+        let mnemonic = Self(MnemonicV2V3 { words, word_list: word_list.clone() }.into());
 
         if mnemonic.words().len() != 12 && mnemonic.words().len() != 24 {
             return Err(Error::MnemonicParse {
@@ -119,12 +235,12 @@ impl Mnemonic {
         let mut word_indecies = Vec::with_capacity(mnemonic.words().len());
         let mut unknown_words = Vec::new();
         for (word_index, word) in mnemonic.words().iter().enumerate() {
-            match BIP39_WORD_LIST.binary_search(&&**word) {
-                Ok(i) => {
-                    word_indecies.push(i as u16);
+            match word_list.index_of(word) {
+                Some(i) => {
+                    word_indecies.push(i);
                 }
                 // error (word not in list)
-                Err(_) => {
+                None => {
                     unknown_words.push(word_index);
                 }
             }
@@ -159,13 +275,68 @@ impl Mnemonic {
     /// Generate a new 12 word `Mnemonic` from the BIP-39 standard English word list.
     #[must_use]
     pub fn generate_12() -> Self {
-        Self(MnemonicV2V3::generate_12().into())
+        Self::generate_12_with_word_list(&WordList::english())
     }
 
     /// Generate a new 24 word `Mnemonic` from the BIP-39 standard English word list.
     #[must_use]
     pub fn generate_24() -> Self {
-        Self(MnemonicV2V3::generate_24().into())
+        Self::generate_24_with_word_list(&WordList::english())
+    }
+
+    /// Generate a new 12 word `Mnemonic` from the given `word_list`.
+    #[must_use]
+    pub fn generate_12_with_word_list(word_list: &WordList) -> Self {
+        Self(MnemonicV2V3::generate::<16>(word_list).into())
+    }
+
+    /// Generate a new 24 word `Mnemonic` from the given `word_list`.
+    #[must_use]
+    pub fn generate_24_with_word_list(word_list: &WordList) -> Self {
+        Self(MnemonicV2V3::generate::<32>(word_list).into())
+    }
+
+    /// Constructs a `Mnemonic` from `entropy` using the BIP-39 standard English word list.
+    ///
+    /// 16 bytes of entropy produce a 12 word mnemonic, 32 bytes produce a 24 word mnemonic.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::BadEntropyLength`] if `entropy` isn't 16 or 32 bytes long.
+    pub fn from_entropy(entropy: &[u8]) -> crate::Result<Self> {
+        Self::from_entropy_with_word_list(entropy, &WordList::english())
+    }
+
+    /// Constructs a `Mnemonic` from `entropy` using the given `word_list`.
+    ///
+    /// 16 bytes of entropy produce a 12 word mnemonic, 32 bytes produce a 24 word mnemonic.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::BadEntropyLength`] if `entropy` isn't 16 or 32 bytes long.
+    pub fn from_entropy_with_word_list(
+        entropy: &[u8],
+        word_list: &WordList,
+    ) -> crate::Result<Self> {
+        if !matches!(entropy.len(), 16 | 32) {
+            return Err(MnemonicEntropyError::BadEntropyLength(entropy.len()).into());
+        }
+
+        Ok(Self(MnemonicV2V3::from_entropy(entropy, word_list).into()))
+    }
+
+    /// Returns the entropy encoded by this `Mnemonic`.
+    ///
+    /// For legacy mnemonics this is the legacy private key, otherwise it's the `BIP-39` entropy (without the checksum).
+    ///
+    /// # Errors
+    /// Under certain circumstances, this function will return a [`Error::MnemonicEntropy`].
+    /// - [`MnemonicEntropyError::BadLength`] if the `Mnemonic` doesn't have 12, 22, or 24 words.
+    /// - [`MnemonicEntropyError::UnknownWords`] if the `Mnemonic` contains words that aren't in its word list.
+    /// - [`MnemonicEntropyError::ChecksumMismatch`] if the computed checksum doesn't match the actual checksum.
+    pub fn to_entropy(&self) -> crate::Result<Vec<u8>> {
+        match &self.0 {
+            MnemonicData::V1(it) => it.to_entropy(),
+            MnemonicData::V2V3(it) => it.to_entropy(),
+        }
     }
 
     /// Recover a [`PrivateKey`] from this `Mnemonic`.
@@ -175,10 +346,10 @@ impl Mnemonic {
     /// - [`MnemonicEntropyError::ChecksumMismatch`] if the computed checksum doesn't match the actual checksum.
     /// - [`MnemonicEntropyError::BadLength`] if this is a v2 legacy mnemonic and doesn't have `24` words.
     pub fn to_legacy_private_key(&self) -> crate::Result<PrivateKey> {
-        let entropy = match &self.0 {
+        let entropy = Zeroizing::new(match &self.0 {
             MnemonicData::V1(it) => it.to_entropy()?,
             MnemonicData::V2V3(it) => it.to_legacy_entropy()?,
-        };
+        });
 
         PrivateKey::from_bytes(&entropy)
     }
@@ -197,23 +368,69 @@ impl Mnemonic {
             MnemonicData::V1(_) if !passphrase.is_empty() => {
                 Err(Error::from(MnemonicEntropyError::LegacyWithPassphrase))
             }
-            MnemonicData::V1(it) => Ok(PrivateKey::from_bytes(&it.to_entropy()?).expect(
-                "BUG: invariant broken - V1 mnemonic should always have exactly enough entropy",
-            )),
+            MnemonicData::V1(it) => {
+                let entropy = Zeroizing::new(it.to_entropy()?);
+
+                Ok(PrivateKey::from_bytes(&entropy).expect(
+                    "BUG: invariant broken - V1 mnemonic should always have exactly enough entropy",
+                ))
+            }
             // known unfixable bug: `PrivateKey::from_mnemonic` can be called with a legacy private key.
             MnemonicData::V2V3(_) => Ok(PrivateKey::from_mnemonic(self, passphrase)),
         }
     }
 
-    pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
-        let mut salt = String::from("mnemonic");
+    /// Recover an Ed25519 [`PrivateKey`] from this `Mnemonic`, derived with `SLIP-10` along `path`.
+    ///
+    /// [`to_private_key`](Self::to_private_key) is equivalent to the path `m/44'/3030'/0'/0'`,
+    /// the Hedera wallets use [`DerivationPath::hedera_ed25519`].
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is a legacy `Mnemonic` (legacy mnemonics aren't hierarchical).
+    /// - [`Error::KeyDerive`] if `path` has non-hardened indices (unsupported by `SLIP-10`).
+    pub fn to_private_key_with_path(
+        &self,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> crate::Result<PrivateKey> {
+        PrivateKey::ed25519_from_seed(self.to_hd_seed(passphrase)?.as_slice()).derive_path(path)
+    }
+
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic`, derived with `BIP-32` along `path`.
+    ///
+    /// Most wallets use [`DerivationPath::standard_ecdsa`].
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is a legacy `Mnemonic` (legacy mnemonics aren't hierarchical).
+    /// - [`Error::KeyDerive`] if `path` derives an invalid key (vanishingly unlikely).
+    pub fn to_ecdsa_private_key_with_path(
+        &self,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> crate::Result<PrivateKey> {
+        PrivateKey::ecdsa_from_seed(self.to_hd_seed(passphrase)?.as_slice())?.derive_path(path)
+    }
+
+    fn to_hd_seed(&self, passphrase: &str) -> crate::Result<Zeroizing<[u8; 64]>> {
+        match &self.0 {
+            MnemonicData::V1(_) => {
+                Err(Error::key_derive("legacy mnemonics don't support derivation paths"))
+            }
+            MnemonicData::V2V3(_) => Ok(self.to_seed(passphrase)),
+        }
+    }
+
+    pub(crate) fn to_seed(&self, phrase: &str) -> Zeroizing<[u8; 64]> {
+        let mut salt = Zeroizing::new(String::from("mnemonic"));
         salt.push_str(phrase);
 
-        pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
-            self.to_string().as_bytes(),
+        let password = Zeroizing::new(self.to_string());
+
+        Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
+            password.as_bytes(),
             salt.as_bytes(),
             2048,
-        )
+        ))
     }
 }
 
@@ -260,7 +477,8 @@ impl MnemonicV1 {
         });
 
         let data = convert_radix(indecies, 4096, 256, 33);
-        let mut data: Vec<_> = data.into_iter().map(|it| it as u8).collect();
+        let mut data: Zeroizing<Vec<_>> =
+            Zeroizing::new(data.into_iter().map(|it| it as u8).collect());
 
         let (crc, data) = data.split_last_mut().unwrap();
 
@@ -281,8 +499,22 @@ impl MnemonicV1 {
     }
 }
 
+impl Drop for MnemonicV1 {
+    fn drop(&mut self) {
+        self.words.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
 struct MnemonicV2V3 {
     words: Vec<String>,
+    word_list: WordList,
+}
+
+impl Drop for MnemonicV2V3 {
+    fn drop(&mut self) {
+        // the words *are* the secret, anyone with them can recover every key.
+        self.words.zeroize();
+    }
 }
 
 impl MnemonicV2V3 {
@@ -290,11 +522,11 @@ impl MnemonicV2V3 {
         &self.words
     }
 
-    fn from_entropy(entropy: &[u8]) -> Self {
+    fn from_entropy(entropy: &[u8], word_list: &WordList) -> Self {
         assert!(entropy.len() == 16 || entropy.len() == 32);
 
         let entropy = {
-            let mut it = Vec::with_capacity(entropy.len() + 1);
+            let mut it = Zeroizing::new(Vec::with_capacity(entropy.len() + 1));
             it.extend_from_slice(entropy);
             let checksum = checksum(entropy);
             it.push(if entropy.len() == 16 { checksum & 0xf0 } else { checksum });
@@ -306,46 +538,49 @@ impl MnemonicV2V3 {
 
         let mut words = Vec::with_capacity((entropy.len() * 8 + 1) / 11);
 
-        for byte in entropy {
+        for &byte in entropy.iter() {
             buffer = (buffer << 8) | u32::from(byte);
             offset += 8;
             if offset >= 11 {
                 let index = (buffer >> (offset - 11) & 0x7ff) as usize;
-                words.push(BIP39_WORD_LIST[index].to_owned());
+                words.push(word_list.words()[index].clone());
                 offset -= 11;
             }
         }
 
-        Self { words }
+        Self { words, word_list: word_list.clone() }
     }
 
-    fn generate_12() -> Self {
+    fn generate<const N: usize>(word_list: &WordList) -> Self {
         let mut rng = thread_rng();
-        let mut entropy = [0; 16];
-        rng.fill_bytes(&mut entropy);
+        let mut entropy = Zeroizing::new([0_u8; N]);
+        rng.fill_bytes(entropy.as_mut_slice());
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(entropy.as_slice(), word_list)
     }
 
-    fn generate_24() -> Self {
-        let mut rng = thread_rng();
-        let mut entropy = [0; 32];
-        rng.fill_bytes(&mut entropy);
-
-        Self::from_entropy(&entropy)
-    }
-
-    fn to_legacy_entropy(&self) -> crate::Result<Vec<u8>> {
-        // error here where we'll have more context than `PrivateKey::from_bytes`.
-        if self.words.len() != 24 {
+    fn to_entropy(&self) -> crate::Result<Vec<u8>> {
+        if !matches!(self.words.len(), 12 | 24) {
             return Err(Error::from(MnemonicEntropyError::BadLength {
                 expected: 24,
                 actual: self.words.len(),
             }));
         }
 
-        // technically, this code all works for 12 words, but I'm going to pretend it doesn't.
-        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words);
+        let mut indecies = Vec::with_capacity(self.words.len());
+        let mut unknown_words = Vec::new();
+        for (word_index, word) in self.words.iter().enumerate() {
+            match self.word_list.index_of(word) {
+                Some(i) => indecies.push(i),
+                None => unknown_words.push(word_index),
+            }
+        }
+
+        if !unknown_words.is_empty() {
+            return Err(Error::from(MnemonicEntropyError::UnknownWords(unknown_words)));
+        }
+
+        let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&indecies);
 
         let expected_checksum = checksum(&entropy);
         let expected_checksum =
@@ -360,6 +595,19 @@ impl MnemonicV2V3 {
 
         Ok(entropy)
     }
+
+    fn to_legacy_entropy(&self) -> crate::Result<Vec<u8>> {
+        // error here where we'll have more context than `PrivateKey::from_bytes`.
+        if self.words.len() != 24 {
+            return Err(Error::from(MnemonicEntropyError::BadLength {
+                expected: 24,
+                actual: self.words.len(),
+            }));
+        }
+
+        // technically, this code all works for 12 words, but I'm going to pretend it doesn't.
+        self.to_entropy()
+    }
 }
 
 enum MnemonicData {
@@ -422,16 +670,6 @@ fn convert_radix<I: IntoIterator<Item = i32>>(
     out
 }
 
-fn words_to_entropy_and_checksum<T: AsRef<str>>(words: &[T]) -> (Vec<u8>, u8) {
-    let indecies: Vec<_> = words
-        .iter()
-        .map(T::as_ref)
-        .map(|it| BIP39_WORD_LIST.binary_search(&it).unwrap() as u16)
-        .collect();
-
-    incecies_to_entropy_and_checksum(&indecies)
-}
-
 fn incecies_to_entropy_and_checksum(indecies: &[u16]) -> (Vec<u8>, u8) {
     assert!(matches!(indecies.len(), 12 | 24));
 
@@ -475,9 +713,18 @@ mod tests {
     use expect_test::expect;
     use hex_literal::hex;
 
-    use super::Mnemonic;
-    use crate::error::MnemonicParseError;
-    use crate::Error;
+    use super::{
+        Mnemonic,
+        WordList,
+    };
+    use crate::error::{
+        MnemonicEntropyError,
+        MnemonicParseError,
+    };
+    use crate::{
+        DerivationPath,
+        Error,
+    };
 
     const KNOWN_GOOD_MNEMONICS: &[&str] = &[
         "inmate flip alley wear offer often piece magnet surge toddler submit right radio absent pear floor belt raven price stove replace reduce plate home",
@@ -602,12 +849,67 @@ mod tests {
         ];
 
         for (entropy, s) in ENTROPY.iter().zip(KNOWN_GOOD_MNEMONICS) {
-            let mnemonic = Mnemonic(super::MnemonicV2V3::from_entropy(entropy).into());
+            let mnemonic = Mnemonic::from_entropy(entropy).unwrap();
 
             assert_eq!(&mnemonic.to_string(), s);
         }
     }
 
+    #[test]
+    fn to_entropy() {
+        const ENTROPY: &[&[u8]] = &[
+            &hex!("744b201a7c399733691c2fda5c6f605ceb0c016882cb14f64ea9eb5b6d68298b"),
+            &hex!("4e172857ab9ac2563fee9c829a4b2e9b"),
+        ];
+
+        for entropy in ENTROPY {
+            let mnemonic = Mnemonic::from_entropy(entropy).unwrap();
+
+            assert_eq!(&mnemonic.to_entropy().unwrap(), entropy);
+        }
+    }
+
+    #[test]
+    fn from_entropy_bad_length() {
+        assert_matches!(
+            Mnemonic::from_entropy(&[0; 20]),
+            Err(Error::MnemonicEntropy(MnemonicEntropyError::BadEntropyLength(20)))
+        );
+    }
+
+    #[test]
+    fn custom_word_list() {
+        // the english list, but shouty, and (importantly) not sorted.
+        let words: Vec<_> =
+            WordList::english().words().iter().rev().map(|it| it.to_uppercase()).collect();
+        let word_list = WordList::new(words).unwrap();
+
+        let entropy = hex!("4e172857ab9ac2563fee9c829a4b2e9b");
+
+        let mnemonic = Mnemonic::from_entropy_with_word_list(&entropy, &word_list).unwrap();
+        assert_eq!(mnemonic.to_entropy().unwrap(), entropy);
+
+        let words = mnemonic.to_string().split_whitespace().map(str::to_owned).collect();
+        let parsed = Mnemonic::from_words_with_word_list(words, &word_list).unwrap();
+        assert_eq!(parsed.to_entropy().unwrap(), entropy);
+
+        let generated = Mnemonic::generate_24_with_word_list(&word_list);
+        assert_eq!(generated.to_entropy().unwrap().len(), 32);
+        assert_matches!(
+            Mnemonic::from_str(&generated.to_string()),
+            Err(Error::MnemonicParse { .. })
+        );
+    }
+
+    #[test]
+    fn word_list_invalid() {
+        assert_matches!(WordList::from_str("abandon ability"), Err(Error::BasicParse(_)));
+
+        let mut words = WordList::english().words().to_vec();
+        words[1] = words[0].clone();
+        assert_matches!(WordList::new(words), Err(Error::BasicParse(_)));
+    }
+
     #[test]
     fn mnemonic_3() {
         // rustfmt does *not* like long strings.
@@ -680,4 +982,27 @@ mod tests {
         "#]]
         .assert_debug_eq(key.debug_pretty());
     }
+
+    #[test]
+    fn to_private_key_with_path() {
+        let mnemonic = Mnemonic::from_str(KNOWN_GOOD_MNEMONICS[0]).unwrap();
+
+        let path = DerivationPath::from_str("m/44'/3030'/0'/0'").unwrap();
+
+        assert_eq!(
+            mnemonic.to_private_key_with_path("", &path).unwrap().to_string(),
+            mnemonic.to_private_key("").unwrap().to_string()
+        );
+
+        assert_matches!(
+            mnemonic.to_private_key_with_path("", &DerivationPath::standard_ecdsa(0)),
+            Err(Error::KeyDerive(_))
+        );
+
+        let key = mnemonic
+            .to_ecdsa_private_key_with_path("", &DerivationPath::standard_ecdsa(0))
+            .unwrap();
+
+        assert!(key.is_ecdsa());
+    }
 }
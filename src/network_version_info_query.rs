@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::network_service_client::NetworkServiceClient;
-use tonic::transport::Channel;
 
 use crate::entity_id::ValidateChecksums;
 use crate::query::{
@@ -28,9 +27,11 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     NetworkVersionInfo,
     Query,
 };
@@ -59,6 +60,12 @@ impl ToQueryProtobuf for NetworkVersionInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::NetworkGetVersionInfoQuery> for NetworkVersionInfoQueryData {
+    fn from_protobuf(_pb: services::NetworkGetVersionInfoQuery) -> crate::Result<Self> {
+        Ok(Self {})
+    }
+}
+
 impl QueryExecute for NetworkVersionInfoQueryData {
     type Response = NetworkVersionInfo;
 
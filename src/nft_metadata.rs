@@ -0,0 +1,409 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Fetching [HIP-412](https://hips.hedera.com/hip/hip-412) NFT metadata.
+//!
+//! The metadata of an NFT (see [`TokenNftInfo::metadata`]) is, by convention, a URI pointing to a JSON document
+//! describing the NFT. [`fetch`] resolves `ipfs://` and `https://` URIs, downloads that document,
+//! and checks it against HIP-412.
+//!
+//! Requires the `nft-metadata` feature.
+//!
+//! # Examples
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() -> hedera::Result<()> {
+//! use hedera::{
+//!     Client,
+//!     NftId,
+//!     TokenNftInfoQuery,
+//! };
+//!
+//! let client = Client::for_testnet();
+//!
+//! let nft_id: NftId = "0.0.1234/1".parse()?;
+//! let info = TokenNftInfoQuery::new().nft_id(nft_id).execute(&client).await?;
+//!
+//! let metadata = hedera::nft_metadata::fetch(&info).await?;
+//!
+//! println!("{} ({}): {}", metadata.name, metadata.mime_type, metadata.image);
+//! # Ok(())
+//! # }
+//! ```
+
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+use crate::{
+    Error,
+    TokenNftInfo,
+};
+
+/// The IPFS gateway that [`fetch`] resolves `ipfs://` URIs with.
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+/// The largest metadata document that will be downloaded, in bytes.
+const MAX_DOCUMENT_SIZE: usize = 1024 * 1024;
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+/// HIP-412 metadata of an NFT.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftMetadata {
+    /// The name of the NFT.
+    pub name: String,
+
+    /// A URI pointing to the preview image of the NFT.
+    pub image: String,
+
+    /// The MIME type of [`image`](Self::image), such as `image/png`.
+    pub mime_type: String,
+
+    /// A human readable description of the NFT.
+    pub description: Option<String>,
+
+    /// The artist(s) who created the NFT.
+    pub creator: Option<String>,
+
+    /// The decentralized identifier of the creator.
+    pub creator_did: Option<String>,
+
+    /// The SHA-256 checksum of [`image`](Self::image), hex encoded.
+    pub checksum: Option<String>,
+
+    /// The name and version of the metadata format, such as `HIP412@2.0.0`.
+    pub format: Option<String>,
+
+    /// Additional files associated with the NFT.
+    pub files: Vec<NftMetadataFile>,
+
+    /// Traits of the NFT.
+    pub attributes: Vec<NftMetadataAttribute>,
+}
+
+/// A file associated with an NFT, see [`NftMetadata::files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NftMetadataFile {
+    /// A URI pointing to the file.
+    pub uri: String,
+
+    /// The MIME type of the file.
+    pub mime_type: String,
+
+    /// The SHA-256 checksum of the file, hex encoded.
+    pub checksum: Option<String>,
+
+    /// Whether this is the main file of the NFT, to be shown instead of [`NftMetadata::image`].
+    pub is_default_file: bool,
+}
+
+/// A trait of an NFT, see [`NftMetadata::attributes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftMetadataAttribute {
+    /// The name of the trait.
+    pub trait_type: String,
+
+    /// The value of the trait.
+    pub value: NftMetadataAttributeValue,
+
+    /// How the trait should be displayed, such as `boost` or `date`.
+    pub display_type: Option<String>,
+}
+
+/// The value of an [`NftMetadataAttribute`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NftMetadataAttributeValue {
+    /// A textual value.
+    String(String),
+
+    /// A numeric value.
+    Number(f64),
+
+    /// A boolean value.
+    Bool(bool),
+}
+
+impl NftMetadata {
+    /// Parses and validates a HIP-412 metadata document.
+    ///
+    /// # Errors
+    /// - [`Error::NftMetadata`] if `bytes` isn't a JSON object.
+    /// - [`Error::NftMetadata`] if `name`, `image`, or `type` is missing, or any field has the wrong type.
+    pub fn from_json(bytes: &[u8]) -> crate::Result<Self> {
+        let json: Value = serde_json::from_slice(bytes).map_err(Error::nft_metadata)?;
+
+        if !json.is_object() {
+            return Err(Error::nft_metadata("metadata is not a JSON object"));
+        }
+
+        Ok(Self {
+            name: required_str(&json, "name")?,
+            image: required_str(&json, "image")?,
+            mime_type: required_str(&json, "type")?,
+            description: optional_str(&json, "description")?,
+            creator: optional_str(&json, "creator")?,
+            creator_did: optional_str(&json, "creatorDID")?,
+            checksum: optional_str(&json, "checksum")?,
+            format: optional_str(&json, "format")?,
+            files: optional_array(&json, "files")?
+                .iter()
+                .map(NftMetadataFile::from_json)
+                .collect::<crate::Result<_>>()?,
+            attributes: optional_array(&json, "attributes")?
+                .iter()
+                .map(NftMetadataAttribute::from_json)
+                .collect::<crate::Result<_>>()?,
+        })
+    }
+}
+
+impl NftMetadataFile {
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        Ok(Self {
+            uri: required_str(json, "uri")?,
+            mime_type: required_str(json, "type")?,
+            checksum: optional_str(json, "checksum")?,
+            is_default_file: match json.get("is_default_file") {
+                None | Some(Value::Null) => false,
+                Some(Value::Bool(it)) => *it,
+                Some(_) => return Err(Error::nft_metadata("`is_default_file` is not a boolean")),
+            },
+        })
+    }
+}
+
+impl NftMetadataAttribute {
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        let value = match json.get("value") {
+            Some(Value::String(it)) => NftMetadataAttributeValue::String(it.clone()),
+            Some(Value::Bool(it)) => NftMetadataAttributeValue::Bool(*it),
+            Some(Value::Number(it)) => NftMetadataAttributeValue::Number(
+                it.as_f64()
+                    .ok_or_else(|| Error::nft_metadata("attribute `value` is out of range"))?,
+            ),
+            _ => {
+                return Err(Error::nft_metadata(
+                    "attribute `value` is missing or not a string, number, or boolean",
+                ))
+            }
+        };
+
+        Ok(Self {
+            trait_type: required_str(json, "trait_type")?,
+            value,
+            display_type: optional_str(json, "display_type")?,
+        })
+    }
+}
+
+/// Fetches the HIP-412 metadata of the NFT described by `info`, resolving `ipfs://` URIs with [`DEFAULT_IPFS_GATEWAY`].
+///
+/// # Errors
+/// - [`Error::NftMetadata`] if the NFT's metadata isn't an `ipfs://` or `https://` URI.
+/// - [`Error::NftMetadata`] if downloading the document fails, or it's larger than 1 MiB.
+/// - [`Error::NftMetadata`] if the document isn't valid HIP-412 metadata, see [`NftMetadata::from_json`].
+pub async fn fetch(info: &TokenNftInfo) -> crate::Result<NftMetadata> {
+    fetch_with_ipfs_gateway(info, DEFAULT_IPFS_GATEWAY).await
+}
+
+/// Fetches the HIP-412 metadata of the NFT described by `info`, resolving `ipfs://` URIs with `ipfs_gateway`.
+///
+/// `ipfs_gateway` is the URL that a CID is appended to, such as `https://ipfs.io/ipfs/`.
+///
+/// # Errors
+/// - [`Error::NftMetadata`] if the NFT's metadata isn't an `ipfs://` or `https://` URI.
+/// - [`Error::NftMetadata`] if downloading the document fails, or it's larger than 1 MiB.
+/// - [`Error::NftMetadata`] if the document isn't valid HIP-412 metadata, see [`NftMetadata::from_json`].
+pub async fn fetch_with_ipfs_gateway(
+    info: &TokenNftInfo,
+    ipfs_gateway: &str,
+) -> crate::Result<NftMetadata> {
+    let uri = std::str::from_utf8(&info.metadata).map_err(Error::nft_metadata)?;
+    let url = resolve_uri(uri, ipfs_gateway)?;
+
+    let response = HTTP_CLIENT
+        .get(&url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(Error::nft_metadata)?;
+
+    // checked up front when the server says, so that an oversized document isn't downloaded at all.
+    if response.content_length().is_some_and(|len| len > MAX_DOCUMENT_SIZE as u64) {
+        return Err(Error::nft_metadata("metadata document is larger than 1 MiB"));
+    }
+
+    let bytes = response.bytes().await.map_err(Error::nft_metadata)?;
+
+    if bytes.len() > MAX_DOCUMENT_SIZE {
+        return Err(Error::nft_metadata("metadata document is larger than 1 MiB"));
+    }
+
+    NftMetadata::from_json(&bytes)
+}
+
+/// Resolves an NFT metadata URI to an HTTPS URL.
+///
+/// `ipfs://<cid>/<path>` (and `ipfs://ipfs/<cid>/<path>`) is resolved with `ipfs_gateway`,
+/// `https://` URLs are returned as is.
+///
+/// # Errors
+/// - [`Error::NftMetadata`] if `uri` is neither an `ipfs://` nor an `https://` URI.
+pub fn resolve_uri(uri: &str, ipfs_gateway: &str) -> crate::Result<String> {
+    let uri = uri.trim();
+
+    if let Some(path) = uri.strip_prefix("ipfs://") {
+        let path = path.strip_prefix("ipfs/").unwrap_or(path);
+
+        if path.is_empty() {
+            return Err(Error::nft_metadata("`ipfs://` URI is missing a CID"));
+        }
+
+        return Ok(format!("{}/{path}", ipfs_gateway.trim_end_matches('/')));
+    }
+
+    if uri.starts_with("https://") {
+        return Ok(uri.to_owned());
+    }
+
+    Err(Error::nft_metadata(format!(
+        "unsupported metadata URI `{uri}`, expected `ipfs://` or `https://`"
+    )))
+}
+
+fn required_str(json: &Value, key: &str) -> crate::Result<String> {
+    optional_str(json, key)?
+        .ok_or_else(|| Error::nft_metadata(format!("required field `{key}` is missing")))
+}
+
+fn optional_str(json: &Value, key: &str) -> crate::Result<Option<String>> {
+    match json.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(it)) => Ok(Some(it.clone())),
+        Some(_) => Err(Error::nft_metadata(format!("`{key}` is not a string"))),
+    }
+}
+
+fn optional_array<'a>(json: &'a Value, key: &str) -> crate::Result<&'a [Value]> {
+    match json.get(key) {
+        None | Some(Value::Null) => Ok(&[]),
+        Some(Value::Array(it)) => Ok(it),
+        Some(_) => Err(Error::nft_metadata(format!("`{key}` is not an array"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        resolve_uri,
+        NftMetadata,
+        NftMetadataAttributeValue,
+        DEFAULT_IPFS_GATEWAY,
+    };
+    use crate::Error;
+
+    const CID: &str = "bafkreibwci24bt2xtqi23g35gfx63wj555u77lwl2t55ajbfjqomgefxce";
+
+    #[test]
+    fn resolve_ipfs() {
+        let expected = format!("https://ipfs.io/ipfs/{CID}/1.json");
+
+        assert_eq!(
+            resolve_uri(&format!("ipfs://{CID}/1.json"), DEFAULT_IPFS_GATEWAY).unwrap(),
+            expected
+        );
+        assert_eq!(
+            resolve_uri(&format!("ipfs://ipfs/{CID}/1.json"), "https://ipfs.io/ipfs").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn resolve_https() {
+        let uri = "https://example.com/nft/1.json";
+
+        assert_eq!(resolve_uri(uri, DEFAULT_IPFS_GATEWAY).unwrap(), uri);
+    }
+
+    #[test]
+    fn resolve_unsupported() {
+        assert_matches!(
+            resolve_uri("http://example.com/1.json", DEFAULT_IPFS_GATEWAY),
+            Err(Error::NftMetadata(_))
+        );
+        assert_matches!(resolve_uri("ipfs://", DEFAULT_IPFS_GATEWAY), Err(Error::NftMetadata(_)));
+    }
+
+    #[test]
+    fn from_json() {
+        let json = br#"{
+            "name": "Example NFT 001",
+            "creator": "Jane Doe",
+            "description": "This describes my NFT",
+            "image": "ipfs://bafkreibwci24bt2xtqi23g35gfx63wj555u77lwl2t55ajbfjqomgefxce",
+            "type": "image/jpeg",
+            "format": "HIP412@2.0.0",
+            "files": [
+                {
+                    "uri": "ipfs://bafybeidbyn7aazxw3ymwg6xemfp3ua3ixm2wdnihvw7j4q5cpbaavtyyw4",
+                    "type": "video/mp4",
+                    "is_default_file": true
+                }
+            ],
+            "attributes": [
+                { "trait_type": "color", "value": "blue" },
+                { "trait_type": "stamina", "display_type": "boost", "value": 10 }
+            ],
+            "properties": { "website": "www.example.com" }
+        }"#;
+
+        let metadata = NftMetadata::from_json(json).unwrap();
+
+        assert_eq!(metadata.name, "Example NFT 001");
+        assert_eq!(metadata.mime_type, "image/jpeg");
+        assert_eq!(metadata.creator.as_deref(), Some("Jane Doe"));
+        assert_eq!(metadata.creator_did, None);
+        assert_eq!(metadata.files.len(), 1);
+        assert!(metadata.files[0].is_default_file);
+        assert_eq!(
+            metadata.attributes[0].value,
+            NftMetadataAttributeValue::String("blue".to_owned())
+        );
+        assert_eq!(metadata.attributes[1].value, NftMetadataAttributeValue::Number(10.0));
+        assert_eq!(metadata.attributes[1].display_type.as_deref(), Some("boost"));
+    }
+
+    #[test]
+    fn from_json_missing_required() {
+        let json = br#"{ "name": "Example NFT 001", "image": "https://example.com/1.png" }"#;
+
+        assert_matches!(NftMetadata::from_json(json), Err(Error::NftMetadata(_)));
+    }
+
+    #[test]
+    fn from_json_wrong_type() {
+        let json = br#"{ "name": 1, "image": "https://example.com/1.png", "type": "image/png" }"#;
+
+        assert_matches!(NftMetadata::from_json(json), Err(Error::NftMetadata(_)));
+    }
+}
@@ -21,6 +21,10 @@
 use std::net::SocketAddrV4;
 
 use hedera_proto::services;
+use sha2::{
+    Digest,
+    Sha384,
+};
 
 use crate::protobuf::ToProtobuf;
 use crate::{
@@ -73,6 +77,33 @@ pub struct NodeAddress {
     pub description: String,
 }
 
+impl NodeAddress {
+    /// Returns the SHA-384 hash of the node's TLS certificate.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if [`tls_certificate_hash`](Self::tls_certificate_hash) isn't 96 hex characters.
+    pub fn decode_tls_certificate_hash(&self) -> crate::Result<[u8; 48]> {
+        let bytes = hex::decode(&self.tls_certificate_hash).map_err(Error::basic_parse)?;
+
+        bytes.try_into().map_err(|v: Vec<u8>| {
+            Error::basic_parse(format!(
+                "expected 48 byte certificate hash, got `{}` bytes",
+                v.len()
+            ))
+        })
+    }
+
+    /// Returns `true` if `certificate` (the node's TLS certificate in PEM format) matches
+    /// [`tls_certificate_hash`](Self::tls_certificate_hash).
+    ///
+    /// Returns `false` if the certificate doesn't match, or if this node has no valid certificate hash.
+    #[must_use]
+    pub fn verify_tls_certificate(&self, certificate: &[u8]) -> bool {
+        self.decode_tls_certificate_hash()
+            .is_ok_and(|hash| Sha384::digest(certificate).as_slice() == hash)
+    }
+}
+
 impl FromProtobuf<services::NodeAddress> for NodeAddress {
     fn from_protobuf(pb: services::NodeAddress) -> crate::Result<Self>
     where
@@ -114,7 +145,8 @@ impl ToProtobuf for NodeAddress {
             .map(|it| services::ServiceEndpoint {
                 ip_address_v4: it.ip().octets().to_vec(),
                 port: i32::from(it.port()),
-                domain_name: it.to_string(),
+                // mutually exclusive with `ip_address_v4`.
+                domain_name: String::new(),
             })
             .collect();
 
@@ -131,3 +163,51 @@ impl ToProtobuf for NodeAddress {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sha2::{
+        Digest,
+        Sha384,
+    };
+
+    use crate::{
+        AccountId,
+        NodeAddress,
+    };
+
+    const CERTIFICATE: &[u8] =
+        b"-----BEGIN CERTIFICATE-----\nnot really a certificate\n-----END CERTIFICATE-----\n";
+
+    fn make_node_address(tls_certificate_hash: Vec<u8>) -> NodeAddress {
+        NodeAddress {
+            node_id: 0,
+            rsa_public_key: Vec::new(),
+            node_account_id: AccountId::new(0, 0, 3),
+            tls_certificate_hash,
+            service_endpoints: Vec::new(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn verify_tls_certificate() {
+        let hash = hex::encode(Sha384::digest(CERTIFICATE));
+
+        assert!(make_node_address(hash.clone().into_bytes()).verify_tls_certificate(CERTIFICATE));
+
+        // hex is case insensitive.
+        assert!(
+            make_node_address(hash.to_uppercase().into_bytes()).verify_tls_certificate(CERTIFICATE)
+        );
+
+        assert!(!make_node_address(hash.into_bytes()).verify_tls_certificate(b"something else"));
+    }
+
+    #[test]
+    fn decode_tls_certificate_hash_invalid() {
+        assert!(make_node_address(Vec::new()).decode_tls_certificate_hash().is_err());
+        assert!(make_node_address(b"not hex".to_vec()).decode_tls_certificate_hash().is_err());
+        assert!(!make_node_address(b"abcd".to_vec()).verify_tls_certificate(CERTIFICATE));
+    }
+}
@@ -45,3 +45,39 @@ impl ToProtobuf for NodeAddressBook {
         services::NodeAddressBook { node_address: self.node_addresses.to_protobuf() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+    };
+
+    #[test]
+    fn to_from_bytes() {
+        let a = NodeAddressBook {
+            node_addresses: Vec::from([NodeAddress {
+                node_id: 3,
+                rsa_public_key: Vec::from([1, 2, 3]),
+                node_account_id: AccountId::new(0, 0, 6),
+                tls_certificate_hash: b"abcd".to_vec(),
+                service_endpoints: Vec::from([SocketAddrV4::new(
+                    Ipv4Addr::new(35, 237, 200, 180),
+                    50211,
+                )]),
+                description: "node 3".to_owned(),
+            }]),
+        };
+
+        let b = NodeAddressBook::from_bytes(&a.to_bytes()).unwrap();
+
+        assert_eq!(a.to_protobuf(), b.to_protobuf());
+    }
+}
@@ -32,7 +32,6 @@ use hedera_proto::{
     services,
 };
 use mirror::network_service_client::NetworkServiceClient;
-use tonic::transport::Channel;
 use tonic::Response;
 
 use crate::mirror_query::{
@@ -41,10 +40,13 @@ use crate::mirror_query::{
     MirrorRequest,
 };
 use crate::protobuf::FromProtobuf;
+use crate::transport::Channel;
 use crate::{
     AnyMirrorQueryResponse,
+    Client,
     FileId,
     MirrorQuery,
+    MirrorQueryStream,
     NodeAddress,
     NodeAddressBook,
     ToProtobuf,
@@ -54,6 +56,30 @@ use crate::{
 
 /// Query for an address book and return its nodes.
 /// The nodes are returned in ascending order by node ID.
+///
+/// [`execute`](MirrorQuery::execute) buffers the entire address book,
+/// use [`subscribe`](MirrorQuery::subscribe) to receive each node as soon as the mirror node sends it.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use futures_util::TryStreamExt;
+/// use hedera::{Client, FileId, NodeAddressBookQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let mut nodes = NodeAddressBookQuery::new()
+///     .file_id(FileId::ADDRESS_BOOK)
+///     .limit(10)
+///     .subscribe(&client);
+///
+/// while let Some(node) = nodes.try_next().await? {
+///     println!("{}: {:?}", node.node_account_id, node.service_endpoints);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 pub type NodeAddressBookQuery = MirrorQuery<NodeAddressBookQueryData>;
 
 #[derive(Debug, Clone)]
@@ -90,7 +116,8 @@ impl NodeAddressBookQuery {
     }
 
     /// Sets the ID of the address book file on the network.
-    /// Can either be `0.0.101` or `0.0.102`. Defaults to `0.0.102`.
+    /// Can either be [`FileId::LEGACY_ADDRESS_BOOK`] (`0.0.101`) or [`FileId::ADDRESS_BOOK`] (`0.0.102`).
+    /// Defaults to `0.0.102`.
     pub fn file_id(&mut self, id: impl Into<FileId>) -> &mut Self {
         self.data.file_id = id.into();
         self
@@ -116,6 +143,14 @@ impl From<NodeAddressBookQueryData> for AnyMirrorQueryData {
     }
 }
 
+impl MirrorQueryStream for NodeAddressBookQuery {
+    type Item = NodeAddress;
+
+    fn stream<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<NodeAddress>> {
+        self.subscribe(client)
+    }
+}
+
 impl MirrorRequest for NodeAddressBookQueryData {
     type GrpcItem = services::NodeAddress;
 
@@ -143,14 +178,14 @@ impl MirrorRequest for NodeAddressBookQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         Box::pin(Self::map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
@@ -187,12 +222,9 @@ impl NodeAddressBookQuery {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        NodeAddressBookQueryData::try_collect(crate::mirror_query::subscribe(
-            channel,
-            timeout,
-            self.data.clone(),
-        ))
-        .await
+        self.data
+            .try_collect(crate::mirror_query::subscribe(channel, timeout, self.data.clone()))
+            .await
     }
 }
 
@@ -0,0 +1,125 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use crate::ping_query::PingQuery;
+use crate::runtime::Instant;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+};
+
+/// Check whether a specific node is reachable, and how long it takes to respond.
+///
+/// This is free: under the hood it's an [`AccountBalanceQuery`](crate::AccountBalanceQuery)
+/// for the node's own account, which doesn't require a payment.
+///
+/// Like any other request, the result of the ping feeds into the client's node health tracking,
+/// so a node that fails to respond will be temporarily avoided by other requests made with the same client.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, NodePingQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let response = NodePingQuery::new().node_account_id(AccountId::new(0, 0, 3)).execute(&client).await?;
+///
+/// println!("node 0.0.3 responded in {:?}", response.latency);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct NodePingQuery {
+    node_account_id: Option<AccountId>,
+}
+
+/// Response from [`NodePingQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodePingResponse {
+    /// The account ID of the node that was pinged.
+    pub node_account_id: AccountId,
+
+    /// How long it took for the node to respond, including any retries.
+    pub latency: Duration,
+}
+
+impl NodePingQuery {
+    /// Create a new `NodePingQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account ID of the node to ping.
+    #[must_use]
+    pub fn get_node_account_id(&self) -> Option<AccountId> {
+        self.node_account_id
+    }
+
+    /// Sets the account ID of the node to ping.
+    pub fn node_account_id(&mut self, node_account_id: AccountId) -> &mut Self {
+        self.node_account_id = Some(node_account_id);
+        self
+    }
+
+    /// Ping the node.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`](crate::Error::NodeAccountUnknown) if the node isn't part of the client's network.
+    /// - Any error returned by the node, or [`Error::TimedOut`](crate::Error::TimedOut) if the node is unreachable.
+    /// - [`Error::MissingField`](crate::Error::MissingField) if `node_account_id` wasn't set.
+    pub async fn execute(&self, client: &Client) -> crate::Result<NodePingResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Ping the node, canceling the ping after `timeout` has elapsed.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`](crate::Error::NodeAccountUnknown) if the node isn't part of the client's network.
+    /// - Any error returned by the node, or [`Error::TimedOut`](crate::Error::TimedOut) if the node is unreachable.
+    /// - [`Error::MissingField`](crate::Error::MissingField) if `node_account_id` wasn't set.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<NodePingResponse> {
+        self.execute_with_optional_timeout(client, Some(timeout)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout: Option<Duration>,
+    ) -> crate::Result<NodePingResponse> {
+        let node_account_id =
+            self.node_account_id.ok_or(Error::MissingField { field: "node_account_id" })?;
+
+        let start = Instant::now();
+
+        PingQuery::new(node_account_id).execute(client, timeout).await?;
+
+        Ok(NodePingResponse { node_account_id, latency: start.elapsed() })
+    }
+}
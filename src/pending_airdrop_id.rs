@@ -40,6 +40,7 @@ use crate::{
 /// that would modify that pending airdrop (such as a `claimAirdrop` or `cancelAirdrop`).
 ///
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct PendingAirdropId {
     /// A sending account.
     ///
@@ -85,10 +86,14 @@ impl fmt::Display for PendingAirdropId {
 }
 
 impl PendingAirdropId {
+    /// Create the ID of a pending airdrop of the NFT `nft_id`.
+    #[must_use]
     pub const fn new_nft_id(sender_id: AccountId, receiver_id: AccountId, nft_id: NftId) -> Self {
         Self { sender_id, receiver_id, token_id: None, nft_id: Some(nft_id) }
     }
 
+    /// Create the ID of a pending airdrop of the fungible token `token_id`.
+    #[must_use]
     pub const fn new_token_id(
         sender_id: AccountId,
         receiver_id: AccountId,
@@ -0,0 +1,327 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::{
+    future,
+    stream,
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+
+use crate::mirror_rest::{
+    self,
+    get_i64,
+    get_str,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    MirrorQueryStream,
+    PendingAirdropId,
+    PendingAirdropRecord,
+    TokenId,
+};
+
+/// Query the pending airdrops an account can claim from the mirror node REST API ([HIP-904](https://hips.hedera.com/hip/hip-904)).
+///
+/// An airdrop to an account that can't be automatically associated with the token is left pending,
+/// until the receiver claims it with a [`TokenClaimAirdropTransaction`](crate::TokenClaimAirdropTransaction)
+/// or the sender cancels it with a [`TokenCancelAirdropTransaction`](crate::TokenCancelAirdropTransaction).
+///
+/// Each record has the airdrop's ID and, for a fungible token, the amount. Records are ordered by token ID.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, PendingAirdropsQuery, TokenClaimAirdropTransaction};
+///
+/// let client = Client::for_testnet();
+///
+/// let airdrops = PendingAirdropsQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     .limit(10)
+///     .execute(&client)
+///     .await?;
+///
+/// let mut claim = TokenClaimAirdropTransaction::new();
+/// claim.pending_airdrop_ids(airdrops.iter().map(|it| it.pending_airdrop_id));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct PendingAirdropsQuery {
+    account_id: Option<AccountId>,
+    sender_account_id: Option<AccountId>,
+    token_id: Option<TokenId>,
+    limit: Option<usize>,
+}
+
+impl PendingAirdropsQuery {
+    /// Create a new `PendingAirdropsQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to query the pending airdrops of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to query the pending airdrops of, that is, the receiver.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the sender to return the pending airdrops from, if only one is wanted.
+    #[must_use]
+    pub fn get_sender_account_id(&self) -> Option<AccountId> {
+        self.sender_account_id
+    }
+
+    /// Sets the sender to return the pending airdrops from, if only one is wanted.
+    pub fn sender_account_id(&mut self, sender_account_id: AccountId) -> &mut Self {
+        self.sender_account_id = Some(sender_account_id);
+        self
+    }
+
+    /// Returns the token to return the pending airdrops of, if only one is wanted.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to return the pending airdrops of, if only one is wanted.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the maximum number of pending airdrops to return.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the maximum number of pending airdrops to return.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `account_id` wasn't set.
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails, or its response couldn't be understood.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<PendingAirdropRecord>> {
+        self.airdrops(client).try_collect().await
+    }
+
+    fn airdrops<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Stream<Item = crate::Result<PendingAirdropRecord>> + 'a {
+        let Some(account_id) = self.account_id else {
+            let error = Error::MissingField { field: "account_id" };
+
+            return stream::once(future::ready(Err(error))).left_stream();
+        };
+
+        mirror_rest::stream_all(
+            client,
+            self.path_and_query(account_id),
+            "airdrops",
+            self.limit,
+            |_| true,
+        )
+        .map(|it| it.and_then(|it| pending_airdrop_from_json(&it)))
+        .right_stream()
+    }
+
+    fn path_and_query(&self, account_id: AccountId) -> String {
+        let mut path =
+            format!("/api/v1/accounts/{account_id}/airdrops/pending?order=asc&limit=100");
+
+        if let Some(sender_account_id) = self.sender_account_id {
+            path.push_str(&format!("&sender.id={sender_account_id}"));
+        }
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        path
+    }
+}
+
+/// Parses a mirror node token airdrop, which is of an NFT if it has a serial number.
+fn pending_airdrop_from_json(json: &Value) -> crate::Result<PendingAirdropRecord> {
+    let sender_id: AccountId = get_str(json, "sender_id")?.parse()?;
+    let receiver_id: AccountId = get_str(json, "receiver_id")?.parse()?;
+    let token_id: TokenId = get_str(json, "token_id")?.parse()?;
+
+    let record = match json.get("serial_number").and_then(Value::as_i64) {
+        Some(serial) => {
+            let serial = u64::try_from(serial).map_err(Error::mirror_rest)?;
+
+            PendingAirdropRecord {
+                pending_airdrop_id: PendingAirdropId::new_nft_id(
+                    sender_id,
+                    receiver_id,
+                    token_id.nft(serial),
+                ),
+                pending_airdrop_value: None,
+            }
+        }
+        None => {
+            let amount = u64::try_from(get_i64(json, "amount")?).map_err(Error::mirror_rest)?;
+
+            PendingAirdropRecord {
+                pending_airdrop_id: PendingAirdropId::new_token_id(
+                    sender_id,
+                    receiver_id,
+                    token_id,
+                ),
+                pending_airdrop_value: Some(amount),
+            }
+        }
+    };
+
+    Ok(record)
+}
+
+impl MirrorQueryStream for PendingAirdropsQuery {
+    type Item = PendingAirdropRecord;
+
+    /// # Errors
+    /// - The stream yields [`Error::MissingField`] if `account_id` wasn't set.
+    fn stream<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<PendingAirdropRecord>> {
+        let stream = self.airdrops(client);
+
+        // the mirror node REST client isn't `Send` on wasm.
+        #[cfg(target_arch = "wasm32")]
+        let stream = send_wrapper::SendWrapper::new(stream);
+
+        Box::pin(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use serde_json::json;
+
+    use super::pending_airdrop_from_json;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        PendingAirdropId,
+        PendingAirdropsQuery,
+        TokenId,
+    };
+
+    #[tokio::test]
+    async fn missing_account_id() {
+        let client = Client::for_testnet();
+
+        let res = PendingAirdropsQuery::new().execute(&client).await;
+
+        assert_matches!(res, Err(Error::MissingField { field: "account_id" }));
+    }
+
+    #[test]
+    fn path_and_query() {
+        let mut query = PendingAirdropsQuery::new();
+
+        query.sender_account_id(AccountId::new(0, 0, 1002)).token_id(TokenId::new(0, 0, 5005));
+
+        expect!["/api/v1/accounts/0.0.1001/airdrops/pending?order=asc&limit=100&sender.id=0.0.1002&token.id=0.0.5005"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn fungible_from_json() {
+        let json = json!({
+            "amount": 1_500,
+            "receiver_id": "0.0.1001",
+            "sender_id": "0.0.1002",
+            "serial_number": null,
+            "timestamp": { "from": "1700000001.000000002", "to": null },
+            "token_id": "0.0.5005"
+        });
+
+        let record = pending_airdrop_from_json(&json).unwrap();
+
+        assert_eq!(
+            record.pending_airdrop_id,
+            PendingAirdropId::new_token_id(
+                AccountId::new(0, 0, 1002),
+                AccountId::new(0, 0, 1001),
+                TokenId::new(0, 0, 5005)
+            )
+        );
+        assert_eq!(record.pending_airdrop_value, Some(1_500));
+    }
+
+    #[test]
+    fn nft_from_json() {
+        let json = json!({
+            "amount": 0,
+            "receiver_id": "0.0.1001",
+            "sender_id": "0.0.1002",
+            "serial_number": 3,
+            "timestamp": { "from": "1700000001.000000002", "to": null },
+            "token_id": "0.0.5005"
+        });
+
+        let record = pending_airdrop_from_json(&json).unwrap();
+
+        assert_eq!(
+            record.pending_airdrop_id,
+            PendingAirdropId::new_nft_id(
+                AccountId::new(0, 0, 1002),
+                AccountId::new(0, 0, 1001),
+                TokenId::new(0, 0, 5005).nft(3)
+            )
+        );
+        assert_eq!(record.pending_airdrop_value, None);
+    }
+
+    #[test]
+    fn from_json_missing_sender_fails() {
+        let json = json!({
+            "amount": 1_500,
+            "receiver_id": "0.0.1001",
+            "token_id": "0.0.5005"
+        });
+
+        assert!(pending_airdrop_from_json(&json).is_err());
+    }
+}
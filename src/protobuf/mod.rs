@@ -24,7 +24,7 @@ mod time;
 #[macro_use]
 pub(crate) mod get;
 
-pub(crate) use convert::{
+pub use convert::{
     FromProtobuf,
     ToProtobuf,
 };
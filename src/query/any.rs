@@ -19,7 +19,6 @@
  */
 
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use super::ToQueryProtobuf;
 use crate::account::{
@@ -39,6 +38,7 @@ use crate::file::{
     FileInfoQueryData,
 };
 use crate::ledger_id::RefLedgerId;
+use crate::query::payment_transaction::PaymentTransaction;
 use crate::query::QueryExecute;
 use crate::schedule::ScheduleInfoQueryData;
 use crate::token::{
@@ -47,6 +47,7 @@ use crate::token::{
 };
 use crate::topic::TopicInfoQueryData;
 use crate::transaction_receipt_query::TransactionReceiptQueryData;
+use crate::transport::Channel;
 use crate::{
     AccountBalance,
     AccountInfo,
@@ -170,6 +171,92 @@ impl ToQueryProtobuf for AnyQueryData {
     }
 }
 
+// note: the payment transaction isn't carried over, a new one is made when the query is executed.
+impl FromProtobuf<services::Query> for AnyQuery {
+    fn from_protobuf(pb: services::Query) -> crate::Result<Self> {
+        use services::query::Query as Pb;
+
+        fn response_type(header: Option<&services::QueryHeader>) -> Option<i32> {
+            header.map(|it| it.response_type)
+        }
+
+        let (response_type, data) = match pb_getf!(pb, query)? {
+            Pb::CryptogetAccountBalance(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::AccountBalance(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::CryptoGetInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::AccountInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::CryptoGetProxyStakers(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::AccountStakers(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::CryptoGetAccountRecords(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::AccountRecords(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::TransactionGetReceipt(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::TransactionReceipt(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::TransactionGetRecord(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::TransactionRecord(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::FileGetContents(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::FileContents(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::FileGetInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::FileInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::ContractGetBytecode(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::ContractBytecode(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::ContractCallLocal(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::ContractCall(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::ContractGetInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::ContractInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::TokenGetNftInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::TokenNftInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::TokenGetInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::TokenInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::ConsensusGetTopicInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::TopicInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::ScheduleGetInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::ScheduleInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            Pb::NetworkGetVersionInfo(pb) => (
+                response_type(pb.header.as_ref()),
+                AnyQueryData::NetworkVersionInfo(FromProtobuf::from_protobuf(pb)?),
+            ),
+            pb => return Err(Error::from_protobuf(format!("unsupported query `{pb:?}`"))),
+        };
+
+        Ok(Self {
+            data,
+            payment: PaymentTransaction::default(),
+            request_state_proof: response_type
+                == Some(services::ResponseType::AnswerStateProof as i32),
+        })
+    }
+}
+
 impl QueryExecute for AnyQueryData {
     type Response = AnyQueryResponse;
 
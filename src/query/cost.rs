@@ -19,7 +19,6 @@
  */
 
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use crate::entity_id::ValidateChecksums;
 use crate::execute::{
@@ -28,6 +27,7 @@ use crate::execute::{
 };
 use crate::query::execute::response_header;
 use crate::query::QueryExecute;
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -87,7 +87,7 @@ where
         _node_account_id: AccountId,
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         let header = services::QueryHeader {
-            response_type: services::ResponseType::CostAnswer as i32,
+            response_type: self.0.cost_response_type() as i32,
             payment: None,
         };
 
@@ -21,7 +21,6 @@
 use std::fmt::Debug;
 
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
@@ -29,6 +28,7 @@ use crate::query::{
     AnyQueryData,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -73,6 +73,16 @@ pub trait QueryExecute:
         None
     }
 
+    /// Returns `true` if this query polls for a receipt.
+    fn is_receipt_poll(&self) -> bool {
+        false
+    }
+
+    /// Get the node to try before any other, if it's healthy.
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        None
+    }
+
     fn make_response(
         &self,
         response: services::response::Response,
@@ -124,6 +134,14 @@ where
         self.data.should_retry(response)
     }
 
+    fn is_receipt_poll(&self) -> bool {
+        self.data.is_receipt_poll()
+    }
+
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        self.data.preferred_node_account_id()
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
@@ -135,7 +153,7 @@ where
             None
         };
 
-        let header = services::QueryHeader { response_type: 0, payment };
+        let header = services::QueryHeader { response_type: self.response_type() as i32, payment };
 
         Ok((self.data.to_query_protobuf(header), ()))
     }
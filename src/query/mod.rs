@@ -21,14 +21,19 @@
 use futures_core::future::BoxFuture;
 use time::Duration;
 
-use crate::execute::execute;
+use crate::execute::{
+    cancellable,
+    execute,
+};
 use crate::query::cost::QueryCost;
 use crate::query::payment_transaction::PaymentTransaction;
 use crate::{
     AccountId,
+    CancellationToken,
     Client,
     Error,
     Hbar,
+    Status,
     TransactionId,
     TransactionReceiptQuery,
 };
@@ -38,6 +43,7 @@ mod cost;
 mod execute;
 pub(super) mod payment_transaction;
 mod protobuf;
+mod state_proof;
 
 pub(crate) use any::AnyQueryData;
 pub use any::{
@@ -49,6 +55,11 @@ pub(crate) use execute::{
     QueryExecute,
 };
 pub(crate) use protobuf::ToQueryProtobuf;
+use state_proof::WithStateProof;
+pub use state_proof::{
+    StateProof,
+    StateProofResponse,
+};
 
 /// A query that can be executed on the Hedera network.
 #[derive(Debug, Default)]
@@ -58,6 +69,7 @@ where
 {
     pub(crate) data: D,
     pub(crate) payment: PaymentTransaction,
+    pub(crate) request_state_proof: bool,
 }
 
 impl<D> Query<D>
@@ -76,6 +88,51 @@ impl<D> Query<D>
 where
     D: QueryExecute,
 {
+    /// Convert `self` to the raw `Query` protobuf, *without* a payment transaction.
+    ///
+    /// [`AnyQuery`](crate::AnyQuery) implements [`FromProtobuf`](crate::FromProtobuf) to convert it back.
+    ///
+    /// Requires the `raw-protobuf` feature, which is exempt from semver.
+    #[cfg(feature = "raw-protobuf")]
+    #[must_use]
+    pub fn to_protobuf(&self) -> hedera_proto::services::Query {
+        self.data.to_query_protobuf(hedera_proto::services::QueryHeader {
+            response_type: self.response_type() as i32,
+            payment: None,
+        })
+    }
+
+    pub(crate) fn response_type(&self) -> hedera_proto::services::ResponseType {
+        match self.request_state_proof {
+            true => hedera_proto::services::ResponseType::AnswerStateProof,
+            false => hedera_proto::services::ResponseType::AnswerOnly,
+        }
+    }
+
+    /// The response type to ask for the cost of this query with, a state proof costs more.
+    pub(crate) fn cost_response_type(&self) -> hedera_proto::services::ResponseType {
+        match self.request_state_proof {
+            true => hedera_proto::services::ResponseType::CostAnswerStateProof,
+            false => hedera_proto::services::ResponseType::CostAnswer,
+        }
+    }
+
+    /// Returns `true` if a state proof will be requested along with the response.
+    #[must_use]
+    pub fn get_request_state_proof(&self) -> bool {
+        self.request_state_proof
+    }
+
+    /// Sets whether to request a state proof along with the response.
+    ///
+    /// Use [`execute_with_state_proof`](Self::execute_with_state_proof) to receive it.
+    ///
+    /// Nodes that don't support state proofs fail the query with [`Status::NotSupported`].
+    pub fn request_state_proof(&mut self, request: bool) -> &mut Self {
+        self.request_state_proof = request;
+        self
+    }
+
     /// Returns the account IDs of the nodes that this query may be submitted to.
     ///
     /// Defaults to the full list of nodes configured on the client; or, the node account IDs
@@ -233,12 +290,35 @@ where
         self.execute_with_optional_timeout(client, None).await
     }
 
+    /// Execute this query against the provided client of the Hedera network,
+    /// returning the state proof the node responded with alongside the response.
+    ///
+    /// A state proof is only returned if one was requested with [`request_state_proof`](Self::request_state_proof).
+    ///
+    /// # Errors
+    /// - Any error that [`execute`](Self::execute) can return.
+    pub async fn execute_with_state_proof(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<StateProofResponse<D::Response>> {
+        self.execute_with_state_proof_and_optional_timeout(client, None).await
+    }
+
     // eww long name
     pub(crate) async fn execute_with_optional_timeout(
         &mut self,
         client: &Client,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<D::Response> {
+        Ok(self.execute_with_state_proof_and_optional_timeout(client, timeout).await?.response)
+    }
+
+    // even longer name
+    async fn execute_with_state_proof_and_optional_timeout(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<StateProofResponse<D::Response>> {
         fn recurse_receipt(
             transaction_id: &TransactionId,
             client: Client,
@@ -261,32 +341,79 @@ where
         }
 
         if self.payment.get_amount().is_none() && self.data.is_payment_required() {
+            let query_type = std::any::type_name::<D>();
+            // costs are cached per type, which doesn't account for the extra cost of a state proof.
+            let cost_cache = client.query_cost_cache().filter(|_| !self.request_state_proof);
+
+            if let Some(cost) = cost_cache.as_ref().and_then(|it| it.get(query_type)) {
+                let unpaid = self.payment.clone();
+
+                if self.pay(client, cost).is_ok() {
+                    match self.submit(client, timeout).await {
+                        // the cost of this query is higher than the cached cost for its type
+                        // (ex. it returns more data), so fall back to asking for it.
+                        Err(
+                            Error::QueryPreCheckStatus {
+                                status: Status::InsufficientTxFee, ..
+                            }
+                            | Error::QueryPaymentPreCheckStatus {
+                                status: Status::InsufficientTxFee,
+                                ..
+                            },
+                        ) => {}
+
+                        result => return result,
+                    }
+                }
+
+                self.payment = unpaid;
+            }
+
             // should this inherit the timeout?
             // payment is required but none was specified, query the cost
             let cost = QueryCost::new(self).execute(client, None).await?;
 
-            if self.payment.get_max_amount().is_none() {
-                // N.B. This can still be `None`.
-                self.payment.max_amount(client.default_max_query_payment());
+            if let Some(cost_cache) = &cost_cache {
+                cost_cache.insert(query_type, cost);
             }
 
-            if let Some(max_amount) = self.payment.get_max_amount() {
-                if cost > max_amount {
-                    return Err(Error::MaxQueryPaymentExceeded {
-                        query_cost: cost,
-                        max_query_payment: max_amount,
-                    });
-                }
-            }
+            self.pay(client, cost)?;
+        }
 
-            self.payment.amount(cost);
+        self.submit(client, timeout).await
+    }
+
+    /// Sets the payment amount to `cost`, unless it exceeds the max payment amount.
+    fn pay(&mut self, client: &Client, cost: Hbar) -> crate::Result<()> {
+        if self.payment.get_max_amount().is_none() {
+            // N.B. This can still be `None`.
+            self.payment.max_amount(client.default_max_query_payment());
         }
 
+        if let Some(max_amount) = self.payment.get_max_amount() {
+            if cost > max_amount {
+                return Err(Error::MaxQueryPaymentExceeded {
+                    query_cost: cost,
+                    max_query_payment: max_amount,
+                });
+            }
+        }
+
+        self.payment.amount(cost);
+
+        Ok(())
+    }
+
+    async fn submit(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<StateProofResponse<D::Response>> {
         if self.data.is_payment_required() {
             self.payment.freeze_with(client)?;
         }
 
-        execute(client, self, timeout).await
+        execute(client, &WithStateProof::new(self), timeout).await
     }
 
     /// Execute this query against the provided client of the Hedera network.
@@ -299,4 +426,126 @@ where
     ) -> crate::Result<D::Response> {
         self.execute_with_optional_timeout(client, Some(timeout)).await
     }
+
+    /// Execute this query against the provided client of the Hedera network,
+    /// unless `cancellation` is cancelled first.
+    ///
+    /// A cancelled query is left as it was before executing, including its payment.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before the query completes,
+    ///   if the query required payment, the payment may still reach consensus.
+    /// - Any error that [`execute`](Self::execute) can return.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<D::Response> {
+        // the payment is set up before the query is sent, so a cancelled query has to have it put back.
+        let unpaid = self.data.is_payment_required().then(|| self.payment.clone());
+
+        let result =
+            cancellable(cancellation, self.execute_with_optional_timeout(client, None)).await;
+
+        if let (Err(Error::Cancelled), Some(unpaid)) = (&result, unpaid) {
+            self.payment = unpaid;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+
+    use crate::account::AccountInfoQueryData;
+    use crate::query::ToQueryProtobuf;
+    use crate::{
+        AccountId,
+        AccountInfoQuery,
+        AnyQuery,
+        CancellationToken,
+        Client,
+        Error,
+        FromProtobuf,
+        Hbar,
+        PrivateKey,
+        TransactionId,
+        TransactionReceiptQuery,
+    };
+
+    #[test]
+    fn to_protobuf_from_protobuf() -> crate::Result<()> {
+        let mut query = TransactionReceiptQuery::new();
+        query
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 1001)))
+            .include_children(true)
+            .request_state_proof(true);
+
+        let header = services::QueryHeader {
+            response_type: services::ResponseType::AnswerStateProof as i32,
+            payment: None,
+        };
+
+        let pb = query.data.to_query_protobuf(header.clone());
+
+        let query2 = AnyQuery::from_protobuf(pb.clone())?;
+
+        assert!(query2.get_request_state_proof());
+        assert_eq!(query2.data.to_query_protobuf(header), pb);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_with_cancellation_restores_payment() {
+        // a node that accepts connections but never answers, so the query is still in flight when it's cancelled.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+
+            while let Ok((connection, _)) = listener.accept().await {
+                connections.push(connection);
+            }
+        });
+
+        let client =
+            Client::for_network(HashMap::from([(address, AccountId::new(0, 0, 3))])).unwrap();
+        client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+        client.set_query_cost_cache_ttl(Some(Duration::from_secs(60)));
+
+        // with a cached cost the payment is set before anything is sent.
+        client
+            .query_cost_cache()
+            .unwrap()
+            .insert(std::any::type_name::<AccountInfoQueryData>(), Hbar::from_tinybars(10));
+
+        let cancellation = CancellationToken::new();
+
+        tokio::spawn({
+            let cancellation = cancellation.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                cancellation.cancel();
+            }
+        });
+
+        let mut query = AccountInfoQuery::new();
+        query.account_id(AccountId::new(0, 0, 1001));
+
+        assert_matches!(
+            query.execute_with_cancellation(&client, &cancellation).await,
+            Err(Error::Cancelled)
+        );
+
+        assert_eq!(query.get_payment_amount(), None);
+        assert_eq!(query.get_max_amount(), None);
+    }
 }
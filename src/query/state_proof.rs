@@ -0,0 +1,224 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use hedera_proto::services;
+
+use crate::entity_id::ValidateChecksums;
+use crate::execute::Execute;
+use crate::query::execute::response_header;
+use crate::query::QueryExecute;
+use crate::transport::Channel;
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Query,
+    TransactionId,
+};
+
+/// A state proof returned alongside a query response.
+///
+/// See [`Query::request_state_proof`](crate::Query::request_state_proof).
+///
+/// The SDK doesn't interpret state proofs, this is the raw bytes the node responded with.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StateProof(Vec<u8>);
+
+impl StateProof {
+    /// Returns the bytes of this state proof.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the bytes of this state proof.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl fmt::Debug for StateProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", hex::encode(&self.0))
+    }
+}
+
+/// A query response, with the state proof the node returned for it.
+///
+/// Returned by [`Query::execute_with_state_proof`](crate::Query::execute_with_state_proof).
+#[derive(Debug, Clone)]
+pub struct StateProofResponse<T> {
+    /// The response to the query.
+    pub response: T,
+
+    /// The state proof for the response, if one was requested and the node returned one.
+    pub state_proof: Option<StateProof>,
+}
+
+impl<T> StateProofResponse<T> {
+    fn new(response: T, state_proof: Vec<u8>) -> Self {
+        let state_proof = (!state_proof.is_empty()).then_some(StateProof(state_proof));
+
+        Self { response, state_proof }
+    }
+}
+
+/// Executes a query like the query itself would, but keeps the state proof from the response header.
+pub(super) struct WithStateProof<'a, D>(&'a Query<D>)
+where
+    D: QueryExecute;
+
+impl<'a, D> WithStateProof<'a, D>
+where
+    D: QueryExecute,
+{
+    pub(super) fn new(query: &'a Query<D>) -> Self {
+        Self(query)
+    }
+}
+
+impl<D> Execute for WithStateProof<'_, D>
+where
+    D: QueryExecute,
+{
+    type GrpcRequest = services::Query;
+
+    type GrpcResponse = services::Response;
+
+    type Response = StateProofResponse<D::Response>;
+
+    type Context = ();
+
+    fn node_account_ids(&self) -> Option<&[AccountId]> {
+        Execute::node_account_ids(self.0)
+    }
+
+    fn transaction_id(&self) -> Option<TransactionId> {
+        Execute::transaction_id(self.0)
+    }
+
+    fn requires_transaction_id(&self) -> bool {
+        Execute::requires_transaction_id(self.0)
+    }
+
+    fn operator_account_id(&self) -> Option<&AccountId> {
+        Execute::operator_account_id(self.0)
+    }
+
+    fn should_retry_pre_check(&self, status: crate::Status) -> bool {
+        Execute::should_retry_pre_check(self.0, status)
+    }
+
+    fn should_retry(&self, response: &Self::GrpcResponse) -> bool {
+        Execute::should_retry(self.0, response)
+    }
+
+    fn is_receipt_poll(&self) -> bool {
+        Execute::is_receipt_poll(self.0)
+    }
+
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        Execute::preferred_node_account_id(self.0)
+    }
+
+    fn make_request(
+        &self,
+        transaction_id: Option<&TransactionId>,
+        node_account_id: AccountId,
+    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+        Execute::make_request(self.0, transaction_id, node_account_id)
+    }
+
+    fn execute(
+        &self,
+        channel: Channel,
+        request: Self::GrpcRequest,
+    ) -> BoxGrpcFuture<'_, Self::GrpcResponse> {
+        Execute::execute(self.0, channel, request)
+    }
+
+    fn make_response(
+        &self,
+        response: Self::GrpcResponse,
+        context: Self::Context,
+        node_account_id: AccountId,
+        transaction_id: Option<&TransactionId>,
+    ) -> crate::Result<Self::Response> {
+        let state_proof = response_header(&response.response)?.state_proof.clone();
+
+        let response =
+            Execute::make_response(self.0, response, context, node_account_id, transaction_id)?;
+
+        Ok(StateProofResponse::new(response, state_proof))
+    }
+
+    fn make_error_pre_check(
+        &self,
+        status: crate::Status,
+        transaction_id: Option<&TransactionId>,
+        response: Self::GrpcResponse,
+    ) -> crate::Error {
+        Execute::make_error_pre_check(self.0, status, transaction_id, response)
+    }
+
+    fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
+        Ok(response_header(&response.response)?.node_transaction_precheck_code)
+    }
+}
+
+impl<D: QueryExecute> ValidateChecksums for WithStateProof<'_, D> {
+    fn validate_checksums(&self, ledger_id: &crate::ledger_id::RefLedgerId) -> crate::Result<()> {
+        self.0.validate_checksums(ledger_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use super::StateProofResponse;
+    use crate::AccountBalanceQuery;
+
+    #[test]
+    fn request_state_proof() {
+        let mut query = AccountBalanceQuery::new();
+
+        assert!(!query.get_request_state_proof());
+        assert_eq!(query.response_type(), services::ResponseType::AnswerOnly);
+        assert_eq!(query.cost_response_type(), services::ResponseType::CostAnswer);
+
+        query.request_state_proof(true);
+
+        assert!(query.get_request_state_proof());
+        assert_eq!(query.response_type(), services::ResponseType::AnswerStateProof);
+        assert_eq!(query.cost_response_type(), services::ResponseType::CostAnswerStateProof);
+    }
+
+    #[test]
+    fn empty_state_proof_is_none() {
+        assert_eq!(StateProofResponse::new((), Vec::new()).state_proof, None);
+
+        let response = StateProofResponse::new((), vec![1, 2, 3]);
+
+        assert_eq!(response.state_proof.map(|it| it.into_bytes()), Some(vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use crate::client::ClientBackoff;
+
+/// Controls how [`TransactionResponse::get_receipt_with_policy`](crate::TransactionResponse::get_receipt_with_policy)
+/// polls the network for a receipt.
+///
+/// Any field left as `None` falls back to the value configured on the [`Client`](crate::Client).
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use hedera::ReceiptPollingPolicy;
+///
+/// let policy = ReceiptPollingPolicy {
+///     poll_interval: Some(Duration::from_millis(50)),
+///     max_wait: Some(Duration::from_secs(5)),
+///     ..ReceiptPollingPolicy::default()
+/// };
+/// # let _ = policy;
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ReceiptPollingPolicy {
+    /// How long to wait before the first re-poll.
+    ///
+    /// Successive polls back off exponentially from here, up to [`max_poll_interval`](Self::max_poll_interval).
+    pub poll_interval: Option<Duration>,
+
+    /// The longest amount of time to wait between two polls.
+    pub max_poll_interval: Option<Duration>,
+
+    /// The maximum total amount of time to spend polling before giving up.
+    pub max_wait: Option<Duration>,
+
+    /// The maximum number of polls to make before giving up.
+    pub max_attempts: Option<usize>,
+
+    /// Return the receipt as soon as the network responds, even if its status is still
+    /// [`Status::Unknown`](crate::Status::Unknown) (the transaction hasn't reached consensus yet).
+    ///
+    /// When `true` the receipt status isn't validated if it's `Unknown`.
+    pub return_on_unknown: bool,
+}
+
+impl ReceiptPollingPolicy {
+    pub(crate) fn to_backoff(self, client_backoff: ClientBackoff) -> ClientBackoff {
+        ClientBackoff {
+            initial_backoff: self.poll_interval.unwrap_or(client_backoff.initial_backoff),
+            max_backoff: self.max_poll_interval.unwrap_or(client_backoff.max_backoff),
+            max_attempts: self.max_attempts.unwrap_or(client_backoff.max_attempts),
+            request_timeout: self.max_wait.or(client_backoff.request_timeout),
+            grpc_timeout: client_backoff.grpc_timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::ClientBackoff;
+    use crate::ReceiptPollingPolicy;
+
+    #[test]
+    fn default_uses_client_backoff() {
+        let client_backoff = ClientBackoff {
+            max_backoff: Duration::from_secs(8),
+            initial_backoff: Duration::from_millis(250),
+            max_attempts: 10,
+            request_timeout: Some(Duration::from_secs(120)),
+            grpc_timeout: None,
+        };
+
+        let backoff = ReceiptPollingPolicy::default().to_backoff(client_backoff);
+
+        assert_eq!(backoff.initial_backoff, client_backoff.initial_backoff);
+        assert_eq!(backoff.max_backoff, client_backoff.max_backoff);
+        assert_eq!(backoff.max_attempts, client_backoff.max_attempts);
+        assert_eq!(backoff.request_timeout, client_backoff.request_timeout);
+    }
+
+    #[test]
+    fn explicit_values_override_client_backoff() {
+        let policy = ReceiptPollingPolicy {
+            poll_interval: Some(Duration::from_millis(10)),
+            max_poll_interval: Some(Duration::from_millis(100)),
+            max_wait: Some(Duration::from_secs(1)),
+            max_attempts: Some(3),
+            return_on_unknown: true,
+        };
+
+        let backoff = policy.to_backoff(ClientBackoff::default());
+
+        assert_eq!(backoff.initial_backoff, Duration::from_millis(10));
+        assert_eq!(backoff.max_backoff, Duration::from_millis(100));
+        assert_eq!(backoff.max_attempts, 3);
+        assert_eq!(backoff.request_timeout, Some(Duration::from_secs(1)));
+    }
+}
@@ -1,5 +1,15 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use backoff::ExponentialBackoffBuilder;
 use futures_core::Future;
-use tokio::time::sleep;
+
+use crate::client::ClientBackoff;
+use crate::runtime::sleep;
+use crate::{
+    AccountId,
+    Client,
+};
 
 #[derive(Debug)]
 pub(crate) enum Error {
@@ -25,16 +35,186 @@ impl Error {
 
 pub(crate) type Result<T> = std::result::Result<T, Error>;
 
+/// Controls how often, and for how long, [`retry`] retries an operation.
+///
+/// # Examples
+/// ```
+/// use std::num::NonZeroUsize;
+/// use std::time::Duration;
+///
+/// use hedera::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: NonZeroUsize::new(5).unwrap(),
+///     max_elapsed: Some(Duration::from_secs(30)),
+///     ..RetryPolicy::default()
+/// };
+/// # let _ = policy;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How long to wait after the first failed attempt.
+    ///
+    /// Successive attempts back off exponentially from here, up to [`max_backoff`](Self::max_backoff).
+    pub initial_backoff: Duration,
+
+    /// The longest amount of time to wait between two attempts.
+    pub max_backoff: Duration,
+
+    /// The maximum number of attempts to make before giving up.
+    pub max_attempts: NonZeroUsize,
+
+    /// The maximum total amount of time to spend retrying before giving up.
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    /// Returns the policy a newly created [`Client`] uses for its requests.
+    fn default() -> Self {
+        Self::from_backoff(ClientBackoff::default())
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the policy `client` currently uses for its requests.
+    ///
+    /// See [`Client::min_backoff`], [`Client::max_backoff`], [`Client::max_attempts`], and [`Client::request_timeout`].
+    ///
+    /// A client with a `max_attempts` of `0` still gets a policy that makes one attempt.
+    #[must_use]
+    pub fn from_client(client: &Client) -> Self {
+        Self::from_backoff(client.backoff())
+    }
+
+    fn from_backoff(backoff: ClientBackoff) -> Self {
+        Self {
+            initial_backoff: backoff.initial_backoff,
+            max_backoff: backoff.max_backoff,
+            max_attempts: NonZeroUsize::new(backoff.max_attempts).unwrap_or(NonZeroUsize::MIN),
+            max_elapsed: backoff.request_timeout,
+        }
+    }
+}
+
+/// A failed attempt of a request that's about to be retried.
+///
+/// See [`Client::set_retry_handler`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct RetryEvent<'a> {
+    /// The kind of request being retried.
+    ///
+    /// This is the name of the request's type, meant for labelling metrics; its exact format isn't stable.
+    pub request: &'static str,
+
+    /// The node the failed attempt was sent to.
+    pub node_account_id: AccountId,
+
+    /// The number of the failed attempt, starting at `1`.
+    ///
+    /// Every node a request is sent to counts as an attempt, same as [`TransactionResponse::attempts`](crate::TransactionResponse::attempts).
+    pub attempt: usize,
+
+    /// How long the SDK waits before the next attempt.
+    ///
+    /// This is zero when the next attempt is sent to another node immediately.
+    pub delay: Duration,
+
+    /// Why the attempt failed.
+    pub cause: &'a crate::Error,
+}
+
+/// Retry `op` according to `policy`, with the same backoff the SDK uses for its own requests.
+///
+/// Use this to wrap operations composed of several requests (for instance, getting an account's info
+/// and then its balance) so that the whole operation is retried.
+///
+/// Every request of the operation is sent again on a retry, so only wrap operations that are safe to repeat.
+/// Re-executing a transaction either fails with [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction),
+/// or, with a new transaction ID, executes it twice.
+///
+/// An attempt is retried if it fails with an error for which [`Error::is_retryable`](crate::Error::is_retryable) returns `true`,
+/// any other error is returned immediately.
+///
+/// # Errors
+/// - [`Error::TimedOut`](crate::Error::TimedOut) with the last error if every attempt failed with a retryable error.
+/// - The first error that isn't retryable.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{retry, AccountBalanceQuery, AccountId, AccountInfoQuery, Client, RetryPolicy};
+///
+/// let client = Client::for_testnet();
+/// let policy = RetryPolicy::from_client(&client);
+///
+/// let client = &client;
+/// let (info, balance) = retry(policy, move || async move {
+///     let account_id = AccountId::new(0, 0, 1001);
+///
+///     let info = AccountInfoQuery::new().account_id(account_id).execute(client).await?;
+///     let balance = AccountBalanceQuery::new().account_id(account_id).execute(client).await?;
+///
+///     Ok((info, balance))
+/// })
+/// .await?;
+/// # let _ = (info, balance);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn retry<T, F, Fut>(policy: RetryPolicy, mut op: F) -> crate::Result<T>
+where
+    F: FnMut() -> Fut + Send,
+    Fut: Future<Output = crate::Result<T>> + Send,
+{
+    let mut backoff_builder = ExponentialBackoffBuilder::new();
+
+    backoff_builder
+        .with_initial_interval(policy.initial_backoff)
+        .with_max_interval(policy.max_backoff);
+
+    if let Some(max_elapsed) = policy.max_elapsed {
+        backoff_builder.with_max_elapsed_time(Some(max_elapsed));
+    }
+
+    let backoff = backoff_builder.build();
+
+    retry_with_backoff(
+        backoff,
+        Some(policy.max_attempts.get()),
+        || {
+            let fut = op();
+
+            async move {
+                fut.await.map_err(|e| match e.is_retryable() {
+                    true => Error::Transient(e),
+                    false => Error::Permanent(e),
+                })
+            }
+        },
+        |_, _| {},
+    )
+    .await
+}
+
 /// Durably retry some function according to the `backoff` until the backoff expires.
-pub(crate) async fn retry<B, Fn, O, Fut>(
+///
+/// `on_backoff` is called with the delay and the error before backing off after a failed attempt.
+///
+/// Fails with [`Error::NoHealthyNodes`](crate::Error::NoHealthyNodes) if no attempt failed with an actual error,
+/// because there never was a healthy node, or because `max_attempts` is `0`.
+pub(crate) async fn retry_with_backoff<B, Fn, O, Fut, OnBackoff>(
     mut backoff: B,
     max_attempts: Option<usize>,
     mut f: Fn,
+    mut on_backoff: OnBackoff,
 ) -> crate::Result<O>
 where
     B: backoff::backoff::Backoff + Send,
     Fn: FnMut() -> Fut + Send,
     Fut: Future<Output = Result<O>> + Send,
+    OnBackoff: FnMut(Duration, &crate::Error) + Send,
 {
     let mut last_error: Option<crate::Error> = None;
     let mut attempt_number = 0;
@@ -42,14 +222,23 @@ where
     while max_attempts.map_or(true, |it| attempt_number < it) {
         attempt_number += 1;
 
-        match f().await {
+        let failed = match f().await {
             Ok(it) => return Ok(it),
-            Err(Error::Transient(e)) => last_error = Some(e),
-            Err(Error::EmptyTransient) => {}
+            Err(Error::Transient(e)) => {
+                last_error = Some(e);
+                true
+            }
+            Err(Error::EmptyTransient) => false,
             Err(Error::Permanent(e)) => return Err(e),
-        }
+        };
 
         if let Some(duration) = backoff.next_backoff() {
+            let will_retry = max_attempts.map_or(true, |it| attempt_number < it);
+
+            if let Some(error) = last_error.as_ref().filter(|_| failed && will_retry) {
+                on_backoff(duration, error);
+            }
+
             let duration_ms = duration.as_millis();
             let err_suffix =
                 last_error.as_ref().map(|l| format!(" due to {l:?}")).unwrap_or_default();
@@ -58,11 +247,155 @@ where
             sleep(duration).await;
             log::warn!("Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
         } else {
-            let last_error = last_error.expect("timeout while network had no healthy nodes");
-            return Err(crate::Error::TimedOut(last_error.into()));
+            break;
         }
     }
 
-    let last_error = last_error.expect("timeout while network had no healthy nodes");
-    Err(crate::Error::TimedOut(last_error.into()))
+    match last_error {
+        Some(last_error) => Err(crate::Error::TimedOut(last_error.into())),
+        None => Err(crate::Error::NoHealthyNodes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use crate::{
+        retry,
+        Error,
+        RetryPolicy,
+        Status,
+    };
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+        max_attempts: match NonZeroUsize::new(3) {
+            Some(it) => it,
+            None => unreachable!(),
+        },
+        max_elapsed: None,
+    };
+
+    fn busy() -> Error {
+        Error::QueryNoPaymentPreCheckStatus { status: Status::Busy }
+    }
+
+    #[tokio::test]
+    async fn retries_until_success() {
+        let attempts = &AtomicUsize::new(0);
+
+        let res = retry(POLICY, move || async move {
+            match attempts.fetch_add(1, Ordering::Relaxed) {
+                0 => Err(busy()),
+                n => Ok(n),
+            }
+        })
+        .await;
+
+        assert_matches!(res, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn stops_at_max_attempts() {
+        let attempts = &AtomicUsize::new(0);
+
+        let res: crate::Result<()> = retry(POLICY, move || async move {
+            attempts.fetch_add(1, Ordering::Relaxed);
+            Err(busy())
+        })
+        .await;
+
+        assert_matches!(res, Err(Error::TimedOut(_)));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn returns_permanent_errors_immediately() {
+        let attempts = &AtomicUsize::new(0);
+
+        let res: crate::Result<()> = retry(POLICY, move || async move {
+            attempts.fetch_add(1, Ordering::Relaxed);
+            Err(Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidSignature })
+        })
+        .await;
+
+        assert_matches!(
+            res,
+            Err(Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidSignature })
+        );
+        assert_eq!(attempts.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn reports_backoff_before_every_retry() {
+        let attempts = &AtomicUsize::new(0);
+        let mut reported = Vec::new();
+
+        let res: crate::Result<()> = retry::retry_with_backoff(
+            backoff::backoff::Constant::new(Duration::from_millis(1)),
+            Some(3),
+            move || async move {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(retry::Error::Transient(busy()))
+            },
+            |delay, cause| reported.push((delay, cause.to_string())),
+        )
+        .await;
+
+        assert_matches!(res, Err(Error::TimedOut(_)));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+        // there's no retry after the last attempt.
+        assert_eq!(reported.len(), 2);
+        assert!(reported.iter().all(
+            |(delay, cause)| *delay == Duration::from_millis(1) && *cause == busy().to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn no_healthy_nodes() {
+        let attempts = &AtomicUsize::new(0);
+
+        let res: crate::Result<()> = retry::retry_with_backoff(
+            backoff::backoff::Constant::new(Duration::from_millis(1)),
+            Some(3),
+            move || async move {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(retry::Error::EmptyTransient)
+            },
+            |_, _| {},
+        )
+        .await;
+
+        assert_matches!(res, Err(Error::NoHealthyNodes));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn malformed_mirror_response_is_not_retryable() {
+        assert!(!Error::mirror_rest("response is missing `balances`").is_retryable());
+    }
+
+    #[tokio::test]
+    async fn no_attempts() {
+        let res: crate::Result<()> = retry::retry_with_backoff(
+            backoff::backoff::Constant::new(Duration::from_millis(1)),
+            Some(0),
+            || async { Ok(()) },
+            |_, _| {},
+        )
+        .await;
+
+        assert_matches!(res, Err(Error::NoHealthyNodes));
+    }
 }
@@ -0,0 +1,109 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Timers and tasks, which come from tokio natively and from the browser on `wasm32`.
+
+use std::future::Future;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+/// The error returned by [`timeout`] when the future didn't finish in time.
+#[derive(Debug)]
+pub(crate) struct Elapsed;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    // single threaded, so the timer never actually gets sent anywhere.
+    send_wrapper::SendWrapper::new(gloo_timers::future::sleep(duration)).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep_until(deadline: Instant) {
+    tokio::time::sleep_until(deadline.into()).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep_until(deadline: Instant) {
+    sleep(deadline.saturating_duration_since(Instant::now())).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn timeout<F: Future>(duration: Duration, fut: F) -> Result<F::Output, Elapsed> {
+    tokio::time::timeout(duration, fut).await.map_err(|_| Elapsed)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn timeout<F: Future>(duration: Duration, fut: F) -> Result<F::Output, Elapsed> {
+    use futures_util::future::{
+        select,
+        Either,
+    };
+
+    let fut = std::pin::pin!(fut);
+    let sleep = std::pin::pin!(sleep(duration));
+
+    match select(fut, sleep).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(((), _)) => Err(Elapsed),
+    }
+}
+
+/// A handle to a task started with [`spawn`], dropping it detaches the task.
+pub(crate) struct TaskHandle {
+    #[cfg(not(target_arch = "wasm32"))]
+    inner: tokio::task::JoinHandle<()>,
+    #[cfg(target_arch = "wasm32")]
+    inner: futures_util::future::AbortHandle,
+}
+
+impl TaskHandle {
+    pub(crate) fn abort(&self) {
+        self.inner.abort();
+    }
+}
+
+/// Runs `fut` in the background.
+///
+/// Natively this needs to be called from within a tokio runtime.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn<F: Future<Output = ()> + Send + 'static>(fut: F) -> TaskHandle {
+    TaskHandle { inner: tokio::task::spawn(fut) }
+}
+
+/// Runs `fut` in the background.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn spawn<F: Future<Output = ()> + 'static>(fut: F) -> TaskHandle {
+    let (fut, inner) = futures_util::future::abortable(fut);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let _ = fut.await;
+    });
+
+    TaskHandle { inner }
+}
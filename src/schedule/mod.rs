@@ -24,15 +24,28 @@ mod schedule_delete_transaction;
 mod schedule_id;
 mod schedule_info;
 mod schedule_info_query;
+mod schedule_sign_flow;
 mod schedule_sign_transaction;
+mod schedule_signature_progress;
+mod scheduled_transfer_flow;
 
 pub use schedule_create_transaction::ScheduleCreateTransaction;
 pub(crate) use schedule_create_transaction::ScheduleCreateTransactionData;
 pub use schedule_delete_transaction::ScheduleDeleteTransaction;
 pub(crate) use schedule_delete_transaction::ScheduleDeleteTransactionData;
 pub use schedule_id::ScheduleId;
-pub use schedule_info::ScheduleInfo;
+pub use schedule_info::{
+    ScheduleInfo,
+    ScheduleNotExecutedReason,
+};
 pub use schedule_info_query::ScheduleInfoQuery;
 pub(crate) use schedule_info_query::ScheduleInfoQueryData;
+pub use schedule_sign_flow::ScheduleSignFlow;
 pub use schedule_sign_transaction::ScheduleSignTransaction;
 pub(crate) use schedule_sign_transaction::ScheduleSignTransactionData;
+pub use schedule_signature_progress::ScheduleSignatureProgress;
+pub use scheduled_transfer_flow::{
+    ScheduledTransferFlow,
+    ScheduledTransferResponse,
+    ScheduledTransferStatus,
+};
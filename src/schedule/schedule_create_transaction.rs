@@ -20,8 +20,10 @@
 
 use hedera_proto::services;
 use hedera_proto::services::schedule_service_client::ScheduleServiceClient;
-use time::OffsetDateTime;
-use tonic::transport::Channel;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
 
 use super::schedulable_transaction_body::SchedulableTransactionBody;
 use crate::protobuf::{
@@ -36,11 +38,13 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
     Key,
+    LedgerId,
     Transaction,
     ValidateChecksums,
 };
@@ -71,6 +75,9 @@ pub struct ScheduleCreateTransactionData {
 }
 
 impl ScheduleCreateTransaction {
+    /// The furthest in the future that a schedule may expire, relative to when it's created.
+    pub const MAX_EXPIRATION: Duration = Duration::days(62);
+
     // note(sr): not sure what the right way to go about this is?
     // pub fn get_scheduled_transaction(&self) -> Option<&SchedulableTransactionBody> {
     //     self.data().scheduled_transaction.as_ref()
@@ -112,6 +119,14 @@ impl ScheduleCreateTransaction {
         self
     }
 
+    /// Sets the expiration time to `duration` from now.
+    ///
+    /// A `duration` of at most [`MAX_EXPIRATION`](Self::MAX_EXPIRATION) should be used,
+    /// less the time it takes for the transaction to reach consensus.
+    pub fn expiration_time_relative(&mut self, duration: Duration) -> &mut Self {
+        self.expiration_time(OffsetDateTime::now_utc() + duration)
+    }
+
     /// Returns `true` if the transaction will be evaluated at `expiration_time` instead
     /// of when all the required signatures are received, `false` otherwise.
     #[must_use]
@@ -121,6 +136,9 @@ impl ScheduleCreateTransaction {
 
     /// Sets if the transaction will be evaluated for execution at `expiration_time` instead
     /// of when all required signatures are received.
+    ///
+    /// If this is `true`, an [`expiration_time`](Self::expiration_time) must be set too,
+    /// otherwise freezing the transaction fails with [`Error::ScheduleExpirationTimeRequired`].
     pub fn wait_for_expiry(&mut self, wait: bool) -> &mut Self {
         self.data_mut().wait_for_expiry = wait;
         self
@@ -165,7 +183,14 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        match self.wait_for_expiry && self.expiration_time.is_none() {
+            true => Err(Error::ScheduleExpirationTimeRequired),
+            false => Ok(()),
+        }
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
@@ -242,9 +267,13 @@ impl FromProtobuf<services::ScheduleCreateTransactionBody> for ScheduleCreateTra
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
-    use time::OffsetDateTime;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
 
     use super::ScheduleCreateTransactionData;
     use crate::protobuf::{
@@ -261,6 +290,7 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Error,
         Hbar,
         PublicKey,
         ScheduleCreateTransaction,
@@ -489,6 +519,18 @@ mod tests {
         assert_eq!(tx.wait_for_expiry, false);
     }
 
+    #[test]
+    fn wait_for_expiry_without_expiration_time_fails() {
+        let mut tx = ScheduleCreateTransaction::new_for_tests();
+
+        tx.scheduled_transaction(scheduled_transaction()).wait_for_expiry(true);
+
+        assert_matches!(tx.freeze(), Err(Error::ScheduleExpirationTimeRequired));
+        assert!(!tx.is_frozen());
+
+        tx.expiration_time(EXPIRATION_TIME).freeze().unwrap();
+    }
+
     mod get_set {
         use super::*;
         #[test]
@@ -546,5 +588,18 @@ mod tests {
         fn wait_for_expiry_frozen_panics() {
             make_transaction().wait_for_expiry(true);
         }
+
+        #[test]
+        fn expiration_time_relative() {
+            let before = OffsetDateTime::now_utc();
+
+            let mut tx = ScheduleCreateTransaction::new();
+            tx.expiration_time_relative(Duration::hours(1));
+
+            let expiration_time = tx.get_expiration_time().unwrap();
+
+            assert!(expiration_time >= before + Duration::hours(1));
+            assert!(expiration_time <= OffsetDateTime::now_utc() + Duration::hours(1));
+        }
     }
 }
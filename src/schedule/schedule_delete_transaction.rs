@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::schedule_service_client::ScheduleServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -35,11 +34,15 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
     ScheduleId,
+    ScheduleInfoQuery,
     Transaction,
+    TransactionResponse,
     ValidateChecksums,
 };
 
@@ -65,6 +68,36 @@ impl ScheduleDeleteTransaction {
         self.data_mut().schedule_id = Some(id);
         self
     }
+
+    /// Check that the schedule can be deleted, then execute this transaction.
+    ///
+    /// The network only reports why a schedule can't be deleted in the receipt, after the transaction has been paid for;
+    /// this fetches the schedule's info first (a paid query) and fails early instead.
+    ///
+    /// # Errors
+    /// - [`Error::ScheduleNotDeletable`] if the schedule has no admin key, or was already deleted or executed,
+    ///   with the [`Status`](crate::Status) the network would have failed the transaction with.
+    /// - Any error that [`ScheduleInfoQuery::execute`] or [`execute`](Self::execute) can return.
+    pub async fn execute_checked(&mut self, client: &Client) -> crate::Result<TransactionResponse> {
+        if let Some(schedule_id) = self.get_schedule_id() {
+            let mut query = ScheduleInfoQuery::new();
+
+            query.schedule_id(schedule_id);
+
+            if let Some(node_account_ids) = self.get_node_account_ids() {
+                query.node_account_ids(node_account_ids.iter().copied());
+            }
+
+            if let Some(status) = query.execute(client).await?.delete_status() {
+                return Err(Error::ScheduleNotDeletable {
+                    schedule_id: Box::new(schedule_id),
+                    status,
+                });
+            }
+        }
+
+        self.execute(client).await
+    }
 }
 impl TransactionData for ScheduleDeleteTransactionData {}
 
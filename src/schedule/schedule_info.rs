@@ -19,9 +19,13 @@
  */
 
 use hedera_proto::services;
-use time::OffsetDateTime;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
 
 use super::schedulable_transaction_body::SchedulableTransactionBody;
+use super::schedule_signature_progress::remaining_signers;
 use crate::protobuf::ToProtobuf;
 use crate::transaction::TransactionBody;
 use crate::{
@@ -31,11 +35,35 @@ use crate::{
     Key,
     KeyList,
     LedgerId,
+    PublicKey,
     ScheduleId,
+    ScheduleSignatureProgress,
+    Status,
     Transaction,
     TransactionId,
 };
 
+/// How long after its expiration time a schedule may still be executed.
+///
+/// A schedule is evaluated by the first transaction handled after it expires, so this allows for some network delay.
+pub(crate) const SCHEDULE_EXPIRY_GRACE: Duration = Duration::seconds(10);
+
+/// Why a schedule didn't execute.
+///
+/// See [`Client::await_schedule_execution`](crate::Client::await_schedule_execution).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScheduleNotExecutedReason {
+    /// The schedule was deleted.
+    Deleted,
+
+    /// The schedule reached its expiration time without executing.
+    Expired,
+
+    /// The schedule may still execute, but the deadline to wait for it passed.
+    DeadlineElapsed,
+}
+
 // TODO: scheduled_transaction
 /// Response from [`ScheduleInfoQuery`][crate::ScheduleInfoQuery].
 #[derive(Debug, Clone)]
@@ -108,11 +136,78 @@ impl ScheduleInfo {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                validate_receipt: None,
+                bypass_duplicate_guard: false,
             },
             Vec::new(),
         ))
     }
 
+    /// Returns which keys of `required_key` have signed the schedule so far, and which are still missing.
+    ///
+    /// `required_key` is the key structure that must sign for the scheduled transaction to execute,
+    /// such as the key of the account being transferred from.
+    #[must_use]
+    pub fn signature_progress(&self, required_key: &Key) -> ScheduleSignatureProgress {
+        ScheduleSignatureProgress::new(required_key, &self.signatories)
+    }
+
+    /// Returns `true` if `key` has signed the schedule.
+    #[must_use]
+    pub fn has_signed(&self, key: &PublicKey) -> bool {
+        self.signatories.contains(&Key::Single(*key))
+    }
+
+    /// Estimates how many more keys must sign for `required_key` to be satisfied,
+    /// or returns `None` if signing alone can't satisfy it
+    /// (because of a contract key, an empty key list or a threshold of `0`).
+    ///
+    /// This is the fewest keys that could sign, taking threshold keys into account.
+    /// A key that appears in `required_key` more than once is counted for each appearance,
+    /// in which case this overestimates.
+    #[must_use]
+    pub fn remaining_signers(&self, required_key: &Key) -> Option<usize> {
+        remaining_signers(required_key, &self.signatories)
+    }
+
+    /// Returns the status a [`ScheduleDeleteTransaction`](crate::ScheduleDeleteTransaction) for this schedule would fail with,
+    /// or `None` if the schedule can be deleted.
+    ///
+    /// This is one of [`Status::ScheduleAlreadyDeleted`], [`Status::ScheduleAlreadyExecuted`], or [`Status::ScheduleIsImmutable`].
+    #[must_use]
+    pub fn delete_status(&self) -> Option<Status> {
+        if self.deleted_at.is_some() {
+            Some(Status::ScheduleAlreadyDeleted)
+        } else if self.executed_at.is_some() {
+            Some(Status::ScheduleAlreadyExecuted)
+        } else if self.admin_key.is_none() {
+            Some(Status::ScheduleIsImmutable)
+        } else {
+            None
+        }
+    }
+
+    /// Returns why the schedule can no longer execute as of `now`, or `None` if it has executed or may still execute.
+    pub(crate) fn not_executed_reason(
+        &self,
+        now: OffsetDateTime,
+    ) -> Option<ScheduleNotExecutedReason> {
+        if self.executed_at.is_some() {
+            return None;
+        }
+
+        if self.deleted_at.is_some() {
+            return Some(ScheduleNotExecutedReason::Deleted);
+        }
+
+        match self.expiration_time {
+            Some(expiration_time) if now > expiration_time + SCHEDULE_EXPIRY_GRACE => {
+                Some(ScheduleNotExecutedReason::Expired)
+            }
+            _ => None,
+        }
+    }
+
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -198,11 +293,18 @@ impl ToProtobuf for ScheduleInfo {
             ledger_id: self.ledger_id.to_bytes(),
             wait_for_expiry: self.wait_for_expiry,
 
-            // unimplemented fields
             scheduled_transaction_body: Some(
                 self.scheduled_transaction.to_scheduled_body_protobuf(),
             ),
-            data: None,
+            data: match (self.executed_at, self.deleted_at) {
+                (Some(executed_at), _) => {
+                    Some(services::schedule_info::Data::ExecutionTime(executed_at.to_protobuf()))
+                }
+                (None, Some(deleted_at)) => {
+                    Some(services::schedule_info::Data::DeletionTime(deleted_at.to_protobuf()))
+                }
+                (None, None) => None,
+            },
         }
     }
 }
@@ -226,8 +328,12 @@ mod tests {
     use crate::transaction::ToSchedulableTransactionDataProtobuf;
     use crate::{
         AccountDeleteTransaction,
+        Key,
+        KeyList,
         LedgerId,
         ScheduleInfo,
+        ScheduleNotExecutedReason,
+        Status,
         TransactionId,
     };
 
@@ -270,6 +376,71 @@ mod tests {
         ScheduleInfo { executed_at: None, deleted_at: Some(VALID_START), ..make_info() }
     }
 
+    #[test]
+    fn delete_status() {
+        assert_eq!(make_info().delete_status(), Some(Status::ScheduleAlreadyExecuted));
+        assert_eq!(make_deleted_info().delete_status(), Some(Status::ScheduleAlreadyDeleted));
+
+        let pending = ScheduleInfo { executed_at: None, ..make_info() };
+        assert_eq!(pending.delete_status(), None);
+
+        let immutable = ScheduleInfo { admin_key: None, ..pending };
+        assert_eq!(immutable.delete_status(), Some(Status::ScheduleIsImmutable));
+    }
+
+    #[test]
+    fn signers() {
+        let signer = unused_private_key().public_key();
+        let other = crate::PrivateKey::generate_ed25519().public_key();
+
+        let info = make_info();
+
+        assert!(info.has_signed(&signer));
+        assert!(!info.has_signed(&other));
+
+        let required = Key::KeyList(KeyList::from([signer, other]));
+
+        assert_eq!(info.remaining_signers(&required), Some(1));
+        assert_eq!(info.remaining_signers(&signer.into()), Some(0));
+    }
+
+    #[test]
+    fn signers_empty_threshold_key() {
+        let signer = unused_private_key().public_key();
+
+        let info = make_info();
+
+        let mut required = KeyList::with_threshold(1);
+        required.push(Key::KeyList(KeyList::new()));
+        let required = Key::KeyList(required);
+
+        assert_eq!(info.remaining_signers(&required), None);
+        assert!(!info.signature_progress(&required).is_satisfied);
+
+        let zero = Key::KeyList(KeyList { keys: vec![signer.into()], threshold: Some(0) });
+
+        assert_eq!(info.remaining_signers(&zero), None);
+        assert!(!info.signature_progress(&zero).is_satisfied);
+    }
+
+    #[test]
+    fn not_executed_reason() {
+        let later = VALID_START + time::Duration::minutes(1);
+
+        assert_eq!(make_info().not_executed_reason(later), None);
+        assert_eq!(
+            make_deleted_info().not_executed_reason(VALID_START),
+            Some(ScheduleNotExecutedReason::Deleted)
+        );
+
+        let pending = ScheduleInfo { executed_at: None, ..make_info() };
+        assert_eq!(pending.not_executed_reason(VALID_START), None);
+        assert_eq!(pending.not_executed_reason(later), Some(ScheduleNotExecutedReason::Expired));
+
+        let no_expiry = ScheduleInfo { expiration_time: None, ..pending };
+        assert_eq!(no_expiry.not_executed_reason(later), None);
+    }
+
     #[test]
     fn serialize() {
         expect![[r#"
@@ -449,7 +620,14 @@ mod tests {
                     1,
                 ],
                 wait_for_expiry: true,
-                data: None,
+                data: Some(
+                    ExecutionTime(
+                        Timestamp {
+                            seconds: 1554158542,
+                            nanos: 0,
+                        },
+                    ),
+                ),
             }
         "#]]
         .assert_debug_eq(&make_info().to_protobuf());
@@ -634,9 +812,25 @@ mod tests {
                     1,
                 ],
                 wait_for_expiry: true,
-                data: None,
+                data: Some(
+                    DeletionTime(
+                        Timestamp {
+                            seconds: 1554158542,
+                            nanos: 0,
+                        },
+                    ),
+                ),
             }
         "#]]
         .assert_debug_eq(&make_deleted_info().to_protobuf());
     }
+
+    #[test]
+    fn to_from_bytes() {
+        for a in [make_info(), make_deleted_info()] {
+            let b = ScheduleInfo::from_bytes(&a.to_bytes()).unwrap();
+
+            assert_eq!(a.to_protobuf(), b.to_protobuf());
+        }
+    }
 }
@@ -20,16 +20,17 @@
 
 use hedera_proto::services;
 use hedera_proto::services::schedule_service_client::ScheduleServiceClient;
-use tonic::transport::Channel;
 
 use crate::query::{
     AnyQueryData,
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ScheduleId,
     ScheduleInfo,
@@ -79,6 +80,12 @@ impl ToQueryProtobuf for ScheduleInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ScheduleGetInfoQuery> for ScheduleInfoQueryData {
+    fn from_protobuf(pb: services::ScheduleGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { schedule_id: Option::from_protobuf(pb.schedule_id)? })
+    }
+}
+
 impl QueryExecute for ScheduleInfoQueryData {
     type Response = ScheduleInfo;
 
@@ -0,0 +1,208 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashSet;
+
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Key,
+    PrivateKey,
+    PublicKey,
+    ScheduleId,
+    ScheduleInfo,
+    ScheduleInfoQuery,
+    ScheduleSignTransaction,
+};
+
+/// Collect signatures for a schedule until it can execute.
+///
+/// The operation of this flow is as follows:
+/// 1. Fetch the [`ScheduleInfo`] for the schedule.
+/// 2. If the schedule has executed or been deleted, return its info.
+/// 3. Compare the schedule's signatories against the required key structure,
+///    and find the locally available signers whose keys are still missing.
+/// 4. If there are none, return the schedule's info, otherwise, submit a [`ScheduleSignTransaction`]
+///    signed by those signers, wait for its receipt, and go back to step 1.
+#[derive(Default, Debug)]
+pub struct ScheduleSignFlow {
+    schedule_id: Option<ScheduleId>,
+    required_key: Option<Key>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+impl ScheduleSignFlow {
+    /// Create a new `ScheduleSignFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the schedule to collect signatures for.
+    #[must_use]
+    pub fn get_schedule_id(&self) -> Option<ScheduleId> {
+        self.schedule_id
+    }
+
+    /// Sets the schedule to collect signatures for.
+    pub fn schedule_id(&mut self, id: ScheduleId) -> &mut Self {
+        self.schedule_id = Some(id);
+        self
+    }
+
+    /// Returns the key structure that must be satisfied for the schedule to execute.
+    #[must_use]
+    pub fn get_required_key(&self) -> Option<&Key> {
+        self.required_key.as_ref()
+    }
+
+    /// Sets the key structure that must be satisfied for the schedule to execute.
+    ///
+    /// This is usually the key of the account(s) the scheduled transaction acts on.
+    ///
+    /// If this isn't set, every locally available signer that hasn't signed the schedule yet will sign it.
+    pub fn required_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.required_key = Some(key.into());
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a locally available signer that may be used to sign the schedule.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a locally available signer that may be used to sign the schedule.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
+        self.signers.push(signer);
+
+        self
+    }
+
+    /// Fetches the schedule's info without signing it.
+    ///
+    /// Use [`ScheduleInfo::signature_progress`] to find out which keys have signed.
+    ///
+    /// # Errors
+    /// - If the [`ScheduleInfoQuery`] fails.
+    /// - [`Error::MissingField`] if `schedule_id` wasn't set.
+    pub async fn get_schedule_info(&self, client: &Client) -> crate::Result<ScheduleInfo> {
+        let schedule_id = self.schedule_id.ok_or(Error::MissingField { field: "schedule_id" })?;
+
+        let mut query = ScheduleInfoQuery::new();
+
+        query.schedule_id(schedule_id);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            query.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        query.execute(client).await
+    }
+
+    /// Signs the schedule with the available signers until it can execute, or until no signer can help.
+    ///
+    /// Returns the last fetched [`ScheduleInfo`].
+    ///
+    /// # Errors
+    /// - If any query or transaction (or their receipts) fail.
+    /// - [`Error::MissingField`] if `schedule_id` wasn't set.
+    pub async fn execute(&self, client: &Client) -> crate::Result<ScheduleInfo> {
+        let schedule_id = self.schedule_id.ok_or(Error::MissingField { field: "schedule_id" })?;
+
+        // keys that we've already tried to sign with, so we never submit the same signature twice.
+        let mut attempted: HashSet<PublicKey> = HashSet::new();
+
+        loop {
+            let info = self.get_schedule_info(client).await?;
+
+            if info.executed_at.is_some() || info.deleted_at.is_some() {
+                return Ok(info);
+            }
+
+            let missing_keys = match &self.required_key {
+                Some(required_key) => info.signature_progress(required_key).missing_keys,
+                None => self.signers.iter().map(AnySigner::public_key).collect(),
+            };
+
+            let signers: Vec<_> = self
+                .signers
+                .iter()
+                .filter(|it| {
+                    let public_key = it.public_key();
+
+                    missing_keys.contains(&public_key)
+                        && !info.signatories.contains(&Key::Single(public_key))
+                        && !attempted.contains(&public_key)
+                })
+                .cloned()
+                .collect();
+
+            if signers.is_empty() {
+                return Ok(info);
+            }
+
+            let mut transaction = ScheduleSignTransaction::new();
+
+            transaction.schedule_id(schedule_id);
+
+            if let Some(node_account_ids) = &self.node_account_ids {
+                transaction.node_account_ids(node_account_ids.iter().copied());
+            }
+
+            for signer in signers {
+                attempted.insert(signer.public_key());
+                transaction.sign_signer(signer);
+            }
+
+            transaction.execute(client).await?.get_receipt(client).await?;
+        }
+    }
+}
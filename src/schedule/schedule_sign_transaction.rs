@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::schedule_service_client::ScheduleServiceClient;
-use tonic::transport::Channel;
 
 use crate::protobuf::{
     FromProtobuf,
@@ -33,6 +32,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
@@ -71,7 +71,7 @@ impl TransactionExecute for ScheduleSignTransactionData {
         channel: Channel,
         request: services::Transaction,
     ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
-        Box::pin(async { ScheduleServiceClient::new(channel).delete_schedule(request).await })
+        Box::pin(async { ScheduleServiceClient::new(channel).sign_schedule(request).await })
     }
 }
 
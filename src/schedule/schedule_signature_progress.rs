@@ -0,0 +1,238 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::{
+    Key,
+    KeyList,
+    PublicKey,
+};
+
+/// How far along a schedule is in collecting the signatures required by some key structure.
+///
+/// See [`ScheduleInfo::signature_progress`](crate::ScheduleInfo::signature_progress).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScheduleSignatureProgress {
+    /// Keys in the required key structure that have already signed the schedule.
+    pub signed_keys: Vec<PublicKey>,
+
+    /// Keys in the required key structure that have not yet signed the schedule.
+    ///
+    /// Note that when the structure contains threshold keys, not every missing key needs to sign.
+    pub missing_keys: Vec<PublicKey>,
+
+    /// `true` if the signatures collected so far satisfy the required key structure.
+    pub is_satisfied: bool,
+}
+
+impl ScheduleSignatureProgress {
+    pub(crate) fn new(required_key: &Key, signatories: &KeyList) -> Self {
+        let mut progress = Self {
+            signed_keys: Vec::new(),
+            missing_keys: Vec::new(),
+            is_satisfied: required_key.is_satisfied_by(&|key| has_signed(key, signatories)),
+        };
+
+        progress.collect_leaves(required_key, signatories);
+
+        progress
+    }
+
+    fn collect_leaves(&mut self, key: &Key, signatories: &KeyList) {
+        match key {
+            Key::Single(public_key) => {
+                let list = match signatories.contains(key) {
+                    true => &mut self.signed_keys,
+                    false => &mut self.missing_keys,
+                };
+
+                if !list.contains(public_key) {
+                    list.push(*public_key);
+                }
+            }
+
+            Key::KeyList(keys) => {
+                for key in &keys.keys {
+                    self.collect_leaves(key, signatories);
+                }
+            }
+
+            // contracts don't sign with keys, there's nothing to report.
+            Key::ContractId(_) | Key::DelegateContractId(_) => {}
+        }
+    }
+}
+
+fn has_signed(key: &PublicKey, signatories: &KeyList) -> bool {
+    signatories.contains(&Key::Single(*key))
+}
+
+/// Returns the fewest additional keys that must sign for `key` to be satisfied by `signatories`,
+/// or `None` if signing can't satisfy it.
+///
+/// This follows [`Key::is_satisfied_by`], so contract keys, empty key lists and thresholds of `0` are never satisfied.
+/// Keys that appear more than once in `key` are counted once per appearance, so this may overestimate.
+pub(crate) fn remaining_signers(key: &Key, signatories: &KeyList) -> Option<usize> {
+    match key {
+        Key::Single(it) => Some(usize::from(!has_signed(it, signatories))),
+        Key::ContractId(_) | Key::DelegateContractId(_) => None,
+        Key::KeyList(list) if list.keys.is_empty() || list.threshold == Some(0) => None,
+        Key::KeyList(list) => {
+            let required = list.threshold.map_or(list.keys.len(), |it| it as usize);
+
+            let mut remaining: Vec<_> =
+                list.keys.iter().filter_map(|it| remaining_signers(it, signatories)).collect();
+
+            if remaining.len() < required {
+                return None;
+            }
+
+            remaining.sort_unstable();
+
+            Some(remaining[..required].iter().sum())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        remaining_signers,
+        ScheduleSignatureProgress,
+    };
+    use crate::{
+        Key,
+        KeyList,
+        PrivateKey,
+        PublicKey,
+    };
+
+    fn keys() -> [PublicKey; 3] {
+        [
+            PrivateKey::generate_ed25519().public_key(),
+            PrivateKey::generate_ed25519().public_key(),
+            PrivateKey::generate_ecdsa().public_key(),
+        ]
+    }
+
+    #[test]
+    fn single_key() {
+        let [key, ..] = keys();
+
+        let progress = ScheduleSignatureProgress::new(&key.into(), &KeyList::new());
+
+        assert!(!progress.is_satisfied);
+        assert_eq!(progress.missing_keys, [key]);
+
+        let progress = ScheduleSignatureProgress::new(&key.into(), &KeyList::from([key]));
+
+        assert!(progress.is_satisfied);
+        assert_eq!(progress.signed_keys, [key]);
+        assert!(progress.missing_keys.is_empty());
+    }
+
+    #[test]
+    fn key_list_requires_all() {
+        let [a, b, c] = keys();
+        let required = Key::KeyList(KeyList::from([a, b, c]));
+
+        let progress = ScheduleSignatureProgress::new(&required, &KeyList::from([a, b]));
+
+        assert!(!progress.is_satisfied);
+        assert_eq!(progress.signed_keys, [a, b]);
+        assert_eq!(progress.missing_keys, [c]);
+    }
+
+    #[test]
+    fn threshold_key() {
+        let [a, b, c] = keys();
+        let required =
+            Key::KeyList(KeyList { keys: vec![a.into(), b.into(), c.into()], threshold: Some(2) });
+
+        let progress = ScheduleSignatureProgress::new(&required, &KeyList::from([c]));
+        assert!(!progress.is_satisfied);
+
+        let progress = ScheduleSignatureProgress::new(&required, &KeyList::from([a, c]));
+        assert!(progress.is_satisfied);
+        assert_eq!(progress.missing_keys, [b]);
+    }
+
+    #[test]
+    fn nested_key_list() {
+        let [a, b, c] = keys();
+        let inner = KeyList { keys: vec![b.into(), c.into()], threshold: Some(1) };
+        let required = Key::KeyList(KeyList::from(vec![a.into(), Key::KeyList(inner)]));
+
+        assert!(!ScheduleSignatureProgress::new(&required, &KeyList::from([b, c])).is_satisfied);
+        assert!(ScheduleSignatureProgress::new(&required, &KeyList::from([a, b])).is_satisfied);
+    }
+
+    #[test]
+    fn remaining() {
+        let [a, b, c] = keys();
+        let threshold =
+            Key::KeyList(KeyList { keys: vec![a.into(), b.into(), c.into()], threshold: Some(2) });
+
+        assert_eq!(remaining_signers(&threshold, &KeyList::new()), Some(2));
+        assert_eq!(remaining_signers(&threshold, &KeyList::from([c])), Some(1));
+        assert_eq!(remaining_signers(&threshold, &KeyList::from([a, c])), Some(0));
+
+        let inner = KeyList { keys: vec![b.into(), c.into()], threshold: Some(1) };
+        let nested = Key::KeyList(KeyList::from(vec![a.into(), Key::KeyList(inner)]));
+
+        assert_eq!(remaining_signers(&nested, &KeyList::new()), Some(2));
+        assert_eq!(remaining_signers(&nested, &KeyList::from([c])), Some(1));
+    }
+
+    #[test]
+    fn remaining_contract_key() {
+        let [a, ..] = keys();
+        let contract_id = crate::ContractId::new(0, 0, 1001);
+
+        let required = Key::KeyList(KeyList::from(vec![a.into(), Key::ContractId(contract_id)]));
+
+        assert_eq!(remaining_signers(&required, &KeyList::new()), None);
+        assert_eq!(
+            remaining_signers(&required, &KeyList::from(vec![Key::ContractId(contract_id)])),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_key_list() {
+        let [a, ..] = keys();
+        let required = Key::KeyList(KeyList::new());
+
+        let progress = ScheduleSignatureProgress::new(&required, &KeyList::from([a]));
+
+        assert!(!progress.is_satisfied);
+        assert_eq!(remaining_signers(&required, &KeyList::from([a])), None);
+    }
+
+    #[test]
+    fn threshold_zero() {
+        let [a, b, _] = keys();
+        let required = Key::KeyList(KeyList { keys: vec![a.into(), b.into()], threshold: Some(0) });
+
+        let progress = ScheduleSignatureProgress::new(&required, &KeyList::from([a, b]));
+
+        assert!(!progress.is_satisfied);
+        assert_eq!(remaining_signers(&required, &KeyList::from([a, b])), None);
+    }
+}
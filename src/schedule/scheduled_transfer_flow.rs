@@ -0,0 +1,498 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Key,
+    KeyList,
+    PrivateKey,
+    PublicKey,
+    ScheduleCreateTransaction,
+    ScheduleId,
+    ScheduleInfo,
+    ScheduleNotExecutedReason,
+    ScheduleSignFlow,
+    TransactionId,
+    TransferTransaction,
+};
+
+/// Schedule a transfer that requires signatures from several key holders, and sign it with the locally available keys.
+///
+/// The operation of this flow is as follows:
+/// 1. Submit a [`ScheduleCreateTransaction`] for the transfer, and wait for its receipt.
+/// 2. Sign the schedule with the locally available signers that the [`required_key`](Self::required_key) is missing,
+///    as with a [`ScheduleSignFlow`].
+/// 3. Return where the schedule stands as a [`ScheduledTransferStatus`].
+///
+/// The remaining key holders can then sign the schedule with their own [`ScheduleSignFlow`].
+#[derive(Default, Debug)]
+pub struct ScheduledTransferFlow {
+    transfer: Option<TransferTransaction>,
+    required_key: Option<Key>,
+    expiration: Option<Duration>,
+    wait_for_expiry: bool,
+    schedule_memo: Option<String>,
+    admin_key: Option<Key>,
+    payer_account_id: Option<AccountId>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+impl ScheduledTransferFlow {
+    /// Create a new `ScheduledTransferFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the transfer to schedule.
+    #[must_use]
+    pub fn get_transfer(&self) -> Option<&TransferTransaction> {
+        self.transfer.as_ref()
+    }
+
+    /// Sets the transfer to schedule.
+    pub fn transfer(&mut self, transfer: TransferTransaction) -> &mut Self {
+        self.transfer = Some(transfer);
+        self
+    }
+
+    /// Returns the key structure that must be satisfied for the transfer to execute.
+    #[must_use]
+    pub fn get_required_key(&self) -> Option<&Key> {
+        self.required_key.as_ref()
+    }
+
+    /// Sets the key structure that must be satisfied for the transfer to execute.
+    ///
+    /// This is usually the key of the account being transferred from.
+    ///
+    /// If this isn't set, every locally available signer will sign the schedule,
+    /// and the missing keys can't be reported.
+    pub fn required_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.required_key = Some(key.into());
+        self
+    }
+
+    /// Sets the required key to `threshold` of `keys`, as in an N-of-M multi-sig account.
+    pub fn required_signatures(
+        &mut self,
+        threshold: u32,
+        keys: impl IntoIterator<Item = PublicKey>,
+    ) -> &mut Self {
+        let mut key_list = KeyList::with_threshold(threshold);
+        key_list.keys = keys.into_iter().map(Key::Single).collect();
+
+        self.required_key(key_list)
+    }
+
+    /// Returns how long after creation the schedule expires.
+    #[must_use]
+    pub fn get_expiration(&self) -> Option<Duration> {
+        self.expiration
+    }
+
+    /// Sets how long after creation the schedule expires,
+    /// see [`ScheduleCreateTransaction::expiration_time_relative`].
+    pub fn expiration(&mut self, expiration: Duration) -> &mut Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Returns `true` if the transfer will execute at the schedule's expiration time
+    /// instead of when all the required signatures are received.
+    #[must_use]
+    pub fn get_wait_for_expiry(&self) -> bool {
+        self.wait_for_expiry
+    }
+
+    /// Sets whether the transfer will execute at the schedule's expiration time
+    /// instead of when all the required signatures are received.
+    ///
+    /// If this is `true`, an [`expiration`](Self::expiration) must be set too.
+    pub fn wait_for_expiry(&mut self, wait: bool) -> &mut Self {
+        self.wait_for_expiry = wait;
+        self
+    }
+
+    /// Returns the memo of the schedule.
+    #[must_use]
+    pub fn get_schedule_memo(&self) -> Option<&str> {
+        self.schedule_memo.as_deref()
+    }
+
+    /// Sets the memo of the schedule.
+    pub fn schedule_memo(&mut self, memo: impl Into<String>) -> &mut Self {
+        self.schedule_memo = Some(memo.into());
+        self
+    }
+
+    /// Returns the key that can delete the schedule.
+    #[must_use]
+    pub fn get_admin_key(&self) -> Option<&Key> {
+        self.admin_key.as_ref()
+    }
+
+    /// Sets the key that can delete the schedule.
+    pub fn admin_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.admin_key = Some(key.into());
+        self
+    }
+
+    /// Returns the account that pays for the transfer when it executes.
+    #[must_use]
+    pub fn get_payer_account_id(&self) -> Option<AccountId> {
+        self.payer_account_id
+    }
+
+    /// Sets the account that pays for the transfer when it executes.
+    ///
+    /// By default the account that creates the schedule pays.
+    pub fn payer_account_id(&mut self, id: AccountId) -> &mut Self {
+        self.payer_account_id = Some(id);
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a locally available signer that may be used to sign the schedule.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a locally available signer that may be used to sign the schedule.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Schedules the transfer, signs it with the available signers, and returns where it stands.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `transfer` wasn't set.
+    /// - [`Error::ScheduleExpirationTimeRequired`] if `wait_for_expiry` is set without an `expiration`.
+    /// - If any query or transaction (or their receipts) fail.
+    pub async fn execute(&self, client: &Client) -> crate::Result<ScheduledTransferResponse> {
+        let transfer = self.transfer.clone().ok_or(Error::MissingField { field: "transfer" })?;
+
+        let mut transaction = ScheduleCreateTransaction::new();
+
+        transaction.scheduled_transaction(transfer).wait_for_expiry(self.wait_for_expiry);
+
+        if let Some(expiration) = self.expiration {
+            transaction.expiration_time_relative(expiration);
+        }
+
+        if let Some(memo) = &self.schedule_memo {
+            transaction.schedule_memo(memo.clone());
+        }
+
+        if let Some(admin_key) = &self.admin_key {
+            transaction.admin_key(admin_key.clone());
+        }
+
+        if let Some(payer_account_id) = self.payer_account_id {
+            transaction.payer_account_id(payer_account_id);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        let receipt = transaction.execute(client).await?.get_receipt(client).await?;
+
+        let schedule_id =
+            receipt.schedule_id.expect("Creating a schedule means there's a schedule ID");
+
+        let mut flow = ScheduleSignFlow::new();
+
+        flow.schedule_id(schedule_id);
+
+        if let Some(required_key) = &self.required_key {
+            flow.required_key(required_key.clone());
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            flow.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        for signer in &self.signers {
+            flow.sign_signer(signer.clone());
+        }
+
+        let info = flow.execute(client).await?;
+
+        let status = ScheduledTransferStatus::new(
+            &info,
+            self.required_key.as_ref(),
+            OffsetDateTime::now_utc(),
+        );
+
+        Ok(ScheduledTransferResponse {
+            schedule_id,
+            scheduled_transaction_id: info.scheduled_transaction_id,
+            status,
+            info,
+        })
+    }
+}
+
+/// Response from [`ScheduledTransferFlow::execute`].
+#[derive(Debug, Clone)]
+pub struct ScheduledTransferResponse {
+    /// The ID of the created schedule.
+    pub schedule_id: ScheduleId,
+
+    /// The transaction ID that the transfer executes (or executed) with.
+    pub scheduled_transaction_id: TransactionId,
+
+    /// Where the schedule stands.
+    pub status: ScheduledTransferStatus,
+
+    /// The schedule's info, as last fetched.
+    pub info: ScheduleInfo,
+}
+
+/// Where a scheduled transfer stands.
+///
+/// See [`ScheduledTransferFlow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScheduledTransferStatus {
+    /// The transfer executed.
+    ///
+    /// Executing doesn't mean that it succeeded, use a receipt query
+    /// for the [`scheduled_transaction_id`](ScheduledTransferResponse::scheduled_transaction_id) to find out.
+    Executed {
+        /// When the transfer executed.
+        executed_at: OffsetDateTime,
+    },
+
+    /// The transfer is waiting for more signatures.
+    AwaitingSignatures {
+        /// Keys of the required key that haven't signed yet.
+        ///
+        /// Not every missing key needs to sign when the required key contains threshold keys.
+        /// This is empty if the flow has no [`required_key`](ScheduledTransferFlow::required_key),
+        /// or if the required key is satisfied and some other key must sign too.
+        missing_keys: Vec<PublicKey>,
+    },
+
+    /// The transfer has the signatures it requires, and will be executed at the schedule's expiration time.
+    AwaitingExpiry {
+        /// When the schedule expires.
+        expiration_time: Option<OffsetDateTime>,
+    },
+
+    /// The transfer can no longer execute.
+    NotExecuted(ScheduleNotExecutedReason),
+}
+
+impl ScheduledTransferStatus {
+    pub(crate) fn new(
+        info: &ScheduleInfo,
+        required_key: Option<&Key>,
+        now: OffsetDateTime,
+    ) -> Self {
+        if let Some(executed_at) = info.executed_at {
+            return Self::Executed { executed_at };
+        }
+
+        if let Some(reason) = info.not_executed_reason(now) {
+            return Self::NotExecuted(reason);
+        }
+
+        let progress = required_key.map(|it| info.signature_progress(it));
+
+        match progress {
+            Some(progress) if progress.is_satisfied && info.wait_for_expiry => {
+                Self::AwaitingExpiry { expiration_time: info.expiration_time }
+            }
+            Some(progress) => Self::AwaitingSignatures { missing_keys: progress.missing_keys },
+            None => Self::AwaitingSignatures { missing_keys: Vec::new() },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+    use time::Duration;
+
+    use super::{
+        ScheduledTransferFlow,
+        ScheduledTransferStatus,
+    };
+    use crate::protobuf::ToProtobuf;
+    use crate::transaction::test_helpers::VALID_START;
+    use crate::transaction::ToSchedulableTransactionDataProtobuf;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        FromProtobuf,
+        Hbar,
+        Key,
+        KeyList,
+        PrivateKey,
+        PublicKey,
+        ScheduleInfo,
+        ScheduleNotExecutedReason,
+        TransactionId,
+        TransferTransaction,
+    };
+
+    fn make_info(signers: &[PublicKey], wait_for_expiry: bool) -> ScheduleInfo {
+        let mut transfer = TransferTransaction::new();
+        transfer
+            .hbar_transfer(AccountId::new(0, 0, 1001), -Hbar::new(10))
+            .hbar_transfer(AccountId::new(0, 0, 1002), Hbar::new(10));
+
+        ScheduleInfo::from_protobuf(services::ScheduleInfo {
+            schedule_id: Some("0.0.5005".parse::<crate::ScheduleId>().unwrap().to_protobuf()),
+            creator_account_id: Some(AccountId::new(0, 0, 2).to_protobuf()),
+            scheduled_transaction_id: Some(
+                TransactionId::generate(AccountId::new(0, 0, 2)).to_protobuf(),
+            ),
+            scheduled_transaction_body: Some(services::SchedulableTransactionBody {
+                data: Some(transfer.data().to_schedulable_transaction_data_protobuf()),
+                ..Default::default()
+            }),
+            signers: Some(services::KeyList {
+                keys: signers.iter().map(|it| Key::Single(*it).to_protobuf()).collect(),
+            }),
+            expiration_time: Some(VALID_START.to_protobuf()),
+            wait_for_expiry,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn make_keys() -> (Vec<PublicKey>, Key) {
+        let keys: Vec<_> = (0..3).map(|_| PrivateKey::generate_ed25519().public_key()).collect();
+
+        let mut required_key = KeyList::with_threshold(2);
+        required_key.keys = keys.iter().copied().map(Key::Single).collect();
+
+        (keys, required_key.into())
+    }
+
+    #[test]
+    fn awaiting_signatures() {
+        let (keys, required_key) = make_keys();
+        let info = make_info(&keys[..1], false);
+
+        assert_eq!(
+            ScheduledTransferStatus::new(&info, Some(&required_key), VALID_START),
+            ScheduledTransferStatus::AwaitingSignatures { missing_keys: keys[1..].to_vec() }
+        );
+
+        assert_eq!(
+            ScheduledTransferStatus::new(&info, None, VALID_START),
+            ScheduledTransferStatus::AwaitingSignatures { missing_keys: Vec::new() }
+        );
+    }
+
+    #[test]
+    fn awaiting_expiry() {
+        let (keys, required_key) = make_keys();
+        let info = make_info(&keys[..2], true);
+
+        assert_eq!(
+            ScheduledTransferStatus::new(&info, Some(&required_key), VALID_START),
+            ScheduledTransferStatus::AwaitingExpiry { expiration_time: Some(VALID_START) }
+        );
+    }
+
+    #[test]
+    fn executed() {
+        let (keys, required_key) = make_keys();
+        let mut info = make_info(&keys[..2], false);
+        info.executed_at = Some(VALID_START);
+
+        assert_eq!(
+            ScheduledTransferStatus::new(&info, Some(&required_key), VALID_START),
+            ScheduledTransferStatus::Executed { executed_at: VALID_START }
+        );
+    }
+
+    #[test]
+    fn not_executed() {
+        let (keys, required_key) = make_keys();
+
+        let mut deleted = make_info(&keys[..1], false);
+        deleted.deleted_at = Some(VALID_START);
+
+        assert_eq!(
+            ScheduledTransferStatus::new(&deleted, Some(&required_key), VALID_START),
+            ScheduledTransferStatus::NotExecuted(ScheduleNotExecutedReason::Deleted)
+        );
+
+        let pending = make_info(&keys[..1], false);
+
+        assert_eq!(
+            ScheduledTransferStatus::new(
+                &pending,
+                Some(&required_key),
+                VALID_START + Duration::minutes(1)
+            ),
+            ScheduledTransferStatus::NotExecuted(ScheduleNotExecutedReason::Expired)
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_transfer() {
+        let client = Client::for_testnet();
+
+        let res = ScheduledTransferFlow::new().execute(&client).await;
+
+        assert_matches!(res, Err(Error::MissingField { field: "transfer" }));
+    }
+}
@@ -0,0 +1,279 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! `serde` support for the SDK's public response types.
+//!
+//! Entity IDs (and other types with a canonical string form) are serialized as that string,
+//! [`Hbar`] as tinybars, bytes as hex, and timestamps as RFC 3339.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serializer,
+};
+
+use crate::{
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EvmAddress,
+    FileId,
+    Hbar,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    TokenId,
+    TopicId,
+    TransactionId,
+};
+
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+}
+
+/// Implements `Serialize` and `Deserialize` in terms of `Display` and `FromStr`.
+macro_rules! impl_serde_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl serde::Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_str!(
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    FileId,
+    TokenId,
+    TopicId,
+    ScheduleId,
+    NftId,
+    EvmAddress,
+    TransactionId,
+    PublicKey,
+    LedgerId,
+);
+
+// tinybars, because it's exact (`Display` switches units).
+impl serde::Serialize for Hbar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.to_tinybars())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Hbar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Hbar::from_tinybars)
+    }
+}
+
+/// `#[serde(with = "...")]` for [`Status`](crate::Status), as its protobuf name (`"SUCCESS"`).
+pub(crate) mod status {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    use crate::Status;
+
+    // allowed: the signature is decided by serde.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn serialize<S: Serializer>(
+        status: &Status,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(status.as_str_name())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Status, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Status::from_str_name(&name)
+            .ok_or_else(|| D::Error::custom(format_args!("unknown status `{name}`")))
+    }
+}
+
+/// `#[serde(with = "...")]` for bytes, as a hex string.
+pub(crate) mod hex {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        hex::decode(String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+
+    /// [`hex`](self), but for `Option<Vec<u8>>`.
+    pub(crate) mod option {
+        use serde::{
+            Deserialize,
+            Deserializer,
+            Serialize,
+            Serializer,
+        };
+
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+        #[serde(transparent)]
+        struct Hex(#[serde(with = "super")] Vec<u8>);
+
+        pub(crate) fn serialize<S: Serializer>(
+            bytes: &Option<Vec<u8>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            bytes.clone().map(Hex).serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<u8>>, D::Error> {
+            Ok(Option::<Hex>::deserialize(deserializer)?.map(|it| it.0))
+        }
+    }
+
+    /// [`hex`](self), but for `Vec<Vec<u8>>`.
+    pub(crate) mod vec {
+        use serde::ser::SerializeSeq;
+        use serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+        };
+
+        #[derive(serde_derive::Deserialize)]
+        #[serde(transparent)]
+        struct Hex(#[serde(with = "super")] Vec<u8>);
+
+        pub(crate) fn serialize<S: Serializer>(
+            items: &[Vec<u8>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(items.len()))?;
+
+            for item in items {
+                seq.serialize_element(&hex::encode(item))?;
+            }
+
+            seq.end()
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Vec<u8>>, D::Error> {
+            Ok(Vec::<Hex>::deserialize(deserializer)?.into_iter().map(|it| it.0).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        AccountId,
+        Hbar,
+        Key,
+        PrivateKey,
+        Status,
+        TokenId,
+        TransactionId,
+    };
+
+    #[test]
+    fn entity_id_is_a_string() {
+        let id = AccountId::new(0, 0, 1001);
+
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""0.0.1001""#);
+        assert_eq!(serde_json::from_str::<AccountId>(r#""0.0.1001""#).unwrap(), id);
+        assert!(serde_json::from_str::<TokenId>(r#""0.0.x""#).is_err());
+    }
+
+    #[test]
+    fn transaction_id_round_trip() {
+        let id = TransactionId::from_str("0.0.1001@1640995200.123").unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, r#""0.0.1001@1640995200.123""#);
+        assert_eq!(serde_json::from_str::<TransactionId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn hbar_is_tinybars() {
+        let hbar = Hbar::new(2);
+
+        assert_eq!(serde_json::to_string(&hbar).unwrap(), "200000000");
+        assert_eq!(serde_json::from_str::<Hbar>("200000000").unwrap(), hbar);
+    }
+
+    #[test]
+    fn key_round_trip() {
+        let key = Key::from(PrivateKey::generate_ed25519().public_key());
+
+        let json = serde_json::to_string(&key).unwrap();
+
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+    }
+
+    #[test]
+    fn status_is_its_name() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, PartialEq, Debug)]
+        struct Wrapper(#[serde(with = "super::status")] Status);
+
+        assert_eq!(serde_json::to_string(&Wrapper(Status::Success)).unwrap(), r#""SUCCESS""#);
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""INVALID_SIGNATURE""#).unwrap(),
+            Wrapper(Status::InvalidSignature)
+        );
+    }
+}
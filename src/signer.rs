@@ -81,13 +81,20 @@ impl AnySigner {
     }
 
     pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
-        match self {
+        let (public, bytes) = match self {
             AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
             AnySigner::Arbitrary(public, signer) => {
                 let bytes = signer(message);
 
                 (**public, bytes)
             }
-        }
+        };
+
+        // `PrivateKey` already signs with low-S, but custom signers (IE, HSMs) might not.
+        #[cfg(feature = "security-hardened")]
+        let bytes =
+            if public.is_ecdsa() { crate::key::normalize_ecdsa_signature(bytes) } else { bytes };
+
+        (public, bytes)
     }
 }
@@ -1,5 +1,10 @@
 use hedera_proto::services;
-use time::OffsetDateTime;
+use time::{
+    Duration,
+    OffsetDateTime,
+    Time,
+    UtcOffset,
+};
 
 use crate::protobuf::ToProtobuf;
 use crate::{
@@ -10,7 +15,8 @@ use crate::{
 
 // todo(sr): is this right?
 /// Info related to account/contract staking settings.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct StakingInfo {
     /// If `true`, the contract declines receiving a staking reward. The default value is `false`.
     pub decline_staking_reward: bool,
@@ -18,6 +24,7 @@ pub struct StakingInfo {
     /// The staking period during which either the staking settings for this account or contract changed (such as starting
     /// staking or changing staked_node_id) or the most recent reward was earned, whichever is later. If this account or contract
     /// is not currently staked to a node, then this field is not set.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339::option"))]
     pub stake_period_start: Option<OffsetDateTime>,
 
     /// The amount in `Hbar` that will be received in the next reward situation.
@@ -34,6 +41,36 @@ pub struct StakingInfo {
 }
 
 impl StakingInfo {
+    /// The length of a staking period.
+    ///
+    /// Staking rewards are calculated per period, and periods start at midnight UTC.
+    pub const STAKING_PERIOD: Duration = Duration::days(1);
+
+    /// Returns the start of the staking period containing `timestamp` (midnight UTC of the same day).
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::StakingInfo;
+    /// use time::OffsetDateTime;
+    ///
+    /// let timestamp = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(); // 2023-11-14 22:13:20 UTC
+    ///
+    /// assert_eq!(
+    ///     StakingInfo::staking_period_start(timestamp),
+    ///     OffsetDateTime::from_unix_timestamp(1_699_920_000).unwrap(), // 2023-11-14 00:00:00 UTC
+    /// );
+    /// ```
+    #[must_use]
+    pub fn staking_period_start(timestamp: OffsetDateTime) -> OffsetDateTime {
+        timestamp.to_offset(UtcOffset::UTC).replace_time(Time::MIDNIGHT)
+    }
+
+    /// Returns the start of the staking period after the one containing `timestamp`.
+    #[must_use]
+    pub fn next_staking_period_start(timestamp: OffsetDateTime) -> OffsetDateTime {
+        Self::staking_period_start(timestamp) + Self::STAKING_PERIOD
+    }
+
     /// Create a new `StakingInfo` from protobuf-encoded `bytes`.
     ///
     /// # Errors
@@ -94,3 +131,54 @@ impl ToProtobuf for StakingInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        Hbar,
+        StakingInfo,
+    };
+
+    fn make_info() -> StakingInfo {
+        StakingInfo {
+            decline_staking_reward: true,
+            stake_period_start: Some(OffsetDateTime::from_unix_timestamp(1_699_920_000).unwrap()),
+            pending_reward: Hbar::from_tinybars(5),
+            staked_to_me: Hbar::new(10),
+            staked_account_id: None,
+            staked_node_id: Some(3),
+        }
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let staked_to_account = StakingInfo {
+            staked_account_id: Some(AccountId::new(0, 0, 1001)),
+            staked_node_id: None,
+            ..make_info()
+        };
+
+        for a in [make_info(), staked_to_account] {
+            let b = StakingInfo::from_bytes(&a.to_bytes()).unwrap();
+
+            assert_eq!(a.to_protobuf(), b.to_protobuf());
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn unset_fields_from_protobuf() {
+        let info = StakingInfo::from_bytes(&[]).unwrap();
+
+        assert!(!info.decline_staking_reward);
+        assert_eq!(info.stake_period_start, None);
+        assert_eq!(info.pending_reward, Hbar::ZERO);
+        assert_eq!(info.staked_to_me, Hbar::ZERO);
+        assert_eq!(info.staked_account_id, None);
+        assert_eq!(info.staked_node_id, None);
+    }
+}
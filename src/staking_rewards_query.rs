@@ -0,0 +1,243 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    get_i64,
+    get_str,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    StakingInfo,
+};
+
+/// Query the staking rewards paid to an account from the mirror node REST API.
+///
+/// Rewards are paid out when a transaction touches the account (or its staking settings change),
+/// so one payout can cover several staking periods.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, StakingRewardsQuery};
+/// use time::OffsetDateTime;
+///
+/// let client = Client::for_mainnet();
+///
+/// let rewards = StakingRewardsQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     // 2024-01-01 00:00:00 UTC
+///     .start_time(OffsetDateTime::from_unix_timestamp(1_704_067_200).unwrap())
+///     // 2025-01-01 00:00:00 UTC
+///     .end_time(OffsetDateTime::from_unix_timestamp(1_735_689_600).unwrap())
+///     .execute(&client)
+///     .await?;
+///
+/// for it in rewards {
+///     println!("{},{}", it.timestamp, it.amount);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StakingRewardsQuery {
+    account_id: Option<AccountId>,
+    start_time: Option<OffsetDateTime>,
+    end_time: Option<OffsetDateTime>,
+    limit: Option<usize>,
+}
+
+/// A staking reward payout, as returned by [`StakingRewardsQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakingReward {
+    /// The account the reward was paid to.
+    pub account_id: AccountId,
+
+    /// The amount of the reward.
+    pub amount: Hbar,
+
+    /// The consensus timestamp of the payout.
+    pub timestamp: OffsetDateTime,
+}
+
+impl StakingReward {
+    /// Returns the start of the staking period the reward was paid in.
+    #[must_use]
+    pub fn staking_period_start(&self) -> OffsetDateTime {
+        StakingInfo::staking_period_start(self.timestamp)
+    }
+
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: get_str(json, "account_id")?.parse()?,
+            amount: Hbar::from_tinybars(get_i64(json, "amount")?),
+            timestamp: mirror_rest::parse_timestamp(get_str(json, "timestamp")?)?,
+        })
+    }
+}
+
+impl StakingRewardsQuery {
+    /// Create a new `StakingRewardsQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to query the rewards of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to query the rewards of.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the consensus time (inclusive) to return payouts from.
+    #[must_use]
+    pub fn get_start_time(&self) -> Option<OffsetDateTime> {
+        self.start_time
+    }
+
+    /// Sets the consensus time (inclusive) to return payouts from.
+    pub fn start_time(&mut self, start_time: OffsetDateTime) -> &mut Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Returns the consensus time (exclusive) to return payouts until.
+    #[must_use]
+    pub fn get_end_time(&self) -> Option<OffsetDateTime> {
+        self.end_time
+    }
+
+    /// Sets the consensus time (exclusive) to return payouts until.
+    pub fn end_time(&mut self, end_time: OffsetDateTime) -> &mut Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Returns the maximum number of payouts to return.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the maximum number of payouts to return.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// Payouts are returned oldest first.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if a request to the mirror node fails, or its response couldn't be understood.
+    /// - [`Error::MissingField`](crate::Error::MissingField) if `account_id` wasn't set.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<StakingReward>> {
+        let account_id = self.account_id.ok_or(Error::MissingField { field: "account_id" })?;
+
+        let rewards = mirror_rest::get_all(
+            client,
+            &self.path_and_query(account_id),
+            "rewards",
+            self.limit,
+            |_| true,
+        )
+        .await?;
+
+        rewards.iter().map(StakingReward::from_json).collect()
+    }
+
+    fn path_and_query(&self, account_id: AccountId) -> String {
+        let mut path = format!("/api/v1/accounts/{account_id}/rewards?order=asc&limit=100");
+
+        if let Some(start_time) = self.start_time {
+            path.push_str(&format!("&timestamp=gte:{}", mirror_rest::format_timestamp(start_time)));
+        }
+
+        if let Some(end_time) = self.end_time {
+            path.push_str(&format!("&timestamp=lt:{}", mirror_rest::format_timestamp(end_time)));
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::StakingReward;
+    use crate::{
+        AccountId,
+        StakingRewardsQuery,
+    };
+
+    #[test]
+    fn path_and_query() {
+        let mut query = StakingRewardsQuery::new();
+
+        query
+            .start_time(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap())
+            .end_time(OffsetDateTime::from_unix_timestamp(1_700_086_400).unwrap());
+
+        expect!["/api/v1/accounts/0.0.1001/rewards?order=asc&limit=100&timestamp=gte:1700000000.000000000&timestamp=lt:1700086400.000000000"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn reward_from_json() {
+        let json = json!({
+            "account_id": "0.0.1001",
+            "amount": 1_234,
+            "timestamp": "1700000000.000000001"
+        });
+
+        let reward = StakingReward::from_json(&json).unwrap();
+
+        expect![[r#"
+            StakingReward {
+                account_id: "0.0.1001",
+                amount: "1234 tℏ",
+                timestamp: 2023-11-14 22:13:20.000000001 +00:00:00,
+            }
+        "#]]
+        .assert_debug_eq(&reward);
+
+        assert_eq!(
+            reward.staking_period_start(),
+            OffsetDateTime::from_unix_timestamp(1_699_920_000).unwrap()
+        );
+    }
+}
@@ -21,7 +21,6 @@
 use hedera_proto::services;
 use hedera_proto::services::freeze_service_client::FreezeServiceClient;
 use time::OffsetDateTime;
-use tonic::transport::Channel;
 
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
@@ -32,6 +31,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
@@ -22,7 +22,6 @@ use hedera_proto::services;
 use hedera_proto::services::file_service_client::FileServiceClient;
 use hedera_proto::services::smart_contract_service_client::SmartContractServiceClient;
 use time::OffsetDateTime;
-use tonic::transport::Channel;
 
 use crate::protobuf::{
     FromProtobuf,
@@ -36,6 +35,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     ContractId,
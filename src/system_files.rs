@@ -0,0 +1,374 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Typed access to the network's system files.
+//!
+//! A handful of files on the network hold configuration that network operators
+//! maintain with [`FileUpdateTransaction`](crate::FileUpdateTransaction) and that
+//! monitoring agents read with [`FileContentsQuery`](crate::FileContentsQuery).
+//! [`SystemFile`] parses those contents into the SDK types and serializes them back.
+
+use hedera_proto::services;
+
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::{
+    Error,
+    ExchangeRates,
+    FeeSchedules,
+    FileId,
+    NodeAddressBook,
+    RequestType,
+};
+
+/// The parsed contents of one of the network's system files.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SystemFile {
+    /// The node address book, stored in [`FileId::ADDRESS_BOOK`] and [`FileId::LEGACY_ADDRESS_BOOK`].
+    AddressBook(NodeAddressBook),
+
+    /// The fee schedules, stored in [`FileId::FEE_SCHEDULE`].
+    FeeSchedules(FeeSchedules),
+
+    /// The exchange rates, stored in [`FileId::EXCHANGE_RATES`].
+    ExchangeRates(ExchangeRates),
+
+    /// The throttle definitions, stored in [`FileId::THROTTLE_DEFINITIONS`].
+    ThrottleDefinitions(ThrottleDefinitions),
+}
+
+impl SystemFile {
+    /// Parse the contents of the system file `file_id` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `file_id` is not a known system file.
+    /// - [`Error::FromProtobuf`] if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`] if decoding the protobuf fails.
+    pub fn from_bytes(file_id: FileId, bytes: &[u8]) -> crate::Result<Self> {
+        // checksums don't matter for lookup.
+        let file_id = FileId::new(file_id.shard, file_id.realm, file_id.num);
+
+        match file_id {
+            FileId::ADDRESS_BOOK | FileId::LEGACY_ADDRESS_BOOK => {
+                NodeAddressBook::from_bytes(bytes).map(Self::AddressBook)
+            }
+            FileId::FEE_SCHEDULE => FeeSchedules::from_bytes(bytes).map(Self::FeeSchedules),
+            FileId::EXCHANGE_RATES => ExchangeRates::from_bytes(bytes).map(Self::ExchangeRates),
+            FileId::THROTTLE_DEFINITIONS => {
+                ThrottleDefinitions::from_bytes(bytes).map(Self::ThrottleDefinitions)
+            }
+            _ => Err(Error::basic_parse(format!("{file_id} is not a known system file"))),
+        }
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`], suitable for a [`FileUpdateTransaction`](crate::FileUpdateTransaction).
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::AddressBook(it) => it.to_bytes(),
+            Self::FeeSchedules(it) => it.to_bytes(),
+            Self::ExchangeRates(it) => it.to_bytes(),
+            Self::ThrottleDefinitions(it) => it.to_bytes(),
+        }
+    }
+}
+
+/// The throttle buckets the network applies to incoming transactions and queries.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ThrottleDefinitions {
+    /// The buckets making up the network's throttles.
+    pub throttle_buckets: Vec<ThrottleBucket>,
+}
+
+impl ThrottleDefinitions {
+    /// A conservative set of throttles, for when the network's own definitions can't be read.
+    ///
+    /// These are well below the limits of any public network,
+    /// and only cover the kinds of transactions that bulk jobs tend to submit.
+    #[must_use]
+    pub fn conservative() -> Self {
+        fn bucket(name: &str, operations: Vec<RequestType>, ops_per_sec: u64) -> ThrottleBucket {
+            ThrottleBucket {
+                name: name.to_owned(),
+                burst_period_ms: 1000,
+                throttle_groups: vec![ThrottleGroup {
+                    operations,
+                    milli_ops_per_sec: ops_per_sec * 1000,
+                }],
+            }
+        }
+
+        Self {
+            throttle_buckets: vec![
+                bucket(
+                    "ThroughputLimits",
+                    vec![
+                        RequestType::CryptoTransfer,
+                        RequestType::CryptoCreate,
+                        RequestType::CryptoUpdate,
+                        RequestType::CryptoApproveAllowance,
+                        RequestType::TokenAssociateToAccount,
+                        RequestType::TokenDissociateFromAccount,
+                        RequestType::TokenAirdrop,
+                        RequestType::ConsensusSubmitMessage,
+                    ],
+                    100,
+                ),
+                bucket(
+                    "TokenSupplyLimits",
+                    vec![RequestType::TokenMint, RequestType::TokenBurn],
+                    50,
+                ),
+                bucket(
+                    "ContractLimits",
+                    vec![
+                        RequestType::ContractCall,
+                        RequestType::ContractCreate,
+                        RequestType::EthereumTransaction,
+                    ],
+                    10,
+                ),
+            ],
+        }
+    }
+
+    /// Create a new `ThrottleDefinitions` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`] if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
+}
+
+impl FromProtobuf<services::ThrottleDefinitions> for ThrottleDefinitions {
+    fn from_protobuf(pb: services::ThrottleDefinitions) -> crate::Result<Self> {
+        Ok(Self { throttle_buckets: Vec::from_protobuf(pb.throttle_buckets)? })
+    }
+}
+
+impl ToProtobuf for ThrottleDefinitions {
+    type Protobuf = services::ThrottleDefinitions;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleDefinitions { throttle_buckets: self.throttle_buckets.to_protobuf() }
+    }
+}
+
+/// A named bucket whose capacity is shared by one or more [`ThrottleGroup`]s.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ThrottleBucket {
+    /// The name of the bucket, used in logs and metrics.
+    pub name: String,
+
+    /// The period, in milliseconds, over which bursts are allowed.
+    pub burst_period_ms: u64,
+
+    /// The groups of operations that draw from this bucket.
+    pub throttle_groups: Vec<ThrottleGroup>,
+}
+
+impl FromProtobuf<services::ThrottleBucket> for ThrottleBucket {
+    fn from_protobuf(pb: services::ThrottleBucket) -> crate::Result<Self> {
+        Ok(Self {
+            name: pb.name,
+            burst_period_ms: pb.burst_period_ms,
+            throttle_groups: Vec::from_protobuf(pb.throttle_groups)?,
+        })
+    }
+}
+
+impl ToProtobuf for ThrottleBucket {
+    type Protobuf = services::ThrottleBucket;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleBucket {
+            name: self.name.clone(),
+            burst_period_ms: self.burst_period_ms,
+            throttle_groups: self.throttle_groups.to_protobuf(),
+        }
+    }
+}
+
+/// A set of operations throttled together at a shared rate.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ThrottleGroup {
+    /// The operations in this group.
+    pub operations: Vec<RequestType>,
+
+    /// The allowed rate, in thousandths of an operation per second.
+    pub milli_ops_per_sec: u64,
+}
+
+impl FromProtobuf<services::ThrottleGroup> for ThrottleGroup {
+    fn from_protobuf(pb: services::ThrottleGroup) -> crate::Result<Self> {
+        // `pb.operations()` would skip operations unknown to this version of the SDK,
+        // which would leave them looking unthrottled.
+        let operations = pb
+            .operations
+            .into_iter()
+            .map(|code| {
+                let operation = services::HederaFunctionality::try_from(code).map_err(|_| {
+                    Error::from_protobuf(format!("unknown operation `{code}` in throttle group"))
+                })?;
+
+                RequestType::from_protobuf(operation)
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { operations, milli_ops_per_sec: pb.milli_ops_per_sec })
+    }
+}
+
+impl ToProtobuf for ThrottleGroup {
+    type Protobuf = services::ThrottleGroup;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleGroup {
+            operations: self.operations.iter().map(|it| it.to_protobuf() as i32).collect(),
+            milli_ops_per_sec: self.milli_ops_per_sec,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use hex_literal::hex;
+
+    use super::{
+        SystemFile,
+        ThrottleBucket,
+        ThrottleDefinitions,
+        ThrottleGroup,
+    };
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        FileId,
+        RequestType,
+    };
+
+    fn make_throttle_definitions() -> ThrottleDefinitions {
+        ThrottleDefinitions {
+            throttle_buckets: vec![ThrottleBucket {
+                name: "ThroughputLimits".to_owned(),
+                burst_period_ms: 1000,
+                throttle_groups: vec![
+                    ThrottleGroup {
+                        operations: vec![RequestType::CryptoTransfer, RequestType::CryptoCreate],
+                        milli_ops_per_sec: 10_500_000,
+                    },
+                    ThrottleGroup {
+                        operations: vec![RequestType::ConsensusSubmitMessage],
+                        milli_ops_per_sec: 5_000_000,
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn throttle_definitions_round_trip() {
+        let definitions = make_throttle_definitions();
+
+        let bytes = definitions.to_bytes();
+
+        assert_eq!(ThrottleDefinitions::from_bytes(&bytes).unwrap(), definitions);
+    }
+
+    #[test]
+    fn parse_exchange_rates() {
+        let bytes =
+            hex!("0a1008b0ea0110b6b4231a0608f0bade9006121008b0ea01108cef231a060880d7de9006");
+
+        let file = SystemFile::from_bytes(FileId::EXCHANGE_RATES, &bytes).unwrap();
+
+        assert_matches!(file, SystemFile::ExchangeRates(_));
+        assert_eq!(file.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn parse_throttle_definitions() {
+        let definitions = make_throttle_definitions();
+
+        let file =
+            SystemFile::from_bytes(FileId::THROTTLE_DEFINITIONS, &definitions.to_bytes()).unwrap();
+
+        expect![[r#"
+            ThrottleDefinitions(
+                ThrottleDefinitions {
+                    throttle_buckets: [
+                        ThrottleBucket {
+                            name: "ThroughputLimits",
+                            burst_period_ms: 1000,
+                            throttle_groups: [
+                                ThrottleGroup {
+                                    operations: [
+                                        CryptoTransfer,
+                                        CryptoCreate,
+                                    ],
+                                    milli_ops_per_sec: 10500000,
+                                },
+                                ThrottleGroup {
+                                    operations: [
+                                        ConsensusSubmitMessage,
+                                    ],
+                                    milli_ops_per_sec: 5000000,
+                                },
+                            ],
+                        },
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&file);
+    }
+
+    #[test]
+    fn parse_unknown_operation_fails() {
+        let mut definitions = make_throttle_definitions().to_protobuf();
+        definitions.throttle_buckets[0].throttle_groups[0].operations.push(i32::MAX);
+
+        let res = SystemFile::from_bytes(
+            FileId::THROTTLE_DEFINITIONS,
+            &prost::Message::encode_to_vec(&definitions),
+        );
+
+        assert_matches!(res, Err(crate::Error::FromProtobuf(_)));
+    }
+
+    #[test]
+    fn parse_unknown_file_fails() {
+        let res = SystemFile::from_bytes(FileId::new(0, 0, 1001), &[]);
+
+        assert_matches!(res, Err(crate::Error::BasicParse(_)));
+    }
+}
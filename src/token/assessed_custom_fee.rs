@@ -11,6 +11,7 @@ use crate::{
 
 /// A custom transfer fee that was assessed during the handling of a `CryptoTransfer`.
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct AssessedCustomFee {
     /// The amount of currency charged to each payer.
     pub amount: i64,
@@ -0,0 +1,256 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use hedera_proto::services;
+
+use crate::{
+    Error,
+    FromProtobuf,
+    ToProtobuf,
+};
+
+/// A non-negative fraction, such as the portion of a transfer taken by a [`FractionalFeeData`](crate::FractionalFeeData).
+///
+/// A `Fraction` is always in lowest terms, never has a zero denominator,
+/// and both its numerator and denominator fit in an `i64`, as the network requires.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(try_from = "RawFraction", into = "RawFraction")
+)]
+pub struct Fraction {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl Fraction {
+    /// Creates the fraction `numerator / denominator`, reduced to lowest terms.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFraction`] if `denominator` is zero.
+    /// - [`Error::InvalidFraction`] if the reduced numerator or denominator is larger than `i64::MAX`.
+    pub fn new(numerator: u64, denominator: u64) -> crate::Result<Self> {
+        if denominator == 0 {
+            return Err(Error::InvalidFraction { numerator, denominator });
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let (reduced_numerator, reduced_denominator) = (numerator / divisor, denominator / divisor);
+
+        if i64::try_from(reduced_numerator).is_err() || i64::try_from(reduced_denominator).is_err()
+        {
+            return Err(Error::InvalidFraction { numerator, denominator });
+        }
+
+        Ok(Self { numerator: reduced_numerator, denominator: reduced_denominator })
+    }
+
+    /// Returns the numerator of the fraction, in lowest terms.
+    #[must_use]
+    pub const fn numerator(self) -> u64 {
+        self.numerator
+    }
+
+    /// Returns the denominator of the fraction, in lowest terms.
+    ///
+    /// This is never zero.
+    #[must_use]
+    pub const fn denominator(self) -> u64 {
+        self.denominator
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`, or `b` if `a` is zero.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while a != 0 {
+        (a, b) = (b % a, a);
+    }
+
+    b
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // both denominators are positive, so cross multiplying preserves the order, and can't overflow a `u128`.
+        let lhs = u128::from(self.numerator) * u128::from(other.denominator);
+        let rhs = u128::from(other.numerator) * u128::from(self.denominator);
+
+        lhs.cmp(&rhs)
+    }
+}
+
+impl Default for Fraction {
+    /// Returns the fraction `0/1`.
+    fn default() -> Self {
+        Self { numerator: 0, denominator: 1 }
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl TryFrom<(u64, u64)> for Fraction {
+    type Error = Error;
+
+    fn try_from((numerator, denominator): (u64, u64)) -> crate::Result<Self> {
+        Self::new(numerator, denominator)
+    }
+}
+
+impl From<Fraction> for (u64, u64) {
+    fn from(fraction: Fraction) -> Self {
+        (fraction.numerator, fraction.denominator)
+    }
+}
+
+impl FromProtobuf<services::Fraction> for Fraction {
+    fn from_protobuf(pb: services::Fraction) -> crate::Result<Self> {
+        let numerator = u64::try_from(pb.numerator).map_err(Error::from_protobuf)?;
+        let denominator = u64::try_from(pb.denominator).map_err(Error::from_protobuf)?;
+
+        Self::new(numerator, denominator).map_err(Error::from_protobuf)
+    }
+}
+
+impl ToProtobuf for Fraction {
+    type Protobuf = services::Fraction;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        // both parts are checked to fit in an `i64` when the fraction is created.
+        services::Fraction {
+            numerator: self.numerator as i64,
+            denominator: self.denominator as i64,
+        }
+    }
+}
+
+/// The serialized form of a [`Fraction`], which is checked when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct RawFraction {
+    numerator: u64,
+    denominator: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawFraction> for Fraction {
+    type Error = Error;
+
+    fn try_from(raw: RawFraction) -> crate::Result<Self> {
+        Self::new(raw.numerator, raw.denominator)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Fraction> for RawFraction {
+    fn from(fraction: Fraction) -> Self {
+        Self { numerator: fraction.numerator, denominator: fraction.denominator }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+
+    use super::Fraction;
+    use crate::{
+        Error,
+        FromProtobuf,
+    };
+
+    #[test]
+    fn new_reduces() {
+        let fraction = Fraction::new(6, 8).unwrap();
+
+        assert_eq!(fraction.numerator(), 3);
+        assert_eq!(fraction.denominator(), 4);
+        assert_eq!(fraction, Fraction::new(3, 4).unwrap());
+    }
+
+    #[test]
+    fn new_zero() {
+        let fraction = Fraction::new(0, 5).unwrap();
+
+        assert_eq!((fraction.numerator(), fraction.denominator()), (0, 1));
+    }
+
+    #[test]
+    fn new_zero_denominator() {
+        assert_matches!(
+            Fraction::new(1, 0),
+            Err(Error::InvalidFraction { numerator: 1, denominator: 0 })
+        );
+    }
+
+    #[test]
+    fn new_overflow() {
+        assert_matches!(Fraction::new(1, u64::MAX), Err(Error::InvalidFraction { .. }));
+
+        // reduces to fit.
+        assert_eq!(
+            Fraction::new(u64::MAX - 1, u64::MAX - 1).unwrap(),
+            Fraction::new(1, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let third = Fraction::new(1, 3).unwrap();
+        let half = Fraction::new(1, 2).unwrap();
+
+        assert!(third < half);
+        assert!(Fraction::new(2, 4).unwrap() == half);
+        assert_eq!(half.max(third), half);
+    }
+
+    #[test]
+    fn default_is_zero() {
+        assert_eq!(Fraction::default(), Fraction::new(0, 1).unwrap());
+    }
+
+    #[test]
+    fn from_protobuf_negative() {
+        let pb = services::Fraction { numerator: -1, denominator: 2 };
+
+        assert_matches!(Fraction::from_protobuf(pb), Err(Error::FromProtobuf(_)));
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let fraction = Fraction::try_from((2, 6)).unwrap();
+
+        assert_eq!(<(u64, u64)>::from(fraction), (1, 3));
+        assert_eq!(fraction.to_string(), "1/3");
+    }
+}
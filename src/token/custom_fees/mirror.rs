@@ -0,0 +1,119 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use serde_json::Value;
+
+use super::{
+    AnyCustomFee,
+    Fee,
+    FeeAssessmentMethod,
+    FixedFeeData,
+    Fraction,
+    FractionalFeeData,
+    RoyaltyFeeData,
+};
+use crate::mirror_rest::get_i64;
+use crate::Error;
+
+impl AnyCustomFee {
+    /// Parses the `custom_fees` object of a token from the mirror node REST API.
+    pub(crate) fn list_from_mirror_json(json: &Value) -> crate::Result<Vec<Self>> {
+        let mut fees = Vec::new();
+
+        for it in mirror_array(json, "fixed_fees")? {
+            fees.push(Self {
+                fee: Fee::Fixed(FixedFeeData::from_mirror_json(it)?),
+                fee_collector_account_id: mirror_id(it, "collector_account_id")?,
+                all_collectors_are_exempt: mirror_exempt(it),
+            });
+        }
+
+        for it in mirror_array(json, "fractional_fees")? {
+            fees.push(Self {
+                fee: Fee::Fractional(FractionalFeeData {
+                    fraction: mirror_fraction(it)?,
+                    minimum_amount: get_i64(it, "minimum")?,
+                    // `null` means there's no maximum.
+                    maximum_amount: it.get("maximum").and_then(Value::as_i64).unwrap_or_default(),
+                    assessment_method: match it.get("net_of_transfers").and_then(Value::as_bool) {
+                        Some(true) => FeeAssessmentMethod::Exclusive,
+                        _ => FeeAssessmentMethod::Inclusive,
+                    },
+                }),
+                fee_collector_account_id: mirror_id(it, "collector_account_id")?,
+                all_collectors_are_exempt: mirror_exempt(it),
+            });
+        }
+
+        for it in mirror_array(json, "royalty_fees")? {
+            fees.push(Self {
+                fee: Fee::Royalty(RoyaltyFeeData {
+                    fraction: mirror_fraction(it)?,
+                    fallback_fee: match it.get("fallback_fee") {
+                        None | Some(Value::Null) => None,
+                        Some(it) => Some(FixedFeeData::from_mirror_json(it)?),
+                    },
+                }),
+                fee_collector_account_id: mirror_id(it, "collector_account_id")?,
+                all_collectors_are_exempt: mirror_exempt(it),
+            });
+        }
+
+        Ok(fees)
+    }
+}
+
+impl FixedFeeData {
+    fn from_mirror_json(json: &Value) -> crate::Result<Self> {
+        Ok(Self {
+            amount: get_i64(json, "amount")?,
+            denominating_token_id: mirror_id(json, "denominating_token_id")?,
+        })
+    }
+}
+
+fn mirror_array<'a>(json: &'a Value, key: &str) -> crate::Result<&'a [Value]> {
+    match json.get(key) {
+        None | Some(Value::Null) => Ok(&[]),
+        Some(Value::Array(it)) => Ok(it),
+        Some(_) => Err(Error::mirror_rest(format!("`{key}` is not an array"))),
+    }
+}
+
+fn mirror_id<T: std::str::FromStr<Err = Error>>(
+    json: &Value,
+    key: &str,
+) -> crate::Result<Option<T>> {
+    json.get(key).and_then(Value::as_str).map(str::parse).transpose()
+}
+
+fn mirror_exempt(json: &Value) -> bool {
+    json.get("all_collectors_are_exempt").and_then(Value::as_bool).unwrap_or_default()
+}
+
+fn mirror_fraction(json: &Value) -> crate::Result<Fraction> {
+    let amount =
+        json.get("amount").ok_or_else(|| Error::mirror_rest("response is missing `amount`"))?;
+
+    let numerator = u64::try_from(get_i64(amount, "numerator")?).map_err(Error::mirror_rest)?;
+    let denominator = u64::try_from(get_i64(amount, "denominator")?).map_err(Error::mirror_rest)?;
+
+    Fraction::new(numerator, denominator).map_err(Error::mirror_rest)
+}
@@ -18,20 +18,93 @@
  * ‍
  */
 
-use fraction::Fraction;
 use hedera_proto::services;
 
 use crate::{
     AccountId,
+    AssessedCustomFee,
+    Client,
+    CustomFeeValidationError,
+    Error,
     FromProtobuf,
     Hbar,
+    Status,
     ToProtobuf,
     TokenId,
+    TokenType,
 };
 
+mod fraction;
+#[cfg(feature = "mirror-rest")]
+mod mirror;
 #[cfg(test)]
 mod tests;
 
+// `self::` as `fraction` alone is ambiguous with the `fraction` crate.
+pub use self::fraction::Fraction;
+
+/// The most custom fees a token may have.
+pub(crate) const MAX_CUSTOM_FEES: usize = 10;
+
+/// Checks `fees` against the limits the network enforces on the custom fees of a token.
+///
+/// If `token_type` is known, also checks that fractional fees are only on fungible tokens,
+/// and royalty fees only on non-fungible tokens.
+pub(crate) fn validate_custom_fees(
+    fees: &[AnyCustomFee],
+    token_type: Option<TokenType>,
+) -> crate::Result<()> {
+    if fees.len() > MAX_CUSTOM_FEES {
+        return Err(Error::custom_fee_validation(CustomFeeValidationError::TooManyFees {
+            count: fees.len(),
+            max: MAX_CUSTOM_FEES,
+        }));
+    }
+
+    for (index, fee) in fees.iter().enumerate() {
+        fee.fee.validate(index, token_type).map_err(Error::custom_fee_validation)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that every token that one of `fees` is denominated in exists, with a [`TokenInfoQuery`](crate::TokenInfoQuery) per token
+/// (see [`Client::token_info`]).
+pub(crate) async fn validate_denominating_tokens(
+    fees: &[AnyCustomFee],
+    client: &Client,
+) -> crate::Result<()> {
+    for (index, fee) in fees.iter().enumerate() {
+        let token_id = match &fee.fee {
+            Fee::Fixed(it) => it.denominating_token_id,
+            Fee::Royalty(it) => it.fallback_fee.as_ref().and_then(|it| it.denominating_token_id),
+            Fee::Fractional(_) => None,
+        };
+
+        // `0.0.0` stands for the token being created, which can't exist yet.
+        let Some(token_id) = token_id.filter(|it| *it != TokenId::new(0, 0, 0)) else {
+            continue;
+        };
+
+        match client.token_info(token_id).await {
+            Ok(_) => {}
+
+            Err(
+                Error::QueryPreCheckStatus { status: Status::InvalidTokenId, .. }
+                | Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidTokenId },
+            ) => {
+                return Err(Error::custom_fee_validation(
+                    CustomFeeValidationError::DenominatingTokenNotFound { index, token_id },
+                ));
+            }
+
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
+
 /// Any `CustomFee`.
 ///
 /// See the documentation for [`CustomFee`] and [`Fee`].
@@ -56,6 +129,7 @@ pub type RoyaltyFee = CustomFee<RoyaltyFeeData>;
 /// fee is attached. A custom fee may be either fixed or fractional, and must specify a fee collector
 /// account to receive the assessed fees. Only positive fees may be assessed.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct CustomFee<Fee> {
     /// The fee to be charged
     pub fee: Fee,
@@ -82,6 +156,39 @@ impl AnyCustomFee {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Simulates assessing this fee when `amount` units of `token_id`, the token it's attached to,
+    /// are transferred by an account that isn't exempt from it.
+    ///
+    /// Returns `None` for royalty fees, which depend on the value exchanged for an NFT instead.
+    /// The `payer_account_id_list` of the returned fee is empty, as the payer isn't known.
+    #[must_use]
+    pub fn assess(&self, amount: u64, token_id: TokenId) -> Option<AssessedCustomFee> {
+        let (amount, token_id) = match &self.fee {
+            Fee::Fixed(it) => (it.amount, it.denominating_token_id),
+            Fee::Fractional(it) => {
+                let fee = u128::from(amount) * u128::from(it.fraction.numerator())
+                    / u128::from(it.fraction.denominator());
+
+                let mut fee = i64::try_from(fee).unwrap_or(i64::MAX).max(it.minimum_amount);
+
+                // zero implies no maximum.
+                if it.maximum_amount > 0 {
+                    fee = fee.min(it.maximum_amount);
+                }
+
+                (fee, Some(token_id))
+            }
+            Fee::Royalty(_) => return None,
+        };
+
+        Some(AssessedCustomFee {
+            amount,
+            token_id,
+            fee_collector_account_id: self.fee_collector_account_id,
+            payer_account_id_list: Vec::new(),
+        })
+    }
 }
 
 impl FromProtobuf<services::CustomFee> for AnyCustomFee {
@@ -153,6 +260,7 @@ impl From<RoyaltyFee> for AnyCustomFee {
 
 /// Represents the possible fee types.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub enum Fee {
     /// A fee using a fixed amount.
     Fixed(FixedFeeData),
@@ -164,6 +272,55 @@ pub enum Fee {
     Royalty(RoyaltyFeeData),
 }
 
+impl Fee {
+    fn validate(
+        &self,
+        index: usize,
+        token_type: Option<TokenType>,
+    ) -> Result<(), CustomFeeValidationError> {
+        match self {
+            Self::Fixed(it) => it.validate(index),
+
+            Self::Fractional(it) => {
+                if token_type == Some(TokenType::NonFungibleUnique) {
+                    return Err(CustomFeeValidationError::FractionalFeeOnNonFungibleToken {
+                        index,
+                    });
+                }
+
+                // the denominator is never zero, and both parts fit in an `i64`, see `Fraction::new`.
+                if it.fraction.numerator() == 0 || it.minimum_amount < 0 || it.maximum_amount < 0 {
+                    return Err(CustomFeeValidationError::NonPositiveAmount { index });
+                }
+
+                // a maximum of zero means there's no maximum.
+                if it.maximum_amount != 0 && it.maximum_amount < it.minimum_amount {
+                    return Err(CustomFeeValidationError::MaximumLessThanMinimum { index });
+                }
+
+                Ok(())
+            }
+
+            Self::Royalty(it) => {
+                if token_type == Some(TokenType::FungibleCommon) {
+                    return Err(CustomFeeValidationError::RoyaltyFeeOnFungibleToken { index });
+                }
+
+                // the denominator is never zero, and both parts fit in an `i64`, see `Fraction::new`.
+                if it.fraction.numerator() == 0 {
+                    return Err(CustomFeeValidationError::NonPositiveAmount { index });
+                }
+
+                if it.fraction.numerator() > it.fraction.denominator() {
+                    return Err(CustomFeeValidationError::RoyaltyFractionExceedsOne { index });
+                }
+
+                it.fallback_fee.as_ref().map_or(Ok(()), |it| it.validate(index))
+            }
+        }
+    }
+}
+
 impl FromProtobuf<services::custom_fee::Fee> for Fee {
     fn from_protobuf(pb: services::custom_fee::Fee) -> crate::Result<Self>
     where
@@ -213,6 +370,7 @@ impl From<RoyaltyFeeData> for Fee {
 /// A fixed number of units (hbar or token) to assess as a fee during a `CryptoTransfer` that transfers
 /// units of the token to which this fixed fee is attached.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct FixedFeeData {
     /// The number of units to assess as a fee
     pub amount: i64,
@@ -234,6 +392,13 @@ impl FixedFeeData {
     pub fn get_hbar(&self) -> Option<Hbar> {
         self.denominating_token_id.is_none().then(|| Hbar::from_tinybars(self.amount))
     }
+
+    fn validate(&self, index: usize) -> Result<(), CustomFeeValidationError> {
+        match self.amount > 0 {
+            true => Ok(()),
+            false => Err(CustomFeeValidationError::NonPositiveAmount { index }),
+        }
+    }
 }
 
 impl FromProtobuf<services::FixedFee> for FixedFeeData {
@@ -260,12 +425,10 @@ impl ToProtobuf for FixedFeeData {
 /// be less than the given `minimum_amount`, and never greater than the given `maximum_amount`.  The
 /// denomination is always units of the token to which this fractional fee is attached.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct FractionalFeeData {
-    /// The denominator of the fraction of transferred units to assess as a fee
-    pub denominator: u64,
-
-    /// The numerator of the fraction of transferred units to assess as a fee
-    pub numerator: u64,
+    /// The fraction of transferred units to assess as a fee
+    pub fraction: Fraction,
 
     /// The minimum amount to assess
     pub minimum_amount: i64,
@@ -283,12 +446,50 @@ pub struct FractionalFeeData {
     pub assessment_method: FeeAssessmentMethod,
 }
 
+impl FractionalFeeData {
+    /// Creates a fractional fee of `numerator / denominator` of the transferred units.
+    ///
+    /// `FractionalFeeData` used to have `numerator` and `denominator` fields,
+    /// which were replaced by [`fraction`](Self::fraction) so that a zero denominator is caught before submission.
+    /// Struct literals need to set `fraction` instead; this keeps the old shape for code that can't be updated yet.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFraction`](crate::Error::InvalidFraction) if `denominator` is zero, see [`Fraction::new`].
+    #[deprecated(
+        note = "set `fraction: Fraction::new(numerator, denominator)?` in a struct literal instead"
+    )]
+    pub fn new(
+        numerator: u64,
+        denominator: u64,
+        minimum_amount: i64,
+        maximum_amount: i64,
+        assessment_method: FeeAssessmentMethod,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            fraction: Fraction::new(numerator, denominator)?,
+            minimum_amount,
+            maximum_amount,
+            assessment_method,
+        })
+    }
+
+    /// Returns the numerator of the fraction of transferred units to assess as a fee, in lowest terms.
+    #[must_use]
+    pub const fn numerator(&self) -> u64 {
+        self.fraction.numerator()
+    }
+
+    /// Returns the denominator of the fraction of transferred units to assess as a fee, in lowest terms.
+    #[must_use]
+    pub const fn denominator(&self) -> u64 {
+        self.fraction.denominator()
+    }
+}
+
 impl FromProtobuf<services::FractionalFee> for FractionalFeeData {
     fn from_protobuf(pb: services::FractionalFee) -> crate::Result<Self> {
-        let amount = pb.fractional_amount.map(Fraction::from).unwrap_or_default();
         Ok(Self {
-            denominator: *amount.denom().unwrap(),
-            numerator: *amount.numer().unwrap(),
+            fraction: Option::from_protobuf(pb.fractional_amount)?.unwrap_or_default(),
             assessment_method: match pb.net_of_transfers {
                 true => FeeAssessmentMethod::Exclusive,
                 false => FeeAssessmentMethod::Inclusive,
@@ -304,7 +505,7 @@ impl ToProtobuf for FractionalFeeData {
 
     fn to_protobuf(&self) -> Self::Protobuf {
         Self::Protobuf {
-            fractional_amount: Some(Fraction::new(self.numerator, self.denominator).into()),
+            fractional_amount: Some(self.fraction.to_protobuf()),
             minimum_amount: self.minimum_amount,
             maximum_amount: self.maximum_amount,
             net_of_transfers: matches!(self.assessment_method, FeeAssessmentMethod::Exclusive),
@@ -318,25 +519,34 @@ impl ToProtobuf for FractionalFeeData {
 /// any fungible value, the ledger will assess the fallback fee, if present, to the new NFT owner.
 /// Royalty fees can only be added to tokens of type type `NON_FUNGIBLE_UNIQUE`.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct RoyaltyFeeData {
-    /// The denominator of the fraction of fungible value exchanged for an NFT to collect as royalty
-    pub denominator: u64,
-
-    /// The numerator of the fraction of fungible value exchanged for an NFT to collect as royalty
-    pub numerator: u64,
+    /// The fraction of fungible value exchanged for an NFT to collect as royalty
+    pub fraction: Fraction,
 
     /// If present, the fixed fee to assess to the NFT receiver when no fungible value is exchanged
     /// with the sender
     pub fallback_fee: Option<FixedFeeData>,
 }
 
+impl RoyaltyFeeData {
+    /// Returns the numerator of the fraction of fungible value to collect as royalty, in lowest terms.
+    #[must_use]
+    pub const fn numerator(&self) -> u64 {
+        self.fraction.numerator()
+    }
+
+    /// Returns the denominator of the fraction of fungible value to collect as royalty, in lowest terms.
+    #[must_use]
+    pub const fn denominator(&self) -> u64 {
+        self.fraction.denominator()
+    }
+}
+
 impl FromProtobuf<services::RoyaltyFee> for RoyaltyFeeData {
     fn from_protobuf(pb: services::RoyaltyFee) -> crate::Result<Self> {
-        let amount = pb.exchange_value_fraction.unwrap_or_default();
-
         Ok(Self {
-            denominator: amount.denominator as u64,
-            numerator: amount.numerator as u64,
+            fraction: Option::from_protobuf(pb.exchange_value_fraction)?.unwrap_or_default(),
             fallback_fee: Option::from_protobuf(pb.fallback_fee)?,
         })
     }
@@ -348,10 +558,7 @@ impl ToProtobuf for RoyaltyFeeData {
     fn to_protobuf(&self) -> Self::Protobuf {
         Self::Protobuf {
             fallback_fee: self.fallback_fee.to_protobuf(),
-            exchange_value_fraction: Some(services::Fraction {
-                numerator: self.numerator as i64,
-                denominator: self.denominator as i64,
-            }),
+            exchange_value_fraction: Some(self.fraction.to_protobuf()),
         }
     }
 }
@@ -360,6 +567,7 @@ impl ToProtobuf for RoyaltyFeeData {
 ///
 /// The terminology here (exclusive vs inclusive) is borrowed from tax assessment.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub enum FeeAssessmentMethod {
     /// The recipient recieves the transfer amount, minus the fee.
     ///
@@ -128,8 +128,7 @@ fn fractional_fee_can_convert_to_protobuf() -> anyhow::Result<()> {
     let assessment_method = super::FeeAssessmentMethod::Exclusive;
 
     let fractional_fee = FractionalFeeData {
-        denominator: 1,
-        numerator: 2,
+        fraction: crate::Fraction::new(2, 1).unwrap(),
         minimum_amount,
         maximum_amount,
         assessment_method,
@@ -163,6 +162,36 @@ fn fractional_fee_can_be_created_from_protobuf() -> anyhow::Result<()> {
     assert_eq!(fractional_fee.minimum_amount, minimum_amount);
     assert_eq!(fractional_fee.maximum_amount, maximum_amount);
     assert_eq!(fractional_fee.assessment_method, assessment_method);
+    assert_eq!((fractional_fee.numerator(), fractional_fee.denominator()), (1, 2));
+
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn fractional_fee_new() {
+    let assessment_method = super::FeeAssessmentMethod::Inclusive;
+
+    let fractional_fee = FractionalFeeData::new(2, 4, 1, 10, assessment_method).unwrap();
+
+    assert_eq!((fractional_fee.numerator(), fractional_fee.denominator()), (1, 2));
+    assert_eq!((fractional_fee.minimum_amount, fractional_fee.maximum_amount), (1, 10));
+
+    assert!(FractionalFeeData::new(1, 0, 1, 10, assessment_method).is_err());
+}
+
+#[test]
+fn fractional_fee_from_protobuf_missing_fraction() -> anyhow::Result<()> {
+    let fractional_fee_protobuf = services::FractionalFee {
+        fractional_amount: None,
+        minimum_amount: 1,
+        maximum_amount: 2,
+        net_of_transfers: false,
+    };
+
+    let fractional_fee = FractionalFeeData::from_protobuf(fractional_fee_protobuf)?;
+
+    assert_eq!(fractional_fee.fraction, crate::Fraction::default());
 
     Ok(())
 }
@@ -172,8 +201,10 @@ fn royalty_fee_can_convert_to_protobuf() -> anyhow::Result<()> {
     let fallback_fee = FixedFeeData { denominating_token_id: Some(TokenId::from(1)), amount: 1000 };
     let exchange_value_fraction: Fraction = (1, 2).into();
 
-    let royalty_fee =
-        RoyaltyFeeData { fallback_fee: Some(fallback_fee.clone()), numerator: 1, denominator: 2 };
+    let royalty_fee = RoyaltyFeeData {
+        fallback_fee: Some(fallback_fee.clone()),
+        fraction: crate::Fraction::new(1, 2).unwrap(),
+    };
 
     let royalty_fee_proto = royalty_fee.to_protobuf();
 
@@ -200,9 +231,114 @@ fn royalty_fee_can_be_created_from_protobuf() -> anyhow::Result<()> {
 
     let royalty_fee = RoyaltyFeeData::from_protobuf(royalty_fee_proto).unwrap();
 
-    assert_eq!(royalty_fee.fallback_fee.map(|it| it.amount), Some(amount));
-    assert_eq!(royalty_fee.denominator, denominator as u64);
-    assert_eq!(royalty_fee.numerator, numerator as u64);
+    assert_eq!(royalty_fee.fallback_fee.as_ref().map(|it| it.amount), Some(amount));
+    assert_eq!(royalty_fee.denominator(), denominator as u64);
+    assert_eq!(royalty_fee.numerator(), numerator as u64);
+
+    Ok(())
+}
+
+#[test]
+fn fractional_fee_assess() {
+    let fee = AnyCustomFee {
+        fee: FractionalFeeData {
+            fraction: crate::Fraction::new(1, 10).unwrap(),
+            minimum_amount: 5,
+            maximum_amount: 50,
+            assessment_method: super::FeeAssessmentMethod::Exclusive,
+        }
+        .into(),
+        fee_collector_account_id: Some(AccountId::from(1)),
+        all_collectors_are_exempt: false,
+    };
+
+    let assess = |amount| fee.assess(amount, TokenId::from(2)).map(|it| it.amount);
+
+    assert_eq!(assess(10), Some(5));
+    assert_eq!(assess(200), Some(20));
+    assert_eq!(assess(1000), Some(50));
+    assert_eq!(fee.assess(200, TokenId::from(2)).unwrap().token_id, Some(TokenId::from(2)));
+}
+
+#[test]
+fn royalty_fee_assess() {
+    let fee = AnyCustomFee {
+        fee: RoyaltyFeeData { fraction: crate::Fraction::new(1, 10).unwrap(), fallback_fee: None }
+            .into(),
+        fee_collector_account_id: Some(AccountId::from(1)),
+        all_collectors_are_exempt: false,
+    };
+
+    assert_eq!(fee.assess(1000, TokenId::from(2)), None);
+}
+
+#[cfg(feature = "mirror-rest")]
+#[test]
+fn list_from_mirror_json() -> anyhow::Result<()> {
+    let json = serde_json::json!({
+        "created_timestamp": "1714564800.000000001",
+        "fixed_fees": [
+            {
+                "all_collectors_are_exempt": false,
+                "amount": 100,
+                "collector_account_id": "0.0.99",
+                "denominating_token_id": "0.0.800"
+            }
+        ],
+        "fractional_fees": [
+            {
+                "all_collectors_are_exempt": true,
+                "amount": { "numerator": 12, "denominator": 30 },
+                "collector_account_id": "0.0.98",
+                "denominating_token_id": "0.0.801",
+                "maximum": null,
+                "minimum": 1,
+                "net_of_transfers": true
+            }
+        ],
+        "royalty_fees": [
+            {
+                "all_collectors_are_exempt": false,
+                "amount": { "numerator": 1, "denominator": 20 },
+                "collector_account_id": "0.0.97",
+                "fallback_fee": { "amount": 11, "denominating_token_id": null }
+            }
+        ]
+    });
+
+    let fees = AnyCustomFee::list_from_mirror_json(&json)?;
+
+    assert_eq!(
+        fees,
+        [
+            AnyCustomFee {
+                fee: FixedFeeData { amount: 100, denominating_token_id: Some(TokenId::from(800)) }
+                    .into(),
+                fee_collector_account_id: Some(AccountId::from(99)),
+                all_collectors_are_exempt: false,
+            },
+            AnyCustomFee {
+                fee: FractionalFeeData {
+                    fraction: crate::Fraction::new(2, 5)?,
+                    minimum_amount: 1,
+                    maximum_amount: 0,
+                    assessment_method: super::FeeAssessmentMethod::Exclusive,
+                }
+                .into(),
+                fee_collector_account_id: Some(AccountId::from(98)),
+                all_collectors_are_exempt: true,
+            },
+            AnyCustomFee {
+                fee: RoyaltyFeeData {
+                    fraction: crate::Fraction::new(1, 20)?,
+                    fallback_fee: Some(FixedFeeData { amount: 11, denominating_token_id: None }),
+                }
+                .into(),
+                fee_collector_account_id: Some(AccountId::from(97)),
+                all_collectors_are_exempt: false,
+            },
+        ]
+    );
 
     Ok(())
 }
@@ -24,6 +24,7 @@ mod nft_id;
 mod token_airdrop_transaction;
 mod token_associate_transaction;
 mod token_association;
+mod token_association_change;
 mod token_burn_transaction;
 mod token_cancel_airdrop_transaction;
 mod token_claim_airdrop_transaction;
@@ -61,6 +62,7 @@ pub use custom_fees::{
     FeeAssessmentMethod,
     FixedFee,
     FixedFeeData,
+    Fraction,
     FractionalFee,
     FractionalFeeData,
     RoyaltyFee,
@@ -164,3 +166,14 @@ pub use token_wipe_transaction::{
     TokenWipeTransaction,
     TokenWipeTransactionData,
 };
+
+/// The most NFTs a single mint, burn, or wipe transaction may list (the network's `tokens.nfts.maxBatchSize*`).
+pub(crate) const MAX_NFTS_PER_TRANSACTION: usize = 10;
+
+/// Checks that a mint, burn, or wipe transaction lists at most [`MAX_NFTS_PER_TRANSACTION`] NFTs.
+pub(crate) fn validate_nft_batch_size(count: usize) -> crate::Result<()> {
+    match count <= MAX_NFTS_PER_TRANSACTION {
+        true => Ok(()),
+        false => Err(crate::Error::NftBatchSizeExceeded { count, max: MAX_NFTS_PER_TRANSACTION }),
+    }
+}
@@ -22,7 +22,6 @@ use std::collections::HashMap;
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use super::{
     NftId,
@@ -46,6 +45,7 @@ use crate::transfer_transaction::{
     TokenTransfer,
     Transfer,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -20,10 +20,13 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
+use crate::token::token_association_change::{
+    execute_idempotent,
+    TokenAssociationChangeData,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -32,13 +35,17 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
+    Status,
     ToProtobuf,
     TokenId,
     Transaction,
+    TransactionReceipt,
     ValidateChecksums,
 };
 
@@ -90,6 +97,39 @@ impl TokenAssociateTransaction {
         self.data_mut().token_ids = token_ids.into_iter().collect();
         self
     }
+
+    /// Execute this transaction and wait for its receipt, treating `TOKEN_ALREADY_ASSOCIATED_TO_ACCOUNT` as success.
+    ///
+    /// This makes it safe to re-run, for instance after a partial failure.
+    /// If any token is already associated the network rejects the *whole* transaction,
+    /// so when there are multiple tokens each one is retried in its own transaction.
+    ///
+    /// Returns the receipts of every transaction that was executed, the last of which is the final result.
+    ///
+    /// Use [`TransactionResponse::get_receipt_accepting`](crate::TransactionResponse::get_receipt_accepting)
+    /// to choose which statuses are treated as success.
+    ///
+    /// # Errors
+    /// - Any error that [`execute`](Self::execute) can return.
+    /// - [`Error::ReceiptStatus`] for a failing receipt with any other status.
+    pub async fn execute_idempotent(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<Vec<TransactionReceipt>> {
+        execute_idempotent(self, client).await
+    }
+}
+
+impl TokenAssociationChangeData for TokenAssociateTransactionData {
+    const ALREADY_DONE: Status = Status::TokenAlreadyAssociatedToAccount;
+
+    fn token_ids(&self) -> &[TokenId] {
+        &self.token_ids
+    }
+
+    fn with_token(&self, token_id: TokenId) -> Self {
+        Self { account_id: self.account_id, token_ids: Vec::from([token_id]) }
+    }
 }
 
 impl TransactionData for TokenAssociateTransactionData {}
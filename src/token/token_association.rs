@@ -29,6 +29,7 @@ use crate::{
 
 /// A token <-> account association.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct TokenAssociation {
     /// The token involved in the association.
     pub token_id: TokenId,
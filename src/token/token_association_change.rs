@@ -0,0 +1,97 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    Client,
+    Status,
+    TokenId,
+    Transaction,
+    TransactionReceipt,
+};
+
+/// The data of a transaction that associates or dissociates an account and a list of tokens.
+pub(super) trait TokenAssociationChangeData: TransactionExecute + Default {
+    /// The status the network fails the *whole* transaction with when any token is already associated (or dissociated).
+    const ALREADY_DONE: Status;
+
+    /// Returns the tokens whose association changes.
+    fn token_ids(&self) -> &[TokenId];
+
+    /// Returns a copy of `self` that only changes the association of `token_id`.
+    fn with_token(&self, token_id: TokenId) -> Self;
+}
+
+/// Executes `tx` and waits for its receipt, treating [`D::ALREADY_DONE`](TokenAssociationChangeData::ALREADY_DONE) as success.
+///
+/// When `tx` has multiple tokens and fails that way, each token is retried in its own transaction.
+pub(super) async fn execute_idempotent<D: TokenAssociationChangeData>(
+    tx: &mut Transaction<D>,
+    client: &Client,
+) -> crate::Result<Vec<TransactionReceipt>> {
+    let accepted = &[D::ALREADY_DONE];
+
+    let receipt = tx.execute(client).await?.get_receipt_accepting(client, accepted).await?;
+
+    let token_ids = tx.data().token_ids().to_vec();
+
+    if receipt.status != D::ALREADY_DONE || token_ids.len() <= 1 {
+        return Ok(Vec::from([receipt]));
+    }
+
+    let mut receipts = Vec::with_capacity(token_ids.len() + 1);
+    receipts.push(receipt);
+
+    for token_id in token_ids {
+        let receipt = single_token(tx, token_id)
+            .execute(client)
+            .await?
+            .get_receipt_accepting(client, accepted)
+            .await?;
+
+        receipts.push(receipt);
+    }
+
+    Ok(receipts)
+}
+
+/// Returns a new transaction for just `token_id`, with the same account, nodes, and signers as `tx`.
+fn single_token<D: TokenAssociationChangeData>(
+    tx: &Transaction<D>,
+    token_id: TokenId,
+) -> Transaction<D> {
+    let mut single = Transaction::new();
+
+    *single.data_mut() = tx.data().with_token(token_id);
+
+    if let Some(node_account_ids) = tx.get_node_account_ids() {
+        single.node_account_ids(node_account_ids.to_vec());
+    }
+
+    if let Some(max_transaction_fee) = tx.get_max_transaction_fee() {
+        single.max_transaction_fee(max_transaction_fee);
+    }
+
+    for signer in tx.signers() {
+        single.sign_signer(signer.clone());
+    }
+
+    single
+}
@@ -20,12 +20,15 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::token::{
+    validate_nft_batch_size,
+    MAX_NFTS_PER_TRANSACTION,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -34,11 +37,15 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
+    LedgerId,
     TokenId,
     Transaction,
+    TransactionResponse,
     ValidateChecksums,
 };
 
@@ -80,6 +87,9 @@ pub struct TokenBurnTransactionData {
 }
 
 impl TokenBurnTransaction {
+    /// The most serial numbers a single transaction may list.
+    pub const MAX_SERIALS: usize = MAX_NFTS_PER_TRANSACTION;
+
     /// Returns the token for which to burn tokens.
     #[must_use]
     pub fn get_token_id(&self) -> Option<TokenId> {
@@ -115,9 +125,42 @@ impl TokenBurnTransaction {
         self.data_mut().serials = serials.into_iter().collect();
         self
     }
+
+    /// Executes this transaction, split into as many transactions as needed to list at most
+    /// [`MAX_SERIALS`](Self::MAX_SERIALS) serial numbers each.
+    ///
+    /// Each transaction is a copy of `self` with a consecutive batch of the [`serials`](Self::serials),
+    /// so a fungible amount is burned by a single transaction.
+    /// If a transaction ID was set explicitly, it's used for the first transaction,
+    /// and every later one gets a new one for the same payer.
+    ///
+    /// # Errors
+    /// - Any error [`execute`](Self::execute) can return, for any of the transactions.
+    ///   Transactions submitted before the error aren't undone.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub async fn execute_batched(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.execute_in_batches(
+            client,
+            &self.data().serials,
+            Self::MAX_SERIALS,
+            |transaction, serials| {
+                transaction.serials(serials.iter().copied());
+            },
+        )
+        .await
+    }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        validate_nft_batch_size(self.serials.len())
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
@@ -184,6 +227,7 @@ impl ToProtobuf for TokenBurnTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect_file;
     use hedera_proto::services;
 
@@ -199,6 +243,7 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
         TokenBurnTransaction,
     };
 
@@ -302,4 +347,20 @@ mod tests {
 
         assert_eq!(tx.get_serials(), serials);
     }
+
+    #[test]
+    fn freeze_max_serials() {
+        let mut tx = TokenBurnTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID).serials(1..=TokenBurnTransaction::MAX_SERIALS as i64);
+
+        tx.freeze().unwrap();
+    }
+
+    #[test]
+    fn freeze_too_many_serials() {
+        let mut tx = TokenBurnTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID).serials(1..=TokenBurnTransaction::MAX_SERIALS as i64 + 1);
+
+        assert_matches!(tx.freeze(), Err(Error::NftBatchSizeExceeded { count: 11, max: 10 }));
+    }
 }
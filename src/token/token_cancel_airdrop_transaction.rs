@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::pending_airdrop_id::PendingAirdropId;
@@ -32,6 +31,7 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
@@ -24,14 +24,17 @@ use time::{
     Duration,
     OffsetDateTime,
 };
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::token::custom_fees::AnyCustomFee;
+use crate::token::custom_fees::{
+    validate_custom_fees,
+    validate_denominating_tokens,
+    AnyCustomFee,
+};
 use crate::token::token_supply_type::TokenSupplyType;
 use crate::token::token_type::TokenType;
 use crate::transaction::{
@@ -42,11 +45,14 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
     Key,
+    LedgerId,
     Transaction,
     ValidateChecksums,
 };
@@ -437,6 +443,20 @@ impl TokenCreateTransaction {
         self
     }
 
+    /// Checks that every token the custom fees are denominated in exists.
+    ///
+    /// This is optional, the other limits on custom fees are checked when the transaction is frozen.
+    /// Each denominating token is looked up with a [`TokenInfoQuery`](crate::TokenInfoQuery),
+    /// which is paid for by the client's operator.
+    ///
+    /// # Errors
+    /// - [`Error::CustomFeeValidation`] with [`CustomFeeValidationError::DenominatingTokenNotFound`](crate::CustomFeeValidationError::DenominatingTokenNotFound)
+    ///   for the first fee whose denominating token doesn't exist.
+    /// - Any error [`TokenInfoQuery::execute`](crate::TokenInfoQuery) can return.
+    pub async fn validate_denominating_tokens(&self, client: &Client) -> crate::Result<()> {
+        validate_denominating_tokens(&self.data().custom_fees, client).await
+    }
+
     /// Returns the key which can pause and unpause the token.
     #[must_use]
     pub fn get_pause_key(&self) -> Option<&Key> {
@@ -478,6 +498,10 @@ impl TransactionData for TokenCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::from_unit(40, crate::HbarUnit::Hbar)
     }
+
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        validate_custom_fees(&self.custom_fees, Some(self.token_type))
+    }
 }
 
 impl TransactionExecute for TokenCreateTransactionData {
@@ -619,6 +643,7 @@ impl ToProtobuf for TokenCreateTransactionData {
 mod tests {
     use std::str::FromStr;
 
+    use assert_matches::assert_matches;
     use expect_test::expect_file;
     use hedera_proto::services;
     use time::OffsetDateTime;
@@ -638,10 +663,17 @@ mod tests {
         AccountId,
         AnyCustomFee,
         AnyTransaction,
+        CustomFeeValidationError,
+        Error,
         FixedFee,
         FixedFeeData,
+        Fraction,
+        FractionalFee,
+        FractionalFeeData,
         Key,
         PublicKey,
+        RoyaltyFee,
+        RoyaltyFeeData,
         TokenCreateTransaction,
         TokenId,
         TokenSupplyType,
@@ -1185,4 +1217,118 @@ mod tests {
         let mut tx = make_transaction();
         tx.metadata_key(key());
     }
+
+    fn fractional_fee(minimum_amount: i64, maximum_amount: i64) -> AnyCustomFee {
+        FractionalFee {
+            fee: FractionalFeeData {
+                fraction: Fraction::new(3, 7).unwrap(),
+                minimum_amount,
+                maximum_amount,
+                assessment_method: crate::FeeAssessmentMethod::Exclusive,
+            },
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5005)),
+            all_collectors_are_exempt: false,
+        }
+        .into()
+    }
+
+    #[track_caller]
+    fn assert_invalid_fees(
+        token_type: TokenType,
+        fees: impl IntoIterator<Item = AnyCustomFee>,
+        expected: CustomFeeValidationError,
+    ) {
+        let mut tx = TokenCreateTransaction::new_for_tests();
+        tx.token_type(token_type).custom_fees(fees);
+
+        assert_matches!(tx.freeze(), Err(Error::CustomFeeValidation(e)) if *e == expected);
+    }
+
+    #[test]
+    fn freeze_max_custom_fees() {
+        let mut tx = TokenCreateTransaction::new_for_tests();
+        tx.custom_fees(std::iter::repeat_with(|| fractional_fee(1, 10)).take(10));
+
+        tx.freeze().unwrap();
+    }
+
+    #[test]
+    fn freeze_too_many_custom_fees() {
+        assert_invalid_fees(
+            TokenType::FungibleCommon,
+            std::iter::repeat_with(|| fractional_fee(1, 10)).take(11),
+            CustomFeeValidationError::TooManyFees { count: 11, max: 10 },
+        );
+    }
+
+    #[test]
+    fn freeze_non_positive_fixed_fee() {
+        let fee = FixedFee {
+            fee: FixedFeeData { amount: 0, denominating_token_id: None },
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5005)),
+            all_collectors_are_exempt: false,
+        };
+
+        assert_invalid_fees(
+            TokenType::FungibleCommon,
+            [fractional_fee(1, 10), fee.into()],
+            CustomFeeValidationError::NonPositiveAmount { index: 1 },
+        );
+    }
+
+    #[test]
+    fn freeze_fractional_fee_maximum_less_than_minimum() {
+        assert_invalid_fees(
+            TokenType::FungibleCommon,
+            [fractional_fee(3, 2)],
+            CustomFeeValidationError::MaximumLessThanMinimum { index: 0 },
+        );
+    }
+
+    #[test]
+    fn freeze_fractional_fee_no_maximum() {
+        let mut tx = TokenCreateTransaction::new_for_tests();
+        tx.custom_fees([fractional_fee(3, 0)]);
+
+        tx.freeze().unwrap();
+    }
+
+    #[test]
+    fn freeze_fractional_fee_on_nft() {
+        assert_invalid_fees(
+            TokenType::NonFungibleUnique,
+            [fractional_fee(1, 10)],
+            CustomFeeValidationError::FractionalFeeOnNonFungibleToken { index: 0 },
+        );
+    }
+
+    #[test]
+    fn freeze_royalty_fee_on_fungible_token() {
+        let fee = RoyaltyFee {
+            fee: RoyaltyFeeData { fraction: Fraction::new(1, 10).unwrap(), fallback_fee: None },
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5005)),
+            all_collectors_are_exempt: false,
+        };
+
+        assert_invalid_fees(
+            TokenType::FungibleCommon,
+            [fee.into()],
+            CustomFeeValidationError::RoyaltyFeeOnFungibleToken { index: 0 },
+        );
+    }
+
+    #[test]
+    fn freeze_royalty_fee_exceeds_one() {
+        let fee = RoyaltyFee {
+            fee: RoyaltyFeeData { fraction: Fraction::new(2, 1).unwrap(), fallback_fee: None },
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5005)),
+            all_collectors_are_exempt: false,
+        };
+
+        assert_invalid_fees(
+            TokenType::NonFungibleUnique,
+            [fee.into()],
+            CustomFeeValidationError::RoyaltyFractionExceedsOne { index: 0 },
+        );
+    }
 }
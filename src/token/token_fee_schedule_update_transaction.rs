@@ -20,13 +20,16 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::token::custom_fees::AnyCustomFee;
+use crate::token::custom_fees::{
+    validate_custom_fees,
+    validate_denominating_tokens,
+    AnyCustomFee,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -35,9 +38,12 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
+    LedgerId,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -90,9 +96,28 @@ impl TokenFeeScheduleUpdateTransaction {
         self.data_mut().custom_fees = custom_fees.into_iter().collect();
         self
     }
+
+    /// Checks that every token the custom fees are denominated in exists.
+    ///
+    /// This is optional, the other limits on custom fees are checked when the transaction is frozen.
+    /// Each denominating token is looked up with a [`TokenInfoQuery`](crate::TokenInfoQuery),
+    /// which is paid for by the client's operator.
+    ///
+    /// # Errors
+    /// - [`Error::CustomFeeValidation`] with [`CustomFeeValidationError::DenominatingTokenNotFound`](crate::CustomFeeValidationError::DenominatingTokenNotFound)
+    ///   for the first fee whose denominating token doesn't exist.
+    /// - Any error [`TokenInfoQuery::execute`](crate::TokenInfoQuery) can return.
+    pub async fn validate_denominating_tokens(&self, client: &Client) -> crate::Result<()> {
+        validate_denominating_tokens(&self.data().custom_fees, client).await
+    }
 }
 
-impl TransactionData for TokenFeeScheduleUpdateTransactionData {}
+impl TransactionData for TokenFeeScheduleUpdateTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        // the type of the token isn't known here, so fee types aren't checked against it.
+        validate_custom_fees(&self.custom_fees, None)
+    }
+}
 
 impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
     fn execute(
@@ -198,8 +223,7 @@ mod tests {
             .into(),
             FractionalFee {
                 fee: crate::FractionalFeeData {
-                    denominator: 7,
-                    numerator: 3,
+                    fraction: crate::Fraction::new(3, 7).unwrap(),
                     minimum_amount: 3,
                     maximum_amount: 100,
                     assessment_method: crate::FeeAssessmentMethod::Exclusive,
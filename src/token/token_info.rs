@@ -29,10 +29,13 @@ use time::{
     OffsetDateTime,
 };
 
+use crate::compat::CompatWarnings;
 use crate::protobuf::ToProtobuf;
 use crate::token::custom_fees::AnyCustomFee;
 use crate::{
     AccountId,
+    AssessedCustomFee,
+    CompatWarning,
     FromProtobuf,
     Key,
     LedgerId,
@@ -43,6 +46,7 @@ use crate::{
 
 /// Response from [`TokenInfoQuery`][crate::TokenInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct TokenInfo {
     /// The ID of the token for which information is requested.
     pub token_id: TokenId,
@@ -97,6 +101,7 @@ pub struct TokenInfo {
     pub auto_renew_period: Option<Duration>,
 
     /// The epoch second at which the token will expire
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339::option"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The memo associated with the token
@@ -124,11 +129,18 @@ pub struct TokenInfo {
     pub ledger_id: LedgerId,
 
     /// Represents the metadata of the token definition.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub metadata: Vec<u8>,
 
     /// The key which can change the metadata of a token
     /// (token definition and individual NFTs).
     pub metadata_key: Option<Key>,
+
+    /// The fields of the response that this SDK doesn't fully understand.
+    ///
+    /// A non-empty list suggests that the SDK is out of date with the network.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub warnings: Vec<CompatWarning>,
 }
 
 impl TokenInfo {
@@ -146,6 +158,45 @@ impl TokenInfo {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns the accounts that collect this token's custom fees, in the order the fees are listed.
+    #[must_use]
+    pub fn fee_collector_account_ids(&self) -> Vec<AccountId> {
+        let mut collectors = Vec::new();
+
+        for id in self.custom_fees.iter().filter_map(|it| it.fee_collector_account_id) {
+            if !collectors.contains(&id) {
+                collectors.push(id);
+            }
+        }
+
+        collectors
+    }
+
+    /// Returns the fee collectors that are exempt from *every* custom fee of this token.
+    ///
+    /// A collector is always exempt from its own fee,
+    /// and is exempt from other fees that have [`all_collectors_are_exempt`](crate::CustomFee::all_collectors_are_exempt) set.
+    #[must_use]
+    pub fn exempt_fee_collector_account_ids(&self) -> Vec<AccountId> {
+        self.fee_collector_account_ids()
+            .into_iter()
+            .filter(|id| {
+                self.custom_fees.iter().all(|fee| {
+                    fee.all_collectors_are_exempt || fee.fee_collector_account_id == Some(*id)
+                })
+            })
+            .collect()
+    }
+
+    /// Estimates the custom fees assessed when `amount` units of this token are transferred by an account that isn't exempt.
+    ///
+    /// Royalty fees only apply to NFTs and depend on the value exchanged, so they aren't included.
+    /// The `payer_account_id_list` of each returned fee is empty, as the payer isn't known.
+    #[must_use]
+    pub fn estimated_custom_fees_for_transfer(&self, amount: u64) -> Vec<AssessedCustomFee> {
+        self.custom_fees.iter().filter_map(|it| it.assess(amount, self.token_id)).collect()
+    }
 }
 
 impl FromProtobuf<services::response::Response> for TokenInfo {
@@ -165,6 +216,13 @@ impl FromProtobuf<services::TokenInfo> for TokenInfo {
     where
         Self: Sized,
     {
+        let mut warnings = CompatWarnings::new("TokenInfo");
+        warnings.enum_value::<services::TokenType>("token_type", pb.token_type);
+        warnings.enum_value::<services::TokenSupplyType>("supply_type", pb.supply_type);
+        warnings.enum_value::<TokenKycStatus>("default_kyc_status", pb.default_kyc_status);
+        warnings.enum_value::<TokenFreezeStatus>("default_freeze_status", pb.default_freeze_status);
+        warnings.enum_value::<TokenPauseStatus>("pause_status", pb.pause_status);
+
         let token_type = TokenType::from_protobuf(pb.token_type())?;
         let token_supply_type = TokenSupplyType::from_protobuf(pb.supply_type())?;
         let token_id = pb_getf!(pb, token_id)?;
@@ -232,6 +290,7 @@ impl FromProtobuf<services::TokenInfo> for TokenInfo {
             ledger_id,
             metadata: pb.metadata,
             metadata_key: Option::from_protobuf(pb.metadata_key)?,
+            warnings: warnings.finish(),
         })
     }
 }
@@ -304,6 +363,9 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        AccountId,
+        AssessedCustomFee,
+        CompatWarning,
         FixedFee,
         FractionalFee,
         LedgerId,
@@ -333,8 +395,7 @@ mod tests {
                 fee_collector_account_id: Some("389042".parse().unwrap()),
                 all_collectors_are_exempt: false,
                 fee: crate::FractionalFeeData {
-                    denominator: 7,
-                    numerator: 3,
+                    fraction: crate::Fraction::new(3, 7).unwrap(),
                     minimum_amount: 3,
                     maximum_amount: 100,
                     assessment_method: crate::FeeAssessmentMethod::Inclusive,
@@ -372,7 +433,8 @@ mod tests {
             pause_status:Some(true),
             ledger_id: LedgerId::mainnet(),
             metadata: metadata(),
-            metadata_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e18").unwrap().public_key().into())
+            metadata_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e18").unwrap().public_key().into()),
+            warnings: Vec::new(),
         }
     }
 
@@ -399,4 +461,73 @@ mod tests {
 
         expect_file!["./snapshots/token_info/to_protobuf.txt"].assert_debug_eq(&token_info)
     }
+
+    #[test]
+    fn unknown_enum_value_warnings() {
+        let mut pb = make_token_info().to_protobuf();
+        pb.pause_status = 42;
+
+        let info = TokenInfo::from_protobuf(pb).unwrap();
+
+        assert_eq!(info.pause_status, None);
+        assert_eq!(
+            info.warnings,
+            [CompatWarning::UnknownEnumValue { field: "pause_status", value: 42 }]
+        );
+    }
+
+    #[test]
+    fn fee_collector_account_ids() {
+        let mut info = make_token_info();
+
+        assert_eq!(
+            info.fee_collector_account_ids(),
+            [AccountId::from(4322), AccountId::from(389042)]
+        );
+
+        assert_eq!(info.exempt_fee_collector_account_ids(), []);
+
+        info.custom_fees[0].all_collectors_are_exempt = true;
+
+        // 4322 is exempt from its own fee, and every collector is exempt from the first fee.
+        assert_eq!(info.exempt_fee_collector_account_ids(), [AccountId::from(389042)]);
+
+        info.custom_fees[1].all_collectors_are_exempt = true;
+
+        assert_eq!(
+            info.exempt_fee_collector_account_ids(),
+            [AccountId::from(4322), AccountId::from(389042)]
+        );
+    }
+
+    #[test]
+    fn estimated_custom_fees_for_transfer() {
+        let info = make_token_info();
+
+        let fees = |amount| {
+            info.estimated_custom_fees_for_transfer(amount)
+                .into_iter()
+                .map(|it| it.amount)
+                .collect::<Vec<_>>()
+        };
+
+        // 3/7ths of 100 is 42.
+        assert_eq!(fees(100), [10, 42]);
+
+        // clamped to the minimum.
+        assert_eq!(fees(1), [10, 3]);
+
+        // clamped to the maximum.
+        assert_eq!(fees(1000), [10, 100]);
+
+        assert_eq!(
+            info.estimated_custom_fees_for_transfer(100)[1],
+            AssessedCustomFee {
+                amount: 42,
+                token_id: Some(info.token_id),
+                fee_collector_account_id: Some(AccountId::from(389042)),
+                payer_account_id_list: Vec::new(),
+            }
+        );
+    }
 }
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -29,9 +28,11 @@ use crate::query::{
     ToQueryProtobuf,
 };
 use crate::token::token_info::TokenInfo;
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     TokenId,
@@ -81,6 +82,12 @@ impl ToQueryProtobuf for TokenInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::TokenGetInfoQuery> for TokenInfoQueryData {
+    fn from_protobuf(pb: services::TokenGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { token_id: Option::from_protobuf(pb.token)? })
+    }
+}
+
 impl QueryExecute for TokenInfoQueryData {
     type Response = TokenInfo;
 
@@ -20,13 +20,16 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::token::{
+    validate_nft_batch_size,
+    MAX_NFTS_PER_TRANSACTION,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -35,11 +38,15 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
+    Client,
     Error,
+    LedgerId,
     TokenId,
     Transaction,
+    TransactionResponse,
     ValidateChecksums,
 };
 
@@ -76,6 +83,9 @@ pub struct TokenMintTransactionData {
 }
 
 impl TokenMintTransaction {
+    /// The most metadata entries a single transaction may list.
+    pub const MAX_METADATA: usize = MAX_NFTS_PER_TRANSACTION;
+
     /// Returns the token for which to mint tokens.
     #[must_use]
     pub fn get_token_id(&self) -> Option<TokenId> {
@@ -116,9 +126,42 @@ impl TokenMintTransaction {
 
         self
     }
+
+    /// Executes this transaction, split into as many transactions as needed to list at most
+    /// [`MAX_METADATA`](Self::MAX_METADATA) metadata entries each.
+    ///
+    /// Each transaction is a copy of `self` with a consecutive batch of the [`metadata`](Self::metadata),
+    /// so a fungible amount is minted by a single transaction.
+    /// If a transaction ID was set explicitly, it's used for the first transaction,
+    /// and every later one gets a new one for the same payer.
+    ///
+    /// # Errors
+    /// - Any error [`execute`](Self::execute) can return, for any of the transactions.
+    ///   Transactions submitted before the error aren't undone.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub async fn execute_batched(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.execute_in_batches(
+            client,
+            &self.data().metadata,
+            Self::MAX_METADATA,
+            |transaction, metadata| {
+                transaction.metadata(metadata);
+            },
+        )
+        .await
+    }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+impl TransactionData for TokenMintTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        validate_nft_batch_size(self.metadata.len())
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
@@ -185,6 +228,7 @@ impl ToProtobuf for TokenMintTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services::TokenMintTransactionBody;
 
@@ -199,6 +243,7 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
         TokenId,
         TokenMintTransaction,
     };
@@ -372,4 +417,12 @@ mod tests {
         let mut tx = make_transaction();
         tx.metadata(metadata());
     }
+
+    #[test]
+    fn freeze_too_many_metadata() {
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID).metadata(vec![[1]; TokenMintTransaction::MAX_METADATA + 1]);
+
+        assert_matches!(tx.freeze(), Err(Error::NftBatchSizeExceeded { count: 11, max: 10 }));
+    }
 }
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -29,9 +28,11 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     NftId,
     ToProtobuf,
     TokenNftInfo,
@@ -81,6 +82,12 @@ impl ToQueryProtobuf for TokenNftInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::TokenGetNftInfoQuery> for TokenNftInfoQueryData {
+    fn from_protobuf(pb: services::TokenGetNftInfoQuery) -> crate::Result<Self> {
+        Ok(Self { nft_id: Option::from_protobuf(pb.nft_id)? })
+    }
+}
+
 impl QueryExecute for TokenNftInfoQueryData {
     type Response = TokenNftInfo;
 
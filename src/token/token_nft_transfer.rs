@@ -9,6 +9,7 @@ use crate::{
 /// Represents a transfer of an NFT from one account to another.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct TokenNftTransfer {
     /// The ID of the NFT's token.
     pub token_id: TokenId,
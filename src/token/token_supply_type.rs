@@ -30,6 +30,7 @@ use crate::{
 /// Defaults to [`Infinite`](Self::Infinite).
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub enum TokenSupplyType {
     /// Indicates the token has a maximum supply of [`u64::MAX`].
     Infinite = 0,
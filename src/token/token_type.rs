@@ -35,6 +35,7 @@ use crate::{
 ///
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub enum TokenType {
     /// Interchangeable value with one another, where any quantity of them has the same value as
     /// another equal quantity if they are in the same class.  Share a single set of properties, not
@@ -20,13 +20,16 @@
 
 use hedera_proto::services;
 use hedera_proto::services::token_service_client::TokenServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
+use crate::token::{
+    validate_nft_batch_size,
+    MAX_NFTS_PER_TRANSACTION,
+};
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
@@ -35,12 +38,16 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
+    LedgerId,
     TokenId,
     Transaction,
+    TransactionResponse,
     ValidateChecksums,
 };
 
@@ -91,6 +98,9 @@ pub struct TokenWipeTransactionData {
 }
 
 impl TokenWipeTransaction {
+    /// The most serial numbers a single transaction may list.
+    pub const MAX_SERIALS: usize = MAX_NFTS_PER_TRANSACTION;
+
     /// Returns the account to be wiped.
     #[must_use]
     pub fn get_account_id(&self) -> Option<AccountId> {
@@ -139,9 +149,42 @@ impl TokenWipeTransaction {
         self.data_mut().serials = serials.into_iter().collect();
         self
     }
+
+    /// Executes this transaction, split into as many transactions as needed to list at most
+    /// [`MAX_SERIALS`](Self::MAX_SERIALS) serial numbers each.
+    ///
+    /// Each transaction is a copy of `self` with a consecutive batch of the [`serials`](Self::serials),
+    /// so a fungible amount is wiped by a single transaction.
+    /// If a transaction ID was set explicitly, it's used for the first transaction,
+    /// and every later one gets a new one for the same payer.
+    ///
+    /// # Errors
+    /// - Any error [`execute`](Self::execute) can return, for any of the transactions.
+    ///   Transactions submitted before the error aren't undone.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub async fn execute_batched(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.execute_in_batches(
+            client,
+            &self.data().serials,
+            Self::MAX_SERIALS,
+            |transaction, serials| {
+                transaction.serials(serials.iter().copied());
+            },
+        )
+        .await
+    }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        validate_nft_batch_size(self.serials.len())
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
@@ -210,6 +253,7 @@ impl ToProtobuf for TokenWipeTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
@@ -224,6 +268,7 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Error,
         TokenId,
         TokenWipeTransaction,
     };
@@ -434,4 +479,14 @@ mod tests {
         let mut tx = make_transaction_nft();
         tx.serials(TEST_SERIALS);
     }
+
+    #[test]
+    fn freeze_too_many_serials() {
+        let mut tx = TokenWipeTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID)
+            .account_id(TEST_ACCOUNT_ID)
+            .serials(1..=TokenWipeTransaction::MAX_SERIALS as u64 + 1);
+
+        assert_matches!(tx.freeze(), Err(Error::NftBatchSizeExceeded { count: 11, max: 10 }));
+    }
 }
@@ -20,7 +20,6 @@
 
 use hedera_proto::services;
 use hedera_proto::services::consensus_service_client::ConsensusServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -28,9 +27,11 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     TopicId,
@@ -79,6 +80,12 @@ impl ToQueryProtobuf for TopicInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ConsensusGetTopicInfoQuery> for TopicInfoQueryData {
+    fn from_protobuf(pb: services::ConsensusGetTopicInfoQuery) -> crate::Result<Self> {
+        Ok(Self { topic_id: Option::from_protobuf(pb.topic_id)? })
+    }
+}
+
 impl QueryExecute for TopicInfoQueryData {
     type Response = TopicInfo;
 
@@ -35,7 +35,6 @@ use time::{
     Duration,
     OffsetDateTime,
 };
-use tonic::transport::Channel;
 use tonic::Response;
 
 use super::topic_message::{
@@ -48,9 +47,12 @@ use crate::mirror_query::{
     MirrorRequest,
 };
 use crate::protobuf::FromProtobuf;
+use crate::transport::Channel;
 use crate::{
     AnyMirrorQueryResponse,
+    Client,
     MirrorQuery,
+    MirrorQueryStream,
     ToProtobuf,
     TopicId,
     TopicMessage,
@@ -71,7 +73,11 @@ pub struct TopicMessageQueryContext {
 /// messages for an HCS Topic via a specific (possibly open-ended) time range.
 pub type TopicMessageQuery = MirrorQuery<TopicMessageQueryData>;
 
-#[derive(Debug, Default, Clone)]
+const DEFAULT_CHUNK_TIMEOUT: Duration = Duration::minutes(15);
+
+const DEFAULT_MAX_PENDING_MESSAGES: usize = 1000;
+
+#[derive(Debug, Clone)]
 pub struct TopicMessageQueryData {
     /// The topic ID to retrieve messages for.
     topic_id: Option<TopicId>,
@@ -85,14 +91,52 @@ pub struct TopicMessageQueryData {
 
     /// The maximum number of messages to receive before stopping.
     limit: u64,
+
+    /// Whether chunked messages are reassembled before being returned.
+    reassemble_chunks: bool,
+
+    /// How long to wait for the rest of a chunked message after its first chunk is received.
+    chunk_timeout: Duration,
+
+    /// The maximum number of chunked messages to buffer while waiting for the rest of their chunks.
+    max_pending_messages: usize,
+}
+
+impl Default for TopicMessageQueryData {
+    fn default() -> Self {
+        Self {
+            topic_id: None,
+            start_time: None,
+            end_time: None,
+            limit: 0,
+            reassemble_chunks: true,
+            chunk_timeout: DEFAULT_CHUNK_TIMEOUT,
+            max_pending_messages: DEFAULT_MAX_PENDING_MESSAGES,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ReassemblyOptions {
+    reassemble_chunks: bool,
+    chunk_timeout: Duration,
+    max_pending_messages: usize,
 }
 
 impl TopicMessageQueryData {
-    fn map_stream<'a, S>(stream: S) -> impl Stream<Item = crate::Result<TopicMessage>>
+    fn map_stream<'a, S>(&self, stream: S) -> impl Stream<Item = crate::Result<TopicMessage>>
     where
         S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send + 'a,
     {
-        MessagesMapStream { inner: stream, incomplete_messages: HashMap::new() }
+        MessagesMapStream {
+            inner: stream,
+            incomplete_messages: HashMap::new(),
+            options: ReassemblyOptions {
+                reassemble_chunks: self.reassemble_chunks,
+                chunk_timeout: self.chunk_timeout,
+                max_pending_messages: self.max_pending_messages,
+            },
+        }
     }
 }
 
@@ -146,6 +190,63 @@ impl TopicMessageQuery {
         self.data.limit = limit;
         self
     }
+
+    /// Returns whether chunked messages are reassembled before being returned.
+    #[must_use]
+    pub fn get_reassemble_chunks(&self) -> bool {
+        self.data.reassemble_chunks
+    }
+
+    /// Sets whether chunked messages are reassembled before being returned.
+    ///
+    /// Defaults to `true`: a message submitted in several chunks is returned once all of its chunks have been received.
+    ///
+    /// If `false`, every chunk is returned as soon as it's received, as its own [`TopicMessage`],
+    /// with [`transaction`](TopicMessage::transaction) set to the ID of the first chunk's transaction.
+    pub fn reassemble_chunks(&mut self, reassemble_chunks: bool) -> &mut Self {
+        self.data.reassemble_chunks = reassemble_chunks;
+        self
+    }
+
+    /// Returns how long to wait for the rest of a chunked message after its first chunk is received.
+    #[must_use]
+    pub fn get_chunk_timeout(&self) -> Duration {
+        self.data.chunk_timeout
+    }
+
+    /// Sets how long to wait for the rest of a chunked message after its first chunk is received.
+    ///
+    /// Chunks of a message that isn't complete in time are dropped.
+    ///
+    /// Defaults to 15 minutes.
+    pub fn chunk_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.data.chunk_timeout = timeout;
+        self
+    }
+
+    /// Returns the maximum number of chunked messages to buffer while waiting for the rest of their chunks.
+    #[must_use]
+    pub fn get_max_pending_messages(&self) -> usize {
+        self.data.max_pending_messages
+    }
+
+    /// Sets the maximum number of chunked messages to buffer while waiting for the rest of their chunks.
+    ///
+    /// When the limit is reached, the message that's closest to timing out is dropped to make room.
+    ///
+    /// Defaults to 1000.
+    pub fn max_pending_messages(&mut self, max_pending_messages: usize) -> &mut Self {
+        self.data.max_pending_messages = max_pending_messages;
+        self
+    }
+}
+
+impl MirrorQueryStream for TopicMessageQuery {
+    type Item = TopicMessage;
+
+    fn stream<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<TopicMessage>> {
+        self.subscribe(client)
+    }
 }
 
 impl From<TopicMessageQueryData> for AnyMirrorQueryData {
@@ -199,19 +300,19 @@ impl MirrorRequest for TopicMessageQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
-        Box::pin(Self::map_stream(stream))
+        Box::pin(self.map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         // this doesn't reuse the work in `make_item_stream`
-        Box::pin(Self::map_stream(stream).try_collect())
+        Box::pin(self.map_stream(stream).try_collect())
     }
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
@@ -256,6 +357,7 @@ pin_project_lite::pin_project! {
         #[pin]
         inner: S,
         incomplete_messages: HashMap<TransactionId, IncompleteMessage>,
+        options: ReassemblyOptions,
     }
 }
 
@@ -280,7 +382,7 @@ where
                 None => return Poll::Ready(None),
             };
 
-            match filter_map(item, this.incomplete_messages) {
+            match filter_map(item, this.incomplete_messages, *this.options) {
                 Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
                 Ok(None) => {}
                 Err(e) => return Poll::Ready(Some(Err(e))),
@@ -292,6 +394,7 @@ where
 fn filter_map(
     mut item: mirror::ConsensusTopicResponse,
     incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
+    options: ReassemblyOptions,
 ) -> crate::Result<Option<TopicMessage>> {
     let header = PbTopicMessageHeader {
         consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
@@ -316,12 +419,18 @@ fn filter_map(
 
     let tx_id = item.initial_transaction_id;
 
+    if !options.reassemble_chunks {
+        let mut message = TopicMessage::from_single(item.header);
+        message.transaction = Some(tx_id);
+        return Ok(Some(message));
+    }
+
+    if !incomplete_messages.contains_key(&tx_id) {
+        make_room(incomplete_messages, options.max_pending_messages);
+    }
+
     let entry = incomplete_messages.entry(tx_id).or_insert_with(|| {
-        IncompleteMessage::Partial(
-            // todo: configurable?
-            OffsetDateTime::now_utc() + time::Duration::minutes(15),
-            Vec::new(),
-        )
+        IncompleteMessage::Partial(OffsetDateTime::now_utc() + options.chunk_timeout, Vec::new())
     });
 
     let IncompleteMessage::Partial(_, messages) = entry.handle_expiry() else { return Ok(None) };
@@ -349,15 +458,140 @@ fn filter_map(
     }
 }
 
+/// Ensures there's room for one more partial message, expiring the one closest to timing out if needed.
+fn make_room(
+    incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
+    max_pending_messages: usize,
+) {
+    let mut pending = 0;
+    let mut oldest: Option<(OffsetDateTime, TransactionId)> = None;
+
+    for (tx_id, message) in incomplete_messages.iter_mut() {
+        if let IncompleteMessage::Partial(expiry, _) = message.handle_expiry() {
+            pending += 1;
+
+            match oldest {
+                Some((it, _)) if it <= *expiry => {}
+                _ => oldest = Some((*expiry, *tx_id)),
+            }
+        }
+    }
+
+    if pending < max_pending_messages {
+        return;
+    }
+
+    if let Some((_, tx_id)) = oldest {
+        log::warn!("too many incomplete chunked topic messages, dropping the chunks of `{tx_id}`");
+        incomplete_messages.insert(tx_id, IncompleteMessage::Expired);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use time::OffsetDateTime;
+    use std::collections::HashMap;
 
+    use hedera_proto::{
+        mirror,
+        services,
+    };
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        filter_map,
+        ReassemblyOptions,
+    };
     use crate::{
+        ToProtobuf,
         TopicId,
         TopicMessageQuery,
+        TransactionId,
     };
 
+    const OPTIONS: ReassemblyOptions = ReassemblyOptions {
+        reassemble_chunks: true,
+        chunk_timeout: Duration::minutes(15),
+        max_pending_messages: 2,
+    };
+
+    fn chunk(tx_id: TransactionId, number: i32, total: i32) -> mirror::ConsensusTopicResponse {
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(OffsetDateTime::now_utc().into()),
+            message: Vec::from([number as u8]),
+            running_hash: Vec::new(),
+            sequence_number: number as u64,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(tx_id.to_protobuf()),
+                total,
+                number,
+            }),
+        }
+    }
+
+    fn tx_id(num: u64) -> TransactionId {
+        TransactionId::generate(num.into())
+    }
+
+    #[test]
+    fn reassembles_chunks() {
+        let tx_id = tx_id(5005);
+        let mut incomplete_messages = HashMap::new();
+
+        assert!(filter_map(chunk(tx_id, 2, 2), &mut incomplete_messages, OPTIONS)
+            .unwrap()
+            .is_none());
+
+        let message =
+            filter_map(chunk(tx_id, 1, 2), &mut incomplete_messages, OPTIONS).unwrap().unwrap();
+
+        assert_eq!(message.contents, [1, 2]);
+        assert_eq!(message.chunks.unwrap().len(), 2);
+        assert_eq!(message.transaction, Some(tx_id));
+    }
+
+    #[test]
+    fn raw_chunks() {
+        let tx_id = tx_id(5005);
+        let mut incomplete_messages = HashMap::new();
+        let options = ReassemblyOptions { reassemble_chunks: false, ..OPTIONS };
+
+        let message =
+            filter_map(chunk(tx_id, 2, 2), &mut incomplete_messages, options).unwrap().unwrap();
+
+        assert_eq!(message.contents, [2]);
+        assert_eq!(message.transaction, Some(tx_id));
+        assert!(incomplete_messages.is_empty());
+    }
+
+    #[test]
+    fn drops_oldest_pending_message() {
+        let (first, second, third) = (tx_id(5005), tx_id(5006), tx_id(5007));
+        let mut incomplete_messages = HashMap::new();
+
+        // `first` is the closest to timing out.
+        for (tx_id, timeout) in [(first, 1), (second, 2), (third, 3)] {
+            let options =
+                ReassemblyOptions { chunk_timeout: Duration::minutes(timeout), ..OPTIONS };
+
+            assert!(filter_map(chunk(tx_id, 1, 2), &mut incomplete_messages, options)
+                .unwrap()
+                .is_none());
+        }
+
+        // `first` was dropped to make room for `third`, so completing it doesn't produce a message.
+        assert!(filter_map(chunk(first, 2, 2), &mut incomplete_messages, OPTIONS)
+            .unwrap()
+            .is_none());
+
+        assert!(filter_map(chunk(third, 2, 2), &mut incomplete_messages, OPTIONS)
+            .unwrap()
+            .is_some());
+    }
+
     #[test]
     fn get_set_topic_id() {
         let mut query = TopicMessageQuery::new();
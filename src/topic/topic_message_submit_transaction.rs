@@ -23,7 +23,6 @@ use std::num::NonZeroUsize;
 
 use hedera_proto::services;
 use hedera_proto::services::consensus_service_client::ConsensusServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
@@ -41,6 +40,7 @@ use crate::transaction::{
     TransactionExecute,
     TransactionExecuteChunked,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
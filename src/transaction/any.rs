@@ -19,7 +19,6 @@
  */
 
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use super::chunked::ChunkInfo;
 use super::{
@@ -35,10 +34,13 @@ use crate::transaction::{
     TransactionBody,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Error,
     Hbar,
+    LedgerId,
+    RequestType,
     Transaction,
     TransactionId,
 };
@@ -429,6 +431,114 @@ impl TransactionData for AnyTransactionData {
         }
     }
 
+    fn validate_data(&self, ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        match self {
+            Self::AccountCreate(it) => it.validate_data(ledger_id),
+            Self::AccountUpdate(it) => it.validate_data(ledger_id),
+            Self::AccountDelete(it) => it.validate_data(ledger_id),
+            Self::AccountAllowanceApprove(it) => it.validate_data(ledger_id),
+            Self::AccountAllowanceDelete(it) => it.validate_data(ledger_id),
+            Self::ContractCreate(it) => it.validate_data(ledger_id),
+            Self::ContractUpdate(it) => it.validate_data(ledger_id),
+            Self::ContractDelete(it) => it.validate_data(ledger_id),
+            Self::ContractExecute(it) => it.validate_data(ledger_id),
+            Self::Transfer(it) => it.validate_data(ledger_id),
+            Self::TopicCreate(it) => it.validate_data(ledger_id),
+            Self::TopicUpdate(it) => it.validate_data(ledger_id),
+            Self::TopicDelete(it) => it.validate_data(ledger_id),
+            Self::TopicMessageSubmit(it) => it.validate_data(ledger_id),
+            Self::FileAppend(it) => it.validate_data(ledger_id),
+            Self::FileCreate(it) => it.validate_data(ledger_id),
+            Self::FileUpdate(it) => it.validate_data(ledger_id),
+            Self::FileDelete(it) => it.validate_data(ledger_id),
+            Self::Prng(it) => it.validate_data(ledger_id),
+            Self::TokenAssociate(it) => it.validate_data(ledger_id),
+            Self::TokenBurn(it) => it.validate_data(ledger_id),
+            Self::TokenCreate(it) => it.validate_data(ledger_id),
+            Self::TokenDelete(it) => it.validate_data(ledger_id),
+            Self::TokenDissociate(it) => it.validate_data(ledger_id),
+            Self::TokenFeeScheduleUpdate(it) => it.validate_data(ledger_id),
+            Self::TokenFreeze(it) => it.validate_data(ledger_id),
+            Self::TokenGrantKyc(it) => it.validate_data(ledger_id),
+            Self::TokenMint(it) => it.validate_data(ledger_id),
+            Self::TokenPause(it) => it.validate_data(ledger_id),
+            Self::TokenRevokeKyc(it) => it.validate_data(ledger_id),
+            Self::TokenUnfreeze(it) => it.validate_data(ledger_id),
+            Self::TokenUnpause(it) => it.validate_data(ledger_id),
+            Self::TokenUpdate(it) => it.validate_data(ledger_id),
+            Self::TokenWipe(it) => it.validate_data(ledger_id),
+            Self::SystemDelete(it) => it.validate_data(ledger_id),
+            Self::SystemUndelete(it) => it.validate_data(ledger_id),
+            Self::Freeze(it) => it.validate_data(ledger_id),
+            Self::ScheduleCreate(it) => it.validate_data(ledger_id),
+            Self::ScheduleSign(it) => it.validate_data(ledger_id),
+            Self::ScheduleDelete(it) => it.validate_data(ledger_id),
+            Self::Ethereum(it) => it.validate_data(ledger_id),
+            Self::TokenUpdateNfts(it) => it.validate_data(ledger_id),
+            Self::NodeCreate(it) => it.validate_data(ledger_id),
+            Self::NodeUpdate(it) => it.validate_data(ledger_id),
+            Self::NodeDelete(it) => it.validate_data(ledger_id),
+            Self::TokenReject(it) => it.validate_data(ledger_id),
+            Self::TokenAirdrop(it) => it.validate_data(ledger_id),
+            Self::TokenClaimAirdrop(it) => it.validate_data(ledger_id),
+            Self::TokenCancelAirdrop(it) => it.validate_data(ledger_id),
+        }
+    }
+
+    fn deprecated_field(&self) -> Option<&'static str> {
+        match self {
+            Self::AccountCreate(it) => it.deprecated_field(),
+            Self::AccountUpdate(it) => it.deprecated_field(),
+            Self::AccountDelete(it) => it.deprecated_field(),
+            Self::AccountAllowanceApprove(it) => it.deprecated_field(),
+            Self::AccountAllowanceDelete(it) => it.deprecated_field(),
+            Self::ContractCreate(it) => it.deprecated_field(),
+            Self::ContractUpdate(it) => it.deprecated_field(),
+            Self::ContractDelete(it) => it.deprecated_field(),
+            Self::ContractExecute(it) => it.deprecated_field(),
+            Self::Transfer(it) => it.deprecated_field(),
+            Self::TopicCreate(it) => it.deprecated_field(),
+            Self::TopicUpdate(it) => it.deprecated_field(),
+            Self::TopicDelete(it) => it.deprecated_field(),
+            Self::TopicMessageSubmit(it) => it.deprecated_field(),
+            Self::FileAppend(it) => it.deprecated_field(),
+            Self::FileCreate(it) => it.deprecated_field(),
+            Self::FileUpdate(it) => it.deprecated_field(),
+            Self::FileDelete(it) => it.deprecated_field(),
+            Self::Prng(it) => it.deprecated_field(),
+            Self::TokenAssociate(it) => it.deprecated_field(),
+            Self::TokenBurn(it) => it.deprecated_field(),
+            Self::TokenCreate(it) => it.deprecated_field(),
+            Self::TokenDelete(it) => it.deprecated_field(),
+            Self::TokenDissociate(it) => it.deprecated_field(),
+            Self::TokenFeeScheduleUpdate(it) => it.deprecated_field(),
+            Self::TokenFreeze(it) => it.deprecated_field(),
+            Self::TokenGrantKyc(it) => it.deprecated_field(),
+            Self::TokenMint(it) => it.deprecated_field(),
+            Self::TokenPause(it) => it.deprecated_field(),
+            Self::TokenRevokeKyc(it) => it.deprecated_field(),
+            Self::TokenUnfreeze(it) => it.deprecated_field(),
+            Self::TokenUnpause(it) => it.deprecated_field(),
+            Self::TokenUpdate(it) => it.deprecated_field(),
+            Self::TokenWipe(it) => it.deprecated_field(),
+            Self::SystemDelete(it) => it.deprecated_field(),
+            Self::SystemUndelete(it) => it.deprecated_field(),
+            Self::Freeze(it) => it.deprecated_field(),
+            Self::ScheduleCreate(it) => it.deprecated_field(),
+            Self::ScheduleSign(it) => it.deprecated_field(),
+            Self::ScheduleDelete(it) => it.deprecated_field(),
+            Self::Ethereum(it) => it.deprecated_field(),
+            Self::TokenUpdateNfts(it) => it.deprecated_field(),
+            Self::NodeCreate(it) => it.deprecated_field(),
+            Self::NodeUpdate(it) => it.deprecated_field(),
+            Self::NodeDelete(it) => it.deprecated_field(),
+            Self::TokenReject(it) => it.deprecated_field(),
+            Self::TokenAirdrop(it) => it.deprecated_field(),
+            Self::TokenClaimAirdrop(it) => it.deprecated_field(),
+            Self::TokenCancelAirdrop(it) => it.deprecated_field(),
+        }
+    }
+
     fn wait_for_receipt(&self) -> bool {
         match self {
             Self::AccountCreate(it) => it.wait_for_receipt(),
@@ -697,6 +807,61 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
 }
 
 impl AnyTransactionData {
+    /// Returns the kind of transaction `self` is, as the network sees it.
+    pub(crate) fn request_type(&self) -> RequestType {
+        match self {
+            Self::AccountCreate(_) => RequestType::CryptoCreate,
+            Self::AccountUpdate(_) => RequestType::CryptoUpdate,
+            Self::AccountDelete(_) => RequestType::CryptoDelete,
+            Self::AccountAllowanceApprove(_) => RequestType::CryptoApproveAllowance,
+            Self::AccountAllowanceDelete(_) => RequestType::CryptoDeleteAllowance,
+            Self::ContractCreate(_) => RequestType::ContractCreate,
+            Self::ContractUpdate(_) => RequestType::ContractUpdate,
+            Self::ContractDelete(_) => RequestType::ContractDelete,
+            Self::ContractExecute(_) => RequestType::ContractCall,
+            Self::Transfer(_) => RequestType::CryptoTransfer,
+            Self::TopicCreate(_) => RequestType::ConsensusCreateTopic,
+            Self::TopicUpdate(_) => RequestType::ConsensusUpdateTopic,
+            Self::TopicDelete(_) => RequestType::ConsensusDeleteTopic,
+            Self::TopicMessageSubmit(_) => RequestType::ConsensusSubmitMessage,
+            Self::FileAppend(_) => RequestType::FileAppend,
+            Self::FileCreate(_) => RequestType::FileCreate,
+            Self::FileUpdate(_) => RequestType::FileUpdate,
+            Self::FileDelete(_) => RequestType::FileDelete,
+            Self::Prng(_) => RequestType::UtilPrng,
+            Self::ScheduleCreate(_) => RequestType::ScheduleCreate,
+            Self::ScheduleSign(_) => RequestType::ScheduleSign,
+            Self::ScheduleDelete(_) => RequestType::ScheduleDelete,
+            Self::TokenAssociate(_) => RequestType::TokenAssociateToAccount,
+            Self::TokenBurn(_) => RequestType::TokenBurn,
+            Self::TokenCreate(_) => RequestType::TokenCreate,
+            Self::TokenDelete(_) => RequestType::TokenDelete,
+            Self::TokenDissociate(_) => RequestType::TokenDissociateFromAccount,
+            Self::TokenFeeScheduleUpdate(_) => RequestType::TokenFeeScheduleUpdate,
+            Self::TokenFreeze(_) => RequestType::TokenFreezeAccount,
+            Self::TokenGrantKyc(_) => RequestType::TokenGrantKycToAccount,
+            Self::TokenMint(_) => RequestType::TokenMint,
+            Self::TokenPause(_) => RequestType::TokenPause,
+            Self::TokenRevokeKyc(_) => RequestType::TokenRevokeKycFromAccount,
+            Self::TokenUnfreeze(_) => RequestType::TokenUnfreezeAccount,
+            Self::TokenUnpause(_) => RequestType::TokenUnpause,
+            Self::TokenUpdate(_) => RequestType::TokenUpdate,
+            Self::TokenWipe(_) => RequestType::TokenAccountWipe,
+            Self::SystemDelete(_) => RequestType::SystemDelete,
+            Self::SystemUndelete(_) => RequestType::SystemUndelete,
+            Self::Freeze(_) => RequestType::Freeze,
+            Self::Ethereum(_) => RequestType::EthereumTransaction,
+            Self::TokenUpdateNfts(_) => RequestType::TokenUpdateNfts,
+            Self::NodeCreate(_) => RequestType::NodeCreate,
+            Self::NodeUpdate(_) => RequestType::NodeUpdate,
+            Self::NodeDelete(_) => RequestType::NodeDelete,
+            Self::TokenReject(_) => RequestType::TokenReject,
+            Self::TokenAirdrop(_) => RequestType::TokenAirdrop,
+            Self::TokenClaimAirdrop(_) => RequestType::TokenClaimAirdrop,
+            Self::TokenCancelAirdrop(_) => RequestType::TokenCancelAirdrop,
+        }
+    }
+
     // can't do anything about the # of lines, since this function just delegates to `data::_::from_protobuf`.
     #[allow(clippy::too_many_lines)]
     fn from_protobuf(data_chunks: ServicesTransactionDataList) -> crate::Result<Self> {
@@ -862,7 +1027,7 @@ impl AnyTransactionData {
 }
 
 impl AnyTransaction {
-    pub(super) fn from_protobuf(
+    pub(super) fn from_protobuf_bodies(
         first_body: services::TransactionBody,
         data_chunks: Vec<services::transaction_body::Data>,
     ) -> crate::Result<Self> {
@@ -882,6 +1047,8 @@ impl AnyTransaction {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                validate_receipt: None,
+                bypass_duplicate_guard: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -1147,6 +1314,8 @@ macro_rules! impl_cast_any {
                             operator: transaction.body.operator,
                             is_frozen: transaction.body.is_frozen,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                            validate_receipt: transaction.body.validate_receipt,
+                            bypass_duplicate_guard: transaction.body.bypass_duplicate_guard,
                         },
                         signers: transaction.signers,
                         sources: transaction.sources,
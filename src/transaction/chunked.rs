@@ -2,7 +2,6 @@ use std::cmp;
 use std::num::NonZeroUsize;
 
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use super::{
     TransactionData,
@@ -11,6 +10,7 @@ use super::{
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -155,6 +155,12 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    // the first chunk's ID becomes the initial transaction ID of every other chunk,
+    // so even an explicit one can be replaced, as long as that happens before any other chunk is sent.
+    fn regenerates_explicit_transaction_id(&self) -> bool {
+        true
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
@@ -193,9 +199,15 @@ where
             transaction_id: *transaction_id.unwrap(),
             transaction_hash: context,
             validate_status: true,
+            max_transaction_fee: self.transaction.response_max_transaction_fee(),
+            attempted_node_account_ids: vec![node_account_id],
         })
     }
 
+    fn record_attempted_nodes(response: &mut Self::Response, node_account_ids: Vec<AccountId>) {
+        response.set_attempted_nodes(node_account_ids);
+    }
+
     fn make_error_pre_check(
         &self,
         status: services::ResponseCodeEnum,
@@ -245,12 +257,15 @@ where
         self.transaction.body.node_account_ids.as_deref()
     }
 
+    // every chunk after the first gets a new, SDK generated, transaction ID,
+    // which (unlike an explicit one) can be regenerated if it expires.
     fn transaction_id(&self) -> Option<TransactionId> {
         None
     }
 
+    // all chunks are paid for by the payer of the first chunk, which isn't necessarily the operator.
     fn operator_account_id(&self) -> Option<&AccountId> {
-        self.transaction.operator_account_id()
+        Some(&self.initial_transaction_id.account_id)
     }
 
     fn requires_transaction_id(&self) -> bool {
@@ -297,9 +312,15 @@ where
             transaction_id: *transaction_id.unwrap(),
             transaction_hash: context,
             validate_status: true,
+            max_transaction_fee: self.transaction.response_max_transaction_fee(),
+            attempted_node_account_ids: vec![node_account_id],
         })
     }
 
+    fn record_attempted_nodes(response: &mut Self::Response, node_account_ids: Vec<AccountId>) {
+        response.set_attempted_nodes(node_account_ids);
+    }
+
     fn make_error_pre_check(
         &self,
         status: services::ResponseCodeEnum,
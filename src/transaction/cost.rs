@@ -1,5 +1,4 @@
 use hedera_proto::services;
-use tonic::transport::Channel;
 
 use super::{
     AnyTransactionData,
@@ -9,6 +8,7 @@ use super::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     BoxGrpcFuture,
     Transaction,
@@ -37,6 +37,8 @@ impl<D: Clone> CostTransaction<D> {
                 operator: transaction.body.operator,
                 is_frozen: transaction.body.is_frozen,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                validate_receipt: transaction.body.validate_receipt,
+                bypass_duplicate_guard: transaction.body.bypass_duplicate_guard,
             },
             // cost transactions have no signers
             signers: Vec::new(),
@@ -23,7 +23,6 @@ use std::collections::HashMap;
 
 use hedera_proto::services;
 use prost::Message;
-use tonic::transport::Channel;
 
 use super::chunked::ChunkInfo;
 use super::source::SourceChunk;
@@ -31,17 +30,23 @@ use super::{
     ChunkData,
     TransactionSources,
 };
+use crate::client::{
+    DuplicateTransactionGuard,
+    TransactionIdReservation,
+};
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
 use crate::transaction::any::AnyTransactionData;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
 use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Client,
     Error,
     Hbar,
+    LedgerId,
     PublicKey,
     ToProtobuf,
     Transaction,
@@ -153,6 +158,18 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Checks for field combinations the network is known to reject, before the transaction is frozen.
+    ///
+    /// `ledger_id` is the ledger of the client freezing the transaction, if there is one.
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Returns the name of a deprecated field that's set, if any.
+    fn deprecated_field(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 pub trait TransactionExecute:
@@ -165,6 +182,72 @@ pub trait TransactionExecute:
     ) -> BoxGrpcFuture<'_, services::TransactionResponse>;
 }
 
+impl<D> Transaction<D>
+where
+    D: TransactionData,
+{
+    /// Runs `client`'s checks before a submission:
+    /// fails if the payer can't afford it (see [`Client::set_payer_balance_guard`]),
+    /// then waits for (or fails without) capacity in the submission throttle, if there is one.
+    pub(super) async fn prepare_submission(&self, client: &Client) -> crate::Result<()> {
+        if let Some(transaction_id) = self.get_transaction_id() {
+            client
+                .check_payer_balance(transaction_id, self.effective_max_transaction_fee())
+                .await?;
+        }
+
+        match client.submission_throttle() {
+            Some(throttle) => {
+                let data: AnyTransactionData = self.body.data.clone().into();
+
+                throttle.acquire(data.request_type()).await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Reserves this transaction's ID with `client`'s duplicate transaction guard, if it has one.
+    ///
+    /// The reservation has to be committed once the transaction is executed, dropping it releases the ID.
+    ///
+    /// # Errors
+    /// - [`Error::DuplicateTransaction`] if the ID is already reserved,
+    ///   unless [`bypass_duplicate_guard`](Transaction::bypass_duplicate_guard) is set.
+    pub(super) fn reserve_transaction_id(
+        &self,
+        client: &Client,
+    ) -> crate::Result<TransactionIdReservation> {
+        if self.body.bypass_duplicate_guard {
+            return Ok(TransactionIdReservation::none());
+        }
+
+        let (Some(guard), Some(transaction_id)) =
+            (client.duplicate_transaction_guard(), self.get_transaction_id())
+        else {
+            return Ok(TransactionIdReservation::none());
+        };
+
+        DuplicateTransactionGuard::reserve(&guard, transaction_id)
+            .ok_or_else(|| Error::DuplicateTransaction { transaction_id: Box::new(transaction_id) })
+    }
+
+    /// Tells `client` that its operator may have been charged for `response`.
+    pub(super) fn report_spend(&self, client: &Client, response: &TransactionResponse) {
+        client.report_operator_spend(response.transaction_id, self.effective_max_transaction_fee());
+    }
+
+    fn effective_max_transaction_fee(&self) -> Hbar {
+        self.body
+            .max_transaction_fee
+            .unwrap_or_else(|| self.body.data.default_max_transaction_fee())
+    }
+
+    /// Returns the max transaction fee to check the charged fee against, `None` for cost estimates.
+    pub(super) fn response_max_transaction_fee(&self) -> Option<Hbar> {
+        (!self.body.data.for_cost_estimate()).then(|| self.effective_max_transaction_fee())
+    }
+}
+
 impl<D> Execute for Transaction<D>
 where
     D: TransactionExecute,
@@ -230,9 +313,15 @@ where
             transaction_id: *transaction_id.unwrap(),
             transaction_hash,
             validate_status: true,
+            max_transaction_fee: self.response_max_transaction_fee(),
+            attempted_node_account_ids: vec![node_account_id],
         })
     }
 
+    fn record_attempted_nodes(response: &mut Self::Response, node_account_ids: Vec<AccountId>) {
+        response.set_attempted_nodes(node_account_ids);
+    }
+
     fn make_error_pre_check(
         &self,
         status: crate::Status,
@@ -273,7 +362,10 @@ where
     D: TransactionData + ToTransactionDataProtobuf,
 {
     #[allow(deprecated)]
-    fn to_transaction_body_protobuf(&self, chunk_info: &ChunkInfo) -> services::TransactionBody {
+    pub(super) fn to_transaction_body_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::TransactionBody {
         assert!(self.is_frozen());
         let data = self.body.data.to_transaction_data_protobuf(chunk_info);
 
@@ -338,6 +430,8 @@ impl<'a, D> SourceTransaction<'a, D> {
     {
         let mut responses = Vec::with_capacity(self.sources.chunks_len());
         for chunk in self.sources.chunks() {
+            self.inner.prepare_submission(client).await?;
+
             let response = crate::execute::execute(
                 client,
                 &SourceTransactionExecuteView::new(self.inner, chunk),
@@ -345,6 +439,8 @@ impl<'a, D> SourceTransaction<'a, D> {
             )
             .await?;
 
+            self.inner.report_spend(client, &response);
+
             if self.inner.data().wait_for_receipt() {
                 response.get_receipt(client).await?;
             }
@@ -435,6 +531,10 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         self.transaction.make_response(response, context, node_account_id, transaction_id)
     }
 
+    fn record_attempted_nodes(response: &mut Self::Response, node_account_ids: Vec<AccountId>) {
+        response.set_attempted_nodes(node_account_ids);
+    }
+
     fn make_error_pre_check(
         &self,
         status: crate::Status,
@@ -28,17 +28,25 @@ use std::fmt::{
 use std::num::NonZeroUsize;
 
 use hedera_proto::services;
+use parking_lot::Mutex;
 use prost::Message;
 use time::Duration;
 use triomphe::Arc;
 
 use crate::downcast::DowncastOwned;
-use crate::execute::execute;
+use crate::execute::{
+    cancellable,
+    execute,
+    execute_with_backoff,
+};
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    CancellationToken,
     Client,
     Error,
+    ExecutedTransaction,
+    FromProtobuf,
     Hbar,
     Operator,
     PrivateKey,
@@ -79,6 +87,21 @@ pub(crate) use protobuf::{
 pub(crate) use source::TransactionSources;
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
+const MIN_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(15);
+const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
+/// Checks that `duration` is within the range of transaction valid durations the network permits.
+pub(crate) fn validate_transaction_valid_duration(duration: Duration) -> crate::Result<()> {
+    if !(MIN_TRANSACTION_VALID_DURATION..=MAX_TRANSACTION_VALID_DURATION).contains(&duration) {
+        return Err(Error::TransactionValidDurationOutOfRange {
+            duration,
+            min: MIN_TRANSACTION_VALID_DURATION,
+            max: MAX_TRANSACTION_VALID_DURATION,
+        });
+    }
+
+    Ok(())
+}
 
 /// A transaction that can be executed on the Hedera network.
 #[derive(Clone)]
@@ -109,6 +132,10 @@ pub(crate) struct TransactionBody<D> {
     pub(crate) is_frozen: bool,
 
     pub(crate) regenerate_transaction_id: Option<bool>,
+
+    pub(crate) validate_receipt: Option<bool>,
+
+    pub(crate) bypass_duplicate_guard: bool,
 }
 
 impl<D> Default for Transaction<D>
@@ -127,6 +154,8 @@ where
                 operator: None,
                 is_frozen: false,
                 regenerate_transaction_id: None,
+                validate_receipt: None,
+                bypass_duplicate_guard: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -158,6 +187,15 @@ where
 }
 
 impl<D> Transaction<D> {
+    /// The maximum size in bytes of a signed transaction that the network will accept.
+    pub const MAX_SIZE_BYTES: usize = 6144;
+
+    /// The shortest [`transaction_valid_duration`](Self::transaction_valid_duration) the network will accept.
+    pub const MIN_TRANSACTION_VALID_DURATION: Duration = MIN_TRANSACTION_VALID_DURATION;
+
+    /// The longest [`transaction_valid_duration`](Self::transaction_valid_duration) the network will accept.
+    pub const MAX_TRANSACTION_VALID_DURATION: Duration = MAX_TRANSACTION_VALID_DURATION;
+
     pub(crate) fn from_parts(body: TransactionBody<D>, signers: Vec<AnySigner>) -> Self {
         Self { body, signers, sources: None }
     }
@@ -243,8 +281,19 @@ impl<D> Transaction<D> {
 
     /// Sets the duration that this transaction is valid for, once finalized and signed.
     ///
-    /// Defaults to 120 seconds (or two minutes).
+    /// Defaults to the client's [`default_transaction_valid_duration`](Client::default_transaction_valid_duration),
+    /// or 120 seconds (two minutes) if that isn't set either.
+    ///
+    /// The network only accepts durations from [`MIN_TRANSACTION_VALID_DURATION`](Self::MIN_TRANSACTION_VALID_DURATION)
+    /// to [`MAX_TRANSACTION_VALID_DURATION`](Self::MAX_TRANSACTION_VALID_DURATION),
+    /// anything else makes [`freeze`](Self::freeze) fail.
     pub fn transaction_valid_duration(&mut self, duration: Duration) -> &mut Self {
+        if validate_transaction_valid_duration(duration).is_err() {
+            log::warn!(
+                "transaction valid duration of {duration} is out of range, freezing will fail"
+            );
+        }
+
         self.body_mut().transaction_valid_duration = Some(duration);
         self
     }
@@ -318,6 +367,37 @@ impl<D> Transaction<D> {
         self.signers.push(signer);
         self
     }
+
+    /// Returns whether [`execute`](Self::execute) should wait for the receipt and fail unless the transaction succeeded.
+    ///
+    /// By default, the value on Client will be used.
+    pub fn get_validate_receipt(&self) -> Option<bool> {
+        self.body.validate_receipt
+    }
+
+    /// Sets whether [`execute`](Self::execute) should wait for the receipt and fail unless the transaction succeeded.
+    pub fn validate_receipt(&mut self, validate_receipt: bool) -> &mut Self {
+        self.body_mut().validate_receipt = Some(validate_receipt);
+
+        self
+    }
+
+    /// Returns whether this transaction is executed even if the client's duplicate transaction guard has seen its ID.
+    #[must_use]
+    pub fn get_bypass_duplicate_guard(&self) -> bool {
+        self.body.bypass_duplicate_guard
+    }
+
+    /// Sets whether this transaction is executed even if the client's duplicate transaction guard has seen its ID.
+    ///
+    /// Unlike most properties, this can be set on a frozen transaction, since re-executing one is the point.
+    ///
+    /// See [`Client::set_duplicate_transaction_guard_ttl`].
+    pub fn bypass_duplicate_guard(&mut self, bypass: bool) -> &mut Self {
+        self.body.bypass_duplicate_guard = bypass;
+
+        self
+    }
 }
 
 impl<D: ChunkedTransactionData> Transaction<D> {
@@ -357,7 +437,8 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     ///
     /// By default, the value on Client will be used.
     ///
-    /// Note: Some operations forcibly disable transaction ID regeneration, such as setting the transaction ID explicitly.
+    /// Note: Some operations forcibly disable transaction ID regeneration, such as setting the transaction ID explicitly
+    /// (except for the first chunk of a chunked transaction, since every other chunk's ID is generated anyway).
     pub fn get_regenerate_transaction_id(&self) -> Option<bool> {
         self.body.regenerate_transaction_id
     }
@@ -372,11 +453,13 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     }
 }
 
-impl<D: ValidateChecksums> Transaction<D> {
+impl<D: TransactionExecute> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set.
+    /// - [`Error::TransactionOversize`] if the transaction body is larger than [`MAX_SIZE_BYTES`](Self::MAX_SIZE_BYTES).
+    /// - [`Error::InvalidObtainer`] if this is a [`ContractDeleteTransaction`](crate::ContractDeleteTransaction) without exactly one transfer target.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -388,6 +471,10 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set and `client.is_none()`.
+    /// - [`Error::TransactionOversize`] if the transaction body is larger than [`MAX_SIZE_BYTES`](Self::MAX_SIZE_BYTES).
+    /// - [`Error::InvalidObtainer`] if this is a [`ContractDeleteTransaction`](crate::ContractDeleteTransaction) without exactly one transfer target.
+    /// - [`Error::DeprecatedField`] if the transaction sets a deprecated field and `client` has [`reject_deprecated_fields`](Client::reject_deprecated_fields) enabled.
+    /// - [`Error::TransactionValidDurationOutOfRange`] if the [`transaction_valid_duration`](Self::transaction_valid_duration) isn't one the network permits.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -398,8 +485,18 @@ impl<D: ValidateChecksums> Transaction<D> {
         if self.is_frozen() {
             return Ok(self);
         }
+
         let client: Option<&Client> = client.into();
 
+        let ledger_id = client.map(Client::ledger_id_internal);
+        self.body.data.validate_data(ledger_id.as_ref().and_then(|it| it.as_deref()))?;
+
+        if client.is_some_and(Client::reject_deprecated_fields) {
+            if let Some(field) = self.body.data.deprecated_field() {
+                return Err(Error::DeprecatedField { field });
+            }
+        }
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => {
@@ -408,12 +505,9 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
             #[allow(clippy::missing_panics_doc)]
             None => {
-                let nodes = client
-                    .ok_or(Error::FreezeUnsetNodeAccountIds)?
-                    .net()
-                    .0
-                    .load()
-                    .random_node_ids();
+                let client = client.ok_or(Error::FreezeUnsetNodeAccountIds)?;
+                let nodes =
+                    client.net().0.load().select_node_ids(client.node_selector().as_deref());
                 assert!(!nodes.is_empty(), "BUG: Client didn't give any nodes (all unhealthy)");
 
                 nodes
@@ -427,11 +521,30 @@ impl<D: ValidateChecksums> Transaction<D> {
             client.and_then(Client::default_max_transaction_fee)
         });
 
-        let operator = client.and_then(Client::full_load_operator);
+        let transaction_valid_duration = self
+            .body
+            .transaction_valid_duration
+            .or_else(|| client.and_then(Client::default_transaction_valid_duration));
+
+        validate_transaction_valid_duration(
+            transaction_valid_duration.unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION),
+        )?;
+
+        let payer = self.body.transaction_id.map(|it| it.account_id);
+        let operator = client.and_then(|it| it.select_operator(payer));
+
+        // what the caller set, so that a failed size check leaves the transaction as it was.
+        let unfrozen = (
+            self.body.node_account_ids.clone(),
+            self.body.max_transaction_fee,
+            self.body.transaction_valid_duration,
+            self.body.operator.clone(),
+        );
 
         // note: yes, there's an `Some(opt.unwrap())`, this is INTENTIONAL.
         self.body.node_account_ids = Some(node_account_ids);
         self.body.max_transaction_fee = max_transaction_fee;
+        self.body.transaction_valid_duration = transaction_valid_duration;
         self.body.operator = operator;
         self.body.is_frozen = true;
 
@@ -446,9 +559,39 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
         }
 
+        if let Err(e) = self.check_body_size() {
+            // an oversized transaction can't ever be sent, so let the caller shrink it instead.
+            (
+                self.body.node_account_ids,
+                self.body.max_transaction_fee,
+                self.body.transaction_valid_duration,
+                self.body.operator,
+            ) = unfrozen;
+            self.body.is_frozen = false;
+            return Err(e);
+        }
+
         Ok(self)
     }
 
+    /// Checks that the largest transaction body fits within [`MAX_SIZE_BYTES`](Self::MAX_SIZE_BYTES).
+    ///
+    /// Signatures aren't known until the transaction is executed, so this is a lower bound.
+    fn check_body_size(&self) -> crate::Result<()> {
+        let size = match self.body_size_bytes() {
+            Ok(size) => size,
+            // without a transaction ID there's no body to measure yet, `execute` will report this instead.
+            Err(Error::NoPayerAccountOrTransactionId) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        if size > Self::MAX_SIZE_BYTES {
+            return Err(Error::TransactionOversize { size, max_size: Self::MAX_SIZE_BYTES });
+        }
+
+        Ok(())
+    }
+
     /// Sign the transaction with the `client`'s operator.
     ///
     /// # Errors
@@ -457,10 +600,18 @@ impl<D: ValidateChecksums> Transaction<D> {
     /// # Panics
     /// If `client` has no operator.
     pub fn sign_with_operator(&mut self, client: &Client) -> crate::Result<&mut Self> {
-        let Some(op) = client.full_load_operator() else { panic!("Client had no operator") };
-
         self.freeze_with(client)?;
 
+        // the operator picked by `freeze_with`, if it was frozen with `client`.
+        let payer = self
+            .body
+            .operator
+            .as_ref()
+            .map(|it| it.account_id)
+            .or_else(|| self.body.transaction_id.map(|it| it.account_id));
+
+        let Some(op) = client.select_operator(payer) else { panic!("Client had no operator") };
+
         self.sign_signer(op.signer.clone());
 
         self.body.operator = Some(op);
@@ -475,7 +626,7 @@ impl<D: TransactionExecute> Transaction<D> {
     ///
     /// # Panics
     /// - If `!self.is_frozen()`
-    fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
+    fn make_chunk_infos(&self) -> crate::Result<Vec<ChunkInfo>> {
         assert!(self.is_frozen());
 
         let operator = || self.body.operator.as_ref().ok_or(Error::NoPayerAccountOrTransactionId);
@@ -489,7 +640,7 @@ impl<D: TransactionExecute> Transaction<D> {
         let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
         let node_account_ids = self.body.node_account_ids.as_deref().unwrap();
 
-        let mut transaction_list = Vec::with_capacity(used_chunks * node_account_ids.len());
+        let mut chunk_infos = Vec::with_capacity(used_chunks * node_account_ids.len());
 
         // Note: This ordering is *important*,
         // there's no documentation for it but `TransactionList` is sorted by chunk number,
@@ -501,19 +652,85 @@ impl<D: TransactionExecute> Transaction<D> {
             };
 
             for node_account_id in node_account_ids.iter().copied() {
-                let chunk_info = ChunkInfo {
+                chunk_infos.push(ChunkInfo {
                     current: chunk,
                     total: used_chunks,
                     initial_transaction_id,
                     current_transaction_id,
                     node_account_id,
-                };
-
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                });
             }
         }
 
-        Ok(transaction_list)
+        Ok(chunk_infos)
+    }
+
+    /// # Errors
+    /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* `self.operator` is not set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`
+    fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
+        Ok(self
+            .make_chunk_infos()?
+            .iter()
+            .map(|chunk_info| self.make_request_inner(chunk_info).0)
+            .collect())
+    }
+
+    /// Returns the size in bytes of the largest transaction body that will be sent to a node.
+    ///
+    /// This doesn't include signatures, see [`signed_size_bytes`](Self::signed_size_bytes) for that.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn body_size_bytes(&self) -> crate::Result<usize> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `body_size_bytes`");
+
+        if let Some(sources) = self.sources() {
+            return Ok(sources
+                .signed_transactions()
+                .iter()
+                .map(|it| it.body_bytes.len())
+                .max()
+                .unwrap_or_default());
+        }
+
+        Ok(self
+            .make_chunk_infos()?
+            .iter()
+            .map(|chunk_info| self.to_transaction_body_protobuf(chunk_info).encoded_len())
+            .max()
+            .unwrap_or_default())
+    }
+
+    /// Returns the size in bytes of the largest signed transaction that will be sent to a node.
+    ///
+    /// This is the size the network compares against [`MAX_SIZE_BYTES`](Self::MAX_SIZE_BYTES),
+    /// and includes a signature from the operator and from every signer added so far.
+    ///
+    /// Note: computing this requires signing the transaction.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn signed_size_bytes(&self) -> crate::Result<usize> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `signed_size_bytes`");
+
+        Ok(self
+            .make_sources()?
+            .transactions()
+            .iter()
+            .map(Message::encoded_len)
+            .max()
+            .unwrap_or_default())
     }
 
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
@@ -536,11 +753,31 @@ impl<D: TransactionExecute> Transaction<D> {
     pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
+        Ok(self.to_transaction_list_protobuf()?.encode_to_vec())
+    }
+
+    /// Convert `self` to the raw `TransactionList` protobuf, with a signed transaction for every node (and chunk).
+    ///
+    /// Requires the `raw-protobuf` feature, which is exempt from semver.
+    ///
+    /// # Errors
+    /// - If `freeze_with` wasn't called with an operator.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    #[cfg(feature = "raw-protobuf")]
+    pub fn to_protobuf(&self) -> crate::Result<hedera_proto::sdk::TransactionList> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `to_protobuf`");
+
+        self.to_transaction_list_protobuf()
+    }
+
+    fn to_transaction_list_protobuf(&self) -> crate::Result<hedera_proto::sdk::TransactionList> {
         let transaction_list = self
             .signed_sources()
             .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
 
-        Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
+        Ok(hedera_proto::sdk::TransactionList { transaction_list })
     }
 
     pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
@@ -718,18 +955,188 @@ where
     }
 
     /// Execute this transaction against the provided client of the Hedera network.
+    ///
+    /// If [`validate_receipt`](Self::validate_receipt) (or the client's default) is enabled,
+    /// this also waits for the receipt and fails unless the transaction succeeded.
     pub async fn execute(&mut self, client: &Client) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, None).await
     }
 
+    /// Execute this transaction against the provided client of the Hedera network, and wait for its receipt.
+    ///
+    /// Unlike [`execute`](Self::execute), this only succeeds if the transaction reached consensus
+    /// with a status of [`Status::Success`](crate::Status::Success).
+    ///
+    /// # Errors
+    /// - [`Error::ReceiptStatus`] if the transaction passed precheck but failed at consensus.
+    pub async fn execute_and_get_receipt(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<ExecutedTransaction> {
+        self.execute_and_get_receipt_with_optional_timeout(client, None).await
+    }
+
+    /// Execute this transaction against the provided client of the Hedera network, and wait for its receipt.
+    ///
+    /// `timeout` applies separately to submitting the transaction and to fetching the receipt.
+    ///
+    /// # Errors
+    /// - [`Error::ReceiptStatus`] if the transaction passed precheck but failed at consensus.
+    pub async fn execute_and_get_receipt_with_timeout(
+        &mut self,
+        client: &Client,
+        timeout: std::time::Duration,
+    ) -> crate::Result<ExecutedTransaction> {
+        self.execute_and_get_receipt_with_optional_timeout(client, Some(timeout)).await
+    }
+
+    /// Freeze this transaction with `client`, sign it with every one of `keys`, execute it, and wait for its receipt.
+    ///
+    /// Equivalent to calling [`freeze_with`](Self::freeze_with), [`sign`](Self::sign) for each key,
+    /// then [`execute_and_get_receipt`](Self::execute_and_get_receipt).
+    ///
+    /// # Errors
+    /// - Any error that [`freeze_with`](Self::freeze_with) can return, in which case nothing is submitted.
+    /// - [`Error::TransactionPreCheckStatus`] if the transaction failed precheck.
+    /// - [`Error::ReceiptStatus`] if the transaction passed precheck but failed at consensus.
+    pub async fn freeze_sign_execute(
+        &mut self,
+        client: &Client,
+        keys: &[PrivateKey],
+    ) -> crate::Result<ExecutedTransaction> {
+        self.freeze_sign_execute_with_optional_timeout(client, keys, None).await
+    }
+
+    /// Freeze this transaction with `client`, sign it with every one of `keys`, execute it, and wait for its receipt.
+    ///
+    /// `timeout` applies separately to submitting the transaction and to fetching the receipt.
+    ///
+    /// # Errors
+    /// - Any error that [`freeze_with`](Self::freeze_with) can return, in which case nothing is submitted.
+    /// - [`Error::TransactionPreCheckStatus`] if the transaction failed precheck.
+    /// - [`Error::ReceiptStatus`] if the transaction passed precheck but failed at consensus.
+    pub async fn freeze_sign_execute_with_timeout(
+        &mut self,
+        client: &Client,
+        keys: &[PrivateKey],
+        timeout: std::time::Duration,
+    ) -> crate::Result<ExecutedTransaction> {
+        self.freeze_sign_execute_with_optional_timeout(client, keys, Some(timeout)).await
+    }
+
+    async fn freeze_sign_execute_with_optional_timeout(
+        &mut self,
+        client: &Client,
+        keys: &[PrivateKey],
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<ExecutedTransaction> {
+        self.freeze_with(client)?;
+
+        for key in keys {
+            self.sign(key.clone());
+        }
+
+        self.execute_and_get_receipt_with_optional_timeout(client, timeout).await
+    }
+
+    async fn execute_and_get_receipt_with_optional_timeout(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<ExecutedTransaction> {
+        let response = self.submit(client, timeout).await?;
+
+        let receipt = response
+            .receipt_query_for(client)
+            .validate_status(true)
+            .execute_with_optional_timeout(client, timeout)
+            .await?;
+
+        Ok(ExecutedTransaction { response, receipt })
+    }
+
+    /// Executes a copy of `self` for every batch of at most `max` of `items`, in order.
+    ///
+    /// `set_items` replaces the items of a copy with a batch.
+    /// If a transaction ID was set explicitly, it's used for the first batch,
+    /// and every later batch gets a new one for the same payer.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub(crate) async fn execute_in_batches<T>(
+        &self,
+        client: &Client,
+        items: &[T],
+        max: usize,
+        set_items: impl Fn(&mut Self, &[T]),
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        self.require_not_frozen();
+
+        // with nothing to split up (such as a fungible amount) there's still exactly one transaction.
+        if items.len() <= max {
+            return Ok(Vec::from([self.clone().execute(client).await?]));
+        }
+
+        let payer_account_id = self.get_transaction_id().map(|it| it.account_id);
+        let mut responses = Vec::with_capacity(items.len().div_ceil(max));
+
+        for batch in items.chunks(max) {
+            let mut transaction = self.clone();
+            set_items(&mut transaction, batch);
+
+            if let Some(payer_account_id) = payer_account_id.filter(|_| !responses.is_empty()) {
+                transaction.transaction_id(TransactionId::generate(payer_account_id));
+            }
+
+            responses.push(transaction.execute(client).await?);
+        }
+
+        Ok(responses)
+    }
+
     pub(crate) async fn execute_with_optional_timeout(
         &mut self,
         client: &Client,
         timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        let validate_receipt =
+            self.body.validate_receipt.unwrap_or_else(|| client.default_validate_receipt());
+
+        if validate_receipt {
+            return self
+                .execute_and_get_receipt_with_optional_timeout(client, timeout)
+                .await
+                .map(|it| it.response);
+        }
+
+        self.submit(client, timeout).await
+    }
+
+    async fn submit(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse> {
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
 
+        let reservation = self.reserve_transaction_id(client)?;
+
+        let response = self.submit_frozen(client, timeout).await?;
+
+        reservation.commit();
+
+        Ok(response)
+    }
+
+    async fn submit_frozen(
+        &self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        // note: nothing below changes `self`, so dropping this future part way through (IE, cancelling it)
+        // leaves `self` frozen with the same sources, exactly like a failed execution does.
+
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
                 .execute(client, timeout)
@@ -746,7 +1153,47 @@ where
                 .map(|mut it| it.swap_remove(0));
         }
 
-        execute(client, self, timeout).await
+        self.prepare_submission(client).await?;
+
+        let response = execute(client, self, timeout).await?;
+
+        self.report_spend(client, &response);
+
+        Ok(response)
+    }
+
+    /// Submits this transaction, pushing every node it's sent to onto `attempted_nodes`,
+    /// so that if it fails the caller can tell whether an earlier submission may still reach consensus.
+    ///
+    /// Unlike [`execute`](Self::execute) this never waits for the receipt,
+    /// and the transaction must be neither chunked nor created from bytes.
+    pub(crate) async fn execute_recording_attempts(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+        attempted_nodes: &Mutex<Vec<AccountId>>,
+    ) -> crate::Result<TransactionResponse> {
+        self.freeze_with(Some(client))?;
+
+        debug_assert!(self.sources().is_none() && self.data().maybe_chunk_data().is_none());
+
+        let reservation = self.reserve_transaction_id(client)?;
+
+        self.prepare_submission(client).await?;
+
+        let mut backoff = client.backoff();
+
+        if let Some(timeout) = timeout {
+            backoff.request_timeout = Some(timeout);
+        }
+
+        let response = execute_with_backoff(client, self, backoff, Some(attempted_nodes)).await?;
+
+        self.report_spend(client, &response);
+
+        reservation.commit();
+
+        Ok(response)
     }
 
     // this is in *this* impl block rather than the `: TransactionExecuteChunked` impl block
@@ -772,6 +1219,8 @@ where
         let mut responses = Vec::with_capacity(chunk_data.used_chunks());
 
         let initial_transaction_id = {
+            self.prepare_submission(client).await?;
+
             let resp = execute(
                 client,
                 &chunked::FirstChunkView { transaction: self, total_chunks: used_chunks },
@@ -779,8 +1228,10 @@ where
             )
             .await?;
 
+            self.report_spend(client, &resp);
+
             if wait_for_receipts {
-                resp.get_receipt_query()
+                resp.receipt_query_for(client)
                     .execute_with_optional_timeout(client, timeout_per_chunk)
                     .await?;
             }
@@ -792,6 +1243,8 @@ where
         };
 
         for chunk in 1..used_chunks {
+            self.prepare_submission(client).await?;
+
             let resp = execute(
                 client,
                 &chunked::ChunkView {
@@ -804,8 +1257,10 @@ where
             )
             .await?;
 
+            self.report_spend(client, &resp);
+
             if wait_for_receipts {
-                resp.get_receipt_query()
+                resp.receipt_query_for(client)
                     .execute_with_optional_timeout(client, timeout_per_chunk)
                     .await?;
             }
@@ -827,6 +1282,23 @@ where
     ) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, Some(timeout)).await
     }
+
+    /// Execute this transaction against the provided client of the Hedera network,
+    /// unless `cancellation` is cancelled first.
+    ///
+    /// Cancelling leaves `self` as a failed [`execute`](Self::execute) would, so it can be executed again.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before the transaction is executed,
+    ///   if it was already sent to a node it may still reach consensus.
+    /// - Any error that [`execute`](Self::execute) can return.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<TransactionResponse> {
+        cancellable(cancellation, Box::pin(self.execute_with_optional_timeout(client, None))).await
+    }
 }
 
 impl<D> Transaction<D>
@@ -841,6 +1313,24 @@ where
         self.execute_all_with_optional_timeout(client, None).await
     }
 
+    /// Execute all transactions against the provided client of the Hedera network,
+    /// unless `cancellation` is cancelled first.
+    ///
+    /// Chunks are sent one at a time, so once cancelled no further chunks are sent.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before every chunk is executed,
+    ///   the chunks that were already sent to a node may still reach consensus.
+    /// - Any error that [`execute_all`](Self::execute_all) can return.
+    pub async fn execute_all_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        cancellable(cancellation, Box::pin(self.execute_all_with_optional_timeout(client, None)))
+            .await
+    }
+
     pub(crate) async fn execute_all_with_optional_timeout(
         &mut self,
         client: &Client,
@@ -851,9 +1341,15 @@ where
 
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
-            return self::execute::SourceTransaction::new(self, sources)
+            let reservation = self.reserve_transaction_id(client)?;
+
+            let responses = self::execute::SourceTransaction::new(self, sources)
                 .execute_all(client, timeout_per_chunk)
-                .await;
+                .await?;
+
+            reservation.commit();
+
+            return Ok(responses);
         }
 
         // sorry for the mess: this can technically infinite loop
@@ -864,7 +1360,13 @@ where
                 .await?]));
         };
 
-        self.execute_all_inner(chunk_data, client, timeout_per_chunk).await
+        let reservation = self.reserve_transaction_id(client)?;
+
+        let responses = self.execute_all_inner(chunk_data, client, timeout_per_chunk).await?;
+
+        reservation.commit();
+
+        Ok(responses)
     }
 }
 
@@ -900,6 +1402,11 @@ impl AnyTransaction {
             list.transaction_list
         };
 
+        Self::from_transaction_list(list)
+    }
+
+    #[allow(deprecated)]
+    fn from_transaction_list(list: Vec<services::Transaction>) -> crate::Result<Self> {
         let sources = TransactionSources::new(list)?;
 
         let transaction_bodies: Result<Vec<_>, _> = sources
@@ -939,7 +1446,7 @@ impl AnyTransaction {
         };
 
         // note: this creates the transaction in a frozen state.
-        let mut res = Self::from_protobuf(transaction_bodies[0].clone(), transaction_data)?;
+        let mut res = Self::from_protobuf_bodies(transaction_bodies[0].clone(), transaction_data)?;
 
         // note: this doesn't check freeze for obvious reasons.
         res.body.node_account_ids = Some(sources.node_ids().to_vec());
@@ -1038,6 +1545,12 @@ fn pb_transaction_body_eq(
     true
 }
 
+impl FromProtobuf<hedera_proto::sdk::TransactionList> for AnyTransaction {
+    fn from_protobuf(pb: hedera_proto::sdk::TransactionList) -> crate::Result<Self> {
+        Self::from_transaction_list(pb.transaction_list)
+    }
+}
+
 // note: This impl is why this has to be a trait (overlapping impls if `D == U` with TryFrom).
 impl<D, U> DowncastOwned<Transaction<U>> for Transaction<D>
 where
@@ -1055,6 +1568,8 @@ where
             operator,
             is_frozen,
             regenerate_transaction_id,
+            validate_receipt,
+            bypass_duplicate_guard,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1070,6 +1585,8 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    validate_receipt,
+                    bypass_duplicate_guard,
                 },
                 signers,
                 sources,
@@ -1086,6 +1603,8 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    validate_receipt,
+                    bypass_duplicate_guard,
                 },
                 signers,
                 sources,
@@ -1,13 +1,23 @@
-use std::collections::HashSet;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 
 use assert_matches::assert_matches;
 use hex_literal::hex;
 use time::OffsetDateTime;
 
+use crate::execute::regeneration_account_id;
+use crate::transaction::chunked::FirstChunkView;
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountId,
     AnyTransaction,
+    CancellationToken,
     Client,
+    Error,
+    FileCreateTransaction,
+    FromProtobuf,
     Hbar,
     PrivateKey,
     TopicMessageSubmitTransaction,
@@ -62,6 +72,27 @@ fn to_bytes_from_bytes() -> crate::Result<()> {
     Ok(())
 }
 
+#[test]
+fn to_protobuf_from_protobuf() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?;
+
+    let list = tx.to_transaction_list_protobuf()?;
+    assert_eq!(list.transaction_list.len(), 2);
+
+    let tx2 = AnyTransaction::from_protobuf(list)?;
+
+    assert_eq!(tx.get_transaction_id(), tx2.get_transaction_id());
+    assert_eq!(tx.to_bytes()?, tx2.to_bytes()?);
+
+    Ok(())
+}
+
 #[test]
 fn from_bytes_sign_to_bytes() -> crate::Result<()> {
     let mut tx = TransferTransaction::new();
@@ -119,3 +150,185 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn size_bytes() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new_for_tests();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2)).hbar_transfer(101.into(), Hbar::new(-2)).freeze()?;
+
+    let body_size = tx.body_size_bytes()?;
+    let signed_size = tx.signed_size_bytes()?;
+
+    assert!(body_size > 0);
+    assert!(signed_size > body_size);
+    assert!(signed_size <= TransferTransaction::MAX_SIZE_BYTES);
+
+    Ok(())
+}
+
+#[test]
+fn freeze_oversize_fails() {
+    let mut tx = FileCreateTransaction::new_for_tests();
+
+    tx.contents(vec![0; FileCreateTransaction::MAX_SIZE_BYTES]);
+
+    let res = tx.freeze();
+
+    let (size, max_size) = assert_matches!(
+        res,
+        Err(Error::TransactionOversize { size, max_size }) => (size, max_size)
+    );
+
+    assert!(size > max_size);
+    assert_eq!(max_size, FileCreateTransaction::MAX_SIZE_BYTES);
+
+    // the transaction is left unfrozen so that it can be shrunk.
+    tx.contents(vec![0; 16]);
+    tx.freeze().unwrap();
+}
+
+#[tokio::test]
+async fn freeze_with_oversize_leaves_client_fields_unset() {
+    let client = Client::for_testnet();
+    client.set_operator(101.into(), PrivateKey::generate_ed25519());
+    client.set_default_max_transaction_fee(Hbar::new(3));
+    client.set_default_transaction_valid_duration(Some(time::Duration::seconds(60))).unwrap();
+
+    let mut tx = FileCreateTransaction::new();
+
+    tx.contents(vec![0; FileCreateTransaction::MAX_SIZE_BYTES])
+        .transaction_id(TransactionId::generate(101.into()));
+
+    assert_matches!(tx.freeze_with(&client), Err(Error::TransactionOversize { .. }));
+
+    assert!(!tx.is_frozen());
+    assert_eq!(tx.get_node_account_ids(), None);
+    assert_eq!(tx.get_max_transaction_fee(), None);
+    assert_eq!(tx.get_transaction_valid_duration(), None);
+    assert!(tx.body.operator.is_none());
+}
+
+#[test]
+fn freeze_invalid_valid_duration_fails() {
+    for seconds in [14, 181] {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.transaction_valid_duration(time::Duration::seconds(seconds));
+
+        assert_matches!(
+            tx.freeze(),
+            Err(Error::TransactionValidDurationOutOfRange { duration, .. })
+                if duration == time::Duration::seconds(seconds)
+        );
+    }
+
+    let mut tx = TransferTransaction::new_for_tests();
+    tx.transaction_valid_duration(TransferTransaction::MAX_TRANSACTION_VALID_DURATION);
+    tx.freeze().unwrap();
+}
+
+#[tokio::test]
+async fn freeze_sign_execute_freeze_fails() {
+    let client = Client::for_testnet();
+
+    let mut tx = TransferTransaction::new_for_tests();
+    tx.transaction_valid_duration(time::Duration::seconds(181));
+
+    // the freeze error is returned as-is, before anything is sent.
+    assert_matches!(
+        tx.freeze_sign_execute(&client, &[PrivateKey::generate_ed25519()]).await,
+        Err(Error::TransactionValidDurationOutOfRange { .. })
+    );
+}
+
+#[tokio::test]
+async fn execute_with_cancellation_already_cancelled() {
+    let client = Client::for_testnet();
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+
+    let mut tx = TransferTransaction::new_for_tests();
+
+    assert_matches!(
+        tx.execute_with_cancellation(&client, &cancellation).await,
+        Err(Error::Cancelled)
+    );
+
+    // nothing ran, so the transaction is left exactly as it was.
+    assert!(!tx.is_frozen());
+}
+
+#[tokio::test]
+async fn client_default_valid_duration() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    assert_matches!(
+        client.set_default_transaction_valid_duration(Some(time::Duration::seconds(200))),
+        Err(Error::TransactionValidDurationOutOfRange { .. })
+    );
+    assert_eq!(client.default_transaction_valid_duration(), None);
+
+    client.set_default_transaction_valid_duration(Some(time::Duration::seconds(30)))?;
+
+    let mut tx = TransferTransaction::new_for_tests();
+    tx.freeze_with(&client)?;
+    assert_eq!(tx.get_transaction_valid_duration(), Some(time::Duration::seconds(30)));
+
+    // an explicit duration wins.
+    let mut tx = TransferTransaction::new_for_tests();
+    tx.transaction_valid_duration(time::Duration::seconds(60)).freeze_with(&client)?;
+    assert_eq!(tx.get_transaction_valid_duration(), Some(time::Duration::seconds(60)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duplicate_transaction_guard() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_duplicate_transaction_guard_ttl(Some(std::time::Duration::from_secs(180)));
+
+    let mut tx = TransferTransaction::new_for_tests();
+    tx.freeze_with(&client)?;
+
+    let reservation = tx.reserve_transaction_id(&client)?;
+
+    assert_matches!(
+        tx.execute(&client).await,
+        Err(Error::DuplicateTransaction { transaction_id }) if *transaction_id == tx.get_transaction_id().unwrap()
+    );
+
+    tx.bypass_duplicate_guard(true);
+    assert!(tx.get_bypass_duplicate_guard());
+    tx.reserve_transaction_id(&client)?.commit();
+    tx.bypass_duplicate_guard(false);
+
+    // a transaction that was never executed is released again.
+    drop(reservation);
+    tx.reserve_transaction_id(&client)?.commit();
+
+    assert_matches!(tx.reserve_transaction_id(&client), Err(Error::DuplicateTransaction { .. }));
+
+    Ok(())
+}
+
+#[test]
+fn explicit_transaction_id_regenerates_only_for_first_chunk() {
+    let client = Client::for_network(HashMap::new()).unwrap();
+
+    let payer = AccountId::new(0, 0, 1001);
+
+    let mut tx = TopicMessageSubmitTransaction::new();
+    tx.transaction_id(TransactionId::generate(payer))
+        .node_account_ids([AccountId::new(0, 0, 3)])
+        .message(vec![0; 2048])
+        .freeze()
+        .unwrap();
+
+    assert_eq!(regeneration_account_id(&tx, &client), None);
+    assert_eq!(
+        regeneration_account_id(&FirstChunkView { transaction: &tx, total_chunks: 2 }, &client),
+        Some(payer)
+    );
+}
@@ -0,0 +1,401 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::{
+    future,
+    stream,
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    get_i64,
+    get_str,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    MirrorQueryStream,
+    Status,
+    TransactionId,
+    TransactionRecordQuery,
+    Transfer,
+};
+
+/// Query the transaction history of an account from the mirror node REST API.
+///
+/// Returns the transactions *paid for* by `account_id`, oldest first,
+/// optionally filtered by transaction type, result, and consensus time range.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, TransactionHistoryQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let transactions = TransactionHistoryQuery::new()
+///     .account_id(AccountId::new(0, 0, 1001))
+///     .transaction_type("CRYPTOTRANSFER")
+///     .successful(true)
+///     .limit(100)
+///     .execute(&client)
+///     .await?;
+///
+/// for it in transactions {
+///     println!("{},{},{}", it.consensus_timestamp, it.transaction_id, it.charged_fee);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TransactionHistoryQuery {
+    account_id: Option<AccountId>,
+    transaction_type: Option<String>,
+    successful: Option<bool>,
+    start_time: Option<OffsetDateTime>,
+    end_time: Option<OffsetDateTime>,
+    limit: Option<usize>,
+}
+
+/// A summary of a transaction, as returned by [`TransactionHistoryQuery`].
+#[derive(Debug, Clone)]
+pub struct TransactionSummary {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The consensus timestamp of the transaction.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The type of the transaction, as named by the mirror node (ex. `CRYPTOTRANSFER`).
+    pub transaction_type: String,
+
+    /// The result of the transaction.
+    pub status: Status,
+
+    /// The fee charged for the transaction.
+    pub charged_fee: Hbar,
+
+    /// The node the transaction was submitted to.
+    pub node_account_id: Option<AccountId>,
+
+    /// The hbar transfers made by the transaction, including fees.
+    pub transfers: Vec<Transfer>,
+}
+
+impl TransactionSummary {
+    /// Returns a [`TransactionRecordQuery`] for the full record of this transaction.
+    ///
+    /// Consensus nodes only keep records for a few minutes after consensus,
+    /// so this is only useful for recent transactions.
+    #[must_use]
+    pub fn record_query(&self) -> TransactionRecordQuery {
+        let mut query = TransactionRecordQuery::new();
+
+        query.transaction_id(self.transaction_id);
+
+        query
+    }
+
+    fn from_json(json: &Value) -> crate::Result<Self> {
+        let result = get_str(json, "result")?;
+
+        let transfers = match json.get("transfers").and_then(Value::as_array) {
+            Some(transfers) => transfers
+                .iter()
+                .map(|it| {
+                    Ok(Transfer {
+                        account_id: get_str(it, "account")?.parse()?,
+                        amount: Hbar::from_tinybars(get_i64(it, "amount")?),
+                    })
+                })
+                .collect::<crate::Result<_>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            transaction_id: get_str(json, "transaction_id")?.parse()?,
+            consensus_timestamp: mirror_rest::parse_timestamp(get_str(
+                json,
+                "consensus_timestamp",
+            )?)?,
+            transaction_type: get_str(json, "name")?.to_owned(),
+            status: Status::from_str_name(result)
+                .ok_or_else(|| Error::mirror_rest(format!("unknown result `{result}`")))?,
+            charged_fee: Hbar::from_tinybars(get_i64(json, "charged_tx_fee")?),
+            node_account_id: json
+                .get("node")
+                .and_then(Value::as_str)
+                .map(str::parse)
+                .transpose()?,
+            transfers,
+        })
+    }
+}
+
+impl TransactionHistoryQuery {
+    /// Create a new `TransactionHistoryQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account that paid for the transactions.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account that paid for the transactions.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the type of transactions to return.
+    #[must_use]
+    pub fn get_transaction_type(&self) -> Option<&str> {
+        self.transaction_type.as_deref()
+    }
+
+    /// Sets the type of transactions to return, as named by the mirror node (ex. `CRYPTOTRANSFER`, `TOKENMINT`).
+    pub fn transaction_type(&mut self, transaction_type: impl Into<String>) -> &mut Self {
+        self.transaction_type = Some(transaction_type.into());
+        self
+    }
+
+    /// Returns whether only successful (`true`) or only failed (`false`) transactions are returned.
+    #[must_use]
+    pub fn get_successful(&self) -> Option<bool> {
+        self.successful
+    }
+
+    /// Sets whether only successful (`true`) or only failed (`false`) transactions are returned.
+    pub fn successful(&mut self, successful: bool) -> &mut Self {
+        self.successful = Some(successful);
+        self
+    }
+
+    /// Returns the consensus time (inclusive) to return transactions from.
+    #[must_use]
+    pub fn get_start_time(&self) -> Option<OffsetDateTime> {
+        self.start_time
+    }
+
+    /// Sets the consensus time (inclusive) to return transactions from.
+    pub fn start_time(&mut self, start_time: OffsetDateTime) -> &mut Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Returns the consensus time (exclusive) to return transactions until.
+    #[must_use]
+    pub fn get_end_time(&self) -> Option<OffsetDateTime> {
+        self.end_time
+    }
+
+    /// Sets the consensus time (exclusive) to return transactions until.
+    pub fn end_time(&mut self, end_time: OffsetDateTime) -> &mut Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Returns the maximum number of transactions to return.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the maximum number of transactions to return.
+    ///
+    /// Without a limit, every matching transaction is returned, which may take many requests.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute this query against the mirror node REST API of `client`.
+    ///
+    /// # Errors
+    /// - [`Error::MissingField`] if `account_id` wasn't set.
+    /// - [`Error::MirrorRest`] if a request to the mirror node fails, or its response couldn't be understood.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<TransactionSummary>> {
+        self.summaries(client).try_collect().await
+    }
+
+    fn summaries<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Stream<Item = crate::Result<TransactionSummary>> + 'a {
+        let Some(account_id) = self.account_id else {
+            let error = Error::MissingField { field: "account_id" };
+
+            return stream::once(future::ready(Err(error))).left_stream();
+        };
+
+        let payer = account_id.to_string();
+
+        // `account.id` matches any transaction involving the account, not only the ones it paid for.
+        mirror_rest::stream_all(
+            client,
+            self.path_and_query(account_id),
+            "transactions",
+            self.limit,
+            move |it| {
+                it.get("transaction_id")
+                    .and_then(Value::as_str)
+                    .and_then(|it| it.split_once('-'))
+                    .is_some_and(|(it, _)| it == payer)
+            },
+        )
+        .map(|it| it.and_then(|it| TransactionSummary::from_json(&it)))
+        .right_stream()
+    }
+
+    fn path_and_query(&self, account_id: AccountId) -> String {
+        let mut path = format!("/api/v1/transactions?account.id={account_id}&order=asc&limit=100");
+
+        if let Some(transaction_type) = &self.transaction_type {
+            path.push_str(&format!("&transactiontype={}", transaction_type.to_uppercase()));
+        }
+
+        if let Some(successful) = self.successful {
+            path.push_str(if successful { "&result=success" } else { "&result=fail" });
+        }
+
+        if let Some(start_time) = self.start_time {
+            path.push_str(&format!("&timestamp=gte:{}", mirror_rest::format_timestamp(start_time)));
+        }
+
+        if let Some(end_time) = self.end_time {
+            path.push_str(&format!("&timestamp=lt:{}", mirror_rest::format_timestamp(end_time)));
+        }
+
+        path
+    }
+}
+
+impl MirrorQueryStream for TransactionHistoryQuery {
+    type Item = TransactionSummary;
+
+    /// # Errors
+    /// - The stream yields [`Error::MissingField`] if `account_id` wasn't set.
+    fn stream<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<TransactionSummary>> {
+        let stream = self.summaries(client);
+
+        // the mirror node REST client isn't `Send` on wasm.
+        #[cfg(target_arch = "wasm32")]
+        let stream = send_wrapper::SendWrapper::new(stream);
+
+        Box::pin(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::TransactionSummary;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        TransactionHistoryQuery,
+    };
+
+    #[tokio::test]
+    async fn missing_account_id() {
+        let client = Client::for_testnet();
+
+        let res = TransactionHistoryQuery::new().execute(&client).await;
+
+        assert_matches!(res, Err(Error::MissingField { field: "account_id" }));
+    }
+
+    #[test]
+    fn path_and_query() {
+        let mut query = TransactionHistoryQuery::new();
+
+        query
+            .transaction_type("cryptotransfer")
+            .successful(true)
+            .start_time(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap())
+            .end_time(OffsetDateTime::from_unix_timestamp(1_700_086_400).unwrap());
+
+        expect!["/api/v1/transactions?account.id=0.0.1001&order=asc&limit=100&transactiontype=CRYPTOTRANSFER&result=success&timestamp=gte:1700000000.000000000&timestamp=lt:1700086400.000000000"]
+            .assert_eq(&query.path_and_query(AccountId::new(0, 0, 1001)));
+    }
+
+    #[test]
+    fn summary_from_json() {
+        let json = json!({
+            "charged_tx_fee": 8_465,
+            "consensus_timestamp": "1700000001.000000002",
+            "name": "CRYPTOTRANSFER",
+            "node": "0.0.3",
+            "result": "SUCCESS",
+            "transaction_id": "0.0.1001-1700000000-000000001",
+            "transfers": [
+                { "account": "0.0.1001", "amount": -9_465, "is_approval": false },
+                { "account": "0.0.1002", "amount": 1_000, "is_approval": false },
+                { "account": "0.0.3", "amount": 8_465, "is_approval": false }
+            ]
+        });
+
+        expect![[r#"
+            TransactionSummary {
+                transaction_id: "0.0.1001@1700000000.1",
+                consensus_timestamp: 2023-11-14 22:13:21.000000002 +00:00:00,
+                transaction_type: "CRYPTOTRANSFER",
+                status: Success,
+                charged_fee: "8465 tℏ",
+                node_account_id: Some(
+                    "0.0.3",
+                ),
+                transfers: [
+                    Transfer {
+                        account_id: "0.0.1001",
+                        amount: "-9465 tℏ",
+                    },
+                    Transfer {
+                        account_id: "0.0.1002",
+                        amount: "1000 tℏ",
+                    },
+                    Transfer {
+                        account_id: "0.0.3",
+                        amount: "8465 tℏ",
+                    },
+                ],
+            }
+        "#]]
+        .assert_debug_eq(&TransactionSummary::from_json(&json).unwrap());
+    }
+}
@@ -18,6 +18,7 @@
  * ‍
  */
 
+use std::cmp::Ordering;
 use std::fmt::{
     self,
     Debug,
@@ -80,6 +81,74 @@ impl TransactionId {
         Self { account_id, valid_start, scheduled: false, nonce: None }
     }
 
+    /// Returns the ID of the child transaction with the given `nonce`.
+    ///
+    /// Child transactions (such as those spawned by a contract call) share the ID of their parent,
+    /// except for the nonce.
+    #[must_use]
+    pub fn child(&self, nonce: i32) -> Self {
+        Self { nonce: Some(nonce), ..*self }
+    }
+
+    /// Returns the ID of the parent transaction, `self` if this is already a parent (has no nonce).
+    #[must_use]
+    pub fn parent(&self) -> Self {
+        Self { nonce: None, ..*self }
+    }
+
+    /// Returns `true` if this is the ID of a child transaction, IE, it has a nonce.
+    #[must_use]
+    pub fn is_child(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    /// Returns `true` if `child` is the ID of a child transaction of `self`.
+    ///
+    /// Unlike `==` this ignores the checksum of the account ID.
+    #[must_use]
+    pub fn is_parent_of(&self, child: &Self) -> bool {
+        !self.is_child() && child.is_child() && self.is_same_transaction(&child.parent())
+    }
+
+    /// Returns `true` if `self` and `other` identify the same transaction.
+    ///
+    /// Unlike `==` this ignores the checksum of the account ID.
+    #[must_use]
+    pub fn is_same_transaction(&self, other: &Self) -> bool {
+        let (a, b) = (&self.account_id, &other.account_id);
+
+        a.shard == b.shard
+            && a.realm == b.realm
+            && a.num == b.num
+            && a.alias == b.alias
+            && a.evm_address == b.evm_address
+            && self.valid_start == other.valid_start
+            && self.scheduled == other.scheduled
+            && self.nonce == other.nonce
+    }
+
+    /// Compares `self` and `other` by valid start, then by payer account number.
+    ///
+    /// Within the same parent, the parent sorts before its children, which sort by nonce,
+    /// and a scheduled transaction sorts after the transaction that scheduled it.
+    ///
+    /// Transactions that differ only by account alias, EVM address, or checksum compare as [`Ordering::Equal`].
+    #[must_use]
+    pub fn cmp_by_valid_start(&self, other: &Self) -> Ordering {
+        let key = |it: &Self| {
+            (
+                it.valid_start,
+                it.account_id.shard,
+                it.account_id.realm,
+                it.account_id.num,
+                it.scheduled,
+                it.nonce,
+            )
+        };
+
+        key(self).cmp(&key(other))
+    }
+
     /// Create a new `TransactionId` from protobuf-encoded `bytes`.
     ///
     /// # Errors
@@ -194,6 +263,11 @@ impl FromStr for TransactionId {
             None => (s, false),
         };
 
+        // a sign would otherwise be accepted by `from_str`, and out of range nanos wouldn't round-trip.
+        if nanos.starts_with(['+', '-']) || nanos.len() > 9 {
+            return Err(Error::basic_parse(EXPECTED));
+        }
+
         let valid_start = {
             let seconds = i64::from_str(seconds).map_err(Error::basic_parse)?;
             let nanos = i64::from_str(nanos).map_err(Error::basic_parse)?;
@@ -238,6 +312,86 @@ mod tests {
         assert_matches!(TransactionId::from_str("0.0.31415?scheduled/1412@1641088801.2"), Err(_));
     }
 
+    #[test]
+    fn from_str_bad_nanos() {
+        assert_matches!(TransactionId::from_str("0.0.31415@1641088801.1000000000"), Err(_));
+        assert_matches!(TransactionId::from_str("0.0.31415@1641088801.-2"), Err(_));
+        assert_matches!(TransactionId::from_str("0.0.31415@1641088801.+2"), Err(_));
+    }
+
+    #[test]
+    fn round_trip_suffixes() {
+        for s in [
+            "0.0.23847@1588539964.632521325",
+            "0.0.23847@1588539964.632521325?scheduled",
+            "0.0.23847@1588539964.632521325/0",
+            "0.0.23847@1588539964.632521325/7",
+            "0.0.23847@1588539964.632521325?scheduled/7",
+            "0.0.23847@1588539964.5",
+        ] {
+            assert_eq!(TransactionId::from_str(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn child() {
+        let parent = TransactionId::from_str("0.0.23847@1588539964.632521325").unwrap();
+        let child = parent.child(3);
+
+        expect!["0.0.23847@1588539964.632521325/3"].assert_eq(&child.to_string());
+
+        assert!(child.is_child());
+        assert!(!parent.is_child());
+        assert!(parent.is_parent_of(&child));
+        assert!(!child.is_parent_of(&parent));
+        assert!(!parent.is_parent_of(&parent));
+        assert_eq!(child.parent(), parent);
+
+        let other = TransactionId::from_str("0.0.23848@1588539964.632521325/3").unwrap();
+        assert!(!parent.is_parent_of(&other));
+    }
+
+    #[test]
+    fn is_same_transaction_ignores_checksum() {
+        let a = TransactionId::from_str("0.0.123-vfmkw@1588539964.632521325").unwrap();
+        let b = TransactionId::from_str("0.0.123@1588539964.632521325").unwrap();
+
+        assert_ne!(a, b);
+        assert!(a.is_same_transaction(&b));
+        assert!(!a.is_same_transaction(&b.child(1)));
+    }
+
+    #[test]
+    fn cmp_by_valid_start() {
+        let mut ids: Vec<TransactionId> = [
+            "0.0.5@1588539964.2/2",
+            "0.0.5@1588539964.2?scheduled",
+            "0.0.5@1588539964.2/1",
+            "0.0.4@1588539964.3",
+            "0.0.5@1588539964.2",
+            "0.0.6@1588539964.1",
+        ]
+        .into_iter()
+        .map(|it| it.parse().unwrap())
+        .collect();
+
+        ids.sort_by(TransactionId::cmp_by_valid_start);
+
+        let ids: Vec<_> = ids.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            ids,
+            [
+                "0.0.6@1588539964.1",
+                "0.0.5@1588539964.2",
+                "0.0.5@1588539964.2/1",
+                "0.0.5@1588539964.2/2",
+                "0.0.5@1588539964.2?scheduled",
+                "0.0.4@1588539964.3",
+            ]
+        );
+    }
+
     #[test]
     fn from_str_single_digit_nanos() {
         let id = TransactionId {
@@ -43,6 +43,7 @@ use crate::{
 /// Response from [`TransactionReceiptQuery`][crate::TransactionReceiptQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct TransactionReceipt {
     // fixme(sr): better doc comment.
     /// The ID of the transaction that this is a receipt for.
@@ -50,6 +51,7 @@ pub struct TransactionReceipt {
 
     /// The consensus status of the transaction; is UNKNOWN if consensus has not been reached, or if
     /// the associated transaction did not have a valid payer signature.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::status"))]
     pub status: Status,
 
     /// In the receipt for an `AccountCreateTransaction`, the id of the newly created account.
@@ -74,6 +76,7 @@ pub struct TransactionReceipt {
     // TODO: use a hash type (for display/debug/serialize purposes)
     /// In the receipt for a `TopicMessageSubmitTransaction`, the new running hash of the
     /// topic that received the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex::option"))]
     pub topic_running_hash: Option<Vec<u8>>,
 
     /// In the receipt of a `TopicMessageSubmitTransaction`, the version of the SHA-384
@@ -450,4 +453,14 @@ mod tests {
 
         assert_eq!(a.to_protobuf(), b.to_protobuf());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let a = make_receipt();
+        let b: TransactionReceipt =
+            serde_json::from_str(&serde_json::to_string(&a).unwrap()).unwrap();
+
+        assert_eq!(a.to_protobuf(), b.to_protobuf());
+    }
 }
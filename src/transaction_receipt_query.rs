@@ -21,17 +21,22 @@
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use hedera_proto::services::response::Response;
-use tonic::transport::Channel;
 
+use crate::client::ClientBackoff;
+use crate::execute::execute_with_backoff;
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
     AnyQueryData,
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
+    AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
+    FromProtobuf,
     Query,
     Status,
     ToProtobuf,
@@ -53,6 +58,8 @@ pub struct TransactionReceiptQueryData {
     include_children: bool,
     include_duplicates: bool,
     validate_status: bool,
+    return_unknown: bool,
+    preferred_node_account_id: Option<AccountId>,
 }
 
 impl From<TransactionReceiptQueryData> for AnyQueryData {
@@ -112,6 +119,36 @@ impl TransactionReceiptQuery {
         self.data.validate_status = validate;
         self
     }
+
+    /// Returns the node asked for the receipt before any other.
+    #[must_use]
+    pub fn get_preferred_node_account_id(&self) -> Option<AccountId> {
+        self.data.preferred_node_account_id
+    }
+
+    /// Sets the node to ask for the receipt before any other, as long as it's healthy.
+    ///
+    /// The node a transaction was submitted to usually has its receipt first,
+    /// see [`TransactionResponse::get_receipt_from_submitting_node`](crate::TransactionResponse::get_receipt_from_submitting_node).
+    pub fn preferred_node_account_id(&mut self, node_account_id: AccountId) -> &mut Self {
+        self.data.preferred_node_account_id = Some(node_account_id);
+        self
+    }
+
+    /// Return a receipt with a status of [`Status::Unknown`] instead of polling until consensus.
+    pub(crate) fn return_unknown(&mut self, return_unknown: bool) -> &mut Self {
+        self.data.return_unknown = return_unknown;
+        self
+    }
+
+    pub(crate) async fn execute_with_backoff(
+        &mut self,
+        client: &Client,
+        backoff: ClientBackoff,
+    ) -> crate::Result<TransactionReceipt> {
+        // receipts are free, so there's no payment to prepare.
+        execute_with_backoff(client, self, backoff, None).await
+    }
 }
 
 impl ToQueryProtobuf for TransactionReceiptQueryData {
@@ -131,6 +168,17 @@ impl ToQueryProtobuf for TransactionReceiptQueryData {
     }
 }
 
+impl FromProtobuf<services::TransactionGetReceiptQuery> for TransactionReceiptQueryData {
+    fn from_protobuf(pb: services::TransactionGetReceiptQuery) -> crate::Result<Self> {
+        Ok(Self {
+            transaction_id: Option::from_protobuf(pb.transaction_id)?,
+            include_children: pb.include_child_receipts,
+            include_duplicates: pb.include_duplicates,
+            ..Self::default()
+        })
+    }
+}
+
 impl QueryExecute for TransactionReceiptQueryData {
     type Response = TransactionReceipt;
 
@@ -156,7 +204,19 @@ impl QueryExecute for TransactionReceiptQueryData {
         matches!(status, Status::ReceiptNotFound | Status::RecordNotFound)
     }
 
+    fn is_receipt_poll(&self) -> bool {
+        true
+    }
+
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        self.preferred_node_account_id
+    }
+
     fn should_retry(&self, response: &services::Response) -> bool {
+        if self.return_unknown {
+            return false;
+        }
+
         // extract the receipt status from the receipt
         // without altering or freeing the memory from the response
 
@@ -179,7 +239,9 @@ impl QueryExecute for TransactionReceiptQueryData {
         let receipt =
             TransactionReceipt::from_response_protobuf(response, self.transaction_id.as_ref())?;
 
-        if self.validate_status && receipt.status != Status::Success {
+        let is_pending = self.return_unknown && receipt.status == Status::Unknown;
+
+        if self.validate_status && !is_pending && receipt.status != Status::Success {
             return Err(Error::ReceiptStatus {
                 transaction_id: self.transaction_id.map(Box::new),
                 status: receipt.status,
@@ -192,7 +254,8 @@ impl QueryExecute for TransactionReceiptQueryData {
 
 impl ValidateChecksums for TransactionReceiptQueryData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
-        self.transaction_id.validate_checksums(ledger_id)
+        self.transaction_id.validate_checksums(ledger_id)?;
+        self.preferred_node_account_id.validate_checksums(ledger_id)
     }
 }
 
@@ -287,4 +350,12 @@ mod tests {
 
         assert_eq!(query.get_validate_status(), true);
     }
+
+    #[test]
+    fn get_set_preferred_node_account_id() {
+        let mut query = TransactionReceiptQuery::new();
+        query.preferred_node_account_id(crate::AccountId::new(0, 0, 3));
+
+        assert_eq!(query.get_preferred_node_account_id(), Some(crate::AccountId::new(0, 0, 3)));
+    }
 }
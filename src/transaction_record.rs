@@ -40,12 +40,14 @@ use crate::{
     TokenNftTransfer,
     TransactionId,
     TransactionReceipt,
+    TransactionSidecarRecord,
     Transfer,
 };
 
 /// The complete record for a transaction on Hedera that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -53,9 +55,11 @@ pub struct TransactionRecord {
 
     /// The hash of the Transaction that executed (not the hash of any Transaction that failed for
     /// having a duplicate TransactionID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub transaction_hash: Vec<u8>,
 
     /// The consensus timestamp.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// Record of the value returned by the smart contract function or constructor.
@@ -93,6 +97,7 @@ pub struct TransactionRecord {
 
     /// In the record of an internal transaction, the consensus timestamp of the user
     /// transaction that spawned it.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339::option"))]
     pub parent_consensus_timestamp: Option<OffsetDateTime>,
 
     /// In the record of an internal CryptoCreate transaction triggered by a user
@@ -109,9 +114,11 @@ pub struct TransactionRecord {
 
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub ethereum_hash: Vec<u8>,
 
     /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex::option"))]
     pub prng_bytes: Option<Vec<u8>>,
 
     /// In the record of a PRNG transaction with an output range, the output of a PRNG
@@ -123,8 +130,18 @@ pub struct TransactionRecord {
 
     /// A list of pending token airdrops.
     pub pending_airdrop_records: Vec<PendingAirdropRecord>,
+
+    /// The staking rewards paid to accounts as a side effect of this transaction.
+    ///
+    /// Pending rewards are paid (from `0.0.800`) to staking accounts touched by the transaction,
+    /// see [`staking_reward`](Self::staking_reward).
+    pub paid_staking_rewards: Vec<Transfer>,
+
+    /// The sidecar records of this transaction.
+    ///
+    /// Always `None` for records returned by consensus nodes, see [`attach_sidecars`](Self::attach_sidecars).
+    pub sidecars: Option<Vec<TransactionSidecarRecord>>,
 }
-// TODO: paid_staking_rewards
 
 impl TransactionRecord {
     /// Create a new `TransactionRecord` from protobuf-encoded `bytes`.
@@ -142,6 +159,71 @@ impl TransactionRecord {
         ToProtobuf::to_bytes(self)
     }
 
+    /// Returns the staking reward paid to `account_id` by this transaction, if any.
+    #[must_use]
+    pub fn staking_reward(&self, account_id: AccountId) -> Option<Hbar> {
+        self.paid_staking_rewards
+            .iter()
+            .filter(|it| it.account_id == account_id)
+            .map(|it| it.amount)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Returns the staking rewards paid by this transaction, summed per account.
+    #[must_use]
+    pub fn staking_rewards_by_account(&self) -> HashMap<AccountId, Hbar> {
+        let mut rewards = HashMap::with_capacity(self.paid_staking_rewards.len());
+
+        for it in &self.paid_staking_rewards {
+            *rewards.entry(it.account_id).or_insert(Hbar::ZERO) += it.amount;
+        }
+
+        rewards
+    }
+
+    /// Returns the total staking rewards paid by this transaction.
+    #[must_use]
+    pub fn total_staking_rewards(&self) -> Hbar {
+        self.paid_staking_rewards.iter().map(|it| it.amount).sum()
+    }
+
+    /// Returns the result of the contract call or contract creation this record is for, if it's for either.
+    ///
+    /// This covers [`ContractExecuteTransaction`](crate::ContractExecuteTransaction),
+    /// [`ContractCreateTransaction`](crate::ContractCreateTransaction),
+    /// and [`EthereumTransaction`](crate::EthereumTransaction).
+    #[must_use]
+    pub fn contract_function_result(&self) -> Option<&ContractFunctionResult> {
+        self.contract_function_result.as_ref()
+    }
+
+    /// Returns the keccak256 hash of the ethereum data, if this record is for an [`EthereumTransaction`](crate::EthereumTransaction).
+    #[must_use]
+    pub fn ethereum_hash(&self) -> Option<&[u8]> {
+        (!self.ethereum_hash.is_empty()).then_some(self.ethereum_hash.as_slice())
+    }
+
+    /// Returns the EVM address of the account this transaction auto-created, if it auto-created one from an ECDSA key.
+    #[must_use]
+    pub fn evm_address(&self) -> Option<EvmAddress> {
+        self.evm_address
+    }
+
+    /// Attaches the sidecars belonging to this transaction (those with the same consensus timestamp)
+    /// from `sidecars`, as read from a record stream source.
+    ///
+    /// Sidecars aren't part of the record's protobuf, so [`to_bytes`](Self::to_bytes) doesn't include them.
+    pub fn attach_sidecars(
+        &mut self,
+        sidecars: impl IntoIterator<Item = TransactionSidecarRecord>,
+    ) {
+        let consensus_timestamp = self.consensus_timestamp;
+
+        self.sidecars.get_or_insert_with(Vec::new).extend(
+            sidecars.into_iter().filter(|it| it.consensus_timestamp == consensus_timestamp),
+        );
+    }
+
     fn from_protobuf(
         record: services::TransactionRecord,
         duplicates: Vec<Self>,
@@ -239,6 +321,8 @@ impl TransactionRecord {
             prng_bytes,
             prng_number,
             pending_airdrop_records,
+            paid_staking_rewards: Vec::from_protobuf(record.paid_staking_rewards)?,
+            sidecars: None,
         })
     }
 }
@@ -280,22 +364,38 @@ impl ToProtobuf for TransactionRecord {
                 self.prng_bytes.clone().map(services::transaction_record::Entropy::PrngBytes)
             });
 
-        let token_transfer_lists = self
-            .token_transfers
-            .iter()
-            .map(|it| services::TokenTransferList {
-                token: Some(it.0.to_protobuf()),
-                transfers: it
-                    .1
-                    .iter()
-                    .map(|it| services::AccountAmount {
-                        account_id: Some(it.0.to_protobuf()),
-                        amount: *it.1,
-                        is_approval: false,
-                    })
-                    .collect(),
-                nft_transfers: Vec::new(),
-                expected_decimals: None,
+        let mut token_ids: Vec<_> =
+            self.token_transfers.keys().chain(self.token_nft_transfers.keys()).copied().collect();
+
+        // the maps are unordered, sorting keeps the bytes stable.
+        token_ids.sort_unstable_by_key(|it| (it.shard, it.realm, it.num));
+        token_ids.dedup();
+
+        let token_transfer_lists = token_ids
+            .into_iter()
+            .map(|token_id| {
+                let mut transfers: Vec<_> =
+                    self.token_transfers.get(&token_id).into_iter().flatten().collect();
+
+                transfers.sort_unstable_by_key(|(it, _)| (it.shard, it.realm, it.num));
+
+                services::TokenTransferList {
+                    token: Some(token_id.to_protobuf()),
+                    transfers: transfers
+                        .into_iter()
+                        .map(|(account_id, amount)| services::AccountAmount {
+                            account_id: Some(account_id.to_protobuf()),
+                            amount: *amount,
+                            is_approval: false,
+                        })
+                        .collect(),
+                    nft_transfers: self
+                        .token_nft_transfers
+                        .get(&token_id)
+                        .map(ToProtobuf::to_protobuf)
+                        .unwrap_or_default(),
+                    expected_decimals: None,
+                }
             })
             .collect();
 
@@ -316,8 +416,7 @@ impl ToProtobuf for TransactionRecord {
             parent_consensus_timestamp: self.parent_consensus_timestamp.to_protobuf(),
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ethereum_hash: self.ethereum_hash.clone(),
-            // TODO:
-            paid_staking_rewards: Vec::new(),
+            paid_staking_rewards: self.paid_staking_rewards.to_protobuf(),
             evm_address: self
                 .evm_address
                 .as_ref()
@@ -337,6 +436,7 @@ mod tests {
     use std::collections::HashMap;
 
     use expect_test::expect_file;
+    use time::Duration;
 
     use crate::pending_airdrop_id::PendingAirdropId;
     use crate::protobuf::ToProtobuf;
@@ -357,6 +457,8 @@ mod tests {
         TokenId,
         TokenNftTransfer,
         TransactionRecord,
+        TransactionSidecar,
+        TransactionSidecarRecord,
         Transfer,
     };
 
@@ -435,6 +537,18 @@ mod tests {
                 ),
                 pending_airdrop_value: Some(2),
             }],
+            paid_staking_rewards: Vec::from([
+                Transfer {
+                    account_id: AccountId::new(0, 0, 1001),
+                    amount: Hbar::from_tinybars(10),
+                },
+                Transfer {
+                    account_id: AccountId::new(0, 0, 1002),
+                    amount: Hbar::from_tinybars(20),
+                },
+                Transfer { account_id: AccountId::new(0, 0, 1001), amount: Hbar::from_tinybars(5) },
+            ]),
+            sidecars: None,
         }
     }
 
@@ -465,4 +579,65 @@ mod tests {
 
         assert_eq!(a.to_protobuf(), b.to_protobuf());
     }
+
+    #[test]
+    fn evm_accessors() {
+        let record = make_record(None, Some(4));
+
+        assert_eq!(
+            record.contract_function_result().unwrap().contract_id,
+            ContractId::new(1, 2, 3)
+        );
+        assert_eq!(record.ethereum_hash(), Some(&b"Some hash"[..]));
+        assert_eq!(record.evm_address(), Some(crate::EvmAddress([0; 20])));
+
+        let record = TransactionRecord {
+            contract_function_result: None,
+            ethereum_hash: Vec::new(),
+            evm_address: None,
+            ..record
+        };
+
+        assert!(record.contract_function_result().is_none());
+        assert_eq!(record.ethereum_hash(), None);
+        assert_eq!(record.evm_address(), None);
+    }
+
+    #[test]
+    fn attach_sidecars() {
+        let sidecar = |consensus_timestamp| TransactionSidecarRecord {
+            consensus_timestamp,
+            migration: false,
+            sidecar: TransactionSidecar::StateChanges(Vec::new()),
+        };
+
+        let mut record = make_record(None, Some(4));
+
+        record.attach_sidecars([
+            sidecar(VALID_START),
+            sidecar(VALID_START + Duration::seconds(1)),
+            sidecar(VALID_START),
+        ]);
+
+        assert_eq!(record.sidecars.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn staking_rewards() {
+        let record = make_record(None, Some(4));
+
+        assert_eq!(
+            record.staking_reward(AccountId::new(0, 0, 1001)),
+            Some(Hbar::from_tinybars(15))
+        );
+        assert_eq!(record.staking_reward(AccountId::new(0, 0, 1003)), None);
+        assert_eq!(
+            record.staking_rewards_by_account(),
+            HashMap::from([
+                (AccountId::new(0, 0, 1001), Hbar::from_tinybars(15)),
+                (AccountId::new(0, 0, 1002), Hbar::from_tinybars(20)),
+            ])
+        );
+        assert_eq!(record.total_staking_rewards(), Hbar::from_tinybars(35));
+    }
 }
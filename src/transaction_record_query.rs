@@ -21,7 +21,6 @@
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use hedera_proto::services::response::Response;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
 use crate::query::{
@@ -29,7 +28,9 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+use crate::transport::Channel;
 use crate::{
+    AccountId,
     BoxGrpcFuture,
     Error,
     FromProtobuf,
@@ -51,6 +52,7 @@ pub struct TransactionRecordQueryData {
     include_children: bool,
     include_duplicates: bool,
     validate_status: bool,
+    preferred_node_account_id: Option<AccountId>,
 }
 
 impl From<TransactionRecordQueryData> for AnyQueryData {
@@ -110,6 +112,21 @@ impl TransactionRecordQuery {
         self.data.validate_status = validate;
         self
     }
+
+    /// Returns the node asked for the record before any other.
+    #[must_use]
+    pub fn get_preferred_node_account_id(&self) -> Option<AccountId> {
+        self.data.preferred_node_account_id
+    }
+
+    /// Sets the node to ask for the record before any other, as long as it's healthy.
+    ///
+    /// The node a transaction was submitted to usually has its record first,
+    /// see [`Client::set_node_affinity`](crate::Client::set_node_affinity).
+    pub fn preferred_node_account_id(&mut self, node_account_id: AccountId) -> &mut Self {
+        self.data.preferred_node_account_id = Some(node_account_id);
+        self
+    }
 }
 
 impl ToQueryProtobuf for TransactionRecordQueryData {
@@ -129,6 +146,17 @@ impl ToQueryProtobuf for TransactionRecordQueryData {
     }
 }
 
+impl FromProtobuf<services::TransactionGetRecordQuery> for TransactionRecordQueryData {
+    fn from_protobuf(pb: services::TransactionGetRecordQuery) -> crate::Result<Self> {
+        Ok(Self {
+            transaction_id: Option::from_protobuf(pb.transaction_id)?,
+            include_children: pb.include_child_records,
+            include_duplicates: pb.include_duplicates,
+            ..Self::default()
+        })
+    }
+}
+
 impl QueryExecute for TransactionRecordQueryData {
     type Response = TransactionRecord;
 
@@ -148,6 +176,10 @@ impl QueryExecute for TransactionRecordQueryData {
         matches!(status, Status::ReceiptNotFound | Status::RecordNotFound)
     }
 
+    fn preferred_node_account_id(&self) -> Option<AccountId> {
+        self.preferred_node_account_id
+    }
+
     fn make_response(&self, response: Response) -> crate::Result<Self::Response> {
         let record = TransactionRecord::from_protobuf(response)?;
 
@@ -164,7 +196,8 @@ impl QueryExecute for TransactionRecordQueryData {
 
 impl ValidateChecksums for TransactionRecordQueryData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
-        self.transaction_id.validate_checksums(ledger_id)
+        self.transaction_id.validate_checksums(ledger_id)?;
+        self.preferred_node_account_id.validate_checksums(ledger_id)
     }
 }
 
@@ -261,4 +294,12 @@ mod tests {
 
         assert_eq!(query.get_validate_status(), true);
     }
+
+    #[test]
+    fn get_set_preferred_node_account_id() {
+        let mut query = TransactionRecordQuery::new();
+        query.preferred_node_account_id(crate::AccountId::new(0, 0, 3));
+
+        assert_eq!(query.get_preferred_node_account_id(), Some(crate::AccountId::new(0, 0, 3)));
+    }
 }
@@ -18,9 +18,14 @@
  * ‍
  */
 
+use crate::runtime::Instant;
 use crate::{
     AccountId,
     Client,
+    Error,
+    Hbar,
+    ReceiptPollingPolicy,
+    Status,
     TransactionHash,
     TransactionId,
     TransactionReceipt,
@@ -55,9 +60,106 @@ pub struct TransactionResponse {
 
     /// Whether the receipt/record status should be validated.
     pub validate_status: bool,
+
+    // the max transaction fee the transaction was submitted with, `None` for cost estimates.
+    pub(crate) max_transaction_fee: Option<Hbar>,
+
+    // every node the transaction was sent to, in order, ending with `node_account_id`.
+    pub(crate) attempted_node_account_ids: Vec<AccountId>,
+}
+
+/// A non-fatal problem with a transaction that went through, see [`TransactionResponse::warnings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TransactionWarning {
+    /// The fee charged for the transaction is within 10% of its max transaction fee.
+    ///
+    /// Fees drift with the exchange rate and network throttling, so a similar transaction
+    /// is likely to intermittently fail with [`Status::InsufficientTxFee`]; consider raising the max transaction fee.
+    FeeNearMax {
+        /// The fee charged for the transaction.
+        transaction_fee: Hbar,
+
+        /// The max transaction fee the transaction was submitted with.
+        max_transaction_fee: Hbar,
+    },
+}
+
+impl TransactionWarning {
+    /// Returns a [`FeeNearMax`](Self::FeeNearMax) warning if `transaction_fee` is within 10% of `max_transaction_fee`.
+    fn fee_near_max(transaction_fee: Hbar, max_transaction_fee: Hbar) -> Option<Self> {
+        let fee = i128::from(transaction_fee.to_tinybars());
+        let max = i128::from(max_transaction_fee.to_tinybars());
+
+        if fee <= 0 || fee * 10 < max * 9 {
+            return None;
+        }
+
+        Some(Self::FeeNearMax { transaction_fee, max_transaction_fee })
+    }
+}
+
+/// A transaction that reached consensus successfully.
+///
+/// Returned by [`Transaction::execute_and_get_receipt`](crate::Transaction::execute_and_get_receipt).
+#[derive(Debug)]
+pub struct ExecutedTransaction {
+    /// The response from the node the transaction was submitted to.
+    pub response: TransactionResponse,
+
+    /// The receipt of the transaction, whose status is always [`Status::Success`].
+    pub receipt: TransactionReceipt,
 }
 
 impl TransactionResponse {
+    /// Returns how many times the transaction was sent to a node, including the attempt that succeeded.
+    ///
+    /// This is `1` unless the transaction was retried, see [`all_attempted_nodes`](Self::all_attempted_nodes).
+    #[must_use]
+    pub fn attempts(&self) -> usize {
+        self.attempted_node_account_ids.len()
+    }
+
+    /// Returns every node the transaction was sent to, in the order they were tried.
+    ///
+    /// The last node is always [`node_account_id`](Self::node_account_id),
+    /// any before it failed or were busy, which can help track failures down to specific nodes.
+    #[must_use]
+    pub fn all_attempted_nodes(&self) -> &[AccountId] {
+        &self.attempted_node_account_ids
+    }
+
+    pub(crate) fn set_attempted_nodes(&mut self, node_account_ids: Vec<AccountId>) {
+        debug_assert_eq!(node_account_ids.last(), Some(&self.node_account_id));
+
+        self.attempted_node_account_ids = node_account_ids;
+    }
+
+    /// Returns the warnings for this transaction given its `record`.
+    ///
+    /// Nodes only report the fee of a transaction in its record, so these can't be known any sooner;
+    /// [`get_record`](Self::get_record) and friends also log them.
+    #[must_use]
+    pub fn warnings(&self, record: &TransactionRecord) -> Vec<TransactionWarning> {
+        self.max_transaction_fee
+            .and_then(|max| TransactionWarning::fee_near_max(record.transaction_fee, max))
+            .into_iter()
+            .collect()
+    }
+
+    fn log_warnings(&self, record: &TransactionRecord) {
+        for warning in self.warnings(record) {
+            match warning {
+                TransactionWarning::FeeNearMax { transaction_fee, max_transaction_fee } => {
+                    log::warn!(
+                        "`{}` was charged {transaction_fee}, within 10% of its max transaction fee of {max_transaction_fee}; consider raising the max transaction fee",
+                        self.transaction_id
+                    );
+                }
+            }
+        }
+    }
+
     /// Whether the receipt/record status should be validated.
     pub fn validate_status(&mut self, validate: bool) -> &mut Self {
         self.validate_status = validate;
@@ -74,6 +176,18 @@ impl TransactionResponse {
         query
     }
 
+    /// Create a query that will get the receipt for this transaction,
+    /// preferring the submitting node if `client` has [node affinity](Client::node_affinity).
+    pub(crate) fn receipt_query_for(&self, client: &Client) -> TransactionReceiptQuery {
+        let mut query = self.get_receipt_query();
+
+        if client.node_affinity() {
+            query.preferred_node_account_id(self.node_account_id);
+        }
+
+        query
+    }
+
     /// Create a query that will get the record for this transaction.
     #[must_use]
     pub fn get_record_query(&self) -> TransactionRecordQuery {
@@ -84,53 +198,252 @@ impl TransactionResponse {
         query
     }
 
+    /// Create a query that will get the record for this transaction,
+    /// preferring the submitting node if `client` has [node affinity](Client::node_affinity).
+    pub(crate) fn record_query_for(&self, client: &Client) -> TransactionRecordQuery {
+        let mut query = self.get_record_query();
+
+        if client.node_affinity() {
+            query.preferred_node_account_id(self.node_account_id);
+        }
+
+        query
+    }
+
     /// Get the receipt for this transaction.
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     ///
     /// fixme: is that it? Surely there are more situations.
     pub async fn get_receipt(&self, client: &Client) -> crate::Result<TransactionReceipt> {
-        self.get_receipt_query().execute(client).await
+        self.receipt_query_for(client).execute(client).await
     }
 
     /// Get the receipt for this transaction.
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     pub async fn get_receipt_with_timeout(
         &self,
         client: &Client,
         timeout: std::time::Duration,
     ) -> crate::Result<TransactionReceipt> {
-        self.get_receipt_query().execute_with_timeout(client, timeout).await
+        self.receipt_query_for(client).execute_with_timeout(client, timeout).await
+    }
+
+    /// Get the receipt for this transaction, asking the node it was submitted to first.
+    /// Will wait for consensus.
+    ///
+    /// The submitting node usually has the receipt before any other node does,
+    /// other nodes are only asked if it's unhealthy or doesn't respond.
+    ///
+    /// # Errors
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    pub async fn get_receipt_from_submitting_node(
+        &self,
+        client: &Client,
+    ) -> crate::Result<TransactionReceipt> {
+        self.get_receipt_query()
+            .preferred_node_account_id(self.node_account_id)
+            .execute(client)
+            .await
+    }
+
+    /// Get the receipt for this transaction, treating any status in `accepted` as success.
+    /// Will wait for consensus.
+    ///
+    /// This is useful for requests that are expected to be re-run,
+    /// where a status such as [`Status::TokenAlreadyAssociatedToAccount`] means there was nothing left to do.
+    ///
+    /// # Errors
+    /// - [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt whose status isn't in `accepted`,
+    ///   regardless of [`validate_status`](Self::validate_status).
+    pub async fn get_receipt_accepting(
+        &self,
+        client: &Client,
+        accepted: &[Status],
+    ) -> crate::Result<TransactionReceipt> {
+        let receipt = self.receipt_query_for(client).validate_status(false).execute(client).await?;
+
+        if receipt.status != Status::Success && !accepted.contains(&receipt.status) {
+            return Err(Error::ReceiptStatus {
+                status: receipt.status,
+                transaction_id: Some(Box::new(self.transaction_id)),
+            });
+        }
+
+        Ok(receipt)
+    }
+
+    /// Get the receipt for this transaction, polling according to `policy` rather than the client's backoff.
+    ///
+    /// If [`policy.return_on_unknown`](ReceiptPollingPolicy::return_on_unknown) is `true`
+    /// this returns as soon as any receipt is available, which may have a status of [`Status::Unknown`].
+    ///
+    /// # Errors
+    /// - [`Error::TimedOut`](crate::Error::TimedOut) if the receipt isn't available within the policy's limits.
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    pub async fn get_receipt_with_policy(
+        &self,
+        client: &Client,
+        policy: ReceiptPollingPolicy,
+    ) -> crate::Result<TransactionReceipt> {
+        self.receipt_query_for(client)
+            .return_unknown(policy.return_on_unknown)
+            .execute_with_backoff(client, policy.to_backoff(client.backoff()))
+            .await
+    }
+
+    /// Get the record for this transaction, polling for its receipt according to `policy`.
+    ///
+    /// A record only exists once the transaction has reached consensus,
+    /// so unlike [`get_receipt_with_policy`](Self::get_receipt_with_policy) this never returns a pending result.
+    /// The record itself is fetched within whatever is left of the policy's [`max_wait`](ReceiptPollingPolicy::max_wait).
+    ///
+    /// # Errors
+    /// - [`Error::TimedOut`](crate::Error::TimedOut) if the receipt isn't available within the policy's limits.
+    /// - [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) with [`Status::Unknown`]
+    ///   if `policy.return_on_unknown` is `true` and the transaction hasn't reached consensus yet.
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    pub async fn get_record_with_policy(
+        &self,
+        client: &Client,
+        policy: ReceiptPollingPolicy,
+    ) -> crate::Result<TransactionRecord> {
+        let start = Instant::now();
+
+        let receipt = self.get_receipt_with_policy(client, policy).await?;
+
+        if receipt.status == Status::Unknown {
+            return Err(Error::ReceiptStatus {
+                status: receipt.status,
+                transaction_id: Some(Box::new(self.transaction_id)),
+            });
+        }
+
+        let timeout = policy.max_wait.map(|max_wait| max_wait.saturating_sub(start.elapsed()));
+
+        let record =
+            self.record_query_for(client).execute_with_optional_timeout(client, timeout).await?;
+
+        self.log_warnings(&record);
+
+        Ok(record)
     }
 
     /// Get the record for this transaction.
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
-        self.get_record_query().execute(client).await
+        let record = self.record_query_for(client).execute(client).await?;
+
+        self.log_warnings(&record);
+
+        Ok(record)
     }
 
     /// Get the record for this transaction.
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record_with_timeout(
         &self,
         client: &Client,
         timeout: std::time::Duration,
     ) -> crate::Result<TransactionRecord> {
-        self.get_record_query().execute_with_timeout(client, timeout).await
+        let record = self.record_query_for(client).execute_with_timeout(client, timeout).await?;
+
+        self.log_warnings(&record);
+
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use super::TransactionWarning;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        Hbar,
+        TransactionHash,
+        TransactionId,
+        TransactionRecord,
+        TransactionResponse,
+    };
+
+    fn response(max_transaction_fee: Option<Hbar>) -> TransactionResponse {
+        TransactionResponse {
+            node_account_id: 3.into(),
+            transaction_id: TransactionId::generate(101.into()),
+            transaction_hash: TransactionHash::new(b"hello"),
+            validate_status: true,
+            max_transaction_fee,
+            attempted_node_account_ids: vec![3.into()],
+        }
+    }
+
+    fn record(response: &TransactionResponse, transaction_fee: u64) -> TransactionRecord {
+        TransactionRecord::from_protobuf(services::TransactionRecord {
+            receipt: Some(services::TransactionReceipt::default()),
+            consensus_timestamp: Some(services::Timestamp::default()),
+            transaction_id: Some(response.transaction_id.to_protobuf()),
+            transaction_fee,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn fee_near_max() {
+        let max = Hbar::from_tinybars(1000);
+
+        assert_eq!(TransactionWarning::fee_near_max(Hbar::ZERO, max), None);
+        assert_eq!(TransactionWarning::fee_near_max(Hbar::from_tinybars(899), max), None);
+        assert_eq!(
+            TransactionWarning::fee_near_max(Hbar::from_tinybars(900), max),
+            Some(TransactionWarning::FeeNearMax {
+                transaction_fee: Hbar::from_tinybars(900),
+                max_transaction_fee: max
+            })
+        );
+    }
+
+    #[test]
+    fn warnings() {
+        let response = response(Some(Hbar::from_tinybars(1000)));
+
+        assert_eq!(response.warnings(&record(&response, 500)), []);
+        assert_eq!(
+            response.warnings(&record(&response, 950)),
+            [TransactionWarning::FeeNearMax {
+                transaction_fee: Hbar::from_tinybars(950),
+                max_transaction_fee: Hbar::from_tinybars(1000)
+            }]
+        );
+    }
+
+    #[test]
+    fn warnings_cost_estimate() {
+        let response = response(None);
+
+        assert_eq!(response.warnings(&record(&response, 950)), []);
     }
 }
@@ -0,0 +1,175 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::streams;
+use prost::Message;
+use time::OffsetDateTime;
+
+use crate::protobuf::FromProtobuf;
+use crate::{
+    ContractAction,
+    ContractBytecode,
+    ContractStateChange,
+    Error,
+};
+
+/// Extra information about a contract transaction, published in the record stream alongside its record.
+///
+/// Consensus nodes don't return these with [`TransactionRecordQuery`](crate::TransactionRecordQuery),
+/// read them from the sidecar files of a record stream source,
+/// and attach them with [`TransactionRecord::attach_sidecars`](crate::TransactionRecord::attach_sidecars).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub struct TransactionSidecarRecord {
+    /// The consensus timestamp of the transaction this sidecar belongs to.
+    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339"))]
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// Whether this sidecar was produced by a migration rather than by the transaction.
+    pub migration: bool,
+
+    /// The sidecar's contents.
+    pub sidecar: TransactionSidecar,
+}
+
+/// The contents of a [`TransactionSidecarRecord`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+pub enum TransactionSidecar {
+    /// The contract storage read or written by the transaction.
+    StateChanges(Vec<ContractStateChange>),
+
+    /// The calls made by the transaction.
+    Actions(Vec<ContractAction>),
+
+    /// The bytecode of a contract the transaction created or called.
+    Bytecode(ContractBytecode),
+}
+
+impl TransactionSidecarRecord {
+    /// Create a new `TransactionSidecarRecord` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<streams::TransactionSidecarRecord>::from_bytes(bytes)
+    }
+
+    /// Parse every record in a (decompressed) record stream sidecar file.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding any of the records fails.
+    pub fn from_sidecar_file(bytes: &[u8]) -> crate::Result<Vec<Self>> {
+        let file = streams::SidecarFile::decode(bytes).map_err(Error::from_protobuf)?;
+
+        Vec::from_protobuf(file.sidecar_records)
+    }
+}
+
+impl FromProtobuf<streams::TransactionSidecarRecord> for TransactionSidecarRecord {
+    fn from_protobuf(pb: streams::TransactionSidecarRecord) -> crate::Result<Self> {
+        use streams::transaction_sidecar_record::SidecarRecords;
+
+        let consensus_timestamp = pb_getf!(pb, consensus_timestamp)?;
+
+        let sidecar = match pb_getf!(pb, sidecar_records)? {
+            SidecarRecords::StateChanges(it) => {
+                TransactionSidecar::StateChanges(Vec::from_protobuf(it.contract_state_changes)?)
+            }
+            SidecarRecords::Actions(it) => {
+                TransactionSidecar::Actions(Vec::from_protobuf(it.contract_actions)?)
+            }
+            SidecarRecords::Bytecode(it) => {
+                TransactionSidecar::Bytecode(ContractBytecode::from_protobuf(it)?)
+            }
+        };
+
+        Ok(Self {
+            consensus_timestamp: consensus_timestamp.into(),
+            migration: pb.migration,
+            sidecar,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::{
+        services,
+        streams,
+    };
+    use prost::Message;
+
+    use super::{
+        TransactionSidecar,
+        TransactionSidecarRecord,
+    };
+    use crate::transaction::test_helpers::VALID_START;
+    use crate::ContractId;
+
+    fn make_record() -> streams::TransactionSidecarRecord {
+        streams::TransactionSidecarRecord {
+            consensus_timestamp: Some(VALID_START.into()),
+            migration: false,
+            sidecar_records: Some(streams::transaction_sidecar_record::SidecarRecords::Bytecode(
+                streams::ContractBytecode {
+                    contract_id: Some(services::ContractId {
+                        shard_num: 0,
+                        realm_num: 0,
+                        contract: Some(services::contract_id::Contract::ContractNum(1001)),
+                    }),
+                    initcode: vec![1, 2, 3],
+                    runtime_bytecode: vec![2, 3],
+                },
+            )),
+        }
+    }
+
+    #[test]
+    fn from_bytes() {
+        let record = TransactionSidecarRecord::from_bytes(&make_record().encode_to_vec()).unwrap();
+
+        assert_eq!(record.consensus_timestamp, VALID_START);
+        assert_matches!(
+            record.sidecar,
+            TransactionSidecar::Bytecode(it) if it.contract_id == ContractId::new(0, 0, 1001)
+        );
+    }
+
+    #[test]
+    fn from_sidecar_file() {
+        let file = streams::SidecarFile { sidecar_records: vec![make_record(), make_record()] };
+
+        let records = TransactionSidecarRecord::from_sidecar_file(&file.encode_to_vec()).unwrap();
+
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn missing_sidecar_fails() {
+        let pb = streams::TransactionSidecarRecord { sidecar_records: None, ..make_record() };
+
+        assert!(TransactionSidecarRecord::from_bytes(&pb.encode_to_vec()).is_err());
+    }
+}
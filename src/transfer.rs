@@ -13,6 +13,7 @@ use crate::{
 ///
 /// Returned as part of a [`TransactionRecord`](crate::TransactionRecord)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
 pub struct Transfer {
     /// The account ID that this transfer is to/from.
     pub account_id: AccountId,
@@ -18,14 +18,18 @@
  * ‍
  */
 
-use std::collections::HashMap;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 use std::ops::Not;
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
-use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
@@ -35,18 +39,29 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+use crate::transport::Channel;
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    Client,
     Error,
     Hbar,
+    LedgerId,
     NftId,
     ToProtobuf,
     TokenId,
     TokenNftTransfer,
     Transaction,
+    TransferValidationError,
     ValidateChecksums,
 };
+#[cfg(feature = "mirror-rest")]
+use crate::{
+    AnyCustomFee,
+    AssessedCustomFee,
+    Fee,
+    FeeAssessmentMethod,
+};
 
 /// Transfers cryptocurrency among two or more accounts by making the desired adjustments to their
 /// balances.
@@ -93,32 +108,64 @@ pub(crate) struct TokenTransfer {
 }
 
 impl TransferTransaction {
+    /// The most hbar transfers a single transaction may have.
+    pub const MAX_HBAR_TRANSFERS: usize = 10;
+
+    /// The most fungible token transfers a single transaction may have, across all tokens.
+    pub const MAX_TOKEN_TRANSFERS: usize = 10;
+
+    /// The most NFT transfers a single transaction may have, across all tokens.
+    pub const MAX_NFT_TRANSFERS: usize = 10;
+
     fn _hbar_transfer(&mut self, account_id: AccountId, amount: Hbar, approved: bool) -> &mut Self {
-        self.data_mut().transfers.push(Transfer {
-            account_id,
-            amount: amount.to_tinybars(),
-            is_approval: approved,
-        });
+        Transfer::merge_into(
+            &mut self.data_mut().transfers,
+            Transfer { account_id, amount: amount.to_tinybars(), is_approval: approved },
+        );
 
         self
     }
 
     /// Add a non-approved hbar transfer to the transaction.
+    ///
+    /// If the transaction already has a non-approved hbar transfer for `account_id`, `amount` is added to it.
     pub fn hbar_transfer(&mut self, account_id: AccountId, amount: Hbar) -> &mut Self {
         self._hbar_transfer(account_id, amount, false)
     }
 
+    /// Add a non-approved hbar transfer to the transaction for each `(account_id, amount)` pair.
+    ///
+    /// Transfers for the same account are merged, see [`hbar_transfer`](Self::hbar_transfer).
+    pub fn hbar_transfers(
+        &mut self,
+        transfers: impl IntoIterator<Item = (AccountId, Hbar)>,
+    ) -> &mut Self {
+        for (account_id, amount) in transfers {
+            self._hbar_transfer(account_id, amount, false);
+        }
+
+        self
+    }
+
     /// Add an approved hbar transfer to the transaction.
     pub fn approved_hbar_transfer(&mut self, account_id: AccountId, amount: Hbar) -> &mut Self {
         self._hbar_transfer(account_id, amount, true)
     }
 
     /// Returns all transfers associated with this transaction.
+    ///
+    /// An account with both an approved and a non-approved transfer gets the sum of the two.
     pub fn get_hbar_transfers(&self) -> HashMap<AccountId, Hbar> {
-        self.data()
-            .transfers
-            .iter()
-            .map(|it| (it.account_id, Hbar::from_tinybars(it.amount)))
+        let mut transfers = HashMap::with_capacity(self.data().transfers.len());
+
+        for it in &self.data().transfers {
+            let amount: &mut i64 = transfers.entry(it.account_id).or_default();
+            *amount = amount.saturating_add(it.amount);
+        }
+
+        transfers
+            .into_iter()
+            .map(|(account_id, amount)| (account_id, Hbar::from_tinybars(amount)))
             .collect()
     }
 
@@ -135,7 +182,7 @@ impl TransferTransaction {
 
         if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
             tt.expected_decimals = expected_decimals;
-            tt.transfers.push(transfer);
+            Transfer::merge_into(&mut tt.transfers, transfer);
         } else {
             data.token_transfers.push(TokenTransfer {
                 token_id,
@@ -151,6 +198,8 @@ impl TransferTransaction {
     /// Add a non-approved token transfer to the transaction.
     ///
     /// `amount` is in the lowest denomination for the token (if the token has `2` decimals this would be `0.01` tokens).
+    ///
+    /// If the transaction already has a non-approved transfer of `token_id` for `account_id`, `amount` is added to it.
     pub fn token_transfer(
         &mut self,
         token_id: TokenId,
@@ -202,19 +251,19 @@ impl TransferTransaction {
     }
 
     /// Returns all the token transfers associated associated with this transaction.
+    ///
+    /// An account with both an approved and a non-approved transfer of a token gets the sum of the two.
+    /// Tokens that only have NFT transfers map to an empty map.
     pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
-        use std::collections::hash_map::Entry;
-
         // note: using fold instead of nested collects on the off chance a token is in here twice.
         self.data().token_transfers.iter().fold(
             HashMap::with_capacity(self.data().token_transfers.len()),
             |mut map, transfer| {
-                let iter = transfer.transfers.iter().map(|it| (it.account_id, it.amount));
-                match map.entry(transfer.token_id) {
-                    Entry::Occupied(mut it) => it.get_mut().extend(iter),
-                    Entry::Vacant(it) => {
-                        it.insert(iter.collect());
-                    }
+                let accounts: &mut HashMap<_, i64> = map.entry(transfer.token_id).or_default();
+
+                for it in &transfer.transfers {
+                    let amount = accounts.entry(it.account_id).or_default();
+                    *amount = amount.saturating_add(it.amount);
                 }
 
                 map
@@ -291,6 +340,221 @@ impl TransferTransaction {
             .map(|it| (it.token_id, it.nft_transfers.clone()))
             .collect()
     }
+
+    /// Checks that the transfers are ones the network could accept.
+    ///
+    /// This is also checked by [`freeze`](Self::freeze), calling it earlier catches mistakes while the transaction can still be changed.
+    ///
+    /// # Errors
+    /// - [`Error::TransferValidation`] with the first problem found, checking the number of transfers,
+    ///   then hbar transfers, then each token in the order they were added.
+    pub fn validate_transfers(&self) -> crate::Result<()> {
+        self.data().validate_transfers()
+    }
+
+    /// Estimates the custom fees the network would assess for the token transfers of this transaction.
+    ///
+    /// The current custom fees of each token are fetched from the mirror node, and assessed with
+    /// [`AnyCustomFee::assess`] for each sender of a fungible token,
+    /// skipping fees that the sender is exempt from as a fee collector.
+    /// The payer of an [`Inclusive`](FeeAssessmentMethod::Inclusive) fractional fee is the receivers, who get less than was sent.
+    ///
+    /// A royalty fee is assessed on the hbar and fungible tokens credited to the NFT sender in this transaction,
+    /// once for each NFT, or if there are none, its fallback fee (if any) is charged to the NFT receiver.
+    ///
+    /// Fees on fees, such as a fixed fee denominated in a token that has custom fees of its own, aren't included.
+    ///
+    /// Requires the `mirror-rest` feature.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if fetching the custom fees of a token fails.
+    #[cfg(feature = "mirror-rest")]
+    pub async fn preview_custom_fees(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<AssessedCustomFee>> {
+        let data = self.data();
+        let mut assessed = Vec::new();
+
+        for token_transfer in &data.token_transfers {
+            let token =
+                mirror_rest::get(client, &format!("/api/v1/tokens/{}", token_transfer.token_id))
+                    .await?;
+
+            let fees = match token.get("custom_fees") {
+                Some(it) => AnyCustomFee::list_from_mirror_json(it)?,
+                None => continue,
+            };
+
+            assessed.extend(data.assess_custom_fees(token_transfer, &fees));
+        }
+
+        Ok(assessed)
+    }
+
+    /// Sets the expected decimals of every fungible token transfer that doesn't have them yet,
+    /// to the token's current decimals, as with [`token_transfer_with_decimals`](Self::token_transfer_with_decimals).
+    ///
+    /// Decimals are fetched with [`Client::token_info`], so enabling the client's
+    /// [token metadata cache](Client::set_token_metadata_cache_ttl) avoids a query per token.
+    ///
+    /// # Errors
+    /// - Any error from the [`TokenInfoQuery`](crate::TokenInfoQuery).
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub async fn fill_token_decimals(&mut self, client: &Client) -> crate::Result<&mut Self> {
+        self.require_not_frozen();
+
+        let token_ids: Vec<_> = self
+            .data()
+            .token_transfers
+            .iter()
+            .filter(|it| it.expected_decimals.is_none() && !it.transfers.is_empty())
+            .map(|it| it.token_id)
+            .collect();
+
+        for token_id in token_ids {
+            let decimals = client.token_decimals(token_id).await?;
+
+            for token_transfer in
+                self.data_mut().token_transfers.iter_mut().filter(|it| it.token_id == token_id)
+            {
+                token_transfer.expected_decimals = Some(decimals);
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "mirror-rest")]
+impl TransferTransactionData {
+    /// Simulates assessing `fees`, the custom fees of `token_transfer`'s token, see [`TransferTransaction::preview_custom_fees`].
+    fn assess_custom_fees(
+        &self,
+        token_transfer: &TokenTransfer,
+        fees: &[AnyCustomFee],
+    ) -> Vec<AssessedCustomFee> {
+        let collectors: Vec<_> = fees.iter().filter_map(|it| it.fee_collector_account_id).collect();
+
+        let is_exempt = |fee: &AnyCustomFee, account_id: AccountId| {
+            fee.fee_collector_account_id == Some(account_id)
+                || (fee.all_collectors_are_exempt && collectors.contains(&account_id))
+        };
+
+        let receivers: Vec<_> = token_transfer
+            .transfers
+            .iter()
+            .filter(|it| it.amount > 0)
+            .map(|it| it.account_id)
+            .collect();
+
+        let mut assessed = Vec::new();
+
+        for sender in token_transfer.transfers.iter().filter(|it| it.amount < 0) {
+            for fee in fees.iter().filter(|fee| !is_exempt(fee, sender.account_id)) {
+                let Some(mut it) =
+                    fee.assess(sender.amount.unsigned_abs(), token_transfer.token_id)
+                else {
+                    continue;
+                };
+
+                it.payer_account_id_list = match &fee.fee {
+                    Fee::Fractional(fractional)
+                        if fractional.assessment_method == FeeAssessmentMethod::Inclusive =>
+                    {
+                        receivers.clone()
+                    }
+                    _ => Vec::from([sender.account_id]),
+                };
+
+                assessed.push(it);
+            }
+        }
+
+        for nft in &token_transfer.nft_transfers {
+            // the fungible value the NFT sender receives in exchange, `None` being hbar.
+            let exchanged: Vec<(Option<TokenId>, i64)> = self
+                .transfers
+                .iter()
+                .map(|it| (None, it))
+                .chain(
+                    self.token_transfers
+                        .iter()
+                        .flat_map(|tt| tt.transfers.iter().map(|it| (Some(tt.token_id), it))),
+                )
+                .filter(|(_, it)| it.account_id == nft.sender && it.amount > 0)
+                .map(|(token_id, it)| (token_id, it.amount))
+                .collect();
+
+            for fee in fees.iter().filter(|fee| !is_exempt(fee, nft.sender)) {
+                let Fee::Royalty(royalty) = &fee.fee else {
+                    continue;
+                };
+
+                if exchanged.is_empty() {
+                    assessed.extend(royalty.fallback_fee.as_ref().map(|fallback| {
+                        AssessedCustomFee {
+                            amount: fallback.amount,
+                            token_id: fallback.denominating_token_id,
+                            fee_collector_account_id: fee.fee_collector_account_id,
+                            payer_account_id_list: Vec::from([nft.receiver]),
+                        }
+                    }));
+
+                    continue;
+                }
+
+                for &(token_id, amount) in &exchanged {
+                    let royalty_amount = i128::from(amount) * i128::from(royalty.numerator())
+                        / i128::from(royalty.denominator());
+
+                    assessed.push(AssessedCustomFee {
+                        amount: i64::try_from(royalty_amount).unwrap_or(i64::MAX),
+                        token_id,
+                        fee_collector_account_id: fee.fee_collector_account_id,
+                        payer_account_id_list: Vec::from([nft.sender]),
+                    });
+                }
+            }
+        }
+
+        assessed
+    }
+}
+
+impl Transfer {
+    /// Adds `transfer` to `transfers`, merging it with an existing transfer for the same account (and approval).
+    fn merge_into(transfers: &mut Vec<Self>, transfer: Self) {
+        match transfers.iter_mut().find(|it| {
+            it.account_id == transfer.account_id && it.is_approval == transfer.is_approval
+        }) {
+            Some(it) => it.amount = it.amount.saturating_add(transfer.amount),
+            None => transfers.push(transfer),
+        }
+    }
+
+    /// Returns the first account that has more than one transfer with the same approval in `transfers`.
+    ///
+    /// An approved and a non-approved transfer for the same account are fine,
+    /// and the setters merge everything else, so this only happens with transfers parsed from bytes.
+    fn repeated_account(transfers: &[Self]) -> Option<AccountId> {
+        let mut accounts = HashSet::with_capacity(transfers.len());
+
+        transfers
+            .iter()
+            .map(|it| (it.account_id, it.is_approval))
+            .find(|&it| !accounts.insert(it))
+            .map(|(account_id, _)| account_id)
+    }
+
+    /// Returns the sum of `transfers`, saturating at the bounds of `i64` (only once the exact sum is known to be non-zero).
+    fn sum(transfers: &[Self]) -> i64 {
+        let sum: i128 = transfers.iter().map(|it| i128::from(it.amount)).sum();
+
+        i64::try_from(sum).unwrap_or(if sum > 0 { i64::MAX } else { i64::MIN })
+    }
 }
 
 impl TransactionExecute for TransferTransactionData {
@@ -304,7 +568,88 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransferTransactionData {
+    fn validate_transfers(&self) -> crate::Result<()> {
+        let count = self.transfers.len();
+        if count > TransferTransaction::MAX_HBAR_TRANSFERS {
+            return Err(Error::transfer_validation(
+                TransferValidationError::TooManyHbarTransfers {
+                    count,
+                    max: TransferTransaction::MAX_HBAR_TRANSFERS,
+                },
+            ));
+        }
+
+        let count = self.token_transfers.iter().map(|it| it.transfers.len()).sum();
+        if count > TransferTransaction::MAX_TOKEN_TRANSFERS {
+            return Err(Error::transfer_validation(
+                TransferValidationError::TooManyTokenTransfers {
+                    count,
+                    max: TransferTransaction::MAX_TOKEN_TRANSFERS,
+                },
+            ));
+        }
+
+        let count = self.token_transfers.iter().map(|it| it.nft_transfers.len()).sum();
+        if count > TransferTransaction::MAX_NFT_TRANSFERS {
+            return Err(Error::transfer_validation(TransferValidationError::TooManyNftTransfers {
+                count,
+                max: TransferTransaction::MAX_NFT_TRANSFERS,
+            }));
+        }
+
+        if let Some(account_id) = Transfer::repeated_account(&self.transfers) {
+            return Err(Error::transfer_validation(TransferValidationError::RepeatedHbarAccount {
+                account_id,
+            }));
+        }
+
+        let sum = Transfer::sum(&self.transfers);
+        if sum != 0 {
+            return Err(Error::transfer_validation(TransferValidationError::UnbalancedHbar {
+                sum: Hbar::from_tinybars(sum),
+            }));
+        }
+
+        for tt in &self.token_transfers {
+            if let Some(account_id) = Transfer::repeated_account(&tt.transfers) {
+                return Err(Error::transfer_validation(
+                    TransferValidationError::RepeatedTokenAccount {
+                        token_id: tt.token_id,
+                        account_id,
+                    },
+                ));
+            }
+
+            let sum = Transfer::sum(&tt.transfers);
+            if sum != 0 {
+                return Err(Error::transfer_validation(TransferValidationError::UnbalancedToken {
+                    token_id: tt.token_id,
+                    sum,
+                }));
+            }
+
+            let mut serials = HashSet::with_capacity(tt.nft_transfers.len());
+            for it in &tt.nft_transfers {
+                if !serials.insert(it.serial) {
+                    return Err(Error::transfer_validation(
+                        TransferValidationError::DuplicateNft {
+                            nft_id: tt.token_id.nft(it.serial),
+                        },
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TransactionData for TransferTransactionData {
+    fn validate_data(&self, _ledger_id: Option<&LedgerId>) -> crate::Result<()> {
+        self.validate_transfers()
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
@@ -445,8 +790,17 @@ impl ToProtobuf for TransferTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
     use expect_test::expect;
+    use hedera_proto::services;
 
+    use super::TransferTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
     use crate::transaction::test_helpers::{
         check_body,
         transaction_body,
@@ -454,9 +808,22 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        Error,
         Hbar,
         TokenId,
+        TokenNftTransfer,
         TransferTransaction,
+        TransferValidationError,
+    };
+    #[cfg(feature = "mirror-rest")]
+    use crate::{
+        AnyCustomFee,
+        AssessedCustomFee,
+        FeeAssessmentMethod,
+        FixedFeeData,
+        Fraction,
+        FractionalFeeData,
+        RoyaltyFeeData,
     };
 
     fn make_transaction() -> TransferTransaction {
@@ -861,4 +1228,378 @@ mod tests {
         tx.token_transfer_with_decimals(TOKEN, AccountId::new(0, 0, 7), -100, 5);
         assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&5));
     }
+
+    #[test]
+    fn merge_duplicate_transfers() {
+        let mut tx = TransferTransaction::new();
+        const TOKEN: TokenId = TokenId::new(0, 0, 5);
+
+        tx.hbar_transfers([
+            (AccountId::new(0, 0, 7), Hbar::from_tinybars(100)),
+            (AccountId::new(0, 0, 8), Hbar::from_tinybars(-300)),
+            (AccountId::new(0, 0, 7), Hbar::from_tinybars(200)),
+        ])
+        .approved_hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(1))
+        .token_transfer(TOKEN, AccountId::new(0, 0, 7), 10)
+        .token_transfer(TOKEN, AccountId::new(0, 0, 7), 5);
+
+        assert_eq!(tx.data().transfers.len(), 3);
+        assert_eq!(tx.get_hbar_transfers()[&AccountId::new(0, 0, 8)], Hbar::from_tinybars(-300));
+        assert_eq!(tx.get_token_transfers()[&TOKEN][&AccountId::new(0, 0, 7)], 15);
+    }
+
+    #[test]
+    fn get_hbar_transfers() {
+        let tx = make_transaction();
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([
+                (AccountId::new(0, 0, 5008), Hbar::from_tinybars(400)),
+                (AccountId::new(0, 0, 5006), Hbar::from_tinybars(-800)),
+                (AccountId::new(0, 0, 5007), Hbar::from_tinybars(400)),
+            ])
+        );
+
+        // approved and non-approved transfers are separate transfers, but the same account.
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(100))
+            .approved_hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(20));
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([(AccountId::new(0, 0, 7), Hbar::from_tinybars(120))])
+        );
+    }
+
+    #[test]
+    fn get_token_transfers() {
+        let tx = make_transaction();
+
+        assert_eq!(
+            tx.get_token_transfers(),
+            HashMap::from([
+                (
+                    TokenId::new(0, 0, 5),
+                    HashMap::from([
+                        (AccountId::new(0, 0, 5008), 400),
+                        (AccountId::new(0, 0, 5006), -800),
+                        (AccountId::new(0, 0, 5007), 400),
+                    ])
+                ),
+                (
+                    TokenId::new(0, 0, 4),
+                    HashMap::from([
+                        (AccountId::new(0, 0, 5008), 1),
+                        (AccountId::new(0, 0, 5006), -1)
+                    ])
+                ),
+                (TokenId::new(0, 0, 3), HashMap::new()),
+                (TokenId::new(0, 0, 2), HashMap::new()),
+            ])
+        );
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 7), 10)
+            .approved_token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 7), 5);
+
+        assert_eq!(tx.get_token_transfers()[&TokenId::new(0, 0, 5)][&AccountId::new(0, 0, 7)], 15);
+    }
+
+    #[test]
+    fn get_nft_transfers() {
+        let tx = make_transaction();
+
+        let transfers = tx.get_nft_transfers();
+
+        assert!(transfers[&TokenId::new(0, 0, 5)].is_empty());
+
+        let serials: Vec<_> =
+            transfers[&TokenId::new(0, 0, 3)].iter().map(|it| it.serial).collect();
+        assert_eq!(serials, [2, 1, 3, 4]);
+
+        assert_eq!(
+            transfers[&TokenId::new(0, 0, 2)],
+            [TokenNftTransfer {
+                token_id: TokenId::new(0, 0, 2),
+                sender: AccountId::new(0, 0, 5007),
+                receiver: AccountId::new(0, 0, 5006),
+                serial: 4,
+                is_approved: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_transfers() {
+        make_transaction().validate_transfers().unwrap();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfers([
+            (AccountId::new(0, 0, 7), Hbar::from_tinybars(100)),
+            (AccountId::new(0, 0, 8), Hbar::from_tinybars(-99)),
+        ]);
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::UnbalancedHbar { sum: Hbar::from_tinybars(1) }
+        );
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 7), -3);
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::UnbalancedToken { token_id: TokenId::new(0, 0, 5), sum: -3 }
+        );
+
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(
+            TokenId::new(0, 0, 3).nft(1),
+            AccountId::new(0, 0, 7),
+            AccountId::new(0, 0, 8),
+        )
+        .nft_transfer(
+            TokenId::new(0, 0, 3).nft(1),
+            AccountId::new(0, 0, 8),
+            AccountId::new(0, 0, 9),
+        );
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::DuplicateNft { nft_id: TokenId::new(0, 0, 3).nft(1) }
+        );
+
+        // an approved and a non-approved transfer for the same account aren't repeats.
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(-100))
+            .approved_hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(-100))
+            .hbar_transfer(AccountId::new(0, 0, 8), Hbar::from_tinybars(200))
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 7), -3)
+            .approved_token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 7), -3)
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 8), 6);
+
+        assert_matches!(tx.validate_transfers(), Ok(()));
+    }
+
+    fn account_amount(account: u64, amount: i64, is_approval: bool) -> services::AccountAmount {
+        services::AccountAmount {
+            account_id: Some(AccountId::new(0, 0, account).to_protobuf()),
+            amount,
+            is_approval,
+        }
+    }
+
+    #[test]
+    fn validate_repeated_transfers() {
+        // the setters merge repeated transfers, so these can only come from bytes.
+        let data =
+            TransferTransactionData::from_protobuf(services::CryptoTransferTransactionBody {
+                transfers: Some(services::TransferList {
+                    account_amounts: vec![
+                        account_amount(7, -100, true),
+                        account_amount(7, -100, true),
+                        account_amount(8, 200, false),
+                    ],
+                }),
+                token_transfers: Vec::new(),
+            })
+            .unwrap();
+
+        assert_matches!(
+            data.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::RepeatedHbarAccount { account_id: AccountId::new(0, 0, 7) }
+        );
+
+        let data =
+            TransferTransactionData::from_protobuf(services::CryptoTransferTransactionBody {
+                transfers: None,
+                token_transfers: vec![services::TokenTransferList {
+                    token: Some(TokenId::new(0, 0, 5).to_protobuf()),
+                    transfers: vec![
+                        account_amount(7, -3, false),
+                        account_amount(7, -3, false),
+                        account_amount(8, 6, false),
+                    ],
+                    nft_transfers: Vec::new(),
+                    expected_decimals: None,
+                }],
+            })
+            .unwrap();
+
+        assert_matches!(
+            data.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::RepeatedTokenAccount {
+                    token_id: TokenId::new(0, 0, 5),
+                    account_id: AccountId::new(0, 0, 7),
+                }
+        );
+    }
+
+    #[test]
+    fn validate_transfer_limits() {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfers((1..=10).map(|it| (AccountId::new(0, 0, it), Hbar::from_tinybars(1))))
+            .hbar_transfer(AccountId::new(0, 0, 11), Hbar::from_tinybars(-10));
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::TooManyHbarTransfers { count: 11, max: 10 }
+        );
+
+        let mut tx = TransferTransaction::new();
+        for token in [5, 6] {
+            for account in 1..=3 {
+                tx.token_transfer(TokenId::new(0, 0, token), AccountId::new(0, 0, account), 2);
+            }
+            tx.token_transfer(TokenId::new(0, 0, token), AccountId::new(0, 0, 4), -3)
+                .token_transfer(TokenId::new(0, 0, token), AccountId::new(0, 0, 5), -3);
+        }
+
+        tx.validate_transfers().unwrap();
+
+        tx.token_transfer(TokenId::new(0, 0, 7), AccountId::new(0, 0, 1), 0);
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::TooManyTokenTransfers { count: 11, max: 10 }
+        );
+
+        let mut tx = TransferTransaction::new();
+        for serial in 1..=11 {
+            tx.nft_transfer(
+                TokenId::new(0, 0, 3).nft(serial),
+                AccountId::new(0, 0, 7),
+                AccountId::new(0, 0, 8),
+            );
+        }
+
+        assert_matches!(
+            tx.validate_transfers(),
+            Err(Error::TransferValidation(e))
+                if *e == TransferValidationError::TooManyNftTransfers { count: 11, max: 10 }
+        );
+    }
+
+    #[test]
+    fn freeze_unbalanced_fails() {
+        let mut tx = TransferTransaction::new_for_tests();
+        tx.hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(100));
+
+        assert_matches!(tx.freeze(), Err(Error::TransferValidation(_)));
+        assert!(!tx.is_frozen());
+
+        tx.hbar_transfer(AccountId::new(0, 0, 8), Hbar::from_tinybars(-100));
+        tx.freeze().unwrap();
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    fn fee(
+        fee: impl Into<crate::Fee>,
+        collector: u64,
+        all_collectors_are_exempt: bool,
+    ) -> AnyCustomFee {
+        AnyCustomFee {
+            fee: fee.into(),
+            fee_collector_account_id: Some(AccountId::new(0, 0, collector)),
+            all_collectors_are_exempt,
+        }
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn assess_custom_fees_fungible() {
+        let token_id = TokenId::new(0, 0, 5);
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(token_id, AccountId::new(0, 0, 7), -1000)
+            .token_transfer(token_id, AccountId::new(0, 0, 8), 900)
+            // a collector, exempt from every fee.
+            .token_transfer(token_id, AccountId::new(0, 0, 100), -100)
+            .token_transfer(token_id, AccountId::new(0, 0, 9), 200);
+
+        let fees = [
+            fee(FixedFeeData::from_hbar(Hbar::from_tinybars(10)), 100, true),
+            fee(
+                FractionalFeeData {
+                    fraction: Fraction::new(1, 10).unwrap(),
+                    minimum_amount: 1,
+                    maximum_amount: 0,
+                    assessment_method: FeeAssessmentMethod::Inclusive,
+                },
+                101,
+                true,
+            ),
+        ];
+
+        let data = tx.data();
+        let assessed = data.assess_custom_fees(&data.token_transfers[0], &fees);
+
+        assert_eq!(
+            assessed,
+            [
+                AssessedCustomFee {
+                    amount: 10,
+                    token_id: None,
+                    fee_collector_account_id: Some(AccountId::new(0, 0, 100)),
+                    payer_account_id_list: Vec::from([AccountId::new(0, 0, 7)]),
+                },
+                AssessedCustomFee {
+                    amount: 100,
+                    token_id: Some(token_id),
+                    fee_collector_account_id: Some(AccountId::new(0, 0, 101)),
+                    payer_account_id_list: Vec::from([
+                        AccountId::new(0, 0, 8),
+                        AccountId::new(0, 0, 9)
+                    ]),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn assess_custom_fees_royalty() {
+        let token_id = TokenId::new(0, 0, 3);
+        let fees = [fee(
+            RoyaltyFeeData {
+                fraction: Fraction::new(1, 20).unwrap(),
+                fallback_fee: Some(FixedFeeData::from_hbar(Hbar::from_tinybars(5))),
+            },
+            100,
+            false,
+        )];
+
+        // sold for hbar.
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(token_id.nft(1), AccountId::new(0, 0, 7), AccountId::new(0, 0, 8))
+            .hbar_transfer(AccountId::new(0, 0, 8), Hbar::from_tinybars(-1000))
+            .hbar_transfer(AccountId::new(0, 0, 7), Hbar::from_tinybars(1000));
+
+        let data = tx.data();
+        let assessed = data.assess_custom_fees(&data.token_transfers[0], &fees);
+
+        assert_eq!(assessed.len(), 1);
+        assert_eq!(assessed[0].amount, 50);
+        assert_eq!(assessed[0].token_id, None);
+        assert_eq!(assessed[0].payer_account_id_list, [AccountId::new(0, 0, 7)]);
+
+        // given away, so the receiver pays the fallback fee.
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(token_id.nft(1), AccountId::new(0, 0, 7), AccountId::new(0, 0, 8));
+
+        let data = tx.data();
+        let assessed = data.assess_custom_fees(&data.token_transfers[0], &fees);
+
+        assert_eq!(assessed.len(), 1);
+        assert_eq!(assessed[0].amount, 5);
+        assert_eq!(assessed[0].payer_account_id_list, [AccountId::new(0, 0, 8)]);
+    }
 }
@@ -0,0 +1,208 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! The gRPC transport.
+//!
+//! Natively this is a tonic [`Channel`](tonic::transport::Channel) speaking HTTP/2 directly to nodes.
+//! In the browser (`wasm32`) it's gRPC-web over `fetch`, which means the addresses must be gRPC-web proxies.
+
+use crate::client::EndpointConfig;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type Channel = tonic::transport::Channel;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web::Channel;
+
+use crate::AccountId;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod tls;
+
+/// Returns a channel to the consensus node `node_account_id`, balanced over `addresses` (`host:port`).
+///
+/// The connections are plaintext unless [`EndpointConfig::tls`] is set.
+///
+/// Connections are opened lazily, on first use.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn node_channel(
+    addresses: impl IntoIterator<Item = String>,
+    config: &EndpointConfig,
+    node_account_id: AccountId,
+) -> Channel {
+    if config.tls() {
+        return tls::node_channel(addresses.into_iter().collect(), config, node_account_id);
+    }
+
+    Channel::balance_list(
+        addresses.into_iter().map(|address| config.endpoint(format!("tcp://{address}"))),
+    )
+}
+
+/// Returns a channel to a consensus node at the first of `addresses` (`host:port`).
+///
+/// gRPC-web has no load balancing, and the connection settings are up to the browser.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn node_channel(
+    addresses: impl IntoIterator<Item = String>,
+    _config: &EndpointConfig,
+    _node_account_id: AccountId,
+) -> Channel {
+    // a node without addresses gets a channel that fails every request, same as natively.
+    let address = addresses.into_iter().next().unwrap_or_default();
+
+    web_channel(&address)
+}
+
+/// Returns a channel to a mirror node, balanced over `addresses` (`host:port`, TLS).
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn mirror_channel(
+    addresses: impl IntoIterator<Item = String>,
+    tls_domain: Option<&str>,
+) -> Channel {
+    use std::time::Duration;
+
+    use tonic::transport::{
+        ClientTlsConfig,
+        Endpoint,
+    };
+
+    let tls_config = match tls_domain {
+        Some(domain) => ClientTlsConfig::new().domain_name(domain),
+        None => ClientTlsConfig::new(),
+    };
+
+    let endpoints = addresses.into_iter().map(|address| {
+        Endpoint::from_shared(format!("https://{address}"))
+            .unwrap()
+            .keep_alive_timeout(Duration::from_secs(10))
+            .tls_config(tls_config.clone())
+            .unwrap()
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(Duration::from_secs(10)))
+            .connect_timeout(Duration::from_secs(10))
+    });
+
+    Channel::balance_list(endpoints)
+}
+
+/// Returns a channel to a mirror node at the first of `addresses` (`host:port`).
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn mirror_channel(
+    addresses: impl IntoIterator<Item = String>,
+    _tls_domain: Option<&str>,
+) -> Channel {
+    let address = addresses.into_iter().next().unwrap_or_default();
+
+    web_channel(&address)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn web_channel(address: &str) -> Channel {
+    // the plaintext node port stays plaintext, anything else is assumed to be behind TLS.
+    let scheme = match address.ends_with(":50211") {
+        true => "http",
+        false => "https",
+    };
+
+    Channel::new(format!("{scheme}://{address}"))
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::fmt::Display;
+    use std::pin::Pin;
+    use std::task::{
+        Context,
+        Poll,
+    };
+
+    use bytes::Bytes;
+    use futures_core::future::BoxFuture;
+    use send_wrapper::SendWrapper;
+    use tonic::body::BoxBody;
+    use tower_service::Service;
+
+    type Inner = tonic_web_wasm_client::Client;
+
+    /// A gRPC-web client that can be used where the SDK expects `Send` futures.
+    ///
+    /// `wasm32-unknown-unknown` is single threaded, so nothing here ever actually crosses a thread.
+    #[derive(Clone)]
+    pub(crate) struct Channel(Inner);
+
+    impl Channel {
+        pub(super) fn new(base_url: String) -> Self {
+            Self(Inner::new(base_url))
+        }
+    }
+
+    impl Service<http::Request<BoxBody>> for Channel {
+        type Response = http::Response<BoxBody>;
+        type Error = <Inner as Service<http::Request<BoxBody>>>::Error;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.0.poll_ready(cx)
+        }
+
+        fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+            let response = self.0.call(request);
+
+            Box::pin(SendWrapper::new(async move {
+                let response = response.await?;
+
+                Ok(response.map(|body| tonic::body::boxed(Body(SendWrapper::new(Box::pin(body))))))
+            }))
+        }
+    }
+
+    struct Body<B>(SendWrapper<Pin<Box<B>>>);
+
+    impl<B> http_body::Body for Body<B>
+    where
+        B: http_body::Body<Data = Bytes>,
+        B::Error: Display,
+    {
+        type Data = Bytes;
+        type Error = tonic::Status;
+
+        fn poll_data(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+            self.0
+                .as_mut()
+                .poll_data(cx)
+                .map(|it| it.map(|it| it.map_err(|e| tonic::Status::unknown(e.to_string()))))
+        }
+
+        fn poll_trailers(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+            self.0.as_mut().poll_trailers(cx).map_err(|e| tonic::Status::unknown(e.to_string()))
+        }
+
+        fn is_end_stream(&self) -> bool {
+            self.0.is_end_stream()
+        }
+    }
+}
@@ -0,0 +1,308 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! TLS to consensus nodes, with custom root certificates and certificate pinning.
+//!
+//! tonic's own TLS always trusts the Mozilla roots (and can't pin), so this brings its own connector.
+
+use std::io;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::task::{
+    Context,
+    Poll,
+};
+
+use futures_core::future::BoxFuture;
+use sha2::{
+    Digest,
+    Sha384,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid,
+    ServerCertVerified,
+    ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::WebPkiSupportedAlgorithms;
+use tokio_rustls::rustls::pki_types::{
+    CertificateDer,
+    ServerName,
+    UnixTime,
+};
+use tokio_rustls::rustls::{
+    self,
+    CertificateError,
+    ClientConfig,
+    DigitallySignedStruct,
+    RootCertStore,
+    SignatureScheme,
+};
+use tokio_rustls::TlsConnector;
+use tonic::codegen::http::Uri;
+use tonic::codegen::Service;
+use triomphe::Arc;
+
+use super::Channel;
+use crate::client::EndpointConfig;
+use crate::AccountId;
+
+/// Returns a TLS channel to the node `node_account_id`, connecting to the first of `addresses` that accepts.
+// rustls only takes `std::sync::Arc`s.
+#[allow(clippy::disallowed_types)]
+pub(super) fn node_channel(
+    addresses: Vec<String>,
+    config: &EndpointConfig,
+    node_account_id: AccountId,
+) -> Channel {
+    // the connector picks the address, this is only the `:authority` of requests.
+    let origin = addresses.first().cloned().unwrap_or_else(|| "localhost".to_owned());
+
+    let connector = NodeConnector {
+        tls: TlsConnector::from(std::sync::Arc::new(client_config(config, node_account_id))),
+        addresses: addresses.into(),
+        next: Arc::default(),
+    };
+
+    config.endpoint(format!("http://{origin}")).connect_with_connector_lazy(connector)
+}
+
+#[allow(clippy::disallowed_types)]
+fn client_config(config: &EndpointConfig, node_account_id: AccountId) -> ClientConfig {
+    let builder = ClientConfig::builder();
+
+    let mut client_config = match config.pinned_certificate_hash(node_account_id) {
+        Some(hash) => builder
+            .dangerous()
+            .with_custom_certificate_verifier(std::sync::Arc::new(PinnedCertificateVerifier::new(
+                hash,
+            )))
+            .with_no_client_auth(),
+        None => builder.with_root_certificates(root_store(config)).with_no_client_auth(),
+    };
+
+    client_config.alpn_protocols = vec![b"h2".to_vec()];
+
+    client_config
+}
+
+fn root_store(config: &EndpointConfig) -> RootCertStore {
+    let mut roots = RootCertStore::empty();
+
+    match config.tls_root_certificates().is_empty() {
+        true => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+        // a certificate that isn't a valid trust anchor can't verify anything anyway.
+        false => {
+            roots.add_parsable_certificates(
+                config.tls_root_certificates().iter().map(|it| CertificateDer::from(it.as_slice())),
+            );
+        }
+    }
+
+    roots
+}
+
+/// Returns the SHA-384 hash of `certificate` PEM encoded, which is how the address book hashes node certificates.
+fn certificate_hash(certificate: &[u8]) -> [u8; 48] {
+    let pem = pem::encode_config(
+        &pem::Pem::new("CERTIFICATE", certificate),
+        pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+    );
+
+    Sha384::digest(pem).into()
+}
+
+#[derive(Clone)]
+struct NodeConnector {
+    tls: TlsConnector,
+    addresses: Arc<[String]>,
+    // where to start next time, so that a node that's down doesn't get tried first every time.
+    next: Arc<AtomicUsize>,
+}
+
+impl NodeConnector {
+    async fn connect(self) -> io::Result<TlsStream<TcpStream>> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        let mut last_error =
+            io::Error::new(io::ErrorKind::AddrNotAvailable, "node has no addresses");
+
+        for i in 0..self.addresses.len() {
+            let address = &self.addresses[(start + i) % self.addresses.len()];
+
+            match self.connect_to(address).await {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_error = e,
+            }
+        }
+
+        Err(last_error)
+    }
+
+    async fn connect_to(&self, address: &str) -> io::Result<TlsStream<TcpStream>> {
+        let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+
+        let server_name = ServerName::try_from(host.to_owned())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let stream = TcpStream::connect(address).await?;
+        stream.set_nodelay(true)?;
+
+        self.tls.connect(server_name, stream).await
+    }
+}
+
+impl Service<Uri> for NodeConnector {
+    type Response = TlsStream<TcpStream>;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<Self::Response>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        Box::pin(self.clone().connect())
+    }
+}
+
+/// Trusts exactly the certificate with the pinned hash.
+///
+/// The handshake signatures are still checked, so the node has to hold the certificate's private key.
+#[derive(Debug)]
+struct PinnedCertificateVerifier {
+    hash: [u8; 48],
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl PinnedCertificateVerifier {
+    fn new(hash: [u8; 48]) -> Self {
+        Self {
+            hash,
+            algorithms: rustls::crypto::ring::default_provider().signature_verification_algorithms,
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedCertificateVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match certificate_hash(end_entity) == self.hash {
+            true => Ok(ServerCertVerified::assertion()),
+            false => Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            )),
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_rustls::rustls::client::danger::ServerCertVerifier;
+    use tokio_rustls::rustls::pki_types::{
+        CertificateDer,
+        ServerName,
+        UnixTime,
+    };
+
+    use super::{
+        certificate_hash,
+        PinnedCertificateVerifier,
+    };
+    use crate::NodeAddress;
+
+    const CERTIFICATE: &[u8] = &[0x30, 0x82, 0x01, 0x0a, 0x02, 0x82, 0x01, 0x01, 0x00];
+
+    fn verify(verifier: &PinnedCertificateVerifier, certificate: &[u8]) -> bool {
+        verifier
+            .verify_server_cert(
+                &CertificateDer::from(certificate),
+                &[],
+                &ServerName::try_from("127.0.0.1").unwrap(),
+                &[],
+                UnixTime::now(),
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn certificate_hash_matches_address_book() {
+        let pem = pem::encode_config(
+            &pem::Pem::new("CERTIFICATE", CERTIFICATE),
+            pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+        );
+
+        let address = NodeAddress {
+            node_id: 0,
+            rsa_public_key: Vec::new(),
+            node_account_id: crate::AccountId::new(0, 0, 3),
+            tls_certificate_hash: hex::encode(certificate_hash(CERTIFICATE)).into_bytes(),
+            service_endpoints: Vec::new(),
+            description: String::new(),
+        };
+
+        assert!(address.verify_tls_certificate(pem.as_bytes()));
+    }
+
+    #[test]
+    fn pinned_verifier_accepts_pinned_certificate() {
+        let verifier = PinnedCertificateVerifier::new(certificate_hash(CERTIFICATE));
+
+        assert!(verify(&verifier, CERTIFICATE));
+    }
+
+    #[test]
+    fn pinned_verifier_rejects_other_certificate() {
+        let verifier = PinnedCertificateVerifier::new(certificate_hash(CERTIFICATE));
+
+        assert!(!verify(&verifier, &CERTIFICATE[1..]));
+    }
+}
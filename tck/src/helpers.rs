@@ -12,7 +12,10 @@ use hedera::{
     TransactionId,
 };
 use hex::ToHex;
-use jsonrpsee::types::error::INVALID_PARAMS_CODE;
+use jsonrpsee::types::error::{
+    INTERNAL_ERROR_CODE,
+    INVALID_PARAMS_CODE,
+};
 use jsonrpsee::types::{
     ErrorObject,
     ErrorObjectOwned,
@@ -266,3 +269,21 @@ pub(crate) fn get_hedera_key(key: &str) -> Result<Key, ErrorObjectOwned> {
         },
     }
 }
+
+pub(crate) fn parse_param<T>(value: &str) -> Result<T, ErrorObjectOwned>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    T::from_str(value)
+        .map_err(|e| ErrorObject::owned(INTERNAL_ERROR_CODE, e.to_string(), None::<()>))
+}
+
+pub(crate) fn required_param<'a>(
+    value: &'a Option<String>,
+    name: &str,
+) -> Result<&'a str, ErrorObjectOwned> {
+    value.as_deref().ok_or_else(|| {
+        ErrorObject::owned(INVALID_PARAMS_CODE, format!("{name} MUST be provided"), None::<()>)
+    })
+}
@@ -26,6 +26,7 @@ use tokio::signal;
 mod errors;
 mod helpers;
 pub(crate) mod methods;
+mod params;
 mod responses;
 
 #[tokio::main]
@@ -6,6 +6,8 @@ use std::sync::{
 };
 
 use hedera::{
+    AccountAllowanceApproveTransaction,
+    AccountAllowanceDeleteTransaction,
     AccountCreateTransaction,
     AccountId,
     AccountUpdateTransaction,
@@ -13,10 +15,14 @@ use hedera::{
     EvmAddress,
     Hbar,
     PrivateKey,
+    TokenId,
 };
 use jsonrpsee::core::async_trait;
 use jsonrpsee::proc_macros::rpc;
-use jsonrpsee::types::error::INTERNAL_ERROR_CODE;
+use jsonrpsee::types::error::{
+    INTERNAL_ERROR_CODE,
+    INVALID_PARAMS_CODE,
+};
 use jsonrpsee::types::{
     ErrorObject,
     ErrorObjectOwned,
@@ -33,8 +39,15 @@ use crate::helpers::{
     fill_common_transaction_params,
     generate_key_helper,
     get_hedera_key,
+    parse_param,
+    required_param,
+};
+use crate::params::{
+    AllowanceParams,
+    RemoveAllowanceParams,
 };
 use crate::responses::{
+    AccountAllowanceResponse,
     AccountCreateResponse,
     AccountUpdateResponse,
     GenerateKeyResponse,
@@ -118,6 +131,28 @@ pub trait Rpc {
         decline_staking_reward: Option<bool>,
         common_transaction_params: Option<HashMap<String, Value>>,
     ) -> Result<AccountUpdateResponse, ErrorObjectOwned>;
+
+    /*
+    / Specification:
+    / https://github.com/hiero-ledger/hiero-sdk-tck/blob/main/test-specifications/crypto-service/accountAllowanceApproveTransaction.md#approveAllowance
+    */
+    #[method(name = "approveAllowance")]
+    async fn approve_allowance(
+        &self,
+        allowances: Option<Vec<AllowanceParams>>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountAllowanceResponse, ErrorObjectOwned>;
+
+    /*
+    / Specification:
+    / https://github.com/hiero-ledger/hiero-sdk-tck/blob/main/test-specifications/crypto-service/accountAllowanceDeleteTransaction.md#deleteAllowance
+    */
+    #[method(name = "deleteAllowance")]
+    async fn delete_allowance(
+        &self,
+        allowances: Option<Vec<RemoveAllowanceParams>>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountAllowanceResponse, ErrorObjectOwned>;
 }
 
 pub struct RpcServerImpl;
@@ -424,4 +459,211 @@ impl RpcServer for RpcServerImpl {
 
         Ok(AccountUpdateResponse { status: tx_receipt.status.as_str_name().to_string() })
     }
+
+    async fn approve_allowance(
+        &self,
+        allowances: Option<Vec<AllowanceParams>>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountAllowanceResponse, ErrorObjectOwned> {
+        let client = {
+            let guard = GLOBAL_SDK_CLIENT.lock().unwrap();
+            guard
+                .as_ref()
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        INTERNAL_ERROR_CODE,
+                        "Client not initialized".to_string(),
+                        None::<()>,
+                    )
+                })?
+                .clone()
+        };
+
+        let mut allowance_approve_tx = AccountAllowanceApproveTransaction::new();
+
+        for allowance in allowances.unwrap_or_default() {
+            let owner_account_id: AccountId = parse_param(required_param(
+                &allowance.owner_account_id,
+                "approveAllowance: ownerAccountId",
+            )?)?;
+
+            let spender_account_id: AccountId = parse_param(required_param(
+                &allowance.spender_account_id,
+                "approveAllowance: spenderAccountId",
+            )?)?;
+
+            if let Some(hbar) = allowance.hbar {
+                let amount =
+                    parse_param(required_param(&hbar.amount, "approveAllowance: amount")?)?;
+
+                allowance_approve_tx.approve_hbar_allowance(
+                    owner_account_id,
+                    spender_account_id,
+                    Hbar::from_tinybars(amount),
+                );
+            } else if let Some(token) = allowance.token {
+                let token_id: TokenId =
+                    parse_param(required_param(&token.token_id, "approveAllowance: tokenId")?)?;
+                let amount =
+                    parse_param(required_param(&token.amount, "approveAllowance: amount")?)?;
+
+                allowance_approve_tx.approve_token_allowance(
+                    token_id,
+                    owner_account_id,
+                    spender_account_id,
+                    amount,
+                );
+            } else if let Some(nft) = allowance.nft {
+                let token_id: TokenId =
+                    parse_param(required_param(&nft.token_id, "approveAllowance: tokenId")?)?;
+
+                match nft.approved_for_all {
+                    Some(true) => {
+                        allowance_approve_tx.approve_token_nft_allowance_all_serials(
+                            token_id,
+                            owner_account_id,
+                            spender_account_id,
+                        );
+                    }
+                    Some(false) => {
+                        allowance_approve_tx.delete_token_nft_allowance_all_serials(
+                            token_id,
+                            owner_account_id,
+                            spender_account_id,
+                        );
+                    }
+                    None => {
+                        let delegate_spender_account_id = nft
+                            .delegate_spender_account_id
+                            .as_deref()
+                            .map(parse_param::<AccountId>)
+                            .transpose()?;
+
+                        for serial in nft.serial_numbers.unwrap_or_default() {
+                            let nft_id = token_id.nft(parse_param(&serial)?);
+
+                            match delegate_spender_account_id {
+                                Some(delegate_spender_account_id) => {
+                                    allowance_approve_tx
+                                        .approve_token_nft_allowance_with_delegating_spender(
+                                            nft_id,
+                                            owner_account_id,
+                                            spender_account_id,
+                                            delegate_spender_account_id,
+                                        );
+                                }
+                                None => {
+                                    allowance_approve_tx.approve_token_nft_allowance(
+                                        nft_id,
+                                        owner_account_id,
+                                        spender_account_id,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                return Err(ErrorObject::borrowed(
+                    INVALID_PARAMS_CODE,
+                    "approveAllowance: allowance MUST contain one of hbar, token, or nft.",
+                    None,
+                ));
+            }
+        }
+
+        if let Some(common_transaction_params) = common_transaction_params {
+            let _ = fill_common_transaction_params(
+                &mut allowance_approve_tx,
+                &common_transaction_params,
+            );
+
+            allowance_approve_tx.freeze_with(&client).unwrap();
+
+            if let Some(signers) = common_transaction_params.get("signers") {
+                if let Value::Array(signers) = signers {
+                    for signer in signers {
+                        if let Value::String(signer_str) = signer {
+                            allowance_approve_tx
+                                .sign(PrivateKey::from_str_der(signer_str).unwrap());
+                        }
+                    }
+                }
+            }
+        }
+
+        let tx_response =
+            allowance_approve_tx.execute(&client).await.map_err(|e| from_hedera_error(e))?;
+
+        let tx_receipt =
+            tx_response.get_receipt(&client).await.map_err(|e| from_hedera_error(e))?;
+
+        Ok(AccountAllowanceResponse { status: tx_receipt.status.as_str_name().to_string() })
+    }
+
+    async fn delete_allowance(
+        &self,
+        allowances: Option<Vec<RemoveAllowanceParams>>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountAllowanceResponse, ErrorObjectOwned> {
+        let client = {
+            let guard = GLOBAL_SDK_CLIENT.lock().unwrap();
+            guard
+                .as_ref()
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        INTERNAL_ERROR_CODE,
+                        "Client not initialized".to_string(),
+                        None::<()>,
+                    )
+                })?
+                .clone()
+        };
+
+        let mut allowance_delete_tx = AccountAllowanceDeleteTransaction::new();
+
+        for allowance in allowances.unwrap_or_default() {
+            let owner_account_id: AccountId = parse_param(required_param(
+                &allowance.owner_account_id,
+                "deleteAllowance: ownerAccountId",
+            )?)?;
+
+            let token_id: TokenId =
+                parse_param(required_param(&allowance.token_id, "deleteAllowance: tokenId")?)?;
+
+            for serial in allowance.serial_numbers.unwrap_or_default() {
+                allowance_delete_tx.delete_all_token_nft_allowances(
+                    token_id.nft(parse_param(&serial)?),
+                    owner_account_id,
+                );
+            }
+        }
+
+        if let Some(common_transaction_params) = common_transaction_params {
+            let _ = fill_common_transaction_params(
+                &mut allowance_delete_tx,
+                &common_transaction_params,
+            );
+
+            allowance_delete_tx.freeze_with(&client).unwrap();
+
+            if let Some(signers) = common_transaction_params.get("signers") {
+                if let Value::Array(signers) = signers {
+                    for signer in signers {
+                        if let Value::String(signer_str) = signer {
+                            allowance_delete_tx.sign(PrivateKey::from_str_der(signer_str).unwrap());
+                        }
+                    }
+                }
+            }
+        }
+
+        let tx_response =
+            allowance_delete_tx.execute(&client).await.map_err(|e| from_hedera_error(e))?;
+
+        let tx_receipt =
+            tx_response.get_receipt(&client).await.map_err(|e| from_hedera_error(e))?;
+
+        Ok(AccountAllowanceResponse { status: tx_receipt.status.as_str_name().to_string() })
+    }
 }
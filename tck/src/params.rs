@@ -0,0 +1,44 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowanceParams {
+    pub owner_account_id: Option<String>,
+    pub spender_account_id: Option<String>,
+    pub hbar: Option<HbarAllowanceParams>,
+    pub token: Option<TokenAllowanceParams>,
+    pub nft: Option<NftAllowanceParams>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HbarAllowanceParams {
+    pub amount: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAllowanceParams {
+    pub token_id: Option<String>,
+    pub amount: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NftAllowanceParams {
+    pub token_id: Option<String>,
+    pub serial_numbers: Option<Vec<String>>,
+    pub approved_for_all: Option<bool>,
+    pub delegate_spender_account_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAllowanceParams {
+    pub owner_account_id: Option<String>,
+    pub token_id: Option<String>,
+    pub serial_numbers: Option<Vec<String>>,
+}
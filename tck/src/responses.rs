@@ -24,3 +24,9 @@ pub struct GenerateKeyResponse {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub private_keys: Vec<Value>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAllowanceResponse {
+    pub status: String,
+}
@@ -0,0 +1,227 @@
+//! Spins a local Hedera network up and down for the e2e tests.
+//!
+//! [`LocalNode::start`] runs the network in the background and waits until it accepts connections,
+//! [`LocalNode::env`] is the environment the e2e tests need to use it,
+//! and dropping the [`LocalNode`] tears the network down again.
+
+use std::net::{
+    SocketAddr,
+    TcpStream,
+};
+use std::process::Command;
+use std::str::FromStr;
+use std::thread;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use anyhow::Context;
+
+/// The operator the e2e tests use on a local network, `0.0.1022`, which local networks fund on startup.
+pub const DEFAULT_OPERATOR_ID: &str = "0.0.1022";
+
+/// The private key of [`DEFAULT_OPERATOR_ID`].
+pub const DEFAULT_OPERATOR_KEY: &str = "302e020100300506032b657004220420a608e2130a0a3cb34f86e757303c862bee353d9ab77ba4387ec084f881d420d4";
+
+/// The tool used to run the local network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// [hedera-local-node](https://github.com/hashgraph/hedera-local-node), in Docker.
+    LocalNode,
+
+    /// [Solo](https://github.com/hashgraph/solo), in a local Kubernetes cluster.
+    Solo,
+}
+
+impl Backend {
+    fn start_command(self, network_tag: Option<&str>) -> Vec<String> {
+        let mut command: Vec<String> = match self {
+            Self::LocalNode => {
+                ["npx", "@hashgraph/hedera-local", "start", "-d", "--network", "local"]
+                    .map(str::to_owned)
+                    .into()
+            }
+            Self::Solo => ["solo", "quick-start", "single", "deploy"].map(str::to_owned).into(),
+        };
+
+        if let Some(network_tag) = network_tag {
+            match self {
+                Self::LocalNode => command.push(format!("--network-tag={network_tag}")),
+                Self::Solo => command.extend(["--release-tag".to_owned(), network_tag.to_owned()]),
+            }
+        }
+
+        command
+    }
+
+    fn stop_command(self) -> Vec<String> {
+        match self {
+            Self::LocalNode => ["npx", "@hashgraph/hedera-local", "stop"].map(str::to_owned).into(),
+            Self::Solo => ["solo", "quick-start", "single", "destroy"].map(str::to_owned).into(),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local-node" => Ok(Self::LocalNode),
+            "solo" => Ok(Self::Solo),
+            _ => anyhow::bail!("unknown backend `{s}`, expected `local-node` or `solo`"),
+        }
+    }
+}
+
+/// How to run the local network.
+#[derive(Debug, Clone)]
+pub struct LocalNodeConfig {
+    /// The tool used to run the network.
+    pub backend: Backend,
+
+    /// The version of the network to run, IE, `0.57.0`, or `None` for the backend's default.
+    pub network_tag: Option<String>,
+
+    /// Ports that must accept connections before the network is considered ready.
+    ///
+    /// Defaults to the consensus node (`50211`), the mirror node gRPC API (`5600`) and the mirror node REST API (`5551`).
+    pub ports: Vec<u16>,
+
+    /// How long to wait for the network to be ready after starting it.
+    pub ready_timeout: Duration,
+}
+
+impl LocalNodeConfig {
+    #[must_use]
+    pub fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            network_tag: None,
+            ports: vec![50211, 5600, 5551],
+            ready_timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+/// A running local network, torn down when dropped.
+#[derive(Debug)]
+pub struct LocalNode {
+    backend: Backend,
+    stopped: bool,
+}
+
+impl LocalNode {
+    /// Starts the local network and waits until every port in `config.ports` accepts connections.
+    ///
+    /// # Errors
+    /// - If the backend fails to start the network.
+    /// - If the network isn't ready within `config.ready_timeout`, in which case it's torn down again.
+    pub fn start(config: &LocalNodeConfig) -> anyhow::Result<Self> {
+        log::info!("starting local network with {:?}", config.backend);
+
+        run(&config.backend.start_command(config.network_tag.as_deref()))
+            .context("failed to start the local network")?;
+
+        let node = Self { backend: config.backend, stopped: false };
+
+        wait_ready(&config.ports, config.ready_timeout)?;
+
+        log::info!("local network is ready");
+
+        Ok(node)
+    }
+
+    /// The environment variables that point the e2e tests at this network, with the default operator.
+    #[must_use]
+    pub fn env(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("TEST_NETWORK_NAME", "localhost"),
+            ("TEST_OPERATOR_ID", DEFAULT_OPERATOR_ID),
+            ("TEST_OPERATOR_KEY", DEFAULT_OPERATOR_KEY),
+            ("TEST_RUN_NONFREE", "1"),
+        ]
+    }
+
+    /// Tears down the local network.
+    ///
+    /// # Errors
+    /// - If the backend fails to stop the network.
+    pub fn stop(mut self) -> anyhow::Result<()> {
+        self.stopped = true;
+
+        log::info!("stopping local network");
+
+        run(&self.backend.stop_command()).context("failed to stop the local network")
+    }
+}
+
+impl Drop for LocalNode {
+    fn drop(&mut self) {
+        if self.stopped {
+            return;
+        }
+
+        if let Err(e) = run(&self.backend.stop_command()) {
+            log::error!("failed to stop the local network: {e:?}");
+        }
+    }
+}
+
+fn run(command: &[String]) -> anyhow::Result<()> {
+    let (program, args) = command.split_first().context("empty command")?;
+
+    let status = Command::new(program)
+        .args(args)
+        .status()
+        .with_context(|| format!("failed to run `{}`", command.join(" ")))?;
+
+    anyhow::ensure!(status.success(), "`{}` exited with {status}", command.join(" "));
+
+    Ok(())
+}
+
+fn wait_ready(ports: &[u16], timeout: Duration) -> anyhow::Result<()> {
+    let deadline = Instant::now() + timeout;
+
+    for &port in ports {
+        let addr = SocketAddr::from(([127, 0, 0, 1], port));
+
+        loop {
+            if TcpStream::connect_timeout(&addr, Duration::from_secs(1)).is_ok() {
+                log::debug!("port {port} is ready");
+                break;
+            }
+
+            anyhow::ensure!(
+                Instant::now() < deadline,
+                "local network wasn't ready after {timeout:?}, port {port} isn't accepting connections"
+            );
+
+            thread::sleep(Duration::from_secs(2));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Backend;
+
+    #[test]
+    fn parse_backend() {
+        assert_eq!("local-node".parse::<Backend>().unwrap(), Backend::LocalNode);
+        assert_eq!("solo".parse::<Backend>().unwrap(), Backend::Solo);
+        assert!("docker".parse::<Backend>().is_err());
+    }
+
+    #[test]
+    fn local_node_network_tag() {
+        assert_eq!(
+            Backend::LocalNode.start_command(Some("0.57.0")).join(" "),
+            "npx @hashgraph/hedera-local start -d --network local --network-tag=0.57.0"
+        );
+    }
+}
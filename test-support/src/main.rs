@@ -0,0 +1,65 @@
+use std::process::{
+    Command,
+    ExitCode,
+};
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Parser;
+use test_support::{
+    Backend,
+    LocalNode,
+    LocalNodeConfig,
+};
+
+/// Runs a command (by default, the e2e tests) against a freshly started local network,
+/// tearing the network down afterwards.
+#[derive(Parser, Debug)]
+struct Args {
+    /// `local-node` or `solo`.
+    #[clap(long, env = "TEST_LOCAL_BACKEND", default_value = "local-node")]
+    backend: Backend,
+
+    /// The version of the network to run, IE, `0.57.0`.
+    #[clap(long, env = "TEST_LOCAL_NETWORK_TAG")]
+    network_tag: Option<String>,
+
+    /// How long to wait for the network to be ready, in seconds.
+    #[clap(long, default_value_t = 300)]
+    ready_timeout: u64,
+
+    /// The command to run once the network is ready.
+    #[clap(last = true)]
+    command: Vec<String>,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let _ =
+        env_logger::builder().parse_default_env().filter_level(log::LevelFilter::Info).try_init();
+
+    let args = Args::parse();
+
+    let mut config = LocalNodeConfig::new(args.backend);
+    config.network_tag = args.network_tag;
+    config.ready_timeout = Duration::from_secs(args.ready_timeout);
+
+    let command = match args.command.is_empty() {
+        true => ["cargo", "test", "--test", "e2e"].map(str::to_owned).into(),
+        false => args.command,
+    };
+
+    let node = LocalNode::start(&config)?;
+
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .envs(node.env())
+        .status()
+        .with_context(|| format!("failed to run `{}`", command.join(" ")))?;
+
+    node.stop()?;
+
+    Ok(match status.success() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    })
+}
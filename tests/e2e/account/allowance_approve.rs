@@ -1,6 +1,8 @@
+use assert_matches::assert_matches;
 use hedera::{
     AccountAllowanceApproveTransaction,
     Hbar,
+    HbarAllowanceFlow,
     TokenAssociateTransaction,
     TransactionId,
     TransferTransaction,
@@ -54,6 +56,68 @@ async fn spend() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn flow_spend() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let (alice, bob) = tokio::try_join!(
+        Account::create(Hbar::new(10), &client),
+        Account::create(Hbar::new(10), &client)
+    )?;
+
+    let response = HbarAllowanceFlow::new()
+        .owner_account_id(bob.id)
+        .owner_key(bob.key.clone())
+        .spender_account_id(alice.id)
+        .spender_key(alice.key.clone())
+        .recipient_account_id(client.get_operator_account_id().unwrap())
+        .allowance(Hbar::new(10))
+        .amount(Hbar::new(4))
+        .execute(&client)
+        .await?;
+
+    assert_eq!(response.estimated_remaining_allowance, Hbar::new(6));
+
+    let _ = tokio::try_join!(alice.delete(&client), bob.delete(&client))?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn flow_amount_over_allowance_fails() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let (alice, bob) = tokio::try_join!(
+        Account::create(Hbar::new(10), &client),
+        Account::create(Hbar::new(10), &client)
+    )?;
+
+    let res = HbarAllowanceFlow::new()
+        .owner_account_id(bob.id)
+        .owner_key(bob.key.clone())
+        .spender_account_id(alice.id)
+        .spender_key(alice.key.clone())
+        .recipient_account_id(client.get_operator_account_id().unwrap())
+        .allowance(Hbar::new(1))
+        .amount(Hbar::new(2))
+        .execute(&client)
+        .await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::InsufficientAllowance { allowance: Some(allowance), amount })
+            if allowance == Hbar::new(1) && amount == Hbar::new(2)
+    );
+
+    let _ = tokio::try_join!(alice.delete(&client), bob.delete(&client))?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn nft_allowance_no_association() -> anyhow::Result<()> {
     let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
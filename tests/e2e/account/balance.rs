@@ -173,11 +173,8 @@ async fn query_token_balances() -> anyhow::Result<()> {
 
     let balance = AccountBalanceQuery::new().account_id(account.id).execute(&client).await?;
 
-    #[allow(deprecated)]
-    {
-        assert_eq!(balance.tokens.get(&token_id).copied(), Some(10000));
-        assert_eq!(balance.token_decimals.get(&token_id).copied(), Some(50));
-    }
+    assert_eq!(balance.tokens.get(&token_id).copied(), Some(10000));
+    assert_eq!(balance.token_decimals.get(&token_id).copied(), Some(50));
 
     TokenBurnTransaction::new()
         .token_id(token_id)
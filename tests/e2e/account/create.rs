@@ -3,6 +3,7 @@ use hedera::{
     AccountInfoQuery,
     Hbar,
     Key,
+    MultisigAccountFlow,
     PrivateKey,
     TransactionId,
     TransferTransaction,
@@ -405,13 +406,54 @@ async fn cannot_create_account_with_invalid_negative_max_auto_token_assocation(
         .execute(&client)
         .await;
 
+    // rejected before it's ever sent.
     assert_matches::assert_matches!(
         res,
-        Err(hedera::Error::TransactionPreCheckStatus {
-            status: hedera::Status::InvalidMaxAutoAssociations,
-            ..
-        })
+        Err(hedera::Error::InvalidMaxAutomaticTokenAssociations(-2))
     );
 
     Ok(())
 }
+
+#[tokio::test]
+async fn multisig_flow() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let keys: Vec<_> = (0..3).map(|_| PrivateKey::generate_ed25519()).collect();
+
+    let mut flow = MultisigAccountFlow::new();
+
+    flow.keys(keys.iter().map(PrivateKey::public_key))
+        .threshold(2)
+        .initial_balance(Hbar::new(1))
+        .receiver_signature_required(true)
+        .freeze_with(&client)?;
+
+    let bytes = flow.to_bytes()?;
+
+    // not enough signatures yet.
+    flow.add_signed_bytes(&MultisigAccountFlow::sign_bytes(&bytes, &keys[0])?)?;
+
+    let res = flow.execute(&client).await;
+
+    assert_matches::assert_matches!(
+        res,
+        Err(hedera::Error::MultisigThresholdNotMet { signatures: 1, threshold: 2 })
+    );
+
+    flow.add_signed_bytes(&MultisigAccountFlow::sign_bytes(&bytes, &keys[2])?)?;
+
+    let receipt = flow.execute(&client).await?.get_receipt(&client).await?;
+
+    let account_id = receipt.account_id.unwrap();
+
+    let info = AccountInfoQuery::new().account_id(account_id).execute(&client).await?;
+
+    assert_eq!(info.key, Key::KeyList(flow.get_key_list()));
+    assert_eq!(info.balance, Hbar::new(1));
+    assert!(info.is_receiver_signature_required);
+
+    Ok(())
+}
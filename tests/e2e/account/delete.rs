@@ -105,3 +105,45 @@ async fn missing_deletee_signature_fails() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn freeze_sign_execute() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let key = PrivateKey::generate_ed25519();
+
+    let executed = AccountCreateTransaction::new()
+        .key(key.public_key())
+        .initial_balance(Hbar::new(1))
+        .freeze_sign_execute(&client, &[])
+        .await?;
+
+    let account_id = executed.receipt.account_id.unwrap();
+
+    // without the deletee's key the receipt fails.
+    let res = AccountDeleteTransaction::new()
+        .transfer_account_id(op.account_id)
+        .account_id(account_id)
+        .freeze_sign_execute(&client, &[])
+        .await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ReceiptStatus { status: Status::InvalidSignature, .. })
+    );
+
+    AccountDeleteTransaction::new()
+        .transfer_account_id(op.account_id)
+        .account_id(account_id)
+        .freeze_sign_execute(&client, &[key])
+        .await?;
+
+    Ok(())
+}
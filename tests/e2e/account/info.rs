@@ -191,3 +191,42 @@ async fn flow_verify_transaction() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn flow_verify_record() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to lack of operator");
+
+        return Ok(());
+    };
+
+    let new_public_key = PrivateKey::generate_ed25519().public_key();
+
+    let mut tx = hedera::AccountCreateTransaction::new();
+    tx.key(new_public_key)
+        .initial_balance(Hbar::from_tinybars(1000))
+        .freeze_with(&client)?
+        .sign_with_operator(&client)?;
+
+    let record = tx.execute(&client).await?.get_record(&client).await?;
+
+    assert_matches!(
+        account_info_flow::verify_record_signature(&client, op.account_id, &mut tx, &record).await,
+        Ok(())
+    );
+
+    let mut other_tx = hedera::AccountCreateTransaction::new();
+    other_tx.key(new_public_key).initial_balance(Hbar::from_tinybars(1000)).freeze_with(&client)?;
+
+    assert_matches!(
+        account_info_flow::verify_record_signature(&client, op.account_id, &mut other_tx, &record)
+            .await,
+        Err(hedera::Error::SignatureVerify(_))
+    );
+
+    Ok(())
+}
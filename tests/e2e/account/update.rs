@@ -1,6 +1,7 @@
 use hedera::{
     AccountCreateTransaction,
     AccountInfoQuery,
+    AccountKeyRotationFlow,
     AccountUpdateTransaction,
     Hbar,
     Key,
@@ -150,3 +151,70 @@ async fn cannot_update_max_token_association_to_lower_value_fails() -> anyhow::R
 
     Ok(())
 }
+
+#[tokio::test]
+async fn key_rotation_flow() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let old_key = PrivateKey::generate_ed25519();
+    let new_key = PrivateKey::generate_ed25519();
+
+    let account_id = AccountCreateTransaction::new()
+        .key(old_key.public_key())
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .account_id
+        .unwrap();
+
+    let response = AccountKeyRotationFlow::new()
+        .account_id(account_id)
+        .old_key(old_key)
+        .new_key(new_key.clone())
+        .execute(&client)
+        .await?;
+
+    assert_eq!(response.info.account_id, account_id);
+    assert_eq!(response.info.key, Key::Single(new_key.public_key()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_rotation_flow_without_old_key_is_not_rotated() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let old_key = PrivateKey::generate_ed25519();
+
+    let account_id = AccountCreateTransaction::new()
+        .key(old_key.public_key())
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .account_id
+        .unwrap();
+
+    let res = AccountKeyRotationFlow::new()
+        .account_id(account_id)
+        .new_key(PrivateKey::generate_ed25519())
+        .execute(&client)
+        .await;
+
+    let error =
+        assert_matches::assert_matches!(res, Err(hedera::Error::KeyRotation(error)) => error);
+
+    assert_eq!(error.kind, hedera::KeyRotationErrorKind::NotRotated);
+    assert_eq!(error.account_id, account_id);
+
+    let info = AccountInfoQuery::new().account_id(account_id).execute(&client).await?;
+
+    assert_eq!(info.key, Key::Single(old_key.public_key()));
+
+    Ok(())
+}
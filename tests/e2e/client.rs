@@ -1,6 +1,18 @@
 use std::collections::HashMap;
 
-use hedera::Client;
+use assert_matches::assert_matches;
+use hedera::{
+    AccountId,
+    Client,
+    Hbar,
+    NodePingQuery,
+    TransferTransaction,
+};
+
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
 
 #[tokio::test]
 async fn initialize_with_mirror_network() -> anyhow::Result<()> {
@@ -14,3 +26,56 @@ async fn initialize_with_mirror_network() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn ping_node() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let node_account_id = *client.network().values().next().unwrap();
+
+    let response = NodePingQuery::new().node_account_id(node_account_id).execute(&client).await?;
+
+    assert_eq!(response.node_account_id, node_account_id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_response_attempted_nodes() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let response = TransferTransaction::new()
+        .hbar_transfer(op.account_id, Hbar::from_tinybars(-1))
+        .hbar_transfer(AccountId::new(0, 0, 98), Hbar::from_tinybars(1))
+        .execute(&client)
+        .await?;
+
+    assert!(response.attempts() >= 1);
+    assert_eq!(response.all_attempted_nodes().len(), response.attempts());
+    assert_eq!(response.all_attempted_nodes().last(), Some(&response.node_account_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ping_unknown_node_fails() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let res =
+        NodePingQuery::new().node_account_id(AccountId::new(0, 0, 999_999)).execute(&client).await;
+
+    assert_matches!(res, Err(hedera::Error::NodeAccountUnknown(_)));
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Detection of what the network under test supports.
+//!
+//! Tests for newer HIPs fail with `INVALID_TRANSACTION_BODY` (or similar) against a network that's too old for them,
+//! which tells you nothing about whether the SDK is right, so they're skipped instead.
+
+use hedera::{
+    Client,
+    NetworkVersionInfoQuery,
+    SemanticVersion,
+};
+use tokio::sync::OnceCell;
+
+use super::keys;
+
+/// Something a network may or may not support, depending on its version or configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Capability {
+    /// HIP-904: `TokenAirdropTransaction`, `TokenClaimAirdropTransaction` and `TokenCancelAirdropTransaction`.
+    TokenAirdrop,
+
+    /// HIP-904: `TokenRejectTransaction`.
+    TokenReject,
+}
+
+impl Capability {
+    const ALL: [Self; 2] = [Self::TokenAirdrop, Self::TokenReject];
+
+    /// The name of the capability, as used in `TEST_DISABLED_CAPABILITIES`.
+    fn name(self) -> &'static str {
+        match self {
+            Self::TokenAirdrop => "token-airdrop",
+            Self::TokenReject => "token-reject",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::TokenAirdrop => "HIP-904 token airdrops",
+            Self::TokenReject => "HIP-904 token rejection",
+        }
+    }
+
+    /// The first services version that supports this capability, as `(major, minor)`.
+    fn min_services_version(self) -> (u32, u32) {
+        match self {
+            Self::TokenAirdrop => (0, 53),
+            Self::TokenReject => (0, 51),
+        }
+    }
+
+    fn is_disabled_by_env(self) -> bool {
+        let Ok(disabled) = dotenvy::var(keys::DISABLED_CAPABILITIES) else {
+            return false;
+        };
+
+        disabled.split(',').map(str::trim).any(|name| {
+            if !Self::ALL.iter().any(|it| it.name() == name) {
+                log::warn!("unknown capability `{name}` in `{}`", keys::DISABLED_CAPABILITIES);
+            }
+
+            name == self.name()
+        })
+    }
+}
+
+/// The services version of the network under test, or `None` if it couldn't be found.
+///
+/// The network is only probed once per test binary.
+async fn services_version(client: &Client) -> Option<&'static SemanticVersion> {
+    static SERVICES_VERSION: OnceCell<Option<SemanticVersion>> = OnceCell::const_new();
+
+    SERVICES_VERSION
+        .get_or_init(|| async {
+            match NetworkVersionInfoQuery::new().execute(client).await {
+                Ok(info) => Some(info.services_version),
+                Err(e) => {
+                    log::warn!(
+                        "failed to probe the network version: {e}; assuming it's up to date"
+                    );
+                    None
+                }
+            }
+        })
+        .await
+        .as_ref()
+}
+
+/// Returns `true` if the network under test supports `capability`, logging why not if it doesn't.
+pub(crate) async fn supports(client: &Client, capability: Capability) -> bool {
+    if capability.is_disabled_by_env() {
+        log::warn!(
+            "skipping test: {} are disabled by `{}`",
+            capability.description(),
+            keys::DISABLED_CAPABILITIES
+        );
+
+        return false;
+    }
+
+    let Some(version) = services_version(client).await else {
+        return true;
+    };
+
+    let (major, minor) = capability.min_services_version();
+
+    if (version.major, version.minor) < (major, minor) {
+        log::warn!(
+            "skipping test: {} require services version {major}.{minor}.0 or later, but the network is on {version}",
+            capability.description(),
+        );
+
+        return false;
+    }
+
+    true
+}
@@ -10,6 +10,10 @@ use hedera::{
 };
 use once_cell::sync::Lazy;
 
+mod capabilities;
+
+pub(crate) use capabilities::Capability;
+
 mod keys {
     pub(super) const NETWORK: &str = "TEST_NETWORK_NAME";
 
@@ -18,6 +22,8 @@ mod keys {
     pub(super) const OPERATOR_ID: &str = "TEST_OPERATOR_ID";
 
     pub(super) const RUN_NONFREE: &str = "TEST_RUN_NONFREE";
+
+    pub(super) const DISABLED_CAPABILITIES: &str = "TEST_DISABLED_CAPABILITIES";
 }
 
 static CONFIG: Lazy<Config> = Lazy::new(Config::parse_env);
@@ -193,3 +199,13 @@ pub(crate) fn setup_nonfree() -> Option<TestEnvironment> {
         }
     }
 }
+
+/// Like [`setup_nonfree`], but also skips the test if the network doesn't support `capability`.
+pub(crate) async fn setup_nonfree_requiring(capability: Capability) -> Option<TestEnvironment> {
+    let env = setup_nonfree()?;
+
+    match capabilities::supports(&env.client, capability).await {
+        true => Some(env),
+        false => None,
+    }
+}
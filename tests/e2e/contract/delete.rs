@@ -72,11 +72,17 @@ async fn missing_admin_key_fails() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn missing_contract_id_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
         return Ok(());
     };
 
-    let res = ContractDeleteTransaction::new().execute(&client).await;
+    let res =
+        ContractDeleteTransaction::new().transfer_account_id(op.account_id).execute(&client).await;
 
     assert_matches!(
         res,
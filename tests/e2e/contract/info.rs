@@ -1,5 +1,6 @@
 use assert_matches::assert_matches;
 use hedera::{
+    contract_info_flow,
     ContractDeleteTransaction,
     ContractInfoQuery,
     Hbar,
@@ -76,6 +77,43 @@ async fn query_no_admin_key() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn flow_verify_signature() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let contract_id =
+        super::create_contract(&client, op.private_key.public_key(), ContractAdminKey::Operator)
+            .await?;
+
+    let message = b"Hello, world!";
+    let signature = op.private_key.sign(message);
+
+    contract_info_flow::verify_signature(&client, contract_id, message, &signature).await?;
+
+    assert_matches!(
+        contract_info_flow::verify_signature(&client, contract_id, b"Goodbye, world!", &signature)
+            .await,
+        Err(hedera::Error::SignatureVerify(_))
+    );
+
+    ContractDeleteTransaction::new()
+        .transfer_account_id(op.account_id)
+        .contract_id(contract_id)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn missing_contract_id_fails() -> anyhow::Result<()> {
     let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
@@ -169,3 +169,48 @@ async fn large_contents_small_valid_duration() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn large_contents_from_reader() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let file_id = FileCreateTransaction::new()
+        .keys([op.private_key.public_key()])
+        .contents("[rust::e2e::file_append::4]")
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .file_id
+        .unwrap();
+
+    let responses = FileAppendTransaction::new()
+        .file_id(file_id)
+        .execute_all_from_reader(&client, resources::BIG_CONTENTS.as_bytes())
+        .await?;
+
+    assert_eq!(responses.len(), resources::BIG_CONTENTS.len().div_ceil(4096));
+
+    let contents = FileContentsQuery::new().file_id(file_id).execute(&client).await?;
+
+    assert_eq!(
+        String::from_utf8(contents.contents).unwrap(),
+        format!("[rust::e2e::file_append::4]{}", resources::BIG_CONTENTS)
+    );
+
+    FileDeleteTransaction::new()
+        .file_id(file_id)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    Ok(())
+}
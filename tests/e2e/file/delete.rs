@@ -72,3 +72,24 @@ async fn immutable_file_fails() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn immutable_file_fails_on_execute_with_validate_receipt() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let file_id = FileCreateTransaction::new()
+        .contents("[rust::e2e::file_delete::3]")
+        .execute_and_get_receipt(&client)
+        .await?
+        .receipt
+        .file_id
+        .unwrap();
+
+    let res = FileDeleteTransaction::new().file_id(file_id).validate_receipt(true).execute(&client).await;
+
+    assert_matches!(res, Err(hedera::Error::ReceiptStatus { status: Status::Unauthorized, .. }));
+
+    Ok(())
+}
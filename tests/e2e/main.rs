@@ -16,3 +16,5 @@ mod resources;
 mod schedule;
 mod token;
 mod topic;
+#[cfg(feature = "mirror-rest")]
+mod transaction_history;
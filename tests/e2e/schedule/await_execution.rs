@@ -0,0 +1,104 @@
+use assert_matches::assert_matches;
+use hedera::{
+    Hbar,
+    ScheduleDeleteTransaction,
+    ScheduleNotExecutedReason,
+    TransferTransaction,
+};
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::account::Account;
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
+
+#[tokio::test]
+async fn executed() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let account = Account::create(Hbar::new(1), &client).await?;
+
+    let schedule_id = {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+        tx.schedule()
+            .freeze_with(&client)?
+            .sign(account.key.clone())
+            .execute(&client)
+            .await?
+            .get_receipt(&client)
+            .await?
+            .schedule_id
+            .unwrap()
+    };
+
+    let info = client
+        .await_schedule_execution(schedule_id, OffsetDateTime::now_utc() + Duration::seconds(30))
+        .await?;
+
+    assert!(info.executed_at.is_some());
+
+    account.delete(&client).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleted() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let account = Account::create(Hbar::new(1), &client).await?;
+
+    let schedule_id = {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+        tx.schedule()
+            .admin_key(op.private_key.public_key())
+            .execute(&client)
+            .await?
+            .get_receipt(&client)
+            .await?
+            .schedule_id
+            .unwrap()
+    };
+
+    ScheduleDeleteTransaction::new()
+        .schedule_id(schedule_id)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    let res = client
+        .await_schedule_execution(schedule_id, OffsetDateTime::now_utc() + Duration::seconds(30))
+        .await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ScheduleNotExecuted { reason: ScheduleNotExecutedReason::Deleted, .. })
+    );
+
+    account.delete(&client).await?;
+
+    Ok(())
+}
@@ -148,3 +148,131 @@ async fn missing_schedule_id_fails() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn checked_missing_admin_key_fails() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let account = Account::create(Hbar::new(1), &client).await?;
+
+    let schedule_id = {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+        tx.schedule().execute(&client).await?.get_receipt(&client).await?.schedule_id.unwrap()
+    };
+
+    let res =
+        ScheduleDeleteTransaction::new().schedule_id(schedule_id).execute_checked(&client).await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ScheduleNotDeletable { status: Status::ScheduleIsImmutable, .. })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn checked_double_delete_fails() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let account = Account::create(Hbar::new(1), &client).await?;
+
+    let schedule_id = {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(account.id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+        tx.schedule()
+            .admin_key(op.private_key.public_key())
+            .execute(&client)
+            .await?
+            .get_receipt(&client)
+            .await?
+            .schedule_id
+            .unwrap()
+    };
+
+    ScheduleDeleteTransaction::new()
+        .schedule_id(schedule_id)
+        .execute_checked(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    let res =
+        ScheduleDeleteTransaction::new().schedule_id(schedule_id).execute_checked(&client).await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ScheduleNotDeletable { status: Status::ScheduleAlreadyDeleted, .. })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn executed_schedule_fails() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let account = Account::create(Hbar::new(0), &client).await?;
+
+    // the operator pays for (and so signs) the schedule, so it executes immediately.
+    let schedule_id = {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(op.account_id, Hbar::from_tinybars(-1))
+            .hbar_transfer(account.id, Hbar::from_tinybars(1));
+
+        tx.schedule()
+            .admin_key(op.private_key.public_key())
+            .execute(&client)
+            .await?
+            .get_receipt(&client)
+            .await?
+            .schedule_id
+            .unwrap()
+    };
+
+    let res =
+        ScheduleDeleteTransaction::new().schedule_id(schedule_id).execute_checked(&client).await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ScheduleNotDeletable { status: Status::ScheduleAlreadyExecuted, .. })
+    );
+
+    let res = ScheduleDeleteTransaction::new()
+        .schedule_id(schedule_id)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await;
+
+    assert_matches!(
+        res,
+        Err(hedera::Error::ReceiptStatus { status: Status::ScheduleAlreadyExecuted, .. })
+    );
+
+    Ok(())
+}
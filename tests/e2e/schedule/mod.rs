@@ -1,3 +1,6 @@
+mod await_execution;
 mod create;
 mod delete;
 mod info;
+mod scheduled_transfer_flow;
+mod sign_flow;
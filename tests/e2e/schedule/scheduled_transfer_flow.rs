@@ -0,0 +1,87 @@
+use hedera::{
+    AccountCreateTransaction,
+    AccountDeleteTransaction,
+    Hbar,
+    KeyList,
+    PrivateKey,
+    ScheduleSignFlow,
+    ScheduledTransferFlow,
+    ScheduledTransferStatus,
+    TransferTransaction,
+};
+
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
+
+#[tokio::test]
+async fn schedule_then_sign() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let key1 = PrivateKey::generate_ed25519();
+    let key2 = PrivateKey::generate_ed25519();
+    let key3 = PrivateKey::generate_ed25519();
+
+    let public_keys = [key1.public_key(), key2.public_key(), key3.public_key()];
+
+    let key_list =
+        KeyList { keys: public_keys.iter().map(|it| (*it).into()).collect(), threshold: Some(2) };
+
+    let account_id = AccountCreateTransaction::new()
+        .key(key_list.clone())
+        .initial_balance(Hbar::new(1))
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .account_id
+        .unwrap();
+
+    let mut transfer = TransferTransaction::new();
+
+    transfer.hbar_transfer(account_id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+    let response = ScheduledTransferFlow::new()
+        .transfer(transfer)
+        .required_signatures(2, public_keys)
+        .expiration(time::Duration::hours(1))
+        .sign(key1.clone())
+        .execute(&client)
+        .await?;
+
+    assert_eq!(
+        response.status,
+        ScheduledTransferStatus::AwaitingSignatures {
+            missing_keys: vec![key2.public_key(), key3.public_key()]
+        }
+    );
+
+    let info = ScheduleSignFlow::new()
+        .schedule_id(response.schedule_id)
+        .required_key(key_list)
+        .sign(key2.clone())
+        .execute(&client)
+        .await?;
+
+    assert!(info.executed_at.is_some());
+
+    AccountDeleteTransaction::new()
+        .account_id(account_id)
+        .transfer_account_id(op.account_id)
+        .sign(key1)
+        .sign(key2)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use hedera::{
+    AccountCreateTransaction,
+    AccountDeleteTransaction,
+    Hbar,
+    Key,
+    KeyList,
+    PrivateKey,
+    ScheduleSignFlow,
+    TransferTransaction,
+};
+
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
+
+#[tokio::test]
+async fn sign_until_executed() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let key1 = PrivateKey::generate_ed25519();
+    let key2 = PrivateKey::generate_ed25519();
+    let key3 = PrivateKey::generate_ed25519();
+
+    let key_list = KeyList {
+        keys: vec![key1.public_key().into(), key2.public_key().into(), key3.public_key().into()],
+        threshold: Some(2),
+    };
+
+    let account_id = AccountCreateTransaction::new()
+        .key(key_list.clone())
+        .initial_balance(Hbar::new(1))
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .account_id
+        .unwrap();
+
+    let mut transfer = TransferTransaction::new();
+
+    transfer.hbar_transfer(account_id, Hbar::new(-1)).hbar_transfer(op.account_id, Hbar::new(1));
+
+    let schedule_id = transfer
+        .schedule()
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?
+        .schedule_id
+        .unwrap();
+
+    let required_key = Key::KeyList(key_list);
+
+    let mut flow = ScheduleSignFlow::new();
+
+    flow.schedule_id(schedule_id).required_key(required_key.clone()).sign(key1.clone());
+
+    let info = flow.execute(&client).await?;
+
+    // one of two signatures isn't enough.
+    assert_eq!(info.executed_at, None);
+
+    let progress = info.signature_progress(&required_key);
+
+    assert!(!progress.is_satisfied);
+    assert_eq!(progress.signed_keys, [key1.public_key()]);
+
+    let info = flow.sign(key2.clone()).execute(&client).await?;
+
+    assert!(info.executed_at.is_some());
+    assert!(info.signature_progress(&required_key).is_satisfied);
+
+    AccountDeleteTransaction::new()
+        .account_id(account_id)
+        .transfer_account_id(op.account_id)
+        .sign(key1)
+        .sign(key2)
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    Ok(())
+}
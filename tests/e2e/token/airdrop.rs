@@ -22,7 +22,8 @@ use hedera::{
 
 use crate::account::Account;
 use crate::common::{
-    setup_nonfree,
+    setup_nonfree_requiring,
+    Capability,
     Config,
     TestEnvironment,
 };
@@ -35,7 +36,9 @@ use crate::token::{
 
 #[tokio::test]
 async fn airdrop_associated_tokens() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -104,7 +107,9 @@ async fn airdrop_associated_tokens() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn airdrop_non_associated_tokens() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -172,7 +177,9 @@ async fn airdrop_non_associated_tokens() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn airdrop_to_alias() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -242,7 +249,9 @@ async fn airdrop_to_alias() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn airdrop_with_custom_fees() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -354,7 +363,9 @@ async fn airdrop_with_custom_fees() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn airdrop_tokens_w_receiver_sig() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -392,7 +403,9 @@ async fn airdrop_tokens_w_receiver_sig() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn airdrop_nfts_w_receiver_sig() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
     let operator_account = test_operator_account(&config).await?;
@@ -449,7 +462,9 @@ async fn airdrop_nfts_w_receiver_sig() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn token_allowance_and_no_balance_ft_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -510,7 +525,9 @@ async fn token_allowance_and_no_balance_ft_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn token_allowance_and_no_balance_nft_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -601,7 +618,9 @@ async fn token_allowance_and_no_balance_nft_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn invalid_body_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
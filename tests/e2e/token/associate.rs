@@ -41,6 +41,57 @@ async fn basic() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn idempotent() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let (alice, bob) = tokio::try_join!(
+        Account::create(Hbar::new(0), &client),
+        Account::create(Hbar::new(0), &client)
+    )?;
+
+    let (token1, token2) = tokio::try_join!(
+        super::FungibleToken::create(&client, &alice, Default::default()),
+        super::FungibleToken::create(&client, &alice, Default::default())
+    )?;
+
+    TokenAssociateTransaction::new()
+        .account_id(bob.id)
+        .token_ids([token1.id])
+        .freeze_with(&client)?
+        .sign(bob.key.clone())
+        .execute(&client)
+        .await?
+        .get_receipt(&client)
+        .await?;
+
+    let receipts = TokenAssociateTransaction::new()
+        .account_id(bob.id)
+        .token_ids([token1.id, token2.id])
+        .sign(bob.key.clone())
+        .execute_idempotent(&client)
+        .await?;
+
+    let statuses: Vec<_> = receipts.iter().map(|it| it.status).collect();
+
+    assert_eq!(
+        statuses,
+        [
+            Status::TokenAlreadyAssociatedToAccount,
+            Status::TokenAlreadyAssociatedToAccount,
+            Status::Success
+        ]
+    );
+
+    tokio::try_join!(token1.delete(&client), token2.delete(&client))?;
+
+    tokio::try_join!(alice.delete(&client), bob.delete(&client))?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn missing_token_id() -> anyhow::Result<()> {
     let Some(TestEnvironment { config, client }) = setup_nonfree() else {
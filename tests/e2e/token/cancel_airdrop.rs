@@ -17,7 +17,8 @@ use hedera::{
 
 use crate::account::Account;
 use crate::common::{
-    setup_nonfree,
+    setup_nonfree_requiring,
+    Capability,
     Config,
     TestEnvironment,
 };
@@ -31,7 +32,9 @@ use crate::token::{
 
 #[tokio::test]
 async fn basic() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -106,7 +109,9 @@ async fn basic() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cancel_frozen_tokens() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -161,7 +166,9 @@ async fn cancel_frozen_tokens() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cancel_paused_tokens() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -204,7 +211,9 @@ async fn cancel_paused_tokens() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cancel_to_multiple_receivers() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -315,7 +324,9 @@ async fn cancel_to_multiple_receivers() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cancel_from_multiple_airdrops() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -407,7 +418,9 @@ async fn cancel_from_multiple_airdrops() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_cancel_nonexisting_airdrops_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -451,7 +464,9 @@ async fn cannot_cancel_nonexisting_airdrops_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_cancel_canceled_airdrops_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -500,7 +515,9 @@ async fn cannot_cancel_canceled_airdrops_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_cancel_empty_airdrop_list_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config: _, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -521,7 +538,9 @@ async fn cannot_cancel_empty_airdrop_list_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_cancel_duplicated_entries_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -16,7 +16,8 @@ use hedera::{
 
 use crate::account::Account;
 use crate::common::{
-    setup_nonfree,
+    setup_nonfree_requiring,
+    Capability,
     Config,
     TestEnvironment,
 };
@@ -27,7 +28,9 @@ use crate::token::{
 
 #[tokio::test]
 async fn basic() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -127,7 +130,9 @@ async fn basic() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn claim_to_multiple_receivers() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -239,7 +244,9 @@ async fn claim_to_multiple_receivers() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn claim_from_multiple_airdrops() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -332,7 +339,9 @@ async fn claim_from_multiple_airdrops() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_nonexisting_tokens_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -373,7 +382,9 @@ async fn cannot_claim_nonexisting_tokens_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_already_claimed_airdrop_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -426,7 +437,9 @@ async fn cannot_claim_already_claimed_airdrop_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_empty_pending_airdrops_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config: _, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -447,7 +460,9 @@ async fn cannot_claim_empty_pending_airdrops_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_duplicate_entries_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -490,7 +505,9 @@ async fn cannot_claim_duplicate_entries_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_deleted_tokens_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -538,7 +555,9 @@ async fn cannot_claim_deleted_tokens_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn cannot_claim_frozen_token_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenAirdrop).await
+    else {
         return Ok(());
     };
 
@@ -4,6 +4,7 @@ use hedera::{
     AnyCustomFee,
     FixedFee,
     FixedFeeData,
+    Fraction,
     FractionalFee,
     FractionalFeeData,
     Hbar,
@@ -41,8 +42,7 @@ fn fixed_fee(fee_collector: AccountId) -> AnyCustomFee {
 fn fractional_fee(fee_collector: AccountId) -> AnyCustomFee {
     FractionalFee {
         fee: FractionalFeeData {
-            denominator: 20,
-            numerator: 1,
+            fraction: Fraction::new(1, 20).unwrap(),
             minimum_amount: 1,
             maximum_amount: 20,
             assessment_method: hedera::FeeAssessmentMethod::Exclusive,
@@ -299,13 +299,12 @@ async fn too_many_custom_fees_fails() -> anyhow::Result<()> {
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .sign(account.key.clone())
         .execute(&client)
-        .await?
-        .get_receipt(&client)
         .await;
 
     assert_matches!(
         res,
-        Err(hedera::Error::ReceiptStatus { status: Status::CustomFeesListTooLong, .. })
+        Err(hedera::Error::CustomFeeValidation(e))
+            if *e == hedera::CustomFeeValidationError::TooManyFees { count: 11, max: 10 }
     );
 
     account.delete(&client).await?;
@@ -382,8 +381,7 @@ async fn fractional_fee_min_bigger_than_max_fails() -> anyhow::Result<()> {
 
     let fee = FractionalFee {
         fee: FractionalFeeData {
-            denominator: 3,
-            numerator: 1,
+            fraction: Fraction::new(1, 3).unwrap(),
             minimum_amount: 3,
             maximum_amount: 2,
             assessment_method: hedera::FeeAssessmentMethod::Exclusive,
@@ -402,16 +400,12 @@ async fn fractional_fee_min_bigger_than_max_fails() -> anyhow::Result<()> {
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .sign(account.key.clone())
         .execute(&client)
-        .await?
-        .get_receipt(&client)
         .await;
 
     assert_matches!(
         res,
-        Err(hedera::Error::ReceiptStatus {
-            status: Status::FractionalFeeMaxAmountLessThanMinAmount,
-            ..
-        })
+        Err(hedera::Error::CustomFeeValidation(e))
+            if *e == hedera::CustomFeeValidationError::MaximumLessThanMinimum { index: 0 }
     );
 
     account.delete(&client).await?;
@@ -479,56 +473,12 @@ async fn negative_fee_fails() -> anyhow::Result<()> {
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
         .sign(account.key.clone())
         .execute(&client)
-        .await?
-        .get_receipt(&client)
-        .await;
-
-    assert_matches!(
-        res,
-        Err(hedera::Error::ReceiptStatus { status: Status::CustomFeeMustBePositive, .. })
-    );
-
-    account.delete(&client).await?;
-    Ok(())
-}
-
-#[tokio::test]
-async fn zero_denominator_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
-        return Ok(());
-    };
-
-    let account = Account::create(Hbar::new(0), &client).await?;
-
-    let fee = FractionalFee {
-        fee: FractionalFeeData {
-            denominator: 0,
-            numerator: 1,
-            minimum_amount: 1,
-            maximum_amount: 10,
-            assessment_method: hedera::FeeAssessmentMethod::Exclusive,
-        },
-        fee_collector_account_id: Some(account.id),
-        all_collectors_are_exempt: false,
-    }
-    .into();
-
-    let res = TokenCreateTransaction::new()
-        .name("ffff")
-        .symbol("F")
-        .admin_key(account.key.public_key())
-        .treasury_account_id(account.id)
-        .custom_fees([fee])
-        .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
-        .sign(account.key.clone())
-        .execute(&client)
-        .await?
-        .get_receipt(&client)
         .await;
 
     assert_matches!(
         res,
-        Err(hedera::Error::ReceiptStatus { status: Status::FractionDividesByZero, .. })
+        Err(hedera::Error::CustomFeeValidation(e))
+            if *e == hedera::CustomFeeValidationError::NonPositiveAmount { index: 0 }
     );
 
     account.delete(&client).await?;
@@ -580,8 +530,7 @@ async fn royalty_fee() -> anyhow::Result<()> {
 
     let fee = RoyaltyFee {
         fee: hedera::RoyaltyFeeData {
-            denominator: 10,
-            numerator: 1,
+            fraction: Fraction::new(1, 10)?,
             fallback_fee: Some(FixedFeeData::from_hbar(Hbar::new(1))),
         },
         fee_collector_account_id: Some(account.id),
@@ -2,6 +2,7 @@ use assert_matches::assert_matches;
 use hedera::{
     FixedFee,
     FixedFeeData,
+    Fraction,
     FractionalFee,
     FractionalFeeData,
     Hbar,
@@ -48,8 +49,7 @@ async fn basic() -> anyhow::Result<()> {
         .into(),
         FractionalFee {
             fee: FractionalFeeData {
-                denominator: 20,
-                numerator: 1,
+                fraction: Fraction::new(1, 20).unwrap(),
                 minimum_amount: 1,
                 maximum_amount: 10,
                 assessment_method: hedera::FeeAssessmentMethod::Exclusive,
@@ -101,8 +101,7 @@ async fn invalid_signature_fails() -> anyhow::Result<()> {
         .into(),
         FractionalFee {
             fee: FractionalFeeData {
-                denominator: 20,
-                numerator: 1,
+                fraction: Fraction::new(1, 20).unwrap(),
                 minimum_amount: 1,
                 maximum_amount: 10,
                 assessment_method: hedera::FeeAssessmentMethod::Exclusive,
@@ -39,7 +39,8 @@ use hedera::{
 
 use crate::account::Account;
 use crate::common::{
-    setup_nonfree,
+    setup_nonfree_requiring,
+    Capability,
     Config,
     TestEnvironment,
 };
@@ -50,7 +51,9 @@ use crate::token::{
 
 #[tokio::test]
 async fn basic_fungible_token() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -99,7 +102,9 @@ async fn basic_fungible_token() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn basic_nft() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -167,7 +172,9 @@ async fn basic_nft() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn ft_and_nft_reject() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -264,7 +271,9 @@ async fn ft_and_nft_reject() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn ft_and_nft_freeze_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -356,7 +365,9 @@ async fn ft_and_nft_freeze_fails() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn ft_and_nft_paused_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -437,7 +448,9 @@ async fn ft_and_nft_paused_fails() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn add_or_set_nft_token_id_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -511,7 +524,9 @@ async fn add_or_set_nft_token_id_fails() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn treasury_fails() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -562,7 +577,9 @@ async fn treasury_fails() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn invalid_sig_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -602,7 +619,9 @@ async fn invalid_sig_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn missing_token_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -622,7 +641,9 @@ async fn missing_token_fail() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn token_reference_list_size_exceeded_fail() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
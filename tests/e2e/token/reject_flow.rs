@@ -38,7 +38,8 @@ use hedera::{
 
 use crate::account::Account;
 use crate::common::{
-    setup_nonfree,
+    setup_nonfree_requiring,
+    Capability,
     Config,
     TestEnvironment,
 };
@@ -49,7 +50,9 @@ use crate::token::{
 
 #[tokio::test]
 async fn basic_flow_fungible_token() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
@@ -110,7 +113,9 @@ async fn basic_flow_fungible_token() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn basic_flow_nft() -> anyhow::Result<()> {
-    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+    let Some(TestEnvironment { config, client }) =
+        setup_nonfree_requiring(Capability::TokenReject).await
+    else {
         return Ok(());
     };
 
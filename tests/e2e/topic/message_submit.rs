@@ -68,6 +68,38 @@ async fn large_message() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn large_message_without_regeneration() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let topic = Topic::create(&client).await?;
+
+    let responses = TopicMessageSubmitTransaction::new()
+        .topic_id(topic.id)
+        .max_chunks(15)
+        .message(resources::BIG_CONTENTS)
+        .regenerate_transaction_id(false)
+        .execute_all(&client)
+        .await?;
+
+    let payer = responses[0].transaction_id.account_id;
+
+    for response in responses {
+        assert_eq!(response.transaction_id.account_id, payer);
+        response.get_receipt(&client).await?;
+    }
+
+    let info = TopicInfoQuery::new().topic_id(topic.id).execute(&client).await?;
+
+    assert_eq!(info.sequence_number, 14);
+
+    topic.delete(&client).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn missing_topic_id_fails() -> anyhow::Result<()> {
     let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
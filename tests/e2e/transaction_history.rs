@@ -0,0 +1,74 @@
+use futures_util::TryStreamExt;
+use hedera::{
+    Hbar,
+    MirrorQueryStream,
+    TransactionHistoryQuery,
+    TransferTransaction,
+};
+
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
+
+#[tokio::test]
+async fn basic() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let response = TransferTransaction::new()
+        .hbar_transfer(op.account_id, Hbar::from_tinybars(-1))
+        .hbar_transfer("0.0.98".parse()?, Hbar::from_tinybars(1))
+        .execute(&client)
+        .await?;
+
+    response.get_receipt(&client).await?;
+
+    let transaction_id = response.transaction_id;
+
+    // give the mirror node a moment to import the transaction.
+    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+
+    let transactions = TransactionHistoryQuery::new()
+        .account_id(op.account_id)
+        .transaction_type("CRYPTOTRANSFER")
+        .successful(true)
+        .start_time(transaction_id.valid_start)
+        .execute(&client)
+        .await?;
+
+    assert!(transactions.iter().any(|it| it.transaction_id == transaction_id));
+    assert!(transactions.iter().all(|it| it.transaction_id.account_id == op.account_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_with_limit() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let transactions: Vec<_> = TransactionHistoryQuery::new()
+        .account_id(op.account_id)
+        .limit(2)
+        .stream(&client)
+        .try_collect()
+        .await?;
+
+    assert!(transactions.len() <= 2);
+    assert!(transactions.iter().all(|it| it.transaction_id.account_id == op.account_id));
+
+    Ok(())
+}